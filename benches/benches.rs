@@ -112,3 +112,75 @@ fn emoji(b: &mut Bencher) {
     let string = std::fs::read_to_string(data_path).unwrap_or_default();
     b.iter(|| test::black_box(UnicodeWidthStr::width(string.as_str())));
 }
+
+#[bench]
+fn arabic_harakat(b: &mut Bencher) {
+    // Fully vocalized Arabic, with Lam-Alef ligatures carrying harakat between their letters.
+    let string = "بِسْمِ ٱللَّهِ ٱلرَّحْمَٰنِ ٱلرَّحِيمِ لَٰٓا لِّۤإِ "
+        .chars()
+        .cycle()
+        .take(4096)
+        .collect::<String>();
+    b.iter(|| test::black_box(UnicodeWidthStr::width(string.as_str())));
+}
+
+#[bench]
+#[cfg(feature = "cjk")]
+fn math_cjk(b: &mut Bencher) {
+    // Negated relations, with marks between the operator and the combining solidus.
+    let string = "a =\u{338} b, x <\u{301}\u{338} y, p >\u{20D2}\u{338} q, ∀x ∈ S: x ≤ y; "
+        .chars()
+        .cycle()
+        .take(4096)
+        .collect::<String>();
+    b.iter(|| test::black_box(UnicodeWidthStr::width_cjk(string.as_str())));
+}
+
+// The benches below measure text in many scripts, so most of their time goes into
+// the width tables. Compare the table layouts by running them with each layout flag:
+// `cargo bench`, `cargo bench --features layout-speed` and `cargo bench --features layout-size`.
+
+fn mixed_scripts_string() -> String {
+    "Grüße, Ελλάδα, Україна, עברית, العربية, हिन्दी, ภาษาไทย, 日本語のテキスト, 한국어, \
+     ｆｕｌｌｗｉｄｔｈ, 𝔪𝔞𝔱𝔥, 𠀋𠂢, 🙂👍🏽, ⌚☕ "
+        .chars()
+        .cycle()
+        .take(4096)
+        .collect::<String>()
+}
+
+#[bench]
+fn mixed_scripts_chars(b: &mut Bencher) {
+    let string = mixed_scripts_string();
+
+    b.iter(|| {
+        for c in string.chars() {
+            test::black_box(UnicodeWidthChar::width(c));
+        }
+    });
+}
+
+#[bench]
+#[cfg(feature = "cjk")]
+fn mixed_scripts_chars_cjk(b: &mut Bencher) {
+    let string = mixed_scripts_string();
+
+    b.iter(|| {
+        for c in string.chars() {
+            test::black_box(UnicodeWidthChar::width_cjk(c));
+        }
+    });
+}
+
+#[bench]
+fn mixed_scripts_str(b: &mut Bencher) {
+    let string = mixed_scripts_string();
+    b.iter(|| test::black_box(UnicodeWidthStr::width(string.as_str())));
+}
+
+#[bench]
+#[cfg(feature = "cjk")]
+fn mixed_scripts_str_cjk(b: &mut Bencher) {
+    let string = mixed_scripts_string();
+    b.iter(|| test::black_box(UnicodeWidthStr::width_cjk(string.as_str())));
+}
@@ -24,6 +24,7 @@
 # - emoji/emoji-data.txt
 # - emoji/emoji-test.txt (for tests only)
 # - emoji/emoji-variation-sequences.txt
+# - emoji-sequences.txt and emoji-zwj-sequences.txt (from the separate emoji directory)
 # - extracted/DerivedCombiningClass.txt
 # - extracted/DerivedGeneralCategory.txt
 # - extracted/DerivedJoiningGroup.txt
@@ -31,6 +32,10 @@
 #
 # Since this should not require frequent updates, we just store this
 # out-of-line and check the generated module into git.
+#
+# Once the tables are built, the script prints the size of each of them for
+# every table layout (the default trie, `layout-speed` and `layout-size`, with
+# and without `bmp-only`), which is where the sizes in the crate docs come from.
 
 import enum
 import math
@@ -39,8 +44,9 @@ import os
 import re
 import sys
 import urllib.request
-from collections import defaultdict
+from collections import Counter, defaultdict
 from itertools import batched
+from textwrap import indent
 from typing import Callable, Iterable, IO
 
 UNICODE_VERSION = "17.0.0"
@@ -49,8 +55,8 @@ UNICODE_VERSION = "17.0.0"
 NUM_CODEPOINTS = 0x110000
 """An upper bound for which `range(0, NUM_CODEPOINTS)` contains Unicode's codespace."""
 
-MAX_CODEPOINT_BITS = math.ceil(math.log2(NUM_CODEPOINTS - 1))
-"""The maximum number of bits required to represent a Unicode codepoint."""
+NUM_BMP_CODEPOINTS = 0x10000
+"""The number of codepoints in the Basic Multilingual Plane, which is all the `bmp-only` tables cover."""
 
 
 class OffsetType(enum.IntEnum):
@@ -80,10 +86,37 @@ WIDTH_INFO_PATH = "../src/gen/width_info.rs"
 TEST_PATH = "../src/gen/tables_test.rs"
 """The path of the emitted Rust tests (relative to the working directory)"""
 
+LAYOUT_TRIE_PATH = "../src/gen/layout_trie.rs"
+"""The path of the emitted default table layout (relative to the working directory)"""
+
+LAYOUT_SPEED_PATH = "../src/gen/layout_speed.rs"
+"""The path of the emitted `layout-speed` table layout (relative to the working directory)"""
+
+LAYOUT_SIZE_PATH = "../src/gen/layout_size.rs"
+"""The path of the emitted `layout-size` table layout (relative to the working directory)"""
+
+LAYOUT_TRIE_BMP_PATH = "../src/gen/layout_trie_bmp.rs"
+"""The path of the emitted `bmp-only` default table layout (relative to the working directory)"""
+
+LAYOUT_SPEED_BMP_PATH = "../src/gen/layout_speed_bmp.rs"
+"""The path of the emitted `bmp-only` `layout-speed` table layout (relative to the working directory)"""
+
+LAYOUT_SIZE_BMP_PATH = "../src/gen/layout_size_bmp.rs"
+"""The path of the emitted `bmp-only` `layout-size` table layout (relative to the working directory)"""
+
+SPEED_BLOCK_BITS = 8
+"""The number of low codepoint bits used to index into each block of the `layout-speed` tables"""
+
+SIZE_RUN_INHERIT = 0x10
+"""Marks runs of the `layout-size` CJK table with the same entries as the non-CJK table"""
+
 TABLE_SPLITS = [7, 13]
 """The splits between the bits of the codepoint used to index each subtable.
 Adjust these values to change the sizes of the subtables"""
 
+PROPS_SPLITS = [5, 11]
+"""Like `TABLE_SPLITS`, but for the `CharProps` trie of the default table layout"""
+
 FILE_HEADER = """// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
 // file at the top-level directory of this distribution and at
 // http://rust-lang.org/COPYRIGHT.
@@ -112,6 +145,28 @@ def emit_rust_file(path: str, generator: Callable[[IO[str]], None]):
 Codepoint = int
 BitPos = int
 
+NERD_FONTS_GLYPH_SETS: list[tuple[str, list[tuple[Codepoint, Codepoint]], bool]] = [
+    ("Pomicons", [(0xE000, 0xE00A)], True),
+    (
+        "Powerline and Powerline Extra",
+        [(0xE0A0, 0xE0A3), (0xE0B0, 0xE0C8), (0xE0CA, 0xE0CA), (0xE0CC, 0xE0D7)],
+        False,
+    ),
+    ("Font Awesome Extension", [(0xE200, 0xE2A9)], True),
+    ("Weather Icons", [(0xE300, 0xE3E3)], True),
+    ("Seti-UI and Custom", [(0xE5FA, 0xE6B7)], True),
+    ("Devicons", [(0xE700, 0xE8EF)], True),
+    ("Codicons", [(0xEA60, 0xEC1E)], True),
+    ("Font Awesome", [(0xED00, 0xF2FF)], True),
+    ("Font Logos", [(0xF300, 0xF381)], True),
+    ("Octicons", [(0xF400, 0xF533)], True),
+    ("Material Design Icons", [(0xF0001, 0xF1AF0)], True),
+]
+"""The glyph sets that Nerd Fonts 3.4 patches into the Private Use Areas, as listed in the
+`font-patcher` script: the name of each set, its inclusive ranges, and whether it holds icons,
+which the regular variants of the fonts draw two columns wide. The other sets hold the Powerline
+glyphs, which are one column wide in every variant. All of the presets are built from this list."""
+
 
 def fetch_open(filename: str, local_prefix: str = "", emoji: bool = False):
     """Opens `filename` and return its corresponding file object. If `filename` isn't on disk,
@@ -198,6 +253,16 @@ class CharWidthInTable(enum.IntEnum):
     SPECIAL = 3
 
 
+class CharProps(enum.IntFlag):
+    """Properties of a character that the width state machine consults. The default table layout
+    stores them in a trie of their own, and the others alongside the widths."""
+
+    TRANSPARENT_ZERO_WIDTH = 1
+    "Zero-width with `Joining_Type=Transparent`."
+    SOLIDUS_TRANSPARENT = 2
+    "Doesn't affect how U+0338 COMBINING LONG SOLIDUS OVERLAY applies to its base."
+
+
 class WidthState(enum.IntEnum):
     """
     Width calculation proceeds according to a state machine.
@@ -426,6 +491,44 @@ class WidthState(enum.IntEnum):
             case _:
                 return 1
 
+    def cfg(self) -> str | None:
+        """The `cfg` predicate under which the state machine has this state,
+        or `None` if it always does. Emoji sequence states are dropped by the
+        `no-emoji-sequences` feature, and states that can only be entered from
+        a supplementary-plane character are dropped by the `bmp-only` feature."""
+        if self in [
+            WidthState.EMOJI_MODIFIER,
+            WidthState.REGIONAL_INDICATOR,
+            WidthState.SEVERAL_REGIONAL_INDICATOR,
+            WidthState.REGIONAL_INDICATOR_ZWJ_PRESENTATION,
+            WidthState.EVEN_REGIONAL_INDICATOR_ZWJ_PRESENTATION,
+            WidthState.ODD_REGIONAL_INDICATOR_ZWJ_PRESENTATION,
+        ] or self.name.startswith("TAG_"):
+            return 'not(any(feature = "bmp-only", feature = "no-emoji-sequences"))'
+        if self in [
+            WidthState.EMOJI_PRESENTATION,
+            WidthState.ZWJ_EMOJI_PRESENTATION,
+            WidthState.VS16_ZWJ_EMOJI_PRESENTATION,
+            WidthState.KEYCAP_ZWJ_EMOJI_PRESENTATION,
+            WidthState.VS16_KEYCAP_ZWJ_EMOJI_PRESENTATION,
+            WidthState.VARIATION_SELECTOR_15,
+            WidthState.VARIATION_SELECTOR_16,
+        ]:
+            return 'not(feature = "no-emoji-sequences")'
+        if self in [
+            WidthState.KIRAT_RAI_VOWEL_SIGN_E,
+            WidthState.KIRAT_RAI_VOWEL_SIGN_AI,
+            WidthState.OLD_TURKIC_LETTER_ORKHON_I,
+            WidthState.ZWJ_OLD_TURKIC_LETTER_ORKHON_I,
+        ]:
+            return 'not(feature = "bmp-only")'
+        return None
+
+    def not_cfg(self) -> str | None:
+        """The negation of `cfg()`."""
+        cfg = self.cfg()
+        return None if cfg is None else cfg.removeprefix("not(").removesuffix(")")
+
     def is_cjk_only(self) -> bool:
         return self in [
             WidthState.COMBINING_LONG_SOLIDUS_OVERLAY,
@@ -725,6 +828,47 @@ def load_joining_group_lam() -> list[tuple[Codepoint, Codepoint]]:
     return to_sorted_ranges(lam_joining)
 
 
+def load_unassigned() -> list[tuple[Codepoint, Codepoint]]:
+    """Returns a sorted list of the ranges of codepoints with `General_Category=Unassigned`,
+    which are those not listed in `UnicodeData.txt`. This includes the noncharacters."""
+    assigned = [False] * NUM_CODEPOINTS
+    with fetch_open("UnicodeData.txt") as udata:
+        range_start = None
+        for line in udata.readlines():
+            fields = line.split(";")
+            cp = int(fields[0], 16)
+            if fields[1].endswith(", First>"):
+                range_start = cp
+                continue
+            if fields[1].endswith(", Last>"):
+                assert range_start is not None
+                for c in range(range_start, cp + 1):
+                    assigned[c] = True
+            else:
+                assigned[cp] = True
+
+    return to_sorted_ranges(cp for cp in range(NUM_CODEPOINTS) if not assigned[cp])
+
+
+def load_rgi_emoji() -> list[str]:
+    """Returns every Recommended for General Interchange emoji sequence, listed in
+    `emoji-sequences.txt` and `emoji-zwj-sequences.txt`, sorted by their UTF-8 encoding."""
+    sequences = set()
+    for filename in ["emoji-sequences.txt", "emoji-zwj-sequences.txt"]:
+        with fetch_open(filename, emoji=True) as file:
+            for line in file.readlines():
+                codepoints = line.split("#")[0].split(";")[0].strip()
+                if not codepoints:
+                    continue
+                if ".." in codepoints:
+                    (low, high) = codepoints.split("..")
+                    for cp in range(int(low, 16), int(high, 16) + 1):
+                        sequences.add(chr(cp))
+                else:
+                    sequences.add("".join(chr(int(cp, 16)) for cp in codepoints.split()))
+    return sorted(sequences, key=lambda seq: seq.encode("utf-8"))
+
+
 def load_non_transparent_zero_widths(
     width_map: list[WidthState],
 ) -> list[tuple[Codepoint, Codepoint]]:
@@ -1023,21 +1167,26 @@ class Table:
 def make_tables(
     width_map: list[WidthState],
     cjk_width_map: list[WidthState],
+    num_codepoints: int = NUM_CODEPOINTS,
 ) -> list[Table]:
     """Creates a table for each configuration in `table_cfgs`, with the first config corresponding
     to the top-level lookup table, the second config corresponding to the second-level lookup
     table, and so forth. `entries` is an iterator over the `(Codepoint, EffectiveWidth)` pairs
-    to include in the top-level table."""
+    to include in the top-level table. Only the first `num_codepoints` codepoints are included.
 
-    entries = enumerate([w.table_width() for w in width_map])
-    cjk_entries = enumerate([w.table_width() for w in cjk_width_map])
+    Each entry of the last table is a character's width alone; its `CharProps` are stored
+    in the trie made by `make_props_tables`, so that the leaves stay at 2 bits per entry."""
+
+    entries = enumerate([w.table_width() for w in width_map[:num_codepoints]])
+    cjk_entries = enumerate([w.table_width() for w in cjk_width_map[:num_codepoints]])
+    max_codepoint_bits = math.ceil(math.log2(num_codepoints - 1))
 
     root_table = Table(
         "WIDTH_ROOT",
         [entries],
         [],
         TABLE_SPLITS[1],
-        MAX_CODEPOINT_BITS,
+        max_codepoint_bits,
         OffsetType.U8,
         128,
     )
@@ -1047,7 +1196,7 @@ def make_tables(
         [cjk_entries],
         [],
         TABLE_SPLITS[1],
-        MAX_CODEPOINT_BITS,
+        max_codepoint_bits,
         OffsetType.U8,
         128,
         starting_indexed=root_table.indexed,
@@ -1081,10 +1230,208 @@ def make_tables(
         2 ** (TABLE_SPLITS[0] - 2),
         bytes_per_row=2 ** (TABLE_SPLITS[0] - 2),
     )
+    leaves_table.indices_to_widths()  # for the last table, indices == widths
 
     return [root_table, cjk_root_table, middle_table, leaves_table]
 
 
+def table_entries(width_map: list[WidthState], props_map: list[CharProps]) -> list[int]:
+    """Returns the table entry of every codepoint: its width in the tables in the low 2 bits,
+    and its `CharProps` in the high 2 bits. The `layout-speed` and `layout-size` tables
+    store these entries."""
+    return [w.table_width() | p << 2 for (w, p) in zip(width_map, props_map)]
+
+
+def make_speed_tables(
+    entries: list[int], cjk_entries: list[int]
+) -> tuple[list[int], list[int], list[list[int]], int]:
+    """Creates the two-level `layout-speed` tables: an index with one byte per block of
+    `2**SPEED_BLOCK_BITS` codepoints, and the deduplicated blocks, with one byte per entry.
+    Returns the index, the CJK index, the blocks, and the number of blocks used by the non-CJK index.
+    The index covers as many codepoints as `entries` has entries.
+    """
+    block_len = 2**SPEED_BLOCK_BITS
+    blocks: list[list[int]] = []
+    block_ids: dict[tuple[int, ...], int] = {}
+
+    def make_index(entries: list[int]) -> list[int]:
+        index = []
+        for start in range(0, len(entries), block_len):
+            block = tuple(entries[start : start + block_len])
+            if block not in block_ids:
+                block_ids[block] = len(blocks)
+                blocks.append(list(block))
+            index.append(block_ids[block])
+        return index
+
+    index = make_index(entries)
+    num_primary_blocks = len(blocks)
+    cjk_index = make_index(cjk_entries)
+    assert len(blocks) <= 256
+    return (index, cjk_index, blocks, num_primary_blocks)
+
+
+def make_size_runs(entries: list[int], cjk_entries: list[int]) -> tuple[list[int], list[int]]:
+    """Creates the `layout-size` tables: lists of runs of codepoints with the same entry, each stored
+    as `first_codepoint << 5 | entry`. The CJK list only covers codepoints whose CJK entry differs;
+    all other runs have the entry `SIZE_RUN_INHERIT`."""
+
+    def make_runs(entries: list[int]) -> list[int]:
+        runs = []
+        for cp, entry in enumerate(entries):
+            if cp == 0 or entry != entries[cp - 1]:
+                runs.append(cp << 5 | entry)
+        return runs
+
+    overlay = [
+        cjk if cjk != entry else SIZE_RUN_INHERIT
+        for (entry, cjk) in zip(entries, cjk_entries)
+    ]
+    return (make_runs(entries), make_runs(overlay))
+
+
+def make_supplementary_entries(entries: list[int]) -> list[int]:
+    """Returns the entry that the `bmp-only` tables give every character in each supplementary plane:
+    the most common entry in that plane. Plane 14 is mostly unassigned, so its entry is instead
+    the most common one among its tags and variation selectors (U+E0000 to U+E0FFF)."""
+    fallbacks = []
+    for plane in range(1, NUM_CODEPOINTS // NUM_BMP_CODEPOINTS):
+        start = plane * NUM_BMP_CODEPOINTS
+        end = start + (0x1000 if plane == 0xE else NUM_BMP_CODEPOINTS)
+        counts = Counter(entries[start:end])
+        entry = counts.most_common(1)[0][0]
+        assert entry & 0b11 != CharWidthInTable.SPECIAL
+        fallbacks.append(entry)
+    return fallbacks
+
+
+def make_props_map(
+    width_map: list[WidthState],
+    non_transparent_zero_widths: list[tuple[Codepoint, Codepoint]],
+    ligature_transparent: list[tuple[Codepoint, Codepoint]],
+    solidus_transparent: list[tuple[Codepoint, Codepoint]],
+) -> list[CharProps]:
+    """Returns the `CharProps` of every codepoint."""
+    props_map = [CharProps(0)] * NUM_CODEPOINTS
+    for cp, width in enumerate(width_map):
+        if width.width_alone() == 0:
+            props_map[cp] |= CharProps.TRANSPARENT_ZERO_WIDTH
+    for lo, hi in non_transparent_zero_widths:
+        for cp in range(lo, hi + 1):
+            props_map[cp] &= ~CharProps.TRANSPARENT_ZERO_WIDTH
+    for lo, hi in ligature_transparent + solidus_transparent:
+        for cp in range(lo, hi + 1):
+            props_map[cp] |= CharProps.SOLIDUS_TRANSPARENT
+    return props_map
+
+
+def make_props_tables(
+    props_map: list[CharProps], num_codepoints: int = NUM_CODEPOINTS
+) -> list[Table]:
+    """Creates the three-level trie of the `CharProps` of the first `num_codepoints` codepoints
+    for the default table layout, split like the width tables but by `PROPS_SPLITS`."""
+    max_codepoint_bits = math.ceil(math.log2(num_codepoints - 1))
+
+    root_table = Table(
+        "PROPS_ROOT",
+        [enumerate(props_map[:num_codepoints])],
+        [],
+        PROPS_SPLITS[1],
+        max_codepoint_bits,
+        OffsetType.U8,
+        32,
+    )
+    # No `char` lies past the last codepoint, so don't pad the root up to a power of two.
+    del root_table.entries[math.ceil(num_codepoints / 2 ** PROPS_SPLITS[1]) :]
+
+    middle_table = Table(
+        "PROPS_MIDDLE",
+        map(lambda bucket: bucket.entries(), root_table.buckets()),
+        [],
+        PROPS_SPLITS[0],
+        PROPS_SPLITS[1],
+        OffsetType.U8,
+        2 ** (PROPS_SPLITS[1] - PROPS_SPLITS[0]),
+        bytes_per_row=2 ** (PROPS_SPLITS[1] - PROPS_SPLITS[0]),
+    )
+
+    leaves_table = Table(
+        "PROPS_LEAVES",
+        map(lambda bucket: bucket.entries(), middle_table.buckets()),
+        [],
+        0,
+        PROPS_SPLITS[0],
+        OffsetType.U2,
+        32,
+        bytes_per_row=2 ** (PROPS_SPLITS[0] - 2),
+    )
+    leaves_table.indices_to_widths()  # for the last table, indices == props
+
+    return [root_table, middle_table, leaves_table]
+
+
+def make_utf8_tables(
+    width_map: list[WidthState],
+    cjk_width_map: list[WidthState],
+) -> list[Table]:
+    """Creates a two-level trie over the Basic Multilingual Plane, split so that it can be indexed
+    directly with the bytes of a two- or three-byte UTF-8 sequence: the first level by the bits
+    of the lead byte and first continuation byte that lie above the last continuation byte
+    (`cp >> 6`), and the second by the 6 payload bits of the last byte.
+
+    Codepoints up to U+00A0 are marked special, as their width in a string is handled
+    by the state machine directly."""
+
+    def entries(widths: list[WidthState]) -> Iterable[tuple[int, CharWidthInTable]]:
+        for cp in range(0, 0x10000):
+            if cp <= 0xA0:
+                yield (cp, CharWidthInTable.SPECIAL)
+            else:
+                yield (cp, widths[cp].table_width())
+
+    index_table = Table(
+        "UTF8_INDEX",
+        [entries(width_map)],
+        [],
+        6,
+        16,
+        OffsetType.U8,
+        128,
+    )
+
+    cjk_index_table = Table(
+        "UTF8_INDEX_CJK",
+        [entries(cjk_width_map)],
+        [],
+        6,
+        16,
+        OffsetType.U8,
+        128,
+        starting_indexed=index_table.indexed,
+        cfged=True,
+    )
+
+    leaves_table = Table(
+        "UTF8_LEAVES",
+        map(
+            lambda bucket: bucket.entries(),
+            index_table.buckets(),
+        ),
+        map(
+            lambda bucket: bucket.entries(),
+            cjk_index_table.buckets(),
+        ),
+        0,
+        6,
+        OffsetType.U2,
+        32,
+        bytes_per_row=2 ** (6 - 2),
+    )
+    leaves_table.indices_to_widths()  # for the last table, indices == widths
+
+    return [index_table, cjk_index_table, leaves_table]
+
+
 def load_emoji_presentation_sequences() -> list[Codepoint]:
     """Outputs a list of cpodepoints, corresponding to all the valid characters for starting
     an emoji presentation sequence."""
@@ -1190,6 +1537,15 @@ def make_presentation_sequence_table(
     return (indexes, leaves)
 
 
+def num_bmp_leaves_of(indexes: list[tuple[int, int]], lsb: int) -> int:
+    """Returns how many leaves of a table made by `make_presentation_sequence_table`
+    or `make_ranges_table` are used by the Basic Multilingual Plane. As codepoints
+    are added in order, these come before the leaves used only by supplementary planes."""
+    return 1 + max(
+        (index for (msbs, index) in indexes if msbs < 0x10000 >> lsb), default=-1
+    )
+
+
 def make_ranges_table(
     seqs: list[Codepoint],
 ) -> tuple[list[tuple[int, int]], list[list[tuple[int, int]]]]:
@@ -1241,58 +1597,51 @@ def lookup_fns(
     if is_cjk:
         cfg = '#[cfg(feature = "cjk")]\n'
         cjk_lo = "_cjk"
-        cjk_cap = "_CJK"
         ambig = "wide"
     else:
         cfg = ""
         cjk_lo = ""
-        cjk_cap = ""
         ambig = "narrow"
-    s = f"""/// Returns the [UAX #11](https://www.unicode.org/reports/tr11/) based width of `c` by
-/// consulting a multi-level lookup table.
+    s = f"""/// Returns the [UAX #11](https://www.unicode.org/reports/tr11/) based width of `cp` by
+/// consulting `tables` (see `lookup_entry{cjk_lo}` in the selected `layout` module).
 ///
 /// # Maintenance
 /// The tables themselves are autogenerated but this function is hardcoded. You should have
 /// nothing to worry about if you re-run `unicode.py` (for example, when updating Unicode.)
-/// However, if you change the *actual structure* of the lookup tables (perhaps by editing the
-/// `make_tables` function in `unicode.py`) you must ensure that this code reflects those changes.
+/// However, if you change what the table entries *mean* (perhaps by editing the
+/// `table_entries` function in `unicode.py`) you must ensure that this code reflects those changes.
 {cfg}#[inline]
-pub(crate) fn lookup_width{cjk_lo}(c: char) -> (u8, WidthInfo) {{
-    let cp = c as usize;
-
-    let t1_offset = WIDTH_ROOT{cjk_cap}.0[cp >> {TABLE_SPLITS[1]}];
-
-    // Each sub-table in WIDTH_MIDDLE is 7 bits, and each stored entry is a byte,
-    // so each sub-table is 128 bytes in size.
-    // (Sub-tables are selected using the computed offset from the previous table.)
-    let t2_offset = WIDTH_MIDDLE.0[usize::from(t1_offset)][cp >> {TABLE_SPLITS[0]} & 0x{(2 ** (TABLE_SPLITS[1] - TABLE_SPLITS[0]) - 1):X}];
-
-    // Each sub-table in WIDTH_LEAVES is 6 bits, but each stored entry is 2 bits.
-    // This is accomplished by packing four stored entries into one byte.
-    // So each sub-table is 2**(7-2) == 32 bytes in size.
-    // Since this is the last table, each entry represents an encoded width.
-    let packed_widths = WIDTH_LEAVES.0[usize::from(t2_offset)][cp >> 2 & 0x{(2 ** (TABLE_SPLITS[0] - 2) - 1):X}];
-
-    // Extract the packed width
-    let width = packed_widths >> (2 * (cp & 0b11)) & 0b11;
+pub(crate) const fn lookup_width{cjk_lo}(tables: &Tables, cp: u32) -> (u8, WidthInfo) {{
+    // The low 2 bits of each entry hold the width.
+    let width = lookup_entry{cjk_lo}(tables, cp) & 0b11;
 
     if width < 3 {{
         (width, WidthInfo::DEFAULT)
     }} else {{
-        match c {{
+        match cp {{
 """
 
     for (lo, hi), width in special_ranges:
-        s += f"            '\\u{{{lo:X}}}'"
+        pattern = f"0x{lo:X}"
         if hi != lo:
-            s += f"..='\\u{{{hi:X}}}'"
+            pattern += f"..=0x{hi:X}"
         if width.is_carried():
             width_info = width.name
         else:
             width_info = "DEFAULT"
-        s += f" => ({width.width_alone()}, WidthInfo::{width_info}),\n"
+        arm = lambda info: f"{pattern} => ({width.width_alone()}, WidthInfo::{info}),\n"
+        # Where the feature flags drop the state, the character is measured on its own.
+        cfg = width.cfg()
+        if cfg is None:
+            s += "            " + arm(width_info)
+        else:
+            s += f"            #[cfg({cfg})]\n            " + arm(width_info)
+            s += f"            #[cfg({width.not_cfg()})]\n            " + arm("DEFAULT")
 
-    s += f"""            _ => (2, WidthInfo::EMOJI_PRESENTATION),
+    s += f"""            #[cfg({WidthState.EMOJI_PRESENTATION.cfg()})]
+            _ => (2, WidthInfo::EMOJI_PRESENTATION),
+            #[cfg({WidthState.EMOJI_PRESENTATION.not_cfg()})]
+            _ => (2, WidthInfo::DEFAULT),
         }}
     }}
 }}
@@ -1313,6 +1662,13 @@ impl WidthInfo {
 
     for variant in WidthState:
         if variant.is_carried():
+            # The variation selector bits are also used to track other states.
+            cfg = variant.cfg()
+            if cfg is not None and variant not in [
+                WidthState.VARIATION_SELECTOR_15,
+                WidthState.VARIATION_SELECTOR_16,
+            ]:
+                module.write(f"    #[cfg({cfg})]\n")
             module.write(
                 f"    pub(crate) const {variant.name}: Self = Self(0b{variant.value:016b});\n"
             )
@@ -1320,6 +1676,17 @@ impl WidthInfo {
     module.write("}\n")
 
 
+def emit_matches(module: IO[str], ranges: list[tuple[Codepoint, Codepoint]]):
+    """Outputs a `matches!` of `cp` against `ranges`, as the body of a function."""
+    pattern = " | ".join(
+        f"0x{lo:X}" if hi == lo else f"0x{lo:X}..=0x{hi:X}" for lo, hi in ranges
+    )
+    if len(f"    matches!(cp, {pattern})") <= 100:
+        module.write(f"    matches!(cp, {pattern})\n")
+    else:
+        module.write(f"    matches!(\n        cp,\n        {pattern}\n    )\n")
+
+
 def emit_props(
     module: IO[str],
     ligature_transparent: list[tuple[Codepoint, Codepoint]],
@@ -1329,60 +1696,34 @@ def emit_props(
     joining_group_lam: list[tuple[Codepoint, Codepoint]],
 ):
     """Outputs a Rust module to `module` containing generated property functions."""
-    module.write("use crate::tables::*;\n")
-    module.write("use core::cmp::Ordering;\n\n")
+    module.write('#[cfg(not(feature = "no-emoji-sequences"))]\n')
+    module.write("use crate::layout::Tables;\n\n")
 
     module.write(
         """/// Whether this character has Joining_Group=Lam.
-pub fn is_joining_group_lam(c: char) -> bool {
-    matches!(
-        c,
-        """
+pub const fn is_joining_group_lam(cp: u32) -> bool {
+"""
     )
-
-    tail = False
-    for lo, hi in joining_group_lam:
-        if tail:
-            module.write(" | ")
-        tail = True
-        module.write(f"'\\u{{{lo:X}}}'")
-        if hi != lo:
-            module.write(f"..='\\u{{{hi:X}}}'")
-
+    emit_matches(module, joining_group_lam)
     module.write(
-        """
-    )
-}
+        """}
 
 /// Whether this character is a default-ignorable combining mark
 /// or ZWJ. These characters won't interrupt non-Arabic ligatures.
-pub fn is_ligature_transparent(c: char) -> bool {
-    matches!(
-        c,
-        """
+pub const fn is_ligature_transparent(cp: u32) -> bool {
+"""
     )
-
-    tail = False
-    for lo, hi in ligature_transparent:
-        if tail:
-            module.write(" | ")
-        tail = True
-        module.write(f"'\\u{{{lo:X}}}'")
-        if hi != lo:
-            module.write(f"..='\\u{{{hi:X}}}'")
-
+    emit_matches(module, ligature_transparent)
     module.write(
-        """
-    )
-}
+        """}
 
 /// Whether this character forms an [emoji presentation sequence]
 /// (https://www.unicode.org/reports/tr51/#def_emoji_presentation_sequence)
 /// when followed by `'\\u{FEOF}'`.
 /// Emoji presentation sequences are considered to have width 2.
+#[cfg(not(feature = "no-emoji-sequences"))]
 #[inline]
-pub fn starts_emoji_presentation_seq(c: char) -> bool {
-    let cp: u32 = c.into();
+pub(crate) const fn starts_emoji_presentation_seq(tables: &Tables, cp: u32) -> bool {
     // First level of lookup uses all but 10 LSB
     let top_bits = cp >> 10;
     let idx_of_leaf: usize = match top_bits {
@@ -1391,6 +1732,8 @@ pub fn starts_emoji_presentation_seq(c: char) -> bool {
 
     emoji_presentation_idx, _ = emoji_presentation_table
     for msbs, i in emoji_presentation_idx:
+        if msbs >= 0x10000 >> 10:
+            module.write('        #[cfg(not(feature = "bmp-only"))]\n')
         module.write(f"        0x{msbs:X} => {i},\n")
 
     module.write(
@@ -1398,75 +1741,80 @@ pub fn starts_emoji_presentation_seq(c: char) -> bool {
     };
     // Extract the 3-9th (0-indexed) least significant bits of `cp`,
     // and use them to index into `leaf_row`.
-    let idx_within_leaf = usize::try_from((cp >> 3) & 0x7F).unwrap();
-    let leaf_byte = EMOJI_PRESENTATION_LEAVES.0[idx_of_leaf][idx_within_leaf];
+    let idx_within_leaf = ((cp >> 3) & 0x7F) as usize;
+    let leaf_byte = tables.emoji_presentation_leaves.0[idx_of_leaf][idx_within_leaf];
     // Use the 3 LSB of `cp` to index into `leaf_byte`.
     ((leaf_byte >> (cp & 7)) & 1) == 1
 }
 
-/// Returns `true` if `c` has default emoji presentation, but forms a [text presentation sequence]
+/// Returns `true` if `cp` has default emoji presentation, but forms a [text presentation sequence]
 /// (https://www.unicode.org/reports/tr51/#def_text_presentation_sequence)
 /// when followed by `'\\u{FEOE}'`, and is not ideographic.
 /// Such sequences are considered to have width 1.
+#[cfg(not(feature = "no-emoji-sequences"))]
 #[inline]
-pub fn starts_non_ideographic_text_presentation_seq(c: char) -> bool {
-    let cp: u32 = c.into();
+pub(crate) const fn starts_non_ideographic_text_presentation_seq(tables: &Tables, cp: u32) -> bool {
     // First level of lookup uses all but 8 LSB
     let top_bits = cp >> 8;
-    let leaf: &[(u8, u8)] = match top_bits {
+    let idx_of_leaf: usize = match top_bits {
 """
     )
 
     text_presentation_idx, _ = text_presentation_table
     for msbs, i in text_presentation_idx:
-        module.write(f"        0x{msbs:X} => &TEXT_PRESENTATION_LEAF_{i},\n")
+        if msbs >= 0x10000 >> 8:
+            module.write('        #[cfg(not(feature = "bmp-only"))]\n')
+        module.write(f"        0x{msbs:X} => {i},\n")
 
     module.write(
         """        _ => return false,
     };
 
-    let bottom_bits = (cp & 0xFF) as u8;
-    leaf.binary_search_by(|&(lo, hi)| {
-        if bottom_bits < lo {
-            Ordering::Greater
-        } else if bottom_bits > hi {
-            Ordering::Less
-        } else {
-            Ordering::Equal
-        }
-    })
-    .is_ok()
+    leaf_contains(
+        tables.text_presentation_leaves[idx_of_leaf],
+        (cp & 0xFF) as u8,
+    )
 }
 
-/// Returns `true` if `c` is an `Emoji_Modifier_Base`.
+/// Returns `true` if `cp` is an `Emoji_Modifier_Base`.
+#[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
 #[inline]
-pub fn is_emoji_modifier_base(c: char) -> bool {
-    let cp: u32 = c.into();
+pub(crate) const fn is_emoji_modifier_base(tables: &Tables, cp: u32) -> bool {
     // First level of lookup uses all but 8 LSB
     let top_bits = cp >> 8;
-    let leaf: &[(u8, u8)] = match top_bits {
+    let idx_of_leaf: usize = match top_bits {
 """
     )
 
     emoji_modifier_idx, _ = emoji_modifier_table
     for msbs, i in emoji_modifier_idx:
-        module.write(f"        0x{msbs:X} => &EMOJI_MODIFIER_LEAF_{i},\n")
+        module.write(f"        0x{msbs:X} => {i},\n")
 
     module.write(
         """        _ => return false,
     };
 
-    let bottom_bits = (cp & 0xFF) as u8;
-    leaf.binary_search_by(|&(lo, hi)| {
+    leaf_contains(tables.emoji_modifier_leaves[idx_of_leaf], (cp & 0xFF) as u8)
+}
+
+/// Binary searches a sorted list of inclusive `(lo, hi)` ranges for `bottom_bits`.
+/// Written as a loop (rather than with `binary_search_by`) so it can be a `const fn`.
+#[cfg(not(feature = "no-emoji-sequences"))]
+#[inline]
+const fn leaf_contains(leaf: &[(u8, u8)], bottom_bits: u8) -> bool {
+    let (mut lo_idx, mut hi_idx) = (0, leaf.len());
+    while lo_idx < hi_idx {
+        let mid = lo_idx + (hi_idx - lo_idx) / 2;
+        let (lo, hi) = leaf[mid];
         if bottom_bits < lo {
-            Ordering::Greater
+            hi_idx = mid;
         } else if bottom_bits > hi {
-            Ordering::Less
+            lo_idx = mid + 1;
         } else {
-            Ordering::Equal
+            return true;
         }
-    })
-    .is_ok()
+    }
+    false
 }
 """
     )
@@ -1479,157 +1827,674 @@ def emit_lookup(
     joining_group_lam: list[tuple[Codepoint, Codepoint]],
 ):
     """Outputs a Rust module to `module` containing generated lookup functions."""
-    module.write("use crate::tables::*;\n")
+    module.write('#[cfg(feature = "cjk")]\n')
+    module.write("use crate::layout::lookup_entry_cjk;\n")
+    module.write("use crate::layout::{lookup_entry, Tables};\n")
     module.write("use crate::width_info::WidthInfo;\n\n")
 
     module.write(lookup_fns(False, special_ranges, joining_group_lam))
     module.write(lookup_fns(True, special_ranges_cjk, joining_group_lam))
 
-
-def emit_tables(
-    module: IO[str],
-    unicode_version: tuple[int, int, int],
-    tables: list[Table],
-    special_ranges: list[tuple[tuple[Codepoint, Codepoint], WidthState]],
-    special_ranges_cjk: list[tuple[tuple[Codepoint, Codepoint], WidthState]],
-    emoji_presentation_table: tuple[list[tuple[int, int]], list[list[int]]],
-    text_presentation_table: tuple[list[tuple[int, int]], list[list[tuple[int, int]]]],
-    emoji_modifier_table: tuple[list[tuple[int, int]], list[list[tuple[int, int]]]],
-    joining_group_lam: list[tuple[Codepoint, Codepoint]],
-    non_transparent_zero_widths: list[tuple[Codepoint, Codepoint]],
-    ligature_transparent: list[tuple[Codepoint, Codepoint]],
-    solidus_transparent: list[tuple[Codepoint, Codepoint]],
-):
-    """Outputs a Rust module to `module` using table data from `tables`.
-    If `TABLE_CFGS` is edited, you may need to edit the included code for `lookup_width`.
-    """
     module.write(
         f"""
-/// The version of [Unicode](http://www.unicode.org/)
-/// that this version of unicode-width is based on.
-pub const UNICODE_VERSION: (u8, u8, u8) = {unicode_version};
+/// Set in the [`lookup_props`](crate::layout::lookup_props) of zero-width characters
+/// with `Joining_Type=Transparent`.
+pub(crate) const TRANSPARENT_ZERO_WIDTH: u8 = 0b{CharProps.TRANSPARENT_ZERO_WIDTH:02b};
+
+/// Set in the [`lookup_props`](crate::layout::lookup_props) of characters that are
+/// transparent wrt the effect of U+0338 COMBINING LONG SOLIDUS OVERLAY on their base character.
+#[cfg(feature = "cjk")]
+pub(crate) const SOLIDUS_TRANSPARENT: u8 = 0b{CharProps.SOLIDUS_TRANSPARENT:02b};
+
 """
     )
 
-    emoji_presentation_idx, emoji_presentation_leaves = emoji_presentation_table
-    text_presentation_idx, text_presentation_leaves = text_presentation_table
-    emoji_modifier_idx, emoji_modifier_leaves = emoji_modifier_table
 
-    module.write(
-        """
-#[repr(align(32))]
-pub(crate) struct Align32<T>(pub(crate) T);
+def wrap_at_100(head: str, tail: str, indent_by: str = "") -> str:
+    """Joins `head` and `tail` with a space, or breaks the line between them and indents
+    `tail` further, the way `rustfmt` does when the joined line is over 100 columns."""
+    line = f"{indent_by}{head} {tail}"
+    if len(line) <= 100:
+        return line
+    return f"{indent_by}{head}\n{indent_by}    {tail}"
 
-#[repr(align(64))]
-pub(crate) struct Align64<T>(pub(crate) T);
 
-#[repr(align(128))]
-pub(crate) struct Align128<T>(pub(crate) T);
+def emit_table_decl(
+    module: IO[str], name: str, ty: str, value: str, const_copy: bool, cfg: str = ""
+):
+    """Outputs the start of the declaration of the `static` table `name`, up to the start
+    of its `value`. If `const_copy` is set, the data goes in a `const` copy of the table
+    instead, which the `static` is initialized from, so that `const fn`s can read it too.
+
+    Every generated table is a `static`, with a `const` copy only if `const` code reads it.
+    The one exception is the preset tables, which only `const`s read (see `emit_preset_table`)."""
+    if const_copy:
+        module.write(
+            f"""{cfg}{wrap_at_100(f"pub(crate) static {name}: {ty} =", f"{name}_CONST;")}
+
+/// [`{name}`], for `const fn`s, which can't read statics.
+{cfg}pub(crate) const {name}_CONST: {ty} = {value}"""
+        )
+    else:
+        module.write(f"{cfg}pub(crate) static {name}: {ty} = {value}")
+
+
+def emit_byte_array(
+    module: IO[str],
+    name: str,
+    align: int,
+    byte_array: list[int],
+    doc: str,
+    cfged: bool = False,
+    const_copy: bool = False,
+):
+    """Outputs a one-dimensional table of bytes."""
+    module.write(f"/// {doc}\n")
+    cfg = '#[cfg(feature = "cjk")]\n' if cfged else ""
+    if len(byte_array) <= 16:
+        cfg += "#[rustfmt::skip]\n"
+    emit_table_decl(
+        module, name, f"Align{align}<[u8; {len(byte_array)}]>", f"Align{align}([", const_copy, cfg
+    )
+    for j, byte in enumerate(byte_array):
+        # Add line breaks for every 15th entry (chosen to match what rustfmt does)
+        if j % 16 == 0:
+            module.write("\n   ")
+        module.write(f" 0x{byte:02X},")
+    module.write("\n]);\n")
+
+
+def emit_byte_rows(
+    module: IO[str],
+    name: str,
+    align: int,
+    rows: list[list[int]],
+    num_primary_rows: int,
+    doc: str,
+    const_copy: bool = False,
+):
+    """Outputs a two-dimensional table of bytes. Rows after the first `num_primary_rows`
+    are only used for CJK widths."""
+    if num_primary_rows < len(rows):
+        module.write(
+            f"""
+#[cfg(feature = "cjk")]
+pub(crate) const {name}_LEN: usize = {len(rows)};
+#[cfg(not(feature = "cjk"))]
+pub(crate) const {name}_LEN: usize = {num_primary_rows};"""
+        )
+    else:
+        module.write(
+            f"""
+pub(crate) const {name}_LEN: usize = {len(rows)};"""
+        )
+    module.write(
+        f"""
+/// {doc}
 """
     )
+    emit_table_decl(
+        module,
+        name,
+        f"Align{align}<[[u8; {len(rows[0])}]; {name}_LEN]>",
+        f"Align{align}([\n",
+        const_copy,
+    )
+    for row_num, row in enumerate(rows):
+        if row_num >= num_primary_rows:
+            module.write('    #[cfg(feature = "cjk")]\n')
+        one_line = ", ".join(f"0x{entry:02X}" for entry in row)
+        if len(f"    [{one_line}],") <= 100:
+            # `rustfmt` keeps short rows on one line
+            module.write(f"    [{one_line}],\n")
+            continue
+        module.write("    [\n")
+        for subrow in batched(row, 15):
+            module.write("       ")
+            for entry in subrow:
+                module.write(f" 0x{entry:02X},")
+            module.write("\n")
+        module.write("    ],\n")
+    module.write("]);\n")
 
+
+def trie_table_type(table: Table) -> str:
+    """The Rust type of `table` as output by `emit_trie`."""
+    if table.bytes_per_row is None:
+        return f"Align{table.align}<[u8; {len(table.to_bytes())}]>"
+    return f"Align{table.align}<[[u8; {table.bytes_per_row}]; {table.name}_LEN]>"
+
+
+def emit_trie(
+    module: IO[str], tables: list[Table], layout_fn: str, const_copy: bool = False
+):
+    """Outputs the lookup tables in `tables`, which together form one multi-level trie.
+    The last table's entries are the widths themselves."""
     subtable_count = 1
     for i, table in enumerate(tables):
-        new_subtable_count = len(table.buckets())
-        if i == len(tables) - 1:
-            table.indices_to_widths()  # for the last table, indices == widths
         byte_array = table.to_bytes()
+        doc = f"Autogenerated. {subtable_count} sub-table(s). Consult [`{layout_fn}`] for layout info."
 
         if table.bytes_per_row is None:
-            module.write(
-                f"/// Autogenerated. {subtable_count} sub-table(s). Consult [`lookup_width`] for layout info.)\n"
-            )
-            if table.cfged:
-                module.write('#[cfg(feature = "cjk")]\n')
-            module.write(
-                f"pub(crate) static {table.name}: Align{table.align}<[u8; {len(byte_array)}]> = Align{table.align}(["
+            emit_byte_array(
+                module,
+                table.name,
+                table.align,
+                byte_array,
+                doc + ")",
+                table.cfged,
+                const_copy,
             )
-            for j, byte in enumerate(byte_array):
-                # Add line breaks for every 15th entry (chosen to match what rustfmt does)
-                if j % 16 == 0:
-                    module.write("\n   ")
-                module.write(f" 0x{byte:02X},")
-            module.write("\n")
         else:
-            num_rows = len(byte_array) // table.bytes_per_row
             num_primary_rows = (
                 table.primary_len
                 // (8 // int(table.offset_type))
                 // table.bytes_per_row
             )
-            module.write(
-                f"""
-#[cfg(feature = "cjk")]
-pub(crate) const {table.name}_LEN: usize = {num_rows};
-#[cfg(not(feature = "cjk"))]
-pub(crate) const {table.name}_LEN: usize = {num_primary_rows};
-/// Autogenerated. {subtable_count} sub-table(s). Consult [`lookup_width`] for layout info.
-pub(crate) static {table.name}: Align{table.align}<[[u8; {table.bytes_per_row}]; {table.name}_LEN]> = Align{table.align}([\n"""
+            emit_byte_rows(
+                module,
+                table.name,
+                table.align,
+                list(batched(byte_array, table.bytes_per_row)),
+                num_primary_rows,
+                doc,
+                const_copy,
             )
-            for row_num in range(0, num_rows):
-                if row_num >= num_primary_rows:
-                    module.write('    #[cfg(feature = "cjk")]\n')
-                module.write("    [\n")
-                row = byte_array[
-                    row_num
-                    * table.bytes_per_row : (row_num + 1)
-                    * table.bytes_per_row
-                ]
-                for subrow in batched(row, 15):
-                    module.write("       ")
-                    for entry in subrow:
-                        module.write(f" 0x{entry:02X},")
-                    module.write("\n")
-                module.write("    ],\n")
-        module.write("]);\n")
-        subtable_count = new_subtable_count
-
-    # non transparent zero width table
+        if i + 1 < len(tables):
+            subtable_count = len(table.buckets())
+
+
+def emit_tables_struct(module: IO[str], layout_tables: list[tuple[str, str, bool]]):
+    """Outputs the `Tables` struct, which gathers the tables that `lookup_entry`, `lookup_props`
+    and the generated property functions read, along with a `static` instance pointing at the tables
+    and a `const` instance pointing at their `const` copies.
+    `layout_tables` holds the name and type of each of the layout's tables,
+    and whether it is only used for CJK widths."""
+    fields = [
+        (name, ty, '#[cfg(feature = "cjk")]\n' if cfged else "")
+        for name, ty, cfged in layout_tables
+    ]
+    emoji_cfg = f"#[cfg({WidthState.EMOJI_PRESENTATION.cfg()})]\n"
+    modifier_cfg = f"#[cfg({WidthState.EMOJI_MODIFIER.cfg()})]\n"
+    fields += [
+        (
+            "EMOJI_PRESENTATION_LEAVES",
+            "Align128<[[u8; 128]; EMOJI_PRESENTATION_LEAVES_LEN]>",
+            emoji_cfg,
+        ),
+        (
+            "TEXT_PRESENTATION_LEAVES",
+            "[&'static [(u8, u8)]; TEXT_PRESENTATION_LEAVES_LEN]",
+            emoji_cfg,
+        ),
+        (
+            "EMOJI_MODIFIER_LEAVES",
+            "[&'static [(u8, u8)]; EMOJI_MODIFIER_LEAVES_LEN]",
+            modifier_cfg,
+        ),
+    ]
 
     module.write(
-        f"""
-/// Sorted list of codepoint ranges (inclusive)
-/// that are zero-width but not `Joining_Type=Transparent`
-/// FIXME: can we get better compression?
-pub(crate) static NON_TRANSPARENT_ZERO_WIDTHS: [([u8; 3], [u8; 3]); {len(non_transparent_zero_widths)}] = [
+        """/// The tables read by [`lookup_entry`], [`lookup_props`] and the generated property functions.
+///
+/// A `const fn` can't read a `static`, so the lookups take the tables as a parameter:
+/// [`TABLES`] at runtime, and [`CONST_TABLES`] in `const fn`s.
+pub(crate) struct Tables {
 """
     )
+    for name, ty, cfg in fields:
+        field = wrap_at_100(f"pub(crate) {name.lower()}:", f"&'static {ty},", "    ")
+        module.write(f"{indent(cfg, '    ')}{field}\n")
+    module.write(
+        """}
 
-    for lo, hi in non_transparent_zero_widths:
+/// The `static` tables, for lookups at runtime.
+pub(crate) static TABLES: Tables = Tables {
+"""
+    )
+    for name, _, cfg in fields:
+        module.write(f"{indent(cfg, '    ')}    {name.lower()}: &{name},\n")
+    module.write(
+        """};
+
+/// The `const` copies of the tables, for `const fn`s.
+pub(crate) const CONST_TABLES: Tables = Tables {
+"""
+    )
+    for name, _, cfg in fields:
+        module.write(f"{indent(cfg, '    ')}    {name.lower()}: &{name}_CONST,\n")
+    module.write("};\n\n")
+
+
+def emit_supplementary_entries(
+    module: IO[str], supplementary_entries: tuple[list[int], list[int]] | None
+):
+    """For the `bmp-only` variant of a layout, outputs the per-plane entries that stand in
+    for the tables outside the Basic Multilingual Plane."""
+    if supplementary_entries is None:
+        return
+    entries, cjk_entries = supplementary_entries
+    module.write(
+        """/// Returns the entry of `cp` if it lies outside the Basic Multilingual Plane,
+/// which the `bmp-only` tables don't cover. Every character in a supplementary plane
+/// has the same entry, the one most common in that plane.
+#[inline]
+const fn supplementary_entry(cp: u32, entries: &[u8; 16]) -> Option<u8> {
+    match cp as usize >> 16 {
+        0 => None,
+        plane => Some(entries[plane - 1]),
+    }
+}
+"""
+    )
+    for name, table, cfged in [
+        ("SUPPLEMENTARY_ENTRIES", entries, False),
+        ("SUPPLEMENTARY_ENTRIES_CJK", cjk_entries, True),
+    ]:
+        module.write("\n/// Autogenerated. The entry of each supplementary plane, from U+1xxxx to U+10xxxx.\n")
+        cfg = '#[cfg(feature = "cjk")]\n' if cfged else ""
+        emit_table_decl(module, name, f"[u8; {len(table)}]", "[\n   ", True, cfg)
+        for entry in table:
+            module.write(f" 0x{entry:02X},")
+        module.write("\n];\n")
+    module.write("\n")
+
+
+def supplementary_fields(
+    supplementary_entries: tuple[list[int], list[int]] | None,
+) -> list[tuple[str, str, bool]]:
+    """The `Tables` fields for the tables output by `emit_supplementary_entries`."""
+    if supplementary_entries is None:
+        return []
+    entries, cjk_entries = supplementary_entries
+    return [
+        ("SUPPLEMENTARY_ENTRIES", f"[u8; {len(entries)}]", False),
+        ("SUPPLEMENTARY_ENTRIES_CJK", f"[u8; {len(cjk_entries)}]", True),
+    ]
+
+
+def supplementary_check(
+    supplementary_entries: tuple[list[int], list[int]] | None, cjk_lo: str, props: bool = False
+) -> str:
+    """The start of the body of a `bmp-only` `lookup_entry` function,
+    or if `props` is set, of a `bmp-only` `lookup_props` function."""
+    if supplementary_entries is None:
+        return ""
+    return f"""    if let Some(entry) = supplementary_entry(cp, tables.supplementary_entries{cjk_lo}) {{
+        return entry{" >> 2" if props else ""};
+    }}
+"""
+
+
+def emit_props_from_entry(module: IO[str]):
+    """Outputs the `lookup_props` function of a table layout
+    that stores the property bits in the high bits of its entries."""
+    module.write(
+        """/// Returns the property bits of `cp` that the width state machine consults.
+#[inline]
+pub(crate) const fn lookup_props(tables: &Tables, cp: u32) -> u8 {
+    lookup_entry(tables, cp) >> 2
+}
+
+"""
+    )
+
+
+def emit_layout_trie(
+    module: IO[str],
+    tables: list[Table],
+    props_tables: list[Table],
+    utf8_tables: list[Table],
+    supplementary_entries: tuple[list[int], list[int]] | None = None,
+):
+    """Outputs a Rust module to `module` containing the default, trie-based table layout.
+    If `supplementary_entries` is given, `tables` and `props_tables` only cover
+    the Basic Multilingual Plane."""
+    module.write("use crate::tables::*;\n\n")
+    emit_supplementary_entries(module, supplementary_entries)
+
+    for is_cjk in [False, True]:
+        cfg = '#[cfg(feature = "cjk")]\n' if is_cjk else ""
+        cjk_lo = "_cjk" if is_cjk else ""
+        module.write(
+            f"""/// Returns the table entry for `cp`: its width, or 3 if it needs special handling.
+/// Its property bits are found by [`lookup_props`].
+///
+/// The entry is found by walking a three-level trie,
+/// indexed by successively lower bits of the codepoint.
+{cfg}#[inline]
+pub(crate) const fn lookup_entry{cjk_lo}(tables: &Tables, cp: u32) -> u8 {{
+{supplementary_check(supplementary_entries, cjk_lo)}    let cp = cp as usize;
+
+    let t1_offset = tables.width_root{cjk_lo}.0[cp >> {TABLE_SPLITS[1]}];
+
+    // Each sub-table in WIDTH_MIDDLE is 7 bits, and each stored entry is a byte,
+    // so each sub-table is 128 bytes in size.
+    // (Sub-tables are selected using the computed offset from the previous table.)
+    let t2_offset = tables.width_middle.0[t1_offset as usize][cp >> {TABLE_SPLITS[0]} & 0x{(2 ** (TABLE_SPLITS[1] - TABLE_SPLITS[0]) - 1):X}];
+
+    // Each sub-table in WIDTH_LEAVES is 7 bits, but each stored entry is 2 bits.
+    // This is accomplished by packing four stored entries into one byte.
+    // So each sub-table is 2**(7-2) == 32 bytes in size.
+    let packed_entries = tables.width_leaves.0[t2_offset as usize][cp >> 2 & 0x{(2 ** (TABLE_SPLITS[0] - 2) - 1):X}];
+
+    // Extract the packed entry
+    packed_entries >> (2 * (cp & 0b11)) & 0b11
+}}
+
+"""
+        )
+
+    module.write(
+        f"""/// Returns the property bits of `cp` that the width state machine consults.
+///
+/// They are kept out of the width trie, which then stores 2 bits per entry,
+/// and are instead found by walking a smaller three-level trie of their own.
+#[inline]
+pub(crate) const fn lookup_props(tables: &Tables, cp: u32) -> u8 {{
+{supplementary_check(supplementary_entries, "", props=True)}    let cp = cp as usize;
+
+    let t1_offset = tables.props_root.0[cp >> {PROPS_SPLITS[1]}];
+
+    // Each sub-table in PROPS_MIDDLE is {PROPS_SPLITS[1] - PROPS_SPLITS[0]} bits, and each stored entry is a byte,
+    // so each sub-table is {2 ** (PROPS_SPLITS[1] - PROPS_SPLITS[0])} bytes in size.
+    // (Sub-tables are selected using the computed offset from the previous table.)
+    let t2_offset = tables.props_middle.0[t1_offset as usize][cp >> {PROPS_SPLITS[0]} & 0x{(2 ** (PROPS_SPLITS[1] - PROPS_SPLITS[0]) - 1):X}];
+
+    // Each sub-table in PROPS_LEAVES is {PROPS_SPLITS[0]} bits, but each stored entry is 2 bits.
+    // This is accomplished by packing four stored entries into one byte.
+    // So each sub-table is 2**({PROPS_SPLITS[0]}-2) == {2 ** (PROPS_SPLITS[0] - 2)} bytes in size.
+    let packed_props = tables.props_leaves.0[t2_offset as usize][cp >> 2 & 0x{(2 ** (PROPS_SPLITS[0] - 2) - 1):X}];
+
+    // Extract the packed props
+    packed_props >> (2 * (cp & 0b11)) & 0b11
+}}
+
+"""
+    )
+
+    emit_tables_struct(
+        module,
+        supplementary_fields(supplementary_entries)
+        + [
+            (table.name, trie_table_type(table), table.cfged)
+            for table in tables + props_tables
+        ],
+    )
+    emit_trie(module, tables, "lookup_entry", const_copy=True)
+    emit_trie(module, props_tables, "lookup_props", const_copy=True)
+    emit_trie(module, utf8_tables, "utf8_suffix_width")
+
+
+def emit_layout_speed(
+    module: IO[str],
+    speed_tables: tuple[list[int], list[int], list[list[int]], int],
+    utf8_tables: list[Table],
+    supplementary_entries: tuple[list[int], list[int]] | None = None,
+):
+    """Outputs a Rust module to `module` containing the `layout-speed` table layout.
+    If `supplementary_entries` is given, `speed_tables` only cover the Basic Multilingual Plane."""
+    index, cjk_index, blocks, num_primary_blocks = speed_tables
+    module.write("use crate::tables::*;\n\n")
+    emit_supplementary_entries(module, supplementary_entries)
+
+    for is_cjk in [False, True]:
+        cfg = '#[cfg(feature = "cjk")]\n' if is_cjk else ""
+        cjk_lo = "_cjk" if is_cjk else ""
         module.write(
-            f"    ([0x{lo & 0xFF:02X}, 0x{lo >> 8 & 0xFF:02X}, 0x{lo >> 16:02X}], [0x{hi & 0xFF:02X}, 0x{hi >> 8 & 0xFF:02X}, 0x{hi >> 16:02X}]),\n"
+            f"""/// Returns the table entry for `cp`: its width in the low 2 bits
+/// (3 if it needs special handling), and its property bits in the high 2 bits.
+///
+/// The top bits of the codepoint select a block of `WIDTH_BLOCKS`,
+/// which holds one entry per byte for each of the {2**SPEED_BLOCK_BITS} codepoints in the block.
+{cfg}#[inline]
+pub(crate) const fn lookup_entry{cjk_lo}(tables: &Tables, cp: u32) -> u8 {{
+{supplementary_check(supplementary_entries, cjk_lo)}    let cp = cp as usize;
+    let block = tables.width_index{cjk_lo}.0[cp >> {SPEED_BLOCK_BITS}];
+    tables.width_blocks.0[block as usize][cp & 0x{2**SPEED_BLOCK_BITS - 1:X}]
+}}
+
+"""
         )
+    emit_props_from_entry(module)
+
+    emit_tables_struct(
+        module,
+        supplementary_fields(supplementary_entries)
+        + [
+            ("WIDTH_INDEX", f"Align128<[u8; {len(index)}]>", False),
+            ("WIDTH_INDEX_CJK", f"Align128<[u8; {len(cjk_index)}]>", True),
+            ("WIDTH_BLOCKS", f"Align128<[[u8; {len(blocks[0])}]; WIDTH_BLOCKS_LEN]>", False),
+        ],
+    )
+    emit_byte_array(
+        module,
+        "WIDTH_INDEX",
+        128,
+        index,
+        "Autogenerated. Consult [`lookup_entry`] for layout info.",
+        const_copy=True,
+    )
+    emit_byte_array(
+        module,
+        "WIDTH_INDEX_CJK",
+        128,
+        cjk_index,
+        "Autogenerated. Consult [`lookup_entry_cjk`] for layout info.",
+        cfged=True,
+        const_copy=True,
+    )
+    emit_byte_rows(
+        module,
+        "WIDTH_BLOCKS",
+        128,
+        blocks,
+        num_primary_blocks,
+        f"Autogenerated. {len(blocks)} block(s). Consult [`lookup_entry`] for layout info.",
+        const_copy=True,
+    )
+    emit_trie(module, utf8_tables, "utf8_suffix_width")
 
-    # solidus transparent table
 
+def emit_layout_size(
+    module: IO[str],
+    size_runs: tuple[list[int], list[int]],
+    supplementary_entries: tuple[list[int], list[int]] | None = None,
+):
+    """Outputs a Rust module to `module` containing the `layout-size` table layout.
+    If `supplementary_entries` is given, `size_runs` only cover the Basic Multilingual Plane."""
+    runs, cjk_runs = size_runs
+    # Only the emoji tables are needed from here.
+    module.write(f"#[cfg({WidthState.EMOJI_PRESENTATION.cfg()})]\n")
+    module.write("use crate::tables::*;\n\n")
+    emit_supplementary_entries(module, supplementary_entries)
     module.write(
-        f"""];
+        f"""/// Returns the entry of the last run in `runs` that starts at or before `cp`.
+/// The first run always starts at U+0000.
+#[inline]
+const fn run_entry(runs: &[u32], cp: u32) -> u8 {{
+    let (mut lo, mut hi) = (0, runs.len());
+    while hi - lo > 1 {{
+        let mid = lo + (hi - lo) / 2;
+        if runs[mid] >> 5 <= cp {{
+            lo = mid;
+        }} else {{
+            hi = mid;
+        }}
+    }}
+    (runs[lo] & 0x1F) as u8
+}}
 
-/// Sorted list of codepoint ranges (inclusive)
-/// that don't affect how the combining solidus applies
-/// (mostly ccc > 1).
-/// FIXME: can we get better compression?
+/// Returns the table entry for `cp`: its width in the low 2 bits
+/// (3 if it needs special handling), and its property bits in the high 2 bits.
+///
+/// The entry is found by binary searching a list of runs of codepoints with the same entry.
+#[inline]
+pub(crate) const fn lookup_entry(tables: &Tables, cp: u32) -> u8 {{
+{supplementary_check(supplementary_entries, "")}    run_entry(tables.width_runs, cp)
+}}
+
+/// Like [`lookup_entry`], but for CJK widths.
+/// Where `WIDTH_RUNS_CJK` has no entry of its own, the non-CJK entry applies.
 #[cfg(feature = "cjk")]
-pub(crate) static SOLIDUS_TRANSPARENT: [([u8; 3], [u8; 3]); {len(solidus_transparent)}] = [
+#[inline]
+pub(crate) const fn lookup_entry_cjk(tables: &Tables, cp: u32) -> u8 {{
+{supplementary_check(supplementary_entries, "_cjk")}    match run_entry(tables.width_runs_cjk, cp) {{
+        0x{SIZE_RUN_INHERIT:X} => lookup_entry(tables, cp),
+        entry => entry,
+    }}
+}}
+
 """
     )
+    emit_props_from_entry(module)
+
+    emit_tables_struct(
+        module,
+        supplementary_fields(supplementary_entries)
+        + [
+            ("WIDTH_RUNS", f"[u32; {len(runs)}]", False),
+            ("WIDTH_RUNS_CJK", f"[u32; {len(cjk_runs)}]", True),
+        ],
+    )
+    for name, table, cfged in [
+        ("WIDTH_RUNS", runs, False),
+        ("WIDTH_RUNS_CJK", cjk_runs, True),
+    ]:
+        if cfged:
+            module.write("\n")
+        module.write(
+            f"""/// Autogenerated. {len(table)} run(s), each stored as `first_codepoint << 5 | entry`.
+/// Consult [`lookup_entry`] for layout info.
+"""
+        )
+        cfg = '#[cfg(feature = "cjk")]\n' if cfged else ""
+        emit_table_decl(
+            module, name, f"[u32; {len(table)}]", "[\n", True, cfg + "#[rustfmt::skip]\n"
+        )
+        for row in batched(table, 8):
+            module.write("   ")
+            for run in row:
+                module.write(f" 0x{run:08X},")
+            module.write("\n")
+        module.write("];\n")
+
 
-    for lo, hi in solidus_transparent:
+def emit_range_leaves(
+    module: IO[str],
+    name: str,
+    leaves: list[list[tuple[int, int]]],
+    num_bmp_leaves: int,
+    cfg: str,
+    what: str,
+):
+    """Outputs the leaves of a table made by `make_ranges_table`, as a `static` and
+    a `const` copy of it. Leaves after the first `num_bmp_leaves` are outside the BMP."""
+    if num_bmp_leaves < len(leaves):
+        module.write(
+            f"""
+#[cfg(all({cfg}, not(feature = "bmp-only")))]
+pub(crate) const {name}_LEN: usize = {len(leaves)};
+#[cfg(all({cfg}, feature = "bmp-only"))]
+pub(crate) const {name}_LEN: usize = {num_bmp_leaves};
+"""
+        )
+    else:
         module.write(
-            f"    ([0x{lo & 0xFF:02X}, 0x{lo >> 8 & 0xFF:02X}, 0x{lo >> 16:02X}], [0x{hi & 0xFF:02X}, 0x{hi >> 8 & 0xFF:02X}, 0x{hi >> 16:02X}]),\n"
+            f"""
+#[cfg({cfg})]
+pub(crate) const {name}_LEN: usize = {len(leaves)};
+"""
         )
+    module.write(
+        f"""/// Sorted lists of inclusive ranges of the 8 LSB of {what}.
+/// There is one list for each value of the other bits that has any.
+#[cfg({cfg})]
+{wrap_at_100(f"pub(crate) static {name}: [&[(u8, u8)]; {name}_LEN] =", f"{name}_CONST;")}
+/// [`{name}`], for `const fn`s, which can't read statics.
+#[cfg({cfg})]
+#[rustfmt::skip]
+pub(crate) const {name}_CONST: [&[(u8, u8)]; {name}_LEN] = [
+"""
+    )
+    for leaf_idx, leaf in enumerate(leaves):
+        if leaf_idx >= num_bmp_leaves:
+            module.write('    #[cfg(not(feature = "bmp-only"))]\n')
+        module.write("    &[\n")
+        for lo, hi in leaf:
+            module.write(f"        (0x{lo:02X}, 0x{hi:02X}),\n")
+        module.write("    ],\n")
+    module.write("];\n")
 
-    # emoji table
 
+def emit_tables(
+    module: IO[str],
+    unicode_version: tuple[int, int, int],
+    special_ranges: list[tuple[tuple[Codepoint, Codepoint], WidthState]],
+    special_ranges_cjk: list[tuple[tuple[Codepoint, Codepoint], WidthState]],
+    emoji_presentation_table: tuple[list[tuple[int, int]], list[list[int]]],
+    text_presentation_table: tuple[list[tuple[int, int]], list[list[tuple[int, int]]]],
+    emoji_modifier_table: tuple[list[tuple[int, int]], list[list[tuple[int, int]]]],
+    joining_group_lam: list[tuple[Codepoint, Codepoint]],
+    unassigned: list[tuple[Codepoint, Codepoint]],
+    rgi_emoji: list[str],
+):
+    """Outputs a Rust module to `module` using table data from `tables`.
+    If `TABLE_CFGS` is edited, you may need to edit the included code for `lookup_width`.
+    """
     module.write(
-        f"""];
+        f"""
+/// The version of [Unicode](http://www.unicode.org/)
+/// that this version of unicode-width is based on.
+pub const UNICODE_VERSION: (u8, u8, u8) = {unicode_version};
+"""
+    )
+
+    emoji_presentation_idx, emoji_presentation_leaves = emoji_presentation_table
+    text_presentation_idx, text_presentation_leaves = text_presentation_table
+    emoji_modifier_idx, emoji_modifier_leaves = emoji_modifier_table
+
+    module.write(
+        """
+// Not every table layout uses every alignment.
+#[allow(dead_code)]
+#[repr(align(32))]
+pub(crate) struct Align32<T>(pub(crate) T);
+
+#[allow(dead_code)]
+#[repr(align(64))]
+pub(crate) struct Align64<T>(pub(crate) T);
+
+#[allow(dead_code)]
+#[repr(align(128))]
+pub(crate) struct Align128<T>(pub(crate) T);
+"""
+    )
 
+    # emoji table
+
+    emoji_cfg = WidthState.EMOJI_PRESENTATION.cfg()
+    num_bmp_leaves = num_bmp_leaves_of(emoji_presentation_idx, 10)
+    module.write(
+        f"""
+#[cfg(all({emoji_cfg}, not(feature = "bmp-only")))]
+pub(crate) const EMOJI_PRESENTATION_LEAVES_LEN: usize = {len(emoji_presentation_leaves)};
+#[cfg(all({emoji_cfg}, feature = "bmp-only"))]
+pub(crate) const EMOJI_PRESENTATION_LEAVES_LEN: usize = {num_bmp_leaves};
 /// Array of 1024-bit bitmaps. Index into the correct bitmap with the 10 LSB of your codepoint
 /// to get whether it can start an emoji presentation sequence.
-pub(crate) static EMOJI_PRESENTATION_LEAVES: Align128<[[u8; 128]; {len(emoji_presentation_leaves)}]> = Align128([
+#[cfg({emoji_cfg})]
+pub(crate) static EMOJI_PRESENTATION_LEAVES: Align128<[[u8; 128]; EMOJI_PRESENTATION_LEAVES_LEN]> =
+    EMOJI_PRESENTATION_LEAVES_CONST;
+/// [`EMOJI_PRESENTATION_LEAVES`], for `const fn`s, which can't read statics.
+#[cfg({emoji_cfg})]
+#[rustfmt::skip]
+pub(crate) const EMOJI_PRESENTATION_LEAVES_CONST: Align128<[[u8; 128]; EMOJI_PRESENTATION_LEAVES_LEN]> = Align128([
 """
     )
-    for leaf in emoji_presentation_leaves:
+    for leaf_idx, leaf in enumerate(emoji_presentation_leaves):
+        if leaf_idx >= num_bmp_leaves:
+            module.write('    #[cfg(not(feature = "bmp-only"))]\n')
         module.write("    [\n")
         for row in batched(leaf, 15):
             module.write("       ")
@@ -1642,30 +2507,141 @@ pub(crate) static EMOJI_PRESENTATION_LEAVES: Align128<[[u8; 128]; {len(emoji_pre
 
     # text table
 
-    for leaf_idx, leaf in enumerate(text_presentation_leaves):
-        module.write(
-            f"""
+    emit_range_leaves(
+        module,
+        "TEXT_PRESENTATION_LEAVES",
+        text_presentation_leaves,
+        num_bmp_leaves_of(text_presentation_idx, 8),
+        emoji_cfg,
+        "text presentation sequence starters",
+    )
+
+    # emoji modifier table
+
+    emit_range_leaves(
+        module,
+        "EMOJI_MODIFIER_LEAVES",
+        emoji_modifier_leaves,
+        len(emoji_modifier_leaves),
+        WidthState.EMOJI_MODIFIER.cfg(),
+        "`Emoji_Modifier_Base` codepoints",
+    )
+
+    # unassigned table
+
+    bmp_unassigned = [(lo, hi) for (lo, hi) in unassigned if hi < NUM_BMP_CODEPOINTS]
+    supplementary_unassigned = [
+        (lo, hi) for (lo, hi) in unassigned if lo >= NUM_BMP_CODEPOINTS
+    ]
+    assert len(bmp_unassigned) + len(supplementary_unassigned) == len(unassigned)
+    module.write(
+        f"""
+/// Sorted list of the inclusive ranges of unassigned codepoints in the BMP,
+/// including noncharacters.
 #[rustfmt::skip]
-pub(crate) static TEXT_PRESENTATION_LEAF_{leaf_idx}: [(u8, u8); {len(leaf)}] = [
+pub(crate) static UNASSIGNED_BMP: [(u16, u16); {len(bmp_unassigned)}] = [
 """
-        )
-        for lo, hi in leaf:
-            module.write(f"    (0x{lo:02X}, 0x{hi:02X}),\n")
-        module.write(f"];\n")
+    )
+    for lo, hi in bmp_unassigned:
+        module.write(f"    (0x{lo:04X}, 0x{hi:04X}),\n")
+    module.write(
+        f"""];
 
-    # emoji modifier table
+/// Sorted list of the inclusive ranges of unassigned codepoints outside of the BMP,
+/// including noncharacters.
+#[cfg(not(feature = "bmp-only"))]
+#[rustfmt::skip]
+pub(crate) static UNASSIGNED_SUPPLEMENTARY: [(u32, u32); {len(supplementary_unassigned)}] = [
+"""
+    )
+    for lo, hi in supplementary_unassigned:
+        module.write(f"    (0x{lo:06X}, 0x{hi:06X}),\n")
+    module.write("];\n")
 
-    for leaf_idx, leaf in enumerate(emoji_modifier_leaves):
-        module.write(
-            f"""
+    # RGI emoji tables
+
+    rgi_ends = []
+    rgi_len = 0
+    for seq in rgi_emoji:
+        rgi_len += len(seq.encode("utf-8"))
+        rgi_ends.append(rgi_len)
+    assert rgi_len <= 0xFFFF
+    module.write(
+        """
+/// Every RGI emoji sequence, sorted by their UTF-8 encoding, one after the other.
+#[cfg(feature = "rgi-emoji")]
 #[rustfmt::skip]
-pub(crate) static EMOJI_MODIFIER_LEAF_{leaf_idx}: [(u8, u8); {len(leaf)}] = [
+pub(crate) static RGI_EMOJI: &str = concat!(
 """
-        )
-        for lo, hi in leaf:
-            module.write(f"    (0x{lo:02X}, 0x{hi:02X}),\n")
-        module.write(f"];\n")
+    )
+    for seq in rgi_emoji:
+        escaped = "".join(f"\\u{{{ord(c):X}}}" for c in seq)
+        module.write(f'    "{escaped}",\n')
+    module.write(
+        f""");
 
+/// The end of each sequence in [`RGI_EMOJI`].
+#[cfg(feature = "rgi-emoji")]
+#[rustfmt::skip]
+pub(crate) static RGI_EMOJI_ENDS: [u16; {len(rgi_ends)}] = ["""
+    )
+    for i, end in enumerate(rgi_ends):
+        if i % 10 == 0:
+            module.write("\n   ")
+        module.write(f" {end},")
+    module.write("\n];\n")
+
+    emit_presets(module)
+
+
+def emit_presets(module: IO[str]):
+    """Outputs the tables of the presets in `presets.rs`, all built from `NERD_FONTS_GLYPH_SETS`.
+    The Mono preset gives every glyph width 1, and the regular one gives the icons width 2."""
+    emit_preset_table(
+        module,
+        "POWERLINE_TABLE",
+        "The Powerline symbols, and the Powerline Extra symbols around them,\n"
+        "/// where Nerd Fonts 3.4 places them.",
+        lambda is_icons: None if is_icons else 1,
+    )
+    emit_preset_table(
+        module,
+        "NERD_FONTS_MONO_TABLE",
+        "The glyph sets of Nerd Fonts 3.4 in the Private Use Areas, one column wide.",
+        lambda is_icons: 1,
+    )
+    emit_preset_table(
+        module,
+        "NERD_FONTS_TABLE",
+        "Like [`NERD_FONTS_MONO_TABLE`], with the icons two columns wide.",
+        lambda is_icons: 2 if is_icons else 1,
+    )
+
+
+def emit_preset_table(
+    module: IO[str], name: str, doc: str, width_of_set: Callable[[bool], int | None]
+):
+    """Outputs a table of `(first, last, width)` overrides for the glyph sets in
+    `NERD_FONTS_GLYPH_SETS`. `width_of_set` gives the width of the glyphs of a set,
+    depending on whether it holds icons, or `None` to leave the set out of the table.
+    The table is a `const`, as the presets in `presets.rs` are `const`s built from it."""
+    glyph_sets = []
+    for set_name, ranges, is_icons in NERD_FONTS_GLYPH_SETS:
+        width = width_of_set(is_icons)
+        if width is not None:
+            glyph_sets.append((set_name, ranges, width))
+    num_ranges = sum(len(ranges) for _, ranges, _ in glyph_sets)
+    module.write(
+        f"""
+/// {doc}
+pub(crate) const {name}: [(char, char, u8); {num_ranges}] = [
+"""
+    )
+    for set_name, ranges, width in glyph_sets:
+        module.write(f"    // {set_name}\n")
+        for lo, hi in ranges:
+            module.write(f"    ('\\u{{{lo:X}}}', '\\u{{{hi:X}}}', {width}),\n")
+    module.write("];\n")
 
 
 def emit_tests(
@@ -1724,6 +2700,41 @@ pub(crate) static NORMALIZATION_TEST: [(&str, &str, &str, &str, &str); {len(norm
     module.write("];\n")
 
 
+def trie_table_sizes(tables: list[Table]) -> list[tuple[str, int]]:
+    """Returns the name and size in bytes of each of `tables`."""
+    return [(table.name, len(table.to_bytes())) for table in tables]
+
+
+def speed_table_sizes(
+    speed_tables: tuple[list[int], list[int], list[list[int]], int],
+) -> list[tuple[str, int]]:
+    """Returns the name and size in bytes of each of the `layout-speed` tables."""
+    index, cjk_index, blocks, _ = speed_tables
+    return [
+        ("WIDTH_INDEX", len(index)),
+        ("WIDTH_INDEX_CJK", len(cjk_index)),
+        ("WIDTH_BLOCKS", len(blocks) * len(blocks[0])),
+    ]
+
+
+def size_run_sizes(size_runs: tuple[list[int], list[int]]) -> list[tuple[str, int]]:
+    """Returns the name and size in bytes of each of the `layout-size` tables."""
+    runs, cjk_runs = size_runs
+    return [("WIDTH_RUNS", 4 * len(runs)), ("WIDTH_RUNS_CJK", 4 * len(cjk_runs))]
+
+
+def print_layout_sizes(layout: str, sizes: list[tuple[str, int]], emoji_size: int):
+    """Prints the size of each of the tables of a table layout, and their total
+    with and without the emoji tables that every layout shares. All sizes include
+    the CJK tables, which the `cjk` feature adds."""
+    print("------------------------")
+    for name, size in sizes:
+        print(f"{name} size: {size} bytes")
+    width_size = sum(size for _, size in sizes)
+    print(f"  {layout} width tables: {width_size} bytes")
+    print(f"  {layout} total: {width_size + emoji_size} bytes")
+
+
 def main(module_path: str):
     """Obtain character data from the latest version of Unicode, transform it into a multi-level
     lookup table for character width, and write a Rust module utilizing that table to
@@ -1736,7 +2747,35 @@ def main(module_path: str):
 
     (width_map, cjk_width_map) = load_width_maps()
 
+    joining_group_lam = load_joining_group_lam()
+    non_transparent_zero_widths = load_non_transparent_zero_widths(width_map)
+    ligature_transparent = load_ligature_transparent()
+    solidus_transparent = load_solidus_transparent(ligature_transparent, cjk_width_map)
+
+    props_map = make_props_map(
+        width_map, non_transparent_zero_widths, ligature_transparent, solidus_transparent
+    )
+
     tables = make_tables(width_map, cjk_width_map)
+    props_tables = make_props_tables(props_map)
+    utf8_tables = make_utf8_tables(width_map, cjk_width_map)
+    entries = table_entries(width_map, props_map)
+    cjk_entries = table_entries(cjk_width_map, props_map)
+    speed_tables = make_speed_tables(entries, cjk_entries)
+    size_runs = make_size_runs(entries, cjk_entries)
+
+    bmp_tables = make_tables(width_map, cjk_width_map, NUM_BMP_CODEPOINTS)
+    bmp_props_tables = make_props_tables(props_map, NUM_BMP_CODEPOINTS)
+    bmp_speed_tables = make_speed_tables(
+        entries[:NUM_BMP_CODEPOINTS], cjk_entries[:NUM_BMP_CODEPOINTS]
+    )
+    bmp_size_runs = make_size_runs(
+        entries[:NUM_BMP_CODEPOINTS], cjk_entries[:NUM_BMP_CODEPOINTS]
+    )
+    supplementary_entries = (
+        make_supplementary_entries(entries),
+        make_supplementary_entries(cjk_entries),
+    )
 
     special_ranges = make_special_ranges(width_map)
     cjk_special_ranges = make_special_ranges(cjk_width_map)
@@ -1750,31 +2789,25 @@ def main(module_path: str):
     emoji_modifier_bases = load_emoji_modifier_bases()
     emoji_modifier_table = make_ranges_table(emoji_modifier_bases)
 
-    joining_group_lam = load_joining_group_lam()
-    non_transparent_zero_widths = load_non_transparent_zero_widths(width_map)
-    ligature_transparent = load_ligature_transparent()
-    solidus_transparent = load_solidus_transparent(ligature_transparent, cjk_width_map)
+    unassigned = load_unassigned()
+
+    rgi_emoji = load_rgi_emoji()
 
     normalization_tests = load_normalization_tests()
 
     fetch_open("emoji-test.txt", "../tests", emoji=True)
 
     print("------------------------")
-    total_size = 0
-    for i, table in enumerate(tables):
-        size_bytes = len(table.to_bytes())
-        print(f"Table {i} size: {size_bytes} bytes")
-        total_size += size_bytes
-
+    emoji_size = 0
     for s, table in [
         ("Emoji presentation", emoji_presentation_table),
     ]:
         index_size = len(table[0]) * (math.ceil(math.log(table[0][-1][0], 256)) + 8)
         print(f"{s} index size: {index_size} bytes")
-        total_size += index_size
+        emoji_size += index_size
         leaves_size = len(table[1]) * len(table[1][0])
         print(f"{s} leaves size: {leaves_size} bytes")
-        total_size += leaves_size
+        emoji_size += leaves_size
 
     for s, table in [
         ("Text presentation", text_presentation_table),
@@ -1782,36 +2815,48 @@ def main(module_path: str):
     ]:
         index_size = len(table[0]) * (math.ceil(math.log(table[0][-1][0], 256)) + 16)
         print(f"{s} index size: {index_size} bytes")
-        total_size += index_size
+        emoji_size += index_size
         leaves_size = 2 * sum(map(len, table[1]))
         print(f"{s} leaves size: {leaves_size} bytes")
-        total_size += leaves_size
-
-    for s, table in [
-        ("Non transparent zero width", non_transparent_zero_widths),
-        ("Solidus transparent", solidus_transparent),
+        emoji_size += leaves_size
+    print(f"  Emoji tables, used by every layout: {emoji_size} bytes")
+
+    utf8_sizes = trie_table_sizes(utf8_tables)
+    supplementary_sizes = [
+        ("SUPPLEMENTARY_ENTRIES", len(supplementary_entries[0])),
+        ("SUPPLEMENTARY_ENTRIES_CJK", len(supplementary_entries[1])),
+    ]
+    for layout, sizes in [
+        ("Default layout", trie_table_sizes(tables + props_tables) + utf8_sizes),
+        ("`layout-speed`", speed_table_sizes(speed_tables) + utf8_sizes),
+        ("`layout-size`", size_run_sizes(size_runs)),
+        (
+            "`bmp-only` default layout",
+            trie_table_sizes(bmp_tables + bmp_props_tables)
+            + supplementary_sizes
+            + utf8_sizes,
+        ),
+        (
+            "`bmp-only` `layout-speed`",
+            speed_table_sizes(bmp_speed_tables) + supplementary_sizes + utf8_sizes,
+        ),
+        ("`bmp-only` `layout-size`", size_run_sizes(bmp_size_runs) + supplementary_sizes),
     ]:
-        table_size = 6 * len(table)
-        print(f"{s} table size: {table_size} bytes")
-        total_size += table_size
-    print("------------------------")
-    print(f"  Total size: {total_size} bytes")
+        print_layout_sizes(layout, sizes, emoji_size)
 
     emit_rust_file(
         module_path,
         lambda f: emit_tables(
             f,
             version,
-            tables,
             special_ranges,
             cjk_special_ranges,
             emoji_presentation_table,
             text_presentation_table,
             emoji_modifier_table,
             joining_group_lam,
-            non_transparent_zero_widths,
-            ligature_transparent,
-            solidus_transparent,
+            unassigned,
+            rgi_emoji,
         ),
     )
     print(f'Wrote to "{module_path}"')
@@ -1840,6 +2885,41 @@ def main(module_path: str):
     )
     print(f'Wrote to "{LOOKUP_PATH}"')
 
+    emit_rust_file(
+        LAYOUT_TRIE_PATH, lambda f: emit_layout_trie(f, tables, props_tables, utf8_tables)
+    )
+    print(f'Wrote to "{LAYOUT_TRIE_PATH}"')
+
+    emit_rust_file(
+        LAYOUT_SPEED_PATH, lambda f: emit_layout_speed(f, speed_tables, utf8_tables)
+    )
+    print(f'Wrote to "{LAYOUT_SPEED_PATH}"')
+
+    emit_rust_file(LAYOUT_SIZE_PATH, lambda f: emit_layout_size(f, size_runs))
+    print(f'Wrote to "{LAYOUT_SIZE_PATH}"')
+
+    emit_rust_file(
+        LAYOUT_TRIE_BMP_PATH,
+        lambda f: emit_layout_trie(
+            f, bmp_tables, bmp_props_tables, utf8_tables, supplementary_entries
+        ),
+    )
+    print(f'Wrote to "{LAYOUT_TRIE_BMP_PATH}"')
+
+    emit_rust_file(
+        LAYOUT_SPEED_BMP_PATH,
+        lambda f: emit_layout_speed(
+            f, bmp_speed_tables, utf8_tables, supplementary_entries
+        ),
+    )
+    print(f'Wrote to "{LAYOUT_SPEED_BMP_PATH}"')
+
+    emit_rust_file(
+        LAYOUT_SIZE_BMP_PATH,
+        lambda f: emit_layout_size(f, bmp_size_runs, supplementary_entries),
+    )
+    print(f'Wrote to "{LAYOUT_SIZE_BMP_PATH}"')
+
     emit_rust_file(WIDTH_INFO_PATH, emit_width_info)
     print(f'Wrote to "{WIDTH_INFO_PATH}"')
 
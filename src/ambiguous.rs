@@ -0,0 +1,173 @@
+// Copyright 2012-2026 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Choosing which ambiguous characters are wide in an East Asian context.
+//!
+//! [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk) gives every
+//! ambiguous character width 2. Many terminals with an "ambiguous characters are
+//! double-width" setting keep some of them narrow anyway, most often the box-drawing
+//! characters and block elements, so that borders and bars line up. A [`Policy`]
+//! picks which [`Block`]s of ambiguous characters are wide.
+//!
+//! Ambiguous letters, such as those of the Greek and Cyrillic alphabets, are already
+//! narrow in both contexts, so the blocks only matter for the symbols and punctuation.
+//!
+//! ```rust
+//! use unicode_width::ambiguous::{Block, Policy};
+//! use unicode_width::UnicodeWidthStr;
+//!
+//! const POLICY: Policy = Policy::ALL
+//!     .narrow(Block::BoxDrawing)
+//!     .narrow(Block::BlockElements);
+//!
+//! let s = "├─ α → ① ██";
+//! assert_eq!(s.width(), 11);
+//! assert_eq!(s.width_cjk(), 17);
+//! assert_eq!(POLICY.str_width(s), 13);
+//! ```
+
+use crate::lookup;
+use crate::overrides;
+
+/// A group of ambiguous characters, named after the Unicode block that holds them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Block {
+    /// Latin-1 Supplement (`'\u{80}'..='\u{FF}'`), such as `'°'`, `'§'`, and `'×'`.
+    Latin1Supplement,
+    /// Greek and Coptic (`'\u{370}'..='\u{3FF}'`), which only holds `'\u{387}'` GREEK ANO TELEIA.
+    Greek,
+    /// General Punctuation (`'\u{2000}'..='\u{206F}'`), such as `'‘'`, `'…'`, and `'※'`.
+    GeneralPunctuation,
+    /// Letterlike Symbols (`'\u{2100}'..='\u{214F}'`), such as `'℃'` and `'№'`.
+    LetterlikeSymbols,
+    /// Number Forms (`'\u{2150}'..='\u{218F}'`), such as `'⅓'` and `'Ⅳ'`.
+    NumberForms,
+    /// Arrows (`'\u{2190}'..='\u{21FF}'`).
+    Arrows,
+    /// Mathematical Operators (`'\u{2200}'..='\u{22FF}'`).
+    MathematicalOperators,
+    /// Enclosed Alphanumerics (`'\u{2460}'..='\u{24FF}'`), such as `'①'`,
+    /// and Enclosed Alphanumeric Supplement (`'\u{1F100}'..='\u{1F1FF}'`).
+    EnclosedAlphanumerics,
+    /// Box Drawing (`'\u{2500}'..='\u{257F}'`).
+    BoxDrawing,
+    /// Block Elements (`'\u{2580}'..='\u{259F}'`).
+    BlockElements,
+    /// Geometric Shapes (`'\u{25A0}'..='\u{25FF}'`).
+    GeometricShapes,
+    /// Miscellaneous Symbols (`'\u{2600}'..='\u{26FF}'`), such as `'★'` and `'♠'`.
+    MiscellaneousSymbols,
+    /// Every other ambiguous character, such as `'€'`, `'❶'`, and `'\u{FFFD}'`.
+    Other,
+}
+
+impl Block {
+    /// Returns the block of `c`, or `None` if `c` is not ambiguous, that is,
+    /// if [`UnicodeWidthChar::width_cjk`](crate::UnicodeWidthChar::width_cjk) doesn't widen it.
+    ///
+    /// Private-use characters are ambiguous, and are in [`Block::Other`].
+    pub const fn of(c: char) -> Option<Block> {
+        if !lookup::const_is_ambiguous(c) {
+            return None;
+        }
+        Some(Block::containing(c))
+    }
+
+    /// Like [`Block::of`], but reads the `static` tables, for use at runtime.
+    fn of_at_runtime(c: char) -> Option<Block> {
+        if !lookup::is_ambiguous(c) {
+            return None;
+        }
+        Some(Block::containing(c))
+    }
+
+    /// Returns the block of the ambiguous character `c`.
+    const fn containing(c: char) -> Block {
+        match c {
+            '\u{80}'..='\u{FF}' => Block::Latin1Supplement,
+            '\u{370}'..='\u{3FF}' => Block::Greek,
+            '\u{2000}'..='\u{206F}' => Block::GeneralPunctuation,
+            '\u{2100}'..='\u{214F}' => Block::LetterlikeSymbols,
+            '\u{2150}'..='\u{218F}' => Block::NumberForms,
+            '\u{2190}'..='\u{21FF}' => Block::Arrows,
+            '\u{2200}'..='\u{22FF}' => Block::MathematicalOperators,
+            '\u{2460}'..='\u{24FF}' | '\u{1F100}'..='\u{1F1FF}' => Block::EnclosedAlphanumerics,
+            '\u{2500}'..='\u{257F}' => Block::BoxDrawing,
+            '\u{2580}'..='\u{259F}' => Block::BlockElements,
+            '\u{25A0}'..='\u{25FF}' => Block::GeometricShapes,
+            '\u{2600}'..='\u{26FF}' => Block::MiscellaneousSymbols,
+            _ => Block::Other,
+        }
+    }
+
+    const fn bit(self) -> u16 {
+        1 << self as u16
+    }
+}
+
+/// The set of [`Block`]s whose ambiguous characters are wide in an East Asian context.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Policy {
+    wide: u16,
+}
+
+impl Policy {
+    /// Every ambiguous character is wide, as with
+    /// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+    pub const ALL: Policy = Policy { wide: u16::MAX };
+
+    /// Every ambiguous character is narrow. Sequences are still measured as in an
+    /// East Asian context, so `"<\u{338}"` has width 2, unlike with
+    /// [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+    pub const NONE: Policy = Policy { wide: 0 };
+
+    /// Returns this policy with the ambiguous characters of `block` wide.
+    pub const fn widen(self, block: Block) -> Policy {
+        Policy {
+            wide: self.wide | block.bit(),
+        }
+    }
+
+    /// Returns this policy with the ambiguous characters of `block` narrow.
+    pub const fn narrow(self, block: Block) -> Policy {
+        Policy {
+            wide: self.wide & !block.bit(),
+        }
+    }
+
+    /// Returns whether the ambiguous characters of `block` are wide.
+    pub const fn is_wide(&self, block: Block) -> bool {
+        self.wide & block.bit() != 0
+    }
+
+    /// Returns the width that this policy gives `c`, if it differs from the one
+    /// in an East Asian context.
+    fn override_for(&self, c: char) -> Option<usize> {
+        match Block::of_at_runtime(c) {
+            Some(block) if !self.is_wide(block) => Some(1),
+            _ => None,
+        }
+    }
+
+    /// Like [`UnicodeWidthChar::width_cjk`](crate::UnicodeWidthChar::width_cjk), but with this policy.
+    pub fn char_width(&self, c: char) -> Option<usize> {
+        self.override_for(c)
+            .or_else(|| lookup::single_char_width_cjk(c))
+    }
+
+    /// Like [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk), but with this policy.
+    pub fn str_width(&self, s: &str) -> usize {
+        if *self == Policy::ALL {
+            return lookup::str_slice_width::<true>(s);
+        }
+        overrides::str_width_with::<true>(s, |c| self.override_for(c))
+    }
+}
@@ -0,0 +1,462 @@
+// Copyright 2012-2026 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parsers for files that override the widths of characters, available with the `"config"` feature.
+//!
+//! Each parser reads a list of ranges into a [`WidthOverridesBuf`], in which later ranges
+//! take precedence over any earlier ones they overlap, and reports the first malformed
+//! entry as a [`ParseError`] with its line number.
+//!
+//! - [`parse_east_asian_width`] reads the format of the Unicode Character Database's
+//!   `EastAsianWidth.txt`.
+//! - [`parse_vim_cellwidths`] reads the list given to Vim's `setcellwidths()`.
+//! - [`parse_wezterm_cell_widths`] reads WezTerm's `cell_widths` setting.
+//!
+//! ```rust
+//! use unicode_width::config::parse_east_asian_width;
+//!
+//! let overrides = parse_east_asian_width(
+//!     "# Powerline symbols
+//!      E0A0..E0A3;W
+//!      E0B0..E0D4;W",
+//!     1,
+//! )
+//! .unwrap();
+//! assert_eq!(overrides.as_overrides().str_width("\u{E0B0} main"), 7);
+//!
+//! let error = parse_east_asian_width("E0A0..E0A3;W\nE0B0:E0D4;W", 1).unwrap_err();
+//! assert_eq!(error.line(), 2);
+//! assert_eq!(error.to_string(), "line 2: `E0B0:E0D4` is not a hexadecimal code point");
+//! ```
+
+use core::fmt;
+use std::format;
+use std::string::String;
+
+use crate::WidthOverridesBuf;
+
+/// The error returned when a file of width overrides is malformed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    message: String,
+}
+
+impl ParseError {
+    fn new(line: usize, message: String) -> Self {
+        ParseError { line, message }
+    }
+
+    /// Returns the line of the malformed entry, counting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns what is wrong with the entry.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses overrides in the format of `EastAsianWidth.txt`, with one range per line,
+/// such as `0000..001F;N` or `3000;F`, and comments starting with `#`.
+///
+/// `W` (Wide) and `F` (Fullwidth) give width 2, `Na` (Narrow), `H` (Halfwidth), and `N` (Neutral)
+/// give width 1, and `A` (Ambiguous) gives `ambiguous_width`.
+///
+/// Every range in the file overrides the built-in width, so this is meant for short files
+/// of fixes. A complete `EastAsianWidth.txt` would give zero-width characters such as
+/// combining marks a width of 1 as well.
+pub fn parse_east_asian_width(
+    text: &str,
+    ambiguous_width: u8,
+) -> Result<WidthOverridesBuf, ParseError> {
+    let mut overrides = WidthOverridesBuf::new();
+    for (i, line) in text.lines().enumerate() {
+        let line_number = i + 1;
+        let error = |message| Err(ParseError::new(line_number, message));
+        let entry = line.split('#').next().unwrap_or_default().trim();
+        if entry.is_empty() {
+            continue;
+        }
+        let Some((range, value)) = entry.split_once(';') else {
+            return error(format!("expected `;` after the code points in `{entry}`"));
+        };
+        let range = range.trim();
+        let (first, last) = range.split_once("..").unwrap_or((range, range));
+        let first = parse_hex_char(first.trim(), line_number)?;
+        let last = parse_hex_char(last.trim(), line_number)?;
+        if first > last {
+            return error(format!("the range `{range}` is empty"));
+        }
+        let width = match value.trim() {
+            "W" | "F" => 2,
+            "Na" | "H" | "N" => 1,
+            "A" => ambiguous_width,
+            value => {
+                return error(format!(
+                    "`{value}` is not an East_Asian_Width (expected W, F, Na, H, N, or A)"
+                ))
+            }
+        };
+        overrides.insert(first, last, width);
+    }
+    Ok(overrides)
+}
+
+fn parse_hex_char(s: &str, line: usize) -> Result<char, ParseError> {
+    let valid_digits = !s.is_empty() && s.len() <= 6 && s.bytes().all(|b| b.is_ascii_hexdigit());
+    let Some(cp) = u32::from_str_radix(s, 16).ok().filter(|_| valid_digits) else {
+        return Err(ParseError::new(
+            line,
+            format!("`{s}` is not a hexadecimal code point"),
+        ));
+    };
+    to_char(cp, s, line)
+}
+
+fn to_char(cp: u32, s: &str, line: usize) -> Result<char, ParseError> {
+    char::from_u32(cp).ok_or_else(|| {
+        ParseError::new(
+            line,
+            format!("`{s}` is not a Unicode scalar value (U+{cp:04X})"),
+        )
+    })
+}
+
+/// Parses the list of `[low, high, width]` ranges given to Vim's `setcellwidths()`,
+/// such as `[[0x2190, 0x21FF, 1], [0xE000, 0xF8FF, 2]]`.
+///
+/// The list may be wrapped in `call setcellwidths(...)`, may span several lines with
+/// `\` continuation lines, and may have `"` or `#` comments. As in Vim, each width must be 1 or 2.
+///
+/// ```rust
+/// use unicode_width::config::parse_vim_cellwidths;
+///
+/// let overrides = parse_vim_cellwidths(
+///     r#"call setcellwidths([
+///         \ [0x2190, 0x21ff, 2],
+///         \ [0xe000, 0xf8ff, 2],
+///         \ ])"#,
+/// )
+/// .unwrap();
+/// assert_eq!(overrides.as_overrides().char_width('\u{2192}'), Some(2));
+///
+/// let error = parse_vim_cellwidths("[[0x2190, 0x21ff, 3]]").unwrap_err();
+/// assert_eq!(error.to_string(), "line 1: the width `3` is not 1 or 2");
+/// ```
+pub fn parse_vim_cellwidths(text: &str) -> Result<WidthOverridesBuf, ParseError> {
+    let mut tokens = Tokens::new(text, Syntax::Vim);
+    let mut overrides = WidthOverridesBuf::new();
+
+    let mut call = false;
+    loop {
+        match tokens.next()? {
+            Some((Token::Punct('['), _)) => break,
+            Some((Token::Punct(':'), _)) | Some((Token::Word("call"), _)) => {}
+            Some((Token::Word("setcellwidths"), _)) => {
+                tokens.expect('(')?;
+                call = true;
+            }
+            token => return Err(tokens.unexpected(token, "`[`")),
+        }
+    }
+
+    loop {
+        match tokens.next()? {
+            Some((Token::Punct(']'), _)) => break,
+            Some((Token::Punct('['), line)) => {
+                let first = tokens.char()?;
+                tokens.expect(',')?;
+                let last = tokens.char()?;
+                tokens.expect(',')?;
+                let (width, width_line) = tokens.number()?;
+                tokens.expect(']')?;
+                if first > last {
+                    return Err(ParseError::new(line, "the range is empty".into()));
+                }
+                if !matches!(width, 1 | 2) {
+                    let message = format!("the width `{width}` is not 1 or 2");
+                    return Err(ParseError::new(width_line, message));
+                }
+                overrides.insert(first, last, width as u8);
+                match tokens.next()? {
+                    Some((Token::Punct(','), _)) => {}
+                    Some((Token::Punct(']'), _)) => break,
+                    token => return Err(tokens.unexpected(token, "`,` or `]`")),
+                }
+            }
+            token => return Err(tokens.unexpected(token, "`[` or `]`")),
+        }
+    }
+
+    if call {
+        tokens.expect(')')?;
+    }
+    tokens.end()?;
+    Ok(overrides)
+}
+
+/// Parses WezTerm's `cell_widths` setting, a Lua table of entries
+/// such as `{ first = 0xE000, last = 0xF8FF, width = 2 }`.
+///
+/// The table may be preceded by the assignment to `cell_widths`, and may have `--` comments.
+/// An entry without a `last` covers just its `first` character. Each width must be 0, 1, or 2.
+///
+/// ```rust
+/// use unicode_width::config::parse_wezterm_cell_widths;
+///
+/// let overrides = parse_wezterm_cell_widths(
+///     "config.cell_widths = {
+///        -- Powerline symbols
+///        { first = 0xE0A0, last = 0xE0D4, width = 2 },
+///        { first = 0x2192, width = 2 },
+///      }",
+/// )
+/// .unwrap();
+/// assert_eq!(overrides.as_overrides().str_width("\u{E0B0}\u{2192}"), 4);
+///
+/// let error = parse_wezterm_cell_widths("{\n  { first = 0xE0A0, widht = 2 },\n}").unwrap_err();
+/// assert_eq!(error.to_string(), "line 2: unknown key `widht` (expected `first`, `last`, or `width`)");
+/// ```
+pub fn parse_wezterm_cell_widths(text: &str) -> Result<WidthOverridesBuf, ParseError> {
+    let mut tokens = Tokens::new(text, Syntax::Lua);
+    let mut overrides = WidthOverridesBuf::new();
+
+    let mut name = None;
+    loop {
+        match tokens.next()? {
+            Some((Token::Punct('{'), _)) if name.is_none() => break,
+            Some((Token::Word(word), _)) => name = Some(word),
+            Some((Token::Punct('.'), _)) if name.is_some() => {}
+            Some((Token::Punct('='), line)) => match name {
+                Some("cell_widths") => {
+                    tokens.expect('{')?;
+                    break;
+                }
+                _ => {
+                    let message = "expected an assignment to `cell_widths`".into();
+                    return Err(ParseError::new(line, message));
+                }
+            },
+            token => return Err(tokens.unexpected(token, "`{`")),
+        }
+    }
+
+    loop {
+        match tokens.next()? {
+            Some((Token::Punct('}'), _)) => break,
+            Some((Token::Punct('{'), _)) => {
+                let (mut first, mut last, mut width) = (None, None, None);
+                let end_line = loop {
+                    let (key, key_line) = match tokens.next()? {
+                        Some((Token::Punct('}'), line)) => break line,
+                        Some((Token::Word(key), line)) => (key, line),
+                        token => return Err(tokens.unexpected(token, "a key or `}`")),
+                    };
+                    let field = match key {
+                        "first" => &mut first,
+                        "last" => &mut last,
+                        "width" => &mut width,
+                        _ => {
+                            let message = format!(
+                                "unknown key `{key}` (expected `first`, `last`, or `width`)"
+                            );
+                            return Err(ParseError::new(key_line, message));
+                        }
+                    };
+                    if field.is_some() {
+                        let message = format!("`{key}` is given twice");
+                        return Err(ParseError::new(key_line, message));
+                    }
+                    tokens.expect('=')?;
+                    *field = Some(tokens.number()?);
+                    match tokens.next()? {
+                        Some((Token::Punct(',' | ';'), _)) => {}
+                        Some((Token::Punct('}'), line)) => break line,
+                        token => return Err(tokens.unexpected(token, "`,` or `}`")),
+                    }
+                };
+
+                let missing = |key| ParseError::new(end_line, format!("the entry has no `{key}`"));
+                let (first, first_line) = first.ok_or_else(|| missing("first"))?;
+                let (width, width_line) = width.ok_or_else(|| missing("width"))?;
+                let (last, last_line) = last.unwrap_or((first, first_line));
+                let first = to_char(first, &format!("{first:#X}"), first_line)?;
+                let last = to_char(last, &format!("{last:#X}"), last_line)?;
+                if first > last {
+                    return Err(ParseError::new(end_line, "the range is empty".into()));
+                }
+                if width > 2 {
+                    let message = format!("the width `{width}` is not 0, 1, or 2");
+                    return Err(ParseError::new(width_line, message));
+                }
+                overrides.insert(first, last, width as u8);
+
+                match tokens.next()? {
+                    Some((Token::Punct(',' | ';'), _)) => {}
+                    Some((Token::Punct('}'), _)) => break,
+                    token => return Err(tokens.unexpected(token, "`,` or `}`")),
+                }
+            }
+            token => return Err(tokens.unexpected(token, "`{` or `}`")),
+        }
+    }
+
+    tokens.end()?;
+    Ok(overrides)
+}
+
+/// The comment syntax of a configuration language.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Syntax {
+    /// `"` or `#` comments, and `\` continuation lines.
+    Vim,
+    /// `--` comments.
+    Lua,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Punct(char),
+    Number(&'a str),
+    Word(&'a str),
+}
+
+impl fmt::Display for Token<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Punct(c) => write!(f, "`{c}`"),
+            Token::Number(s) | Token::Word(s) => write!(f, "`{s}`"),
+        }
+    }
+}
+
+/// Splits the text of a configuration file into [`Token`]s, along with their line numbers.
+struct Tokens<'a> {
+    rest: &'a str,
+    line: usize,
+    syntax: Syntax,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(text: &'a str, syntax: Syntax) -> Self {
+        Tokens {
+            rest: text,
+            line: 1,
+            syntax,
+        }
+    }
+
+    /// Skips over whitespace and comments.
+    fn skip(&mut self) {
+        loop {
+            let is_comment = match self.syntax {
+                Syntax::Vim => self.rest.starts_with(['"', '#']),
+                Syntax::Lua => self.rest.starts_with("--"),
+            };
+            let skip = if is_comment {
+                self.rest.find('\n').unwrap_or(self.rest.len())
+            } else {
+                self.rest
+                    .find(|c: char| {
+                        !(c.is_whitespace() || (self.syntax == Syntax::Vim && c == '\\'))
+                    })
+                    .unwrap_or(self.rest.len())
+            };
+            if skip == 0 {
+                return;
+            }
+            self.line += self.rest[..skip].matches('\n').count();
+            self.rest = &self.rest[skip..];
+        }
+    }
+
+    fn next(&mut self) -> Result<Option<(Token<'a>, usize)>, ParseError> {
+        self.skip();
+        let Some(c) = self.rest.chars().next() else {
+            return Ok(None);
+        };
+        let len = if c.is_ascii_alphanumeric() || c == '_' {
+            self.rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(self.rest.len())
+        } else if c.is_ascii_punctuation() {
+            1
+        } else {
+            let message = format!("unexpected character {c:?}");
+            return Err(ParseError::new(self.line, message));
+        };
+        let (text, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        let token = if c.is_ascii_digit() {
+            Token::Number(text)
+        } else if c.is_ascii_punctuation() {
+            Token::Punct(c)
+        } else {
+            Token::Word(text)
+        };
+        Ok(Some((token, self.line)))
+    }
+
+    /// Returns the error for finding `token` where `expected` should have been.
+    fn unexpected(&self, token: Option<(Token<'_>, usize)>, expected: &str) -> ParseError {
+        match token {
+            Some((token, line)) => {
+                ParseError::new(line, format!("expected {expected}, found {token}"))
+            }
+            None => ParseError::new(self.line, format!("expected {expected}, found the end")),
+        }
+    }
+
+    fn expect(&mut self, punct: char) -> Result<(), ParseError> {
+        match self.next()? {
+            Some((Token::Punct(c), _)) if c == punct => Ok(()),
+            token => Err(self.unexpected(token, &format!("`{punct}`"))),
+        }
+    }
+
+    /// Reads a decimal or `0x` hexadecimal number.
+    fn number(&mut self) -> Result<(u32, usize), ParseError> {
+        match self.next()? {
+            Some((Token::Number(s), line)) => {
+                let number = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+                    Some(hex) => u32::from_str_radix(hex, 16),
+                    None => s.parse(),
+                };
+                let number = number
+                    .map_err(|_| ParseError::new(line, format!("`{s}` is not a valid number")))?;
+                Ok((number, line))
+            }
+            token => Err(self.unexpected(token, "a number")),
+        }
+    }
+
+    /// Reads a number, as a code point.
+    fn char(&mut self) -> Result<char, ParseError> {
+        let (cp, line) = self.number()?;
+        to_char(cp, &format!("{cp:#X}"), line)
+    }
+
+    fn end(&mut self) -> Result<(), ParseError> {
+        match self.next()? {
+            None => Ok(()),
+            token => Err(self.unexpected(token, "the end")),
+        }
+    }
+}
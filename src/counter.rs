@@ -0,0 +1,181 @@
+// Copyright 2012-2026 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt::{self, Display, Write};
+
+use crate::lookup;
+use crate::width_info::WidthInfo;
+
+/// How many bytes of text [`WidthCounter`] holds on to while waiting to see what follows them.
+const TAIL_CAPACITY: usize = 128;
+
+/// A [`fmt::Write`] sink that measures the text written to it, instead of storing it.
+///
+/// The text is measured as a whole, like [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width),
+/// so sequences split across several calls to [`write_str`](fmt::Write::write_str)
+/// are still measured correctly. This holds for any sequence of at most 64 bytes;
+/// longer sequences that are split may be measured as if the split were not there.
+///
+/// ```rust
+/// use core::fmt::Write;
+/// use unicode_width::WidthCounter;
+///
+/// let mut counter = WidthCounter::new();
+/// write!(counter, "{}: {}", "ｗｉｄｔｈ", 42).unwrap();
+/// assert_eq!(counter.width(), 14);
+///
+/// // The Hebrew Alef-ZWJ-Lamed ligature, written in pieces.
+/// let mut counter = WidthCounter::new();
+/// for piece in ["א", "\u{200D}", "ל"] {
+///     counter.write_str(piece).unwrap();
+/// }
+/// assert_eq!(counter.width(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct WidthCounter {
+    /// The width of the text before `tail`, which nothing written later can affect.
+    width: usize,
+    /// The most recently written text, as UTF-8.
+    tail: [u8; TAIL_CAPACITY],
+    tail_len: usize,
+    #[cfg(feature = "cjk")]
+    is_cjk: bool,
+}
+
+impl WidthCounter {
+    /// Creates a counter that measures like [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+    pub const fn new() -> Self {
+        WidthCounter {
+            width: 0,
+            tail: [0; TAIL_CAPACITY],
+            tail_len: 0,
+            #[cfg(feature = "cjk")]
+            is_cjk: false,
+        }
+    }
+
+    /// Creates a counter that measures like [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+    #[cfg(feature = "cjk")]
+    pub const fn new_cjk() -> Self {
+        WidthCounter {
+            is_cjk: true,
+            ..WidthCounter::new()
+        }
+    }
+
+    /// Returns the width of all the text written so far.
+    pub fn width(&self) -> usize {
+        self.width
+            .wrapping_add(self.measure(self.tail(), WidthInfo::DEFAULT).0)
+    }
+
+    fn tail(&self) -> &str {
+        // Only whole `str`s and their suffixes starting on a char boundary are ever copied in.
+        core::str::from_utf8(&self.tail[..self.tail_len]).unwrap_or_default()
+    }
+
+    fn measure(&self, s: &str, next_info: WidthInfo) -> (usize, WidthInfo) {
+        #[cfg(feature = "cjk")]
+        if self.is_cjk {
+            return lookup::str_width_from::<true>(s, next_info);
+        }
+        lookup::str_width_from::<false>(s, next_info)
+    }
+
+    /// Adds the width of the tail followed by `s` to the total, given the info
+    /// left off by the text after `s`, and empties the tail.
+    fn settle(&mut self, s: &str, next_info: WidthInfo) {
+        let (width, info) = self.measure(s, next_info);
+        let (tail_width, _) = self.measure(self.tail(), info);
+        self.width = self.width.wrapping_add(width).wrapping_add(tail_width);
+        self.tail_len = 0;
+    }
+}
+
+impl Default for WidthCounter {
+    fn default() -> Self {
+        WidthCounter::new()
+    }
+}
+
+/// Whether the character encoded as `b` leaves the same info for the characters
+/// before it no matter what follows, so that they can be measured right away.
+///
+/// This holds for all ASCII characters, except for those that can start a keycap sequence.
+fn is_boundary(b: u8) -> bool {
+    b.is_ascii() && !matches!(b, b'#' | b'*' | b'0'..=b'9')
+}
+
+impl Write for WidthCounter {
+    fn write_str(&mut self, mut s: &str) -> fmt::Result {
+        if let Some(i) = s.bytes().rposition(is_boundary) {
+            let (_, next_info) = self.measure(&s[i..i + 1], WidthInfo::DEFAULT);
+            self.settle(&s[..i], next_info);
+            s = &s[i..];
+        }
+
+        if self.tail_len + s.len() > TAIL_CAPACITY {
+            // Keep at least the last half of the tail's worth of text, and measure
+            // everything before it as if that were all that followed.
+            if s.len() > TAIL_CAPACITY / 2 {
+                let mut i = s.len() - TAIL_CAPACITY / 2;
+                while !s.is_char_boundary(i) {
+                    i += 1;
+                }
+                let (_, next_info) = self.measure(&s[i..], WidthInfo::DEFAULT);
+                self.settle(&s[..i], next_info);
+                s = &s[i..];
+            } else {
+                let tail = self.tail();
+                let mut i = tail.len() - TAIL_CAPACITY / 2;
+                while !tail.is_char_boundary(i) {
+                    i += 1;
+                }
+                let (_, next_info) = self.measure(s, WidthInfo::DEFAULT);
+                let (_, next_info) = self.measure(&tail[i..], next_info);
+                let (width, _) = self.measure(&tail[..i], next_info);
+                self.width = self.width.wrapping_add(width);
+                self.tail.copy_within(i..self.tail_len, 0);
+                self.tail_len -= i;
+            }
+        }
+
+        self.tail[self.tail_len..][..s.len()].copy_from_slice(s.as_bytes());
+        self.tail_len += s.len();
+        Ok(())
+    }
+}
+
+/// Returns the width of `value` as formatted by its [`Display`] implementation,
+/// measured like [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width),
+/// without allocating.
+///
+/// If the [`Display`] implementation returns an error,
+/// the width of the text it wrote up to that point is returned.
+///
+/// ```rust
+/// use unicode_width::display_width;
+///
+/// assert_eq!(display_width(&-1.5), 4);
+/// assert_eq!(display_width(&format_args!("{}ｘ", 3)), 3);
+/// ```
+pub fn display_width<T: Display + ?Sized>(value: &T) -> usize {
+    let mut counter = WidthCounter::new();
+    let _ = write!(counter, "{value}");
+    counter.width()
+}
+
+/// Like [`display_width`], but measures like [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(feature = "cjk")]
+pub fn display_width_cjk<T: Display + ?Sized>(value: &T) -> usize {
+    let mut counter = WidthCounter::new_cjk();
+    let _ = write!(counter, "{value}");
+    counter.width()
+}
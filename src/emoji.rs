@@ -0,0 +1,216 @@
+// Copyright 2012-2026 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::layout::TABLES;
+use crate::lookup;
+use crate::props::starts_non_ideographic_text_presentation_seq;
+use crate::width_info::WidthInfo;
+
+/// Which kinds of emoji sequences a terminal renders as a single emoji.
+///
+/// Terminals differ in how much of the emoji sequences they support. With one of
+/// these switches off, the sequences it covers are measured the way a terminal
+/// without that support draws them, as the separate characters they are made of:
+///
+/// - `zwj_sequences`: an emoji ZWJ sequence, such as a family, is as wide as its parts
+///   added up, as if the `'\u{200D}'` ZERO WIDTH JOINERs between them were left out.
+/// - `modifier_sequences`: an emoji modifier (skin tone) is drawn as a swatch of
+///   width 2 after its base, and doesn't join a ZWJ sequence.
+/// - `flags`: regional indicators don't pair up into flags, and each one is drawn as
+///   a letter of width 2.
+/// - `tag_sequences`: the tag characters after a black flag are ignored, so a
+///   subdivision flag is drawn as a black flag, of the same width, that doesn't
+///   join a ZWJ sequence.
+/// - `keycaps`: a keycap sequence, such as `"#\u{FE0F}\u{20E3}"`, is drawn as its
+///   base character with a combining keycap, which has width 1.
+/// - `emoji_presentation`: `'\u{FE0F}'` VARIATION SELECTOR-16 is ignored, instead of
+///   widening the character before it.
+/// - `text_presentation`: `'\u{FE0E}'` VARIATION SELECTOR-15 is ignored, instead of
+///   narrowing the emoji before it. Unlike the rules of the crate, this also narrows
+///   those emoji in an East Asian context.
+///
+/// [`EmojiSupport::ALL`] measures like [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+/// More switches may be added in the future, so the struct can't be built with a struct
+/// expression outside of this crate. Start from [`EmojiSupport::ALL`] or [`EmojiSupport::NONE`]
+/// instead, and change it with the `with_*` methods or by setting its fields.
+///
+/// ```rust
+/// use unicode_width::{EmojiSupport, UnicodeWidthStr};
+///
+/// let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+/// let support = EmojiSupport::ALL.with_zwj_sequences(false);
+/// # #[cfg(not(feature = "bmp-only"))]
+/// # {
+/// assert_eq!(family.width(), 2);
+/// assert_eq!(support.str_width(family), 6);
+/// assert_eq!(EmojiSupport::NONE.str_width("\u{2764}\u{FE0F} \u{1F44D}\u{1F3FD}"), 6);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct EmojiSupport {
+    /// Whether emoji ZWJ sequences are drawn as a single emoji.
+    pub zwj_sequences: bool,
+    /// Whether emoji modifier sequences are drawn as a single emoji.
+    pub modifier_sequences: bool,
+    /// Whether pairs of regional indicators are drawn as flags.
+    pub flags: bool,
+    /// Whether emoji tag sequences are drawn as subdivision flags.
+    pub tag_sequences: bool,
+    /// Whether emoji keycap sequences are drawn as a single emoji.
+    pub keycaps: bool,
+    /// Whether emoji presentation sequences (with `'\u{FE0F}'`) are drawn wide.
+    pub emoji_presentation: bool,
+    /// Whether text presentation sequences (with `'\u{FE0E}'`) are drawn narrow.
+    pub text_presentation: bool,
+}
+
+impl EmojiSupport {
+    /// Every kind of emoji sequence is supported.
+    pub const ALL: EmojiSupport = EmojiSupport {
+        zwj_sequences: true,
+        modifier_sequences: true,
+        flags: true,
+        tag_sequences: true,
+        keycaps: true,
+        emoji_presentation: true,
+        text_presentation: true,
+    };
+
+    /// No kind of emoji sequence is supported.
+    pub const NONE: EmojiSupport = EmojiSupport {
+        zwj_sequences: false,
+        modifier_sequences: false,
+        flags: false,
+        tag_sequences: false,
+        keycaps: false,
+        emoji_presentation: false,
+        text_presentation: false,
+    };
+
+    /// Returns a copy with support for emoji ZWJ sequences set to `supported`.
+    pub const fn with_zwj_sequences(self, supported: bool) -> Self {
+        EmojiSupport {
+            zwj_sequences: supported,
+            ..self
+        }
+    }
+
+    /// Returns a copy with support for emoji modifier sequences set to `supported`.
+    pub const fn with_modifier_sequences(self, supported: bool) -> Self {
+        EmojiSupport {
+            modifier_sequences: supported,
+            ..self
+        }
+    }
+
+    /// Returns a copy with support for flags set to `supported`.
+    pub const fn with_flags(self, supported: bool) -> Self {
+        EmojiSupport {
+            flags: supported,
+            ..self
+        }
+    }
+
+    /// Returns a copy with support for emoji tag sequences set to `supported`.
+    pub const fn with_tag_sequences(self, supported: bool) -> Self {
+        EmojiSupport {
+            tag_sequences: supported,
+            ..self
+        }
+    }
+
+    /// Returns a copy with support for emoji keycap sequences set to `supported`.
+    pub const fn with_keycaps(self, supported: bool) -> Self {
+        EmojiSupport {
+            keycaps: supported,
+            ..self
+        }
+    }
+
+    /// Returns a copy with support for emoji presentation sequences set to `supported`.
+    pub const fn with_emoji_presentation(self, supported: bool) -> Self {
+        EmojiSupport {
+            emoji_presentation: supported,
+            ..self
+        }
+    }
+
+    /// Returns a copy with support for text presentation sequences set to `supported`.
+    pub const fn with_text_presentation(self, supported: bool) -> Self {
+        EmojiSupport {
+            text_presentation: supported,
+            ..self
+        }
+    }
+
+    /// Like [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width),
+    /// but with this support for emoji sequences.
+    pub fn str_width(&self, s: &str) -> usize {
+        self.str_width_generic::<false>(s)
+    }
+
+    /// Like [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk),
+    /// but with this support for emoji sequences.
+    ///
+    /// Even with [`EmojiSupport::ALL`], text presentation sequences are narrowed,
+    /// as described for `text_presentation` above.
+    #[cfg(feature = "cjk")]
+    pub fn str_width_cjk(&self, s: &str) -> usize {
+        self.str_width_generic::<true>(s)
+    }
+
+    fn str_width_generic<const IS_CJK: bool>(&self, s: &str) -> usize {
+        s.chars()
+            .rfold(
+                (0usize, WidthInfo::DEFAULT, None),
+                |(sum, next_info, next_char), c| {
+                    let (add, info) = self.width_in::<IS_CJK>(c, next_char, next_info);
+                    (sum.wrapping_add_signed(isize::from(add)), info, Some(c))
+                },
+            )
+            .0
+    }
+
+    /// Like [`lookup::width_in_generic`], but leaves out the rules for the
+    /// sequences that aren't supported. `next_char` is the character after `c`.
+    fn width_in<const IS_CJK: bool>(
+        &self,
+        c: char,
+        next_char: Option<char>,
+        next_info: WidthInfo,
+    ) -> (i8, WidthInfo) {
+        match c {
+            // Ignored variation selectors don't break up the sequences around them.
+            '\u{FE0F}' if !self.emoji_presentation => return (0, next_info),
+            '\u{FE0F}' if !self.keycaps && next_char == Some('\u{20E3}') => return (0, next_info),
+            '\u{FE0E}' if !self.text_presentation => return (0, next_info),
+            '\u{20E3}' if !self.keycaps => return (0, WidthInfo::DEFAULT),
+            '\u{1F1E6}'..='\u{1F1FF}' if !self.flags => return (2, WidthInfo::DEFAULT),
+            '\u{1F3FB}'..='\u{1F3FF}' if !self.modifier_sequences => {
+                return (2, WidthInfo::DEFAULT)
+            }
+            '\u{E0020}'..='\u{E007F}' if !self.tag_sequences => return (0, WidthInfo::DEFAULT),
+            _ => {}
+        }
+        if IS_CJK
+            && self.text_presentation
+            && next_char == Some('\u{FE0E}')
+            && starts_non_ideographic_text_presentation_seq(&TABLES, c as u32)
+        {
+            return (1, WidthInfo::DEFAULT);
+        }
+        let (width, info) = lookup::width_in_generic::<IS_CJK>(c, next_info);
+        if c == '\u{200D}' && !self.zwj_sequences && info == WidthInfo::ZWJ_EMOJI_PRESENTATION {
+            return (0, WidthInfo::DEFAULT);
+        }
+        (width, info)
+    }
+}
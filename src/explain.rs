@@ -0,0 +1,371 @@
+// Copyright 2012-2026 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::iter::FusedIterator;
+use core::ops::Range;
+use core::str::CharIndices;
+
+use crate::lookup::{self, FlagBoundaries, Step};
+use crate::width_info::WidthInfo;
+
+/// The rule that determined the width of a character or of a sequence of characters.
+///
+/// The variants correspond to the numbered list in the
+/// [Rules for determining width](crate#rules-for-determining-width) section.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Rule {
+    /// `"\r\n"` has width 1.
+    CarriageReturnLineFeed,
+    /// A well-formed emoji ZWJ sequence has width 2.
+    EmojiZwjSequence,
+    /// An emoji modifier sequence has width 2.
+    EmojiModifierSequence,
+    /// A pair of regional indicators, an emoji flag sequence, has width 2.
+    EmojiFlagSequence,
+    /// An emoji presentation sequence (`'\u{FE0F}'`) has width 2.
+    EmojiPresentationSequence,
+    /// Outside of an East Asian context, a text presentation sequence (`'\u{FE0E}'`) has width 1.
+    TextPresentationSequence,
+    /// A curly quotation mark followed by `'\u{FE00}'`, `'\u{FE01}'`, or `'\u{FE02}'`.
+    QuotationMarkVariationSequence,
+    /// The Arabic Lam-Alef ligature has width 1.
+    ArabicLamAlef,
+    /// The Buginese <a, -i> ya ligature has width 1.
+    BugineseAIYa,
+    /// The Hebrew Alef-ZWJ-Lamed ligature has width 1.
+    HebrewAlefLamed,
+    /// A Khmer coeng sign has width 0.
+    KhmerCoeng,
+    /// A Kirat Rai vowel sign sequence has width 1.
+    KiratRai,
+    /// A Lisu tone letter combination has width 1.
+    LisuToneLetter,
+    /// The Old Turkic Orkhon Ec-ZWJ-Orkhon I ligature has width 1.
+    OldTurkicLigature,
+    /// A Tifinagh bi-consonant has width 1.
+    TifinaghBiconsonant,
+    /// In an East Asian context, `<`, `=`, or `>` followed by `'\u{0338}'` has width 2.
+    SolidusOverlay,
+    /// A control character. These have no width on their own,
+    /// and width 1 inside a string.
+    Control,
+    /// `'\u{2D7F}'`, `'\u{FF9E}'`, and `'\u{FF9F}'` have width 1.
+    NarrowSpecialCase,
+    /// `'\u{115F}'` and `'\u{17A4}'` have width 2.
+    WideSpecialCase,
+    /// `'\u{17D8}'` KHMER SIGN BEYYAL has width 3.
+    KhmerSignBeyyal,
+    /// Default-ignorable characters, `Grapheme_Extend` characters,
+    /// Hangul vowel and trailing jamo, and some prepended marks have width 0.
+    ZeroWidth,
+    /// Characters with an `East_Asian_Width` of `Fullwidth` or `Wide` have width 2.
+    Wide,
+    /// Ambiguous characters have width 2 in an East Asian context, and width 1 otherwise.
+    ///
+    /// Only reported when the `cjk` feature is enabled;
+    /// otherwise these characters are reported as [`Rule::Narrow`].
+    Ambiguous,
+    /// All other characters have width 1.
+    Narrow,
+}
+
+/// The width of a single character, and the rule that determined it.
+/// Returned by [`explain_char`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WidthReason {
+    /// The width of the character, as returned by [`UnicodeWidthChar::width`](crate::UnicodeWidthChar::width).
+    pub width: Option<usize>,
+    /// The rule that determined the width.
+    pub rule: Rule,
+}
+
+/// Returns the width of `c`, like [`UnicodeWidthChar::width`](crate::UnicodeWidthChar::width),
+/// together with the rule that determined it.
+pub fn explain_char(c: char) -> WidthReason {
+    WidthReason {
+        width: lookup::single_char_width(c),
+        rule: char_rule(c),
+    }
+}
+
+/// Returns the width of `c`, like [`UnicodeWidthChar::width_cjk`](crate::UnicodeWidthChar::width_cjk),
+/// together with the rule that determined it.
+#[cfg(feature = "cjk")]
+pub fn explain_char_cjk(c: char) -> WidthReason {
+    WidthReason {
+        width: lookup::single_char_width_cjk(c),
+        rule: char_rule(c),
+    }
+}
+
+fn char_rule(c: char) -> Rule {
+    match c {
+        '\0'..='\x1F' | '\x7F'..='\u{9F}' => Rule::Control,
+        '\u{2D7F}' | '\u{FF9E}' | '\u{FF9F}' => Rule::NarrowSpecialCase,
+        '\u{115F}' | '\u{17A4}' => Rule::WideSpecialCase,
+        '\u{17D8}' => Rule::KhmerSignBeyyal,
+        _ => match lookup::lookup_width(c).0 {
+            0 => Rule::ZeroWidth,
+            #[cfg(feature = "cjk")]
+            1 if lookup::lookup_width_cjk(c).0 == 2 => Rule::Ambiguous,
+            1 => Rule::Narrow,
+            _ => Rule::Wide,
+        },
+    }
+}
+
+/// Splits `s` into the segments that [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width)
+/// measures as a unit, and reports the width of each segment and the rule that determined it.
+///
+/// Each item is a `(byte_range, width, rule)` tuple, from the front of `s` to the back.
+/// The widths of all segments add up to the width of `s`.
+///
+/// ```rust
+/// use unicode_width::{explain_str, Rule};
+///
+/// let segments: Vec<_> = explain_str("a\u{0644}\u{0627}").collect();
+/// assert_eq!(
+///     segments,
+///     [(0..1, 1, Rule::Narrow), (1..5, 1, Rule::ArabicLamAlef)]
+/// );
+/// ```
+pub fn explain_str(s: &str) -> ExplainStr<'_> {
+    ExplainStr {
+        s,
+        pos: 0,
+        checkpoints: [(0, WidthInfo::DEFAULT); CHECKPOINTS],
+        checkpoints_len: 0,
+        batch: [(0, 0, Rule::Narrow); SEGMENT_BATCH],
+        batch_pos: 0,
+        batch_len: 0,
+        #[cfg(feature = "cjk")]
+        is_cjk: false,
+    }
+}
+
+/// Like [`explain_str`], but measures like [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(feature = "cjk")]
+pub fn explain_str_cjk(s: &str) -> ExplainStr<'_> {
+    ExplainStr {
+        is_cjk: true,
+        ..explain_str(s)
+    }
+}
+
+/// How many segments [`ExplainStr`] keeps track of at a time.
+const SEGMENT_BATCH: usize = 16;
+
+/// How many checkpoints [`ExplainStr`] can stack up. Each one halves the number of
+/// segments left to split, so a string never needs more than one per bit of its length.
+const CHECKPOINTS: usize = usize::BITS as usize;
+
+/// Iterator over the segments of a string, with their widths and rules.
+/// Created by [`explain_str`].
+//
+// Widths are determined from back to front, so the string is cut into pieces
+// at each boundary for which `lookup::is_segment_boundary` holds and between the flags
+// in a run of regional indicators (see `FlagBoundaries`), and each piece
+// is split into segments by `PieceSegments`. When the rest of a piece has more than
+// `SEGMENT_BATCH` segments, it is split in two at its middle segment, and the info
+// the back half leaves off with there is kept as a checkpoint to measure the front
+// half from. Each segment is measured about twice per halving, so a piece of n bytes
+// takes O(n log n) steps rather than O(n²).
+#[derive(Clone, Debug)]
+pub struct ExplainStr<'a> {
+    s: &'a str,
+    /// The start of the next segment.
+    pos: usize,
+    /// The ends of the parts of the current piece that are still to be split,
+    /// back to front, with the info the characters after each end leave off with.
+    checkpoints: [(usize, WidthInfo); CHECKPOINTS],
+    checkpoints_len: usize,
+    /// The ends, widths, and rules of the next few segments, front to back.
+    batch: [(usize, usize, Rule); SEGMENT_BATCH],
+    batch_pos: usize,
+    batch_len: usize,
+    #[cfg(feature = "cjk")]
+    is_cjk: bool,
+}
+
+impl<'a> ExplainStr<'a> {
+    fn is_segment_boundary(&self, c: char, next: char) -> bool {
+        #[cfg(feature = "cjk")]
+        if self.is_cjk {
+            return lookup::is_segment_boundary::<true>(c, next);
+        }
+        lookup::is_segment_boundary::<false>(c, next)
+    }
+
+    fn piece_segments(&self, piece: &'a str, next_info: WidthInfo) -> PieceSegments<'a> {
+        PieceSegments {
+            chars: piece.char_indices(),
+            next_info,
+            last_info: next_info,
+            pending: None,
+            #[cfg(feature = "cjk")]
+            is_cjk: self.is_cjk,
+        }
+    }
+
+    fn push_checkpoint(&mut self, end: usize, info: WidthInfo) {
+        self.checkpoints[self.checkpoints_len] = (end, info);
+        self.checkpoints_len += 1;
+    }
+
+    fn fill_batch(&mut self) {
+        if self.checkpoints_len == 0 {
+            let rest = &self.s[self.pos..];
+            let mut chars = rest.char_indices().peekable();
+            let mut flags = FlagBoundaries::default();
+            let mut end = rest.len();
+            while let (Some((_, c)), Some(&(i, next))) = (chars.next(), chars.peek()) {
+                if flags.is_flag_end(c, next) || self.is_segment_boundary(c, next) {
+                    end = i;
+                    break;
+                }
+            }
+            self.push_checkpoint(self.pos + end, WidthInfo::DEFAULT);
+        }
+        loop {
+            let (end, info) = self.checkpoints[self.checkpoints_len - 1];
+            let part = &self.s[self.pos..end];
+            // The segments come back to front, so the frontmost ones are the last to be kept.
+            let mut last = [(0, 0, Rule::Narrow); SEGMENT_BATCH];
+            let mut count = 0;
+            for (range, width, rule) in self.piece_segments(part, info) {
+                last[count % SEGMENT_BATCH] = (self.pos + range.end, width, rule);
+                count += 1;
+            }
+            if count <= SEGMENT_BATCH {
+                self.checkpoints_len -= 1;
+                self.batch_len = count;
+                for (i, slot) in self.batch[..count].iter_mut().enumerate() {
+                    *slot = last[count - 1 - i];
+                }
+                self.batch_pos = 0;
+                return;
+            }
+            let mut segments = self.piece_segments(part, info);
+            let middle = segments
+                .nth(count / 2 - 1)
+                .map_or(0, |(range, ..)| range.start);
+            self.push_checkpoint(self.pos + middle, segments.last_info);
+        }
+    }
+}
+
+impl Iterator for ExplainStr<'_> {
+    type Item = (Range<usize>, usize, Rule);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.batch_pos == self.batch_len {
+            if self.pos == self.s.len() {
+                return None;
+            }
+            self.fill_batch();
+        }
+        let (end, width, rule) = self.batch[self.batch_pos];
+        self.batch_pos += 1;
+        let start = self.pos;
+        self.pos = end;
+        Some((start..self.pos, width, rule))
+    }
+}
+
+impl FusedIterator for ExplainStr<'_> {}
+
+/// Iterator over the segments of a piece of a string from back to front,
+/// which is the order their widths are determined in.
+#[derive(Clone, Debug)]
+struct PieceSegments<'a> {
+    chars: CharIndices<'a>,
+    next_info: WidthInfo,
+    /// The info that the characters from the start of the last segment returned leave off with.
+    last_info: WidthInfo,
+    pending: Option<(Range<usize>, isize, Rule)>,
+    #[cfg(feature = "cjk")]
+    is_cjk: bool,
+}
+
+impl PieceSegments<'_> {
+    fn step(&self, c: char) -> (i8, WidthInfo, Step) {
+        #[cfg(feature = "cjk")]
+        if self.is_cjk {
+            return lookup::width_step_in_generic::<true>(c, self.next_info);
+        }
+        lookup::width_step_in_generic::<false>(c, self.next_info)
+    }
+}
+
+impl Iterator for PieceSegments<'_> {
+    type Item = (Range<usize>, usize, Rule);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((start, c)) = self.chars.next_back() {
+            let (width, info, step) = self.step(c);
+            let next_info = core::mem::replace(&mut self.next_info, info);
+            match (step, &mut self.pending) {
+                (Step::Absorbed, Some((range, sum, _))) => {
+                    range.start = start;
+                    *sum += isize::from(width);
+                }
+                (Step::Rule(rule), Some((range, sum, seg_rule))) => {
+                    range.start = start;
+                    *sum += isize::from(width);
+                    *seg_rule = rule;
+                }
+                _ => {
+                    let seg = (
+                        start..start + c.len_utf8(),
+                        isize::from(width),
+                        char_rule(c),
+                    );
+                    if let Some(done) = self.pending.replace(seg) {
+                        self.last_info = next_info;
+                        return Some(finish(done));
+                    }
+                }
+            }
+        }
+        self.pending.take().map(finish)
+    }
+}
+
+fn finish((range, width, rule): (Range<usize>, isize, Rule)) -> (Range<usize>, usize, Rule) {
+    // Sequences only ever take back width that was added earlier in the same sequence,
+    // so a whole segment never has negative width.
+    debug_assert!(width >= 0, "segment {range:?} has negative width {width}");
+    (range, width as usize, rule)
+}
+
+/// Iterator over the segments of a string from front to back,
+/// as `(byte_range, width)` pairs. Used by the types that lay text out on a screen.
+#[derive(Clone, Debug)]
+pub(crate) struct Segments<'a>(ExplainStr<'a>);
+
+impl<'a> Segments<'a> {
+    pub(crate) fn new(s: &'a str) -> Self {
+        Segments(explain_str(s))
+    }
+
+    #[cfg(feature = "cjk")]
+    pub(crate) fn new_cjk(s: &'a str) -> Self {
+        Segments(explain_str_cjk(s))
+    }
+}
+
+impl Iterator for Segments<'_> {
+    type Item = (Range<usize>, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(range, width, _)| (range, width))
+    }
+}
@@ -0,0 +1,296 @@
+// Copyright 2012-2026 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Characters whose width comes from a fallback rule rather than from the character itself.
+//!
+//! Three kinds of code points have no width of their own in the tables:
+//!
+//! - Unassigned code points take the default [`East_Asian_Width`] of the range they are in.
+//!   They have width 2 in the blocks and planes reserved for wide characters (such as
+//!   planes 2 and 3), width 0 in the parts of plane 14 reserved for default-ignorable
+//!   characters, and width 1 everywhere else. A terminal whose Unicode data is older
+//!   than this crate's treats the characters that have since been assigned there the same way.
+//! - Noncharacters (`'\u{FDD0}'..='\u{FDEF}'`, and the last two code points of each plane)
+//!   are unassigned code points that are never meant to be exchanged as text.
+//! - Private-use characters have an [`East_Asian_Width`] of [`Ambiguous`], so they have width 1,
+//!   or 2 in an East Asian context. What they actually look like is up to the font.
+//!
+//! A [`Policy`] measures text with other widths for any of these, and [`fallbacks`] finds them.
+//!
+//! ```rust
+//! use unicode_width::fallback::{fallbacks, Fallback, Policy};
+//!
+//! let s = "a\u{E000}\u{378}";
+//! assert_eq!(
+//!     fallbacks(s).collect::<Vec<_>>(),
+//!     [(1, '\u{E000}', Fallback::PrivateUse), (4, '\u{378}', Fallback::Unassigned)]
+//! );
+//!
+//! let policy = Policy {
+//!     private_use: Some(2),
+//!     unassigned: Some(0),
+//!     ..Policy::DEFAULT
+//! };
+//! assert_eq!(policy.str_width(s), 3);
+//!
+//! // The CJK mode has its own widths for private-use characters and unassigned code points.
+//! # #[cfg(feature = "cjk")]
+//! # {
+//! assert_eq!(policy.str_width_cjk(s), 4);
+//! let policy = Policy {
+//!     private_use_cjk: Some(1),
+//!     ..policy
+//! };
+//! assert_eq!(policy.str_width_cjk(s), 3);
+//! # }
+//! ```
+//!
+//! [`East_Asian_Width`]: https://www.unicode.org/reports/tr11/#ED1
+//! [`Ambiguous`]: https://www.unicode.org/reports/tr11/#ED6
+
+use core::iter::FusedIterator;
+use core::str::CharIndices;
+
+use crate::explain::Segments;
+use crate::lookup;
+use crate::tables::UNASSIGNED_BMP;
+#[cfg(not(feature = "bmp-only"))]
+use crate::tables::UNASSIGNED_SUPPLEMENTARY;
+
+/// The fallback rule that gives a character its width.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Fallback {
+    /// An unassigned code point, other than a noncharacter.
+    Unassigned,
+    /// A noncharacter.
+    Noncharacter,
+    /// A private-use character.
+    PrivateUse,
+}
+
+impl Fallback {
+    /// Returns the fallback rule that gives `c` its width,
+    /// or `None` if `c` is an assigned character that is not private-use.
+    pub fn of(c: char) -> Option<Fallback> {
+        if is_noncharacter(c) {
+            Some(Fallback::Noncharacter)
+        } else if is_private_use(c) {
+            Some(Fallback::PrivateUse)
+        } else if !is_assigned(c) {
+            Some(Fallback::Unassigned)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns whether `c` has been assigned a meaning by the version of Unicode
+/// in [`UNICODE_VERSION`](crate::UNICODE_VERSION), that is, whether its
+/// `General_Category` is anything but `Unassigned`.
+///
+/// Private-use characters are assigned, and noncharacters are not.
+///
+/// With the `"bmp-only"` feature, there is no table for the supplementary planes,
+/// and every code point outside of the BMP is taken to be assigned,
+/// other than the noncharacters.
+///
+/// ```rust
+/// use unicode_width::fallback::is_assigned;
+///
+/// assert!(is_assigned('a'));
+/// assert!(is_assigned('\u{E000}'));
+/// assert!(!is_assigned('\u{378}'));
+/// assert!(!is_assigned('\u{FFFF}'));
+/// ```
+pub fn is_assigned(c: char) -> bool {
+    let cp = c as u32;
+    match u16::try_from(cp) {
+        Ok(cp) => !ranges_contain(&UNASSIGNED_BMP, cp),
+        #[cfg(not(feature = "bmp-only"))]
+        Err(_) => !ranges_contain(&UNASSIGNED_SUPPLEMENTARY, cp),
+        #[cfg(feature = "bmp-only")]
+        Err(_) => !is_noncharacter(c),
+    }
+}
+
+/// Whether one of the sorted, inclusive `ranges` contains `value`.
+fn ranges_contain<T: Ord>(ranges: &[(T, T)], value: T) -> bool {
+    ranges
+        .binary_search_by(|(lo, hi)| {
+            if *hi < value {
+                core::cmp::Ordering::Less
+            } else if *lo > value {
+                core::cmp::Ordering::Greater
+            } else {
+                core::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Returns whether `c` is a noncharacter: one of `'\u{FDD0}'..='\u{FDEF}'`,
+/// or one of the last two code points of a plane, such as `'\u{FFFE}'` and `'\u{FFFF}'`.
+pub const fn is_noncharacter(c: char) -> bool {
+    matches!(c, '\u{FDD0}'..='\u{FDEF}') || (c as u32) & 0xFFFE == 0xFFFE
+}
+
+/// Returns whether `c` is a private-use character, from the Private Use Area
+/// or one of the Supplementary Private Use Areas.
+pub const fn is_private_use(c: char) -> bool {
+    matches!(
+        c,
+        '\u{E000}'..='\u{F8FF}' | '\u{F0000}'..='\u{FFFFD}' | '\u{100000}'..='\u{10FFFD}'
+    )
+}
+
+/// Widths to give the characters that fall under each [`Fallback`] rule,
+/// in place of the widths from the tables.
+///
+/// `None` keeps the width from the tables. With the `"cjk"` feature, unassigned code points
+/// and private-use characters have their own widths for the CJK mode, as the tables give
+/// many of them a different width there. Noncharacters have the same width in both modes.
+/// The policy applies to each such character that is measured on its own,
+/// with any zero-width characters after it. In the rare cases where one is part
+/// of a sequence, such as an unassigned code point in an emoji block after a
+/// `'\u{200D}'` ZERO WIDTH JOINER, the rules for the sequence still apply.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Policy {
+    /// The width of unassigned code points other than noncharacters.
+    pub unassigned: Option<usize>,
+    /// The width of unassigned code points other than noncharacters, in the CJK mode.
+    ///
+    /// Only available with the `"cjk"` feature.
+    #[cfg(feature = "cjk")]
+    pub unassigned_cjk: Option<usize>,
+    /// The width of noncharacters, in both modes.
+    pub noncharacter: Option<usize>,
+    /// The width of private-use characters.
+    pub private_use: Option<usize>,
+    /// The width of private-use characters, in the CJK mode.
+    ///
+    /// Only available with the `"cjk"` feature.
+    #[cfg(feature = "cjk")]
+    pub private_use_cjk: Option<usize>,
+}
+
+impl Policy {
+    /// Keeps the widths from the tables for everything.
+    pub const DEFAULT: Policy = Policy {
+        unassigned: None,
+        #[cfg(feature = "cjk")]
+        unassigned_cjk: None,
+        noncharacter: None,
+        private_use: None,
+        #[cfg(feature = "cjk")]
+        private_use_cjk: None,
+    };
+
+    /// Returns the width that this policy gives to characters under `fallback`,
+    /// if it overrides the tables for them.
+    pub const fn width_of(&self, fallback: Fallback) -> Option<usize> {
+        match fallback {
+            Fallback::Unassigned => self.unassigned,
+            Fallback::Noncharacter => self.noncharacter,
+            Fallback::PrivateUse => self.private_use,
+        }
+    }
+
+    /// Like [`width_of`](Policy::width_of), but for the CJK mode.
+    #[cfg(feature = "cjk")]
+    pub const fn width_of_cjk(&self, fallback: Fallback) -> Option<usize> {
+        match fallback {
+            Fallback::Unassigned => self.unassigned_cjk,
+            Fallback::Noncharacter => self.noncharacter,
+            Fallback::PrivateUse => self.private_use_cjk,
+        }
+    }
+
+    fn override_for<const IS_CJK: bool>(&self, c: char) -> Option<usize> {
+        if *self == Policy::DEFAULT {
+            return None;
+        }
+        let fallback = Fallback::of(c)?;
+        #[cfg(feature = "cjk")]
+        if IS_CJK {
+            return self.width_of_cjk(fallback);
+        }
+        self.width_of(fallback)
+    }
+
+    /// Like [`UnicodeWidthChar::width`](crate::UnicodeWidthChar::width), but with this policy.
+    pub fn char_width(&self, c: char) -> Option<usize> {
+        self.override_for::<false>(c)
+            .or_else(|| lookup::single_char_width(c))
+    }
+
+    /// Like [`UnicodeWidthChar::width_cjk`](crate::UnicodeWidthChar::width_cjk), but with this policy.
+    #[cfg(feature = "cjk")]
+    pub fn char_width_cjk(&self, c: char) -> Option<usize> {
+        self.override_for::<true>(c)
+            .or_else(|| lookup::single_char_width_cjk(c))
+    }
+
+    /// Like [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width), but with this policy.
+    pub fn str_width(&self, s: &str) -> usize {
+        if *self == Policy::DEFAULT {
+            return lookup::str_slice_width::<false>(s);
+        }
+        self.segments_width::<false>(s, Segments::new(s))
+    }
+
+    /// Like [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk), but with this policy.
+    #[cfg(feature = "cjk")]
+    pub fn str_width_cjk(&self, s: &str) -> usize {
+        if *self == Policy::DEFAULT {
+            return lookup::str_slice_width::<true>(s);
+        }
+        self.segments_width::<true>(s, Segments::new_cjk(s))
+    }
+
+    fn segments_width<const IS_CJK: bool>(&self, s: &str, segments: Segments<'_>) -> usize {
+        segments
+            .map(|(range, width)| {
+                let mut chars = s[range].chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => self.override_for::<IS_CJK>(c).unwrap_or(width),
+                    _ => width,
+                }
+            })
+            .fold(0, usize::wrapping_add)
+    }
+}
+
+/// Iterator over the characters of a string that fall under a [`Fallback`] rule,
+/// returned by [`fallbacks`].
+#[derive(Clone, Debug)]
+pub struct Fallbacks<'a> {
+    chars: CharIndices<'a>,
+}
+
+impl Iterator for Fallbacks<'_> {
+    type Item = (usize, char, Fallback);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chars.find_map(|(i, c)| Some((i, c, Fallback::of(c)?)))
+    }
+}
+
+impl FusedIterator for Fallbacks<'_> {}
+
+/// Returns an iterator over the characters of `s` that fall under a [`Fallback`] rule,
+/// as `(byte_index, char, fallback)` triples.
+///
+/// These are the characters whose width may differ from one terminal to the next
+/// for want of any width of their own, or that may be worth rejecting from stored text.
+pub fn fallbacks(s: &str) -> Fallbacks<'_> {
+    Fallbacks {
+        chars: s.char_indices(),
+    }
+}
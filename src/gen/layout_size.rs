@@ -0,0 +1,307 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// NOTE: The following code was generated by "scripts/unicode.py", do not edit directly
+#[cfg(not(feature = "no-emoji-sequences"))]
+use crate::tables::*;
+
+/// Returns the entry of the last run in `runs` that starts at or before `cp`.
+/// The first run always starts at U+0000.
+#[inline]
+const fn run_entry(runs: &[u32], cp: u32) -> u8 {
+    let (mut lo, mut hi) = (0, runs.len());
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if runs[mid] >> 5 <= cp {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    (runs[lo] & 0x1F) as u8
+}
+
+/// Returns the table entry for `cp`: its width in the low 2 bits
+/// (3 if it needs special handling), and its property bits in the high 2 bits.
+///
+/// The entry is found by binary searching a list of runs of codepoints with the same entry.
+#[inline]
+pub(crate) const fn lookup_entry(tables: &Tables, cp: u32) -> u8 {
+    run_entry(tables.width_runs, cp)
+}
+
+/// Like [`lookup_entry`], but for CJK widths.
+/// Where `WIDTH_RUNS_CJK` has no entry of its own, the non-CJK entry applies.
+#[cfg(feature = "cjk")]
+#[inline]
+pub(crate) const fn lookup_entry_cjk(tables: &Tables, cp: u32) -> u8 {
+    match run_entry(tables.width_runs_cjk, cp) {
+        0x10 => lookup_entry(tables, cp),
+        entry => entry,
+    }
+}
+
+/// Returns the property bits of `cp` that the width state machine consults.
+#[inline]
+pub(crate) const fn lookup_props(tables: &Tables, cp: u32) -> u8 {
+    lookup_entry(tables, cp) >> 2
+}
+
+/// The tables read by [`lookup_entry`], [`lookup_props`] and the generated property functions.
+///
+/// A `const fn` can't read a `static`, so the lookups take the tables as a parameter:
+/// [`TABLES`] at runtime, and [`CONST_TABLES`] in `const fn`s.
+pub(crate) struct Tables {
+    pub(crate) width_runs: &'static [u32; 1230],
+    #[cfg(feature = "cjk")]
+    pub(crate) width_runs_cjk: &'static [u32; 253],
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    pub(crate) emoji_presentation_leaves:
+        &'static Align128<[[u8; 128]; EMOJI_PRESENTATION_LEAVES_LEN]>,
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    pub(crate) text_presentation_leaves:
+        &'static [&'static [(u8, u8)]; TEXT_PRESENTATION_LEAVES_LEN],
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    pub(crate) emoji_modifier_leaves: &'static [&'static [(u8, u8)]; EMOJI_MODIFIER_LEAVES_LEN],
+}
+
+/// The `static` tables, for lookups at runtime.
+pub(crate) static TABLES: Tables = Tables {
+    width_runs: &WIDTH_RUNS,
+    #[cfg(feature = "cjk")]
+    width_runs_cjk: &WIDTH_RUNS_CJK,
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    emoji_presentation_leaves: &EMOJI_PRESENTATION_LEAVES,
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    text_presentation_leaves: &TEXT_PRESENTATION_LEAVES,
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    emoji_modifier_leaves: &EMOJI_MODIFIER_LEAVES,
+};
+
+/// The `const` copies of the tables, for `const fn`s.
+pub(crate) const CONST_TABLES: Tables = Tables {
+    width_runs: &WIDTH_RUNS_CONST,
+    #[cfg(feature = "cjk")]
+    width_runs_cjk: &WIDTH_RUNS_CJK_CONST,
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    emoji_presentation_leaves: &EMOJI_PRESENTATION_LEAVES_CONST,
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    text_presentation_leaves: &TEXT_PRESENTATION_LEAVES_CONST,
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    emoji_modifier_leaves: &EMOJI_MODIFIER_LEAVES_CONST,
+};
+
+/// Autogenerated. 1230 run(s), each stored as `first_codepoint << 5 | entry`.
+/// Consult [`lookup_entry`] for layout info.
+#[rustfmt::skip]
+pub(crate) static WIDTH_RUNS: [u32; 1230] = WIDTH_RUNS_CONST;
+
+/// [`WIDTH_RUNS`], for `const fn`s, which can't read statics.
+#[rustfmt::skip]
+pub(crate) const WIDTH_RUNS_CONST: [u32; 1230] = [
+    0x00000001, 0x00000143, 0x00000161, 0x000015A4, 0x000015C1, 0x0000600C, 0x00006684, 0x0000672C,
+    0x00006E01, 0x0000906C, 0x00009104, 0x00009141, 0x0000B22C, 0x0000B7C1, 0x0000B7EC, 0x0000B801,
+    0x0000B82C, 0x0000B861, 0x0000B88C, 0x0000B8C1, 0x0000B8EC, 0x0000B901, 0x0000BB83, 0x0000BBA1,
+    0x0000C0A0, 0x0000C0C1, 0x0000C20C, 0x0000C361, 0x0000C384, 0x0000C3A1, 0x0000C443, 0x0000C481,
+    0x0000C4A3, 0x0000C4C1, 0x0000C4E3, 0x0000C501, 0x0000C96C, 0x0000CC01, 0x0000CE0C, 0x0000CE23,
+    0x0000CE81, 0x0000CEA3, 0x0000CEC1, 0x0000DACC, 0x0000DBA1, 0x0000DBEC, 0x0000DCA1, 0x0000DCEC,
+    0x0000DD21, 0x0000DD4C, 0x0000DDC1, 0x0000E1E4, 0x0000E201, 0x0000E22C, 0x0000E241, 0x0000E60C,
+    0x0000E961, 0x0000EE63, 0x0000EEA1, 0x0000F4C4, 0x0000F621, 0x0000FD6C, 0x0000FE81, 0x0000FFAC,
+    0x0000FFC1, 0x000102CC, 0x00010341, 0x0001036C, 0x00010481, 0x000104AC, 0x00010501, 0x0001052C,
+    0x000105C1, 0x00010B2C, 0x00010B81, 0x00010E03, 0x00011061, 0x00011200, 0x00011241, 0x000112EC,
+    0x00011401, 0x0001194C, 0x00011C40, 0x00011C6C, 0x00012004, 0x00012061, 0x00012744, 0x00012761,
+    0x0001278C, 0x000127A1, 0x00012824, 0x00012921, 0x000129AC, 0x000129C1, 0x00012A2C, 0x00012AA4,
+    0x00012B01, 0x00012C44, 0x00012C81, 0x00013024, 0x00013041, 0x0001378C, 0x000137A1, 0x000137C0,
+    0x000137E1, 0x00013824, 0x000138A1, 0x000139AC, 0x000139C1, 0x00013AE0, 0x00013B01, 0x00013C44,
+    0x00013C81, 0x00013FCC, 0x00013FE1, 0x00014024, 0x00014061, 0x0001478C, 0x000147A1, 0x00014824,
+    0x00014861, 0x000148E4, 0x00014921, 0x00014964, 0x000149AC, 0x000149C1, 0x00014A24, 0x00014A41,
+    0x00014E04, 0x00014E41, 0x00014EA4, 0x00014EC1, 0x00015024, 0x00015061, 0x0001578C, 0x000157A1,
+    0x00015824, 0x000158C1, 0x000158E4, 0x00015921, 0x000159AC, 0x000159C1, 0x00015C44, 0x00015C81,
+    0x00015F44, 0x00016001, 0x00016024, 0x00016041, 0x0001678C, 0x000167A1, 0x000167C0, 0x000167E4,
+    0x00016801, 0x00016824, 0x000168A1, 0x000169AC, 0x000169C1, 0x00016AA4, 0x00016AE0, 0x00016B01,
+    0x00016C44, 0x00016C81, 0x00017044, 0x00017061, 0x000177C0, 0x000177E1, 0x00017804, 0x00017821,
+    0x000179AC, 0x000179C1, 0x00017AE0, 0x00017B01, 0x00018004, 0x00018021, 0x00018084, 0x000180A1,
+    0x0001878C, 0x000187A1, 0x000187C4, 0x00018821, 0x000188C4, 0x00018921, 0x00018944, 0x000189AC,
+    0x000189C1, 0x00018AAC, 0x00018AE1, 0x00018C44, 0x00018C81, 0x00019024, 0x00019041, 0x0001978C,
+    0x000197A1, 0x000197E4, 0x00019800, 0x00019821, 0x00019840, 0x00019861, 0x000198C4, 0x000198E0,
+    0x00019921, 0x00019940, 0x00019984, 0x000199AC, 0x000199C1, 0x00019AA0, 0x00019AE1, 0x00019C44,
+    0x00019C81, 0x0001A004, 0x0001A041, 0x0001A76C, 0x0001A7A1, 0x0001A7C0, 0x0001A7E1, 0x0001A824,
+    0x0001A8A1, 0x0001A9AC, 0x0001A9C0, 0x0001A9E1, 0x0001AAE0, 0x0001AB01, 0x0001AC44, 0x0001AC81,
+    0x0001B024, 0x0001B041, 0x0001B94C, 0x0001B961, 0x0001B9E0, 0x0001BA01, 0x0001BA44, 0x0001BAA1,
+    0x0001BAC4, 0x0001BAE1, 0x0001BBE0, 0x0001BC01, 0x0001C624, 0x0001C641, 0x0001C684, 0x0001C70C,
+    0x0001C761, 0x0001C8E4, 0x0001C90C, 0x0001C984, 0x0001C9E1, 0x0001D624, 0x0001D641, 0x0001D684,
+    0x0001D70C, 0x0001D764, 0x0001D7A1, 0x0001D90C, 0x0001D984, 0x0001D9E1, 0x0001E30C, 0x0001E341,
+    0x0001E6AC, 0x0001E6C1, 0x0001E6EC, 0x0001E701, 0x0001E72C, 0x0001E741, 0x0001EE2C, 0x0001EEC4,
+    0x0001EF4C, 0x0001EFC4, 0x0001EFE1, 0x0001F00C, 0x0001F0A1, 0x0001F0CC, 0x0001F101, 0x0001F1A4,
+    0x0001F301, 0x0001F324, 0x0001F7A1, 0x0001F8CC, 0x0001F8E1, 0x000205A4, 0x00020621, 0x00020644,
+    0x000206EC, 0x00020701, 0x0002072C, 0x00020761, 0x000207A4, 0x000207E1, 0x00020B04, 0x00020B41,
+    0x00020BC4, 0x00020C21, 0x00020E24, 0x00020EA1, 0x00021044, 0x00021061, 0x000210A4, 0x000210E1,
+    0x000211AC, 0x000211C1, 0x000213A4, 0x000213C1, 0x00022002, 0x00022C00, 0x00024001, 0x00026BAC,
+    0x00026C01, 0x0002E244, 0x0002E28C, 0x0002E2A8, 0x0002E2C1, 0x0002E644, 0x0002E688, 0x0002E6A1,
+    0x0002EA44, 0x0002EA81, 0x0002EE44, 0x0002EE81, 0x0002F003, 0x0002F061, 0x0002F083, 0x0002F101,
+    0x0002F123, 0x0002F1A1, 0x0002F1C3, 0x0002F281, 0x0002F2A3, 0x0002F321, 0x0002F363, 0x0002F3C1,
+    0x0002F403, 0x0002F421, 0x0002F443, 0x0002F461, 0x0002F482, 0x0002F4A1, 0x0002F4E3, 0x0002F501,
+    0x0002F563, 0x0002F5A1, 0x0002F5E3, 0x0002F601, 0x0002F68C, 0x0002F6C1, 0x0002F6E4, 0x0002F7C1,
+    0x0002F8C4, 0x0002F8E1, 0x0002F924, 0x0002FA4C, 0x0002FA64, 0x0002FA81, 0x0002FB03, 0x0002FB21,
+    0x0002FBAC, 0x0002FBC1, 0x0003016C, 0x000301C0, 0x000301EC, 0x00030201, 0x000310A4, 0x000310E1,
+    0x0003152C, 0x00031541, 0x00032404, 0x00032461, 0x000324E4, 0x00032521, 0x00032644, 0x00032661,
+    0x0003272C, 0x00032781, 0x00034203, 0x00034221, 0x000342EC, 0x00034321, 0x00034364, 0x00034381,
+    0x00034AC4, 0x00034AE1, 0x00034B04, 0x00034BE1, 0x00034C0C, 0x00034C21, 0x00034C44, 0x00034C61,
+    0x00034CA4, 0x00034DA1, 0x00034E64, 0x00034EAC, 0x00034FA1, 0x00034FEC, 0x00035001, 0x0003560C,
+    0x000357C4, 0x000357EC, 0x00035BC1, 0x00035C0C, 0x00035D81, 0x00036004, 0x00036081, 0x0003668C,
+    0x000366A0, 0x000366C4, 0x00036760, 0x00036784, 0x000367A0, 0x000367C1, 0x00036844, 0x00036860,
+    0x00036888, 0x000368A1, 0x00036D6C, 0x00036E81, 0x00037004, 0x00037041, 0x00037444, 0x000374C1,
+    0x00037504, 0x00037548, 0x0003756C, 0x00037584, 0x000375C1, 0x00037CCC, 0x00037CE1, 0x00037D04,
+    0x00037D41, 0x00037DA4, 0x00037DC1, 0x00037DE4, 0x00037E48, 0x00037E81, 0x00038584, 0x00038681,
+    0x000386C4, 0x000386EC, 0x00038701, 0x00039A0C, 0x00039A61, 0x00039A84, 0x00039AAC, 0x00039C21,
+    0x00039C44, 0x00039D21, 0x00039DAC, 0x00039DC1, 0x00039E8C, 0x00039EA1, 0x00039F0C, 0x00039F41,
+    0x0003B80C, 0x0003C001, 0x00040164, 0x00040180, 0x000401A8, 0x000401C4, 0x00040201, 0x00040544,
+    0x000405E1, 0x00040C04, 0x00040CA0, 0x00040D44, 0x00040E01, 0x00041A0C, 0x00041A44, 0x00041A8C,
+    0x00041B04, 0x00041B6C, 0x00041BA4, 0x00041C2C, 0x00041C44, 0x00041CEC, 0x00041D44, 0x00041D8C,
+    0x00041E21, 0x00046343, 0x00046381, 0x00046522, 0x00046561, 0x00047D23, 0x00047DA1, 0x00047E03,
+    0x00047E21, 0x00047E63, 0x00047E81, 0x0004BFA3, 0x0004BFE1, 0x0004C283, 0x0004C2C1, 0x0004C602,
+    0x0004C701, 0x0004C903, 0x0004CA81, 0x0004CFE3, 0x0004D001, 0x0004D142, 0x0004D201, 0x0004D263,
+    0x0004D281, 0x0004D423, 0x0004D441, 0x0004D543, 0x0004D581, 0x0004D7A3, 0x0004D7E1, 0x0004D883,
+    0x0004D8C1, 0x0004D9C3, 0x0004D9E1, 0x0004DA83, 0x0004DAA1, 0x0004DD43, 0x0004DD61, 0x0004DE43,
+    0x0004DE81, 0x0004DEA3, 0x0004DEC1, 0x0004DF43, 0x0004DF61, 0x0004DFA3, 0x0004DFC1, 0x0004E0A3,
+    0x0004E0C1, 0x0004E143, 0x0004E181, 0x0004E503, 0x0004E521, 0x0004E983, 0x0004E9A1, 0x0004E9C3,
+    0x0004E9E1, 0x0004EA63, 0x0004EAC1, 0x0004EAE3, 0x0004EB01, 0x0004F2A3, 0x0004F301, 0x0004F603,
+    0x0004F621, 0x0004F7E3, 0x0004F801, 0x00056363, 0x000563A1, 0x00056A03, 0x00056A21, 0x00056AA3,
+    0x00056AC1, 0x00059DEC, 0x00059E41, 0x0005A623, 0x0005ACC1, 0x0005ADE3, 0x0005AE01, 0x0005AFE9,
+    0x0005B001, 0x0005BC0C, 0x0005C001, 0x0005D002, 0x0005D341, 0x0005D362, 0x0005DE81, 0x0005E002,
+    0x0005FAC1, 0x0005FE02, 0x0006054C, 0x000605C8, 0x00060602, 0x000607E1, 0x00060822, 0x000612E1,
+    0x0006132C, 0x00061362, 0x00062001, 0x000620A2, 0x00062601, 0x00062622, 0x00062C80, 0x00062CA2,
+    0x000631E1, 0x00063202, 0x00063CC1, 0x00063DE2, 0x000643E1, 0x00064402, 0x00064901, 0x00064A02,
+    0x001491A1, 0x00149202, 0x001498E1, 0x00149F83, 0x00149FC1, 0x0014CDEC, 0x0014CE04, 0x0014CE61,
+    0x0014CE8C, 0x0014CFC1, 0x0014D3CC, 0x0014D401, 0x0014DE0C, 0x0014DE41, 0x00150044, 0x00150061,
+    0x001500CC, 0x001500E1, 0x00150164, 0x00150181, 0x001504A4, 0x001504E1, 0x0015058C, 0x001505A1,
+    0x0015188C, 0x001518A4, 0x001518C1, 0x00151C0C, 0x00151E41, 0x00151F40, 0x00151F61, 0x00151FE4,
+    0x00152001, 0x001524C4, 0x0015256C, 0x001525C1, 0x001528E4, 0x00152A41, 0x00152A68, 0x00152A81,
+    0x00152C02, 0x00152FA1, 0x00153004, 0x00153061, 0x0015366C, 0x00153681, 0x001536C4, 0x00153741,
+    0x00153784, 0x001537C1, 0x00153808, 0x00153821, 0x00153CA4, 0x00153CC1, 0x00154524, 0x001545E1,
+    0x00154624, 0x00154661, 0x001546A4, 0x001546E1, 0x00154864, 0x00154881, 0x00154984, 0x001549A1,
+    0x00154F84, 0x00154FA1, 0x0015560C, 0x00155621, 0x0015564C, 0x001556A1, 0x001556EC, 0x00155721,
+    0x001557CC, 0x00155801, 0x0015582C, 0x00155841, 0x00155D84, 0x00155DC1, 0x00155ECC, 0x00155EE1,
+    0x00157CA4, 0x00157CC1, 0x00157D04, 0x00157D21, 0x00157DAC, 0x00157DC1, 0x00158002, 0x001AF481,
+    0x001AF600, 0x001AF8E1, 0x001AF960, 0x001AFF81, 0x001F2002, 0x001F6001, 0x001F63CC, 0x001F63E1,
+    0x001FC00C, 0x001FC02F, 0x001FC04C, 0x001FC1CF, 0x001FC202, 0x001FC341, 0x001FC40C, 0x001FC602,
+    0x001FCA61, 0x001FCA82, 0x001FCCE1, 0x001FCD02, 0x001FCD81, 0x001FDFE4, 0x001FE001, 0x001FE022,
+    0x001FEC21, 0x001FF400, 0x001FF421, 0x001FFC02, 0x001FFCE1, 0x001FFE00, 0x001FFF21, 0x00202369,
+    0x00202381, 0x00202D69, 0x00202D81, 0x00203FAC, 0x00203FC1, 0x00205C0C, 0x00205C21, 0x00205EA9,
+    0x00205EC1, 0x00206ECC, 0x00206F61, 0x00213A89, 0x00213AA1, 0x00214024, 0x00214081, 0x002140A4,
+    0x002140E1, 0x00214184, 0x002141AC, 0x002141C4, 0x002141EC, 0x00214201, 0x0021470C, 0x00214724,
+    0x0021474C, 0x00214761, 0x002147EC, 0x00214801, 0x00215CAC, 0x00215CE1, 0x00218063, 0x00218081,
+    0x0021A48C, 0x0021A501, 0x0021AD2C, 0x0021ADC1, 0x0021CE89, 0x0021CEA1, 0x0021D56C, 0x0021D5A1,
+    0x0021DF4C, 0x0021DF84, 0x0021DFAC, 0x0021E001, 0x0021E8CC, 0x0021EA21, 0x0021F04C, 0x0021F0C1,
+    0x00220024, 0x00220041, 0x00220704, 0x002208CC, 0x002208E1, 0x00220E0C, 0x00220E21, 0x00220E64,
+    0x00220EA1, 0x00220FEC, 0x00221004, 0x00221041, 0x00221664, 0x002216E1, 0x0022172C, 0x00221761,
+    0x00221844, 0x00221861, 0x0022200C, 0x00222061, 0x002224E4, 0x00222581, 0x002225A4, 0x0022266C,
+    0x002226A1, 0x00222E6C, 0x00222E81, 0x00223004, 0x00223041, 0x002236C4, 0x002237E1, 0x00223808,
+    0x00223821, 0x00223840, 0x00223881, 0x00223924, 0x0022394C, 0x00223964, 0x002239A1, 0x002239E4,
+    0x00223A01, 0x002245E4, 0x00224641, 0x00224684, 0x002246A8, 0x002246CC, 0x002246E4, 0x00224701,
+    0x002247C4, 0x002247E1, 0x00224824, 0x00224841, 0x00225BE4, 0x00225C01, 0x00225C64, 0x00225D2C,
+    0x00225D61, 0x00226004, 0x00226041, 0x0022676C, 0x002267A1, 0x002267C0, 0x002267E1, 0x00226804,
+    0x00226821, 0x002269A8, 0x002269C1, 0x00226AE0, 0x00226B01, 0x00226CCC, 0x00226DA1, 0x00226E0C,
+    0x00226EA1, 0x00227700, 0x00227721, 0x00227764, 0x00227821, 0x00227840, 0x00227861, 0x002278A0,
+    0x002278C1, 0x002278E0, 0x00227941, 0x002279CC, 0x002279E8, 0x00227A0C, 0x00227A20, 0x00227A44,
+    0x00227A61, 0x00227C24, 0x00227C61, 0x00228704, 0x00228801, 0x0022884C, 0x00228864, 0x002288A1,
+    0x002288CC, 0x002288E1, 0x00228BCC, 0x00228BE1, 0x00229600, 0x00229621, 0x00229664, 0x00229721,
+    0x00229744, 0x00229761, 0x002297A0, 0x002297C1, 0x002297E4, 0x00229821, 0x0022984C, 0x00229881,
+    0x0022B5E0, 0x0022B601, 0x0022B644, 0x0022B6C1, 0x0022B784, 0x0022B7C1, 0x0022B7EC, 0x0022B821,
+    0x0022BB84, 0x0022BBC1, 0x0022C664, 0x0022C761, 0x0022C7A4, 0x0022C7C1, 0x0022C7EC, 0x0022C804,
+    0x0022C821, 0x0022D564, 0x0022D581, 0x0022D5A4, 0x0022D5C1, 0x0022D604, 0x0022D6C8, 0x0022D6EC,
+    0x0022D701, 0x0022E3A4, 0x0022E3C1, 0x0022E3E4, 0x0022E401, 0x0022E444, 0x0022E4C1, 0x0022E4E4,
+    0x0022E56C, 0x0022E581, 0x002305E4, 0x00230701, 0x0023072C, 0x00230761, 0x00232600, 0x00232621,
+    0x00232764, 0x002327A8, 0x002327CC, 0x002327E0, 0x00232801, 0x00232820, 0x00232841, 0x0023286C,
+    0x00232881, 0x00233A84, 0x00233B01, 0x00233B44, 0x00233B81, 0x00233C0C, 0x00233C21, 0x00234024,
+    0x00234161, 0x00234664, 0x0023468C, 0x002346A4, 0x00234721, 0x00234764, 0x002347E1, 0x002348EC,
+    0x00234901, 0x00234A24, 0x00234AE1, 0x00234B24, 0x00234B81, 0x00235080, 0x00235144, 0x002352E1,
+    0x00235304, 0x0023532C, 0x00235341, 0x00236C04, 0x00236C21, 0x00236C44, 0x00236CA1, 0x00236CC4,
+    0x00236CE1, 0x00238604, 0x002386E1, 0x00238704, 0x002387C1, 0x002387EC, 0x00238801, 0x00239244,
+    0x00239501, 0x00239544, 0x00239621, 0x00239644, 0x00239681, 0x002396A4, 0x002396E1, 0x0023A624,
+    0x0023A6E1, 0x0023A744, 0x0023A761, 0x0023A784, 0x0023A7C1, 0x0023A7E4, 0x0023A84C, 0x0023A864,
+    0x0023A88C, 0x0023A8C0, 0x0023A8E4, 0x0023A901, 0x0023B204, 0x0023B241, 0x0023B2A4, 0x0023B2C1,
+    0x0023B2EC, 0x0023B301, 0x0023DE64, 0x0023DEA1, 0x0023E004, 0x0023E040, 0x0023E061, 0x0023E6C4,
+    0x0023E761, 0x0023E804, 0x0023E828, 0x0023E84C, 0x0023E861, 0x0023EB44, 0x0023EB61, 0x00268804,
+    0x00268821, 0x002688E4, 0x00268AC1, 0x002C23C4, 0x002C2541, 0x002C25A4, 0x002C25EC, 0x002C2601,
+    0x002D5E04, 0x002D5EA1, 0x002D660C, 0x002D66E1, 0x002DACE3, 0x002DAD21, 0x002DE9E4, 0x002DEA01,
+    0x002DF1E4, 0x002DF261, 0x002DFC02, 0x002DFC84, 0x002DFCA1, 0x002DFE08, 0x002DFE42, 0x002DFEE1,
+    0x002E0002, 0x00319AC1, 0x00319FE2, 0x0031A3E1, 0x0031B002, 0x0031BE61, 0x0035FE02, 0x0035FE81,
+    0x0035FEA2, 0x0035FF81, 0x0035FFA2, 0x0035FFE1, 0x00360002, 0x00362461, 0x00362642, 0x00362661,
+    0x00362A02, 0x00362A61, 0x00362AA2, 0x00362AC1, 0x00362C82, 0x00362D01, 0x00362E02, 0x00365F81,
+    0x003793A4, 0x003793E1, 0x00379404, 0x00379481, 0x0039E004, 0x0039E5C1, 0x0039E604, 0x0039E8E1,
+    0x003A2CA8, 0x003A2CE4, 0x003A2D41, 0x003A2DA8, 0x003A2E64, 0x003A2F6C, 0x003A3061, 0x003A30AC,
+    0x003A3181, 0x003A354C, 0x003A35C1, 0x003A484C, 0x003A48A1, 0x003A6002, 0x003A6AE1, 0x003A6C02,
+    0x003A6EE1, 0x003B4004, 0x003B46E1, 0x003B4764, 0x003B4DA1, 0x003B4EA4, 0x003B4EC1, 0x003B5084,
+    0x003B50A1, 0x003B5364, 0x003B5401, 0x003B5424, 0x003B5601, 0x003C000C, 0x003C00E1, 0x003C010C,
+    0x003C0321, 0x003C036C, 0x003C0441, 0x003C046C, 0x003C04A1, 0x003C04CC, 0x003C0561, 0x003C11EC,
+    0x003C1201, 0x003C260C, 0x003C26E1, 0x003C55CC, 0x003C55E1, 0x003C5D8C, 0x003C5E01, 0x003C9D8C,
+    0x003C9E01, 0x003CBDCC, 0x003CBE01, 0x003CDC6C, 0x003CDC81, 0x003CDCCC, 0x003CDCE1, 0x003CDDCC,
+    0x003CDE01, 0x003CDEAC, 0x003CDEC1, 0x003D1A0C, 0x003D1AE1, 0x003D288C, 0x003D2961, 0x003D90A9,
+    0x003D90C1, 0x003DA2A9, 0x003DA2C1, 0x003E0083, 0x003E00A1, 0x003E19E3, 0x003E1A01, 0x003E31C3,
+    0x003E31E1, 0x003E3223, 0x003E3361, 0x003E3CC3, 0x003E4002, 0x003E4023, 0x003E4042, 0x003E4061,
+    0x003E4202, 0x003E4343, 0x003E4362, 0x003E45E3, 0x003E4602, 0x003E4643, 0x003E46E2, 0x003E4703,
+    0x003E4762, 0x003E4781, 0x003E4802, 0x003E4921, 0x003E4A03, 0x003E4A41, 0x003E4C02, 0x003E4CC1,
+    0x003E6003, 0x003E6421, 0x003E65A3, 0x003E66C1, 0x003E66E3, 0x003E6FA1, 0x003E6FC3, 0x003E7281,
+    0x003E7403, 0x003E7961, 0x003E79E3, 0x003E7A81, 0x003E7C03, 0x003E7E21, 0x003E7E83, 0x003E7EA1,
+    0x003E7F03, 0x003E87E1, 0x003E8803, 0x003E8821, 0x003E8843, 0x003E9FA1, 0x003E9FE3, 0x003EA7C1,
+    0x003EA963, 0x003EA9E1, 0x003EAA03, 0x003EAD01, 0x003EAF43, 0x003EAF61, 0x003EB2A3, 0x003EB2E1,
+    0x003EB483, 0x003EB4A1, 0x003EBF63, 0x003ECA01, 0x003ED003, 0x003ED8C1, 0x003ED983, 0x003ED9A1,
+    0x003EDA03, 0x003EDA61, 0x003EDAA3, 0x003EDB21, 0x003EDB83, 0x003EDC01, 0x003EDD63, 0x003EDDA1,
+    0x003EDE83, 0x003EDFA1, 0x003EFC03, 0x003EFD81, 0x003EFE03, 0x003EFE21, 0x003F2183, 0x003F2761,
+    0x003F2783, 0x003F28C1, 0x003F28E3, 0x003F4001, 0x003F4E03, 0x003F4FA1, 0x003F5003, 0x003F5161,
+    0x003F51C3, 0x003F58E1, 0x003F5903, 0x003F5921, 0x003F59A3, 0x003F5BA1, 0x003F5BE3, 0x003F5D61,
+    0x003F5DE3, 0x003F5F21, 0x00400002, 0x005FFFC1, 0x00600002, 0x007FFFC1, 0x01C00000, 0x01C00024,
+    0x01C00040, 0x01C00404, 0x01C01000, 0x01C0200C, 0x01C03E00, 0x01C20001,
+];
+
+/// Autogenerated. 253 run(s), each stored as `first_codepoint << 5 | entry`.
+/// Consult [`lookup_entry`] for layout info.
+#[cfg(feature = "cjk")]
+#[rustfmt::skip]
+pub(crate) static WIDTH_RUNS_CJK: [u32; 253] = WIDTH_RUNS_CJK_CONST;
+
+/// [`WIDTH_RUNS_CJK`], for `const fn`s, which can't read statics.
+#[cfg(feature = "cjk")]
+#[rustfmt::skip]
+pub(crate) const WIDTH_RUNS_CJK_CONST: [u32; 253] = [
+    0x00000010, 0x00001422, 0x00001450, 0x00001482, 0x000014B0, 0x000014E2, 0x00001510, 0x000015C2,
+    0x000015F0, 0x00001602, 0x00001690, 0x000016C2, 0x00001710, 0x00001722, 0x00001750, 0x00001782,
+    0x00001810, 0x00001AE2, 0x00001B10, 0x00001EE2, 0x00001F10, 0x00006707, 0x00006730, 0x000070E2,
+    0x00007110, 0x00040202, 0x00040230, 0x00040262, 0x000402F0, 0x00040302, 0x00040350, 0x00040382,
+    0x000403D0, 0x00040402, 0x00040470, 0x00040482, 0x00040510, 0x00040602, 0x00040630, 0x00040642,
+    0x00040690, 0x000406A2, 0x000406D0, 0x00040762, 0x00040790, 0x000407C2, 0x000407F0, 0x00040E82,
+    0x00040EB0, 0x00041022, 0x000410B0, 0x00041582, 0x000415B0, 0x00042062, 0x00042090, 0x000420A2,
+    0x000420D0, 0x00042122, 0x00042150, 0x000422C2, 0x000422F0, 0x00042422, 0x00042470, 0x00042A02,
+    0x00042BF0, 0x00042C02, 0x00042D90, 0x00042E02, 0x00042F50, 0x00043122, 0x00043150, 0x00043202,
+    0x00043390, 0x000435C2, 0x000435F0, 0x00043702, 0x00043750, 0x000439C2, 0x00043A10, 0x00043A42,
+    0x00043A70, 0x00043A82, 0x00043AB0, 0x00043CE2, 0x00043D10, 0x00044002, 0x00044030, 0x00044042,
+    0x000440B0, 0x000440E2, 0x00044150, 0x00044162, 0x000441B0, 0x000441E2, 0x00044210, 0x00044222,
+    0x00044250, 0x000442A2, 0x000442D0, 0x00044342, 0x00044370, 0x000443A2, 0x00044430, 0x00044462,
+    0x000445B0, 0x000445C2, 0x000445F0, 0x00044682, 0x00044710, 0x00044782, 0x000447D0, 0x00044822,
+    0x00044850, 0x00044902, 0x00044950, 0x00044982, 0x000449B0, 0x00044A42, 0x00044A70, 0x00044C02,
+    0x00044C70, 0x00044C82, 0x00044D10, 0x00044D42, 0x00044D90, 0x00044DC2, 0x00044E50, 0x00045042,
+    0x00045150, 0x000452A2, 0x000452D0, 0x00045322, 0x00045350, 0x000454A2, 0x000454D0, 0x000457E2,
+    0x00045810, 0x00046242, 0x00046270, 0x00048C02, 0x0004A990, 0x0004AA02, 0x0004AEB0, 0x0004B002,
+    0x0004B210, 0x0004B242, 0x0004B2D0, 0x0004B402, 0x0004B450, 0x0004B462, 0x0004B550, 0x0004B642,
+    0x0004B690, 0x0004B6C2, 0x0004B710, 0x0004B782, 0x0004B7D0, 0x0004B802, 0x0004B850, 0x0004B8C2,
+    0x0004B930, 0x0004B962, 0x0004B990, 0x0004B9C2, 0x0004BA50, 0x0004BC42, 0x0004BCD0, 0x0004BDE2,
+    0x0004BE10, 0x0004C0A2, 0x0004C0F0, 0x0004C122, 0x0004C150, 0x0004C1C2, 0x0004C210, 0x0004C2C2,
+    0x0004C310, 0x0004C382, 0x0004C3B0, 0x0004C3C2, 0x0004C3F0, 0x0004C802, 0x0004C830, 0x0004C842,
+    0x0004C870, 0x0004CC02, 0x0004CC50, 0x0004CC62, 0x0004CCD0, 0x0004CCE2, 0x0004CD70, 0x0004CD82,
+    0x0004CDD0, 0x0004CDE2, 0x0004CE10, 0x0004D3C2, 0x0004D410, 0x0004D7E2, 0x0004D810, 0x0004D8C2,
+    0x0004D9D0, 0x0004D9E2, 0x0004DA90, 0x0004DAA2, 0x0004DC50, 0x0004DC62, 0x0004DC90, 0x0004DD02,
+    0x0004DD50, 0x0004DD62, 0x0004DE50, 0x0004DE82, 0x0004DEB0, 0x0004DEC2, 0x0004DF50, 0x0004DF62,
+    0x0004DFB0, 0x0004DFC2, 0x0004E010, 0x0004E7A2, 0x0004E7D0, 0x0004EEC2, 0x0004F290, 0x00056AC2,
+    0x00056B50, 0x00064902, 0x00064A10, 0x001C0002, 0x001F2010, 0x001FC00F, 0x001FC02C, 0x001FC04F,
+    0x001FC070, 0x001FC1CC, 0x001FC1F0, 0x001FFFA2, 0x001FFFD0, 0x003E2002, 0x003E21B0, 0x003E2202,
+    0x003E25D0, 0x003E2602, 0x003E2D50, 0x003E2E02, 0x003E31D0, 0x003E31E2, 0x003E3230, 0x003E3362,
+    0x003E35B0, 0x01E00002, 0x01FFFFD0, 0x02000002, 0x021FFFD0,
+];
@@ -0,0 +1,283 @@
+// Copyright 2012-2025 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// NOTE: The following code was generated by "scripts/unicode.py", do not edit directly
+#[cfg(not(feature = "no-emoji-sequences"))]
+use crate::tables::*;
+
+/// Returns the entry of `cp` if it lies outside the Basic Multilingual Plane,
+/// which the `bmp-only` tables don't cover. Every character in a supplementary plane
+/// has the same entry, the one most common in that plane.
+#[inline]
+const fn supplementary_entry(cp: u32, entries: &[u8; 16]) -> Option<u8> {
+    match cp as usize >> 16 {
+        0 => None,
+        plane => Some(entries[plane - 1]),
+    }
+}
+
+/// Autogenerated. The entry of each supplementary plane, from U+1xxxx to U+10xxxx.
+pub(crate) static SUPPLEMENTARY_ENTRIES: [u8; 16] = SUPPLEMENTARY_ENTRIES_CONST;
+
+/// [`SUPPLEMENTARY_ENTRIES`], for `const fn`s, which can't read statics.
+pub(crate) const SUPPLEMENTARY_ENTRIES_CONST: [u8; 16] = [
+    0x01, 0x02, 0x02, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x00, 0x01, 0x01,
+];
+
+/// Autogenerated. The entry of each supplementary plane, from U+1xxxx to U+10xxxx.
+#[cfg(feature = "cjk")]
+pub(crate) static SUPPLEMENTARY_ENTRIES_CJK: [u8; 16] = SUPPLEMENTARY_ENTRIES_CJK_CONST;
+
+/// [`SUPPLEMENTARY_ENTRIES_CJK`], for `const fn`s, which can't read statics.
+#[cfg(feature = "cjk")]
+pub(crate) const SUPPLEMENTARY_ENTRIES_CJK_CONST: [u8; 16] = [
+    0x01, 0x02, 0x02, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x00, 0x02, 0x02,
+];
+
+/// Returns the entry of the last run in `runs` that starts at or before `cp`.
+/// The first run always starts at U+0000.
+#[inline]
+const fn run_entry(runs: &[u32], cp: u32) -> u8 {
+    let (mut lo, mut hi) = (0, runs.len());
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if runs[mid] >> 5 <= cp {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    (runs[lo] & 0x1F) as u8
+}
+
+/// Returns the table entry for `cp`: its width in the low 2 bits
+/// (3 if it needs special handling), and its property bits in the high 2 bits.
+///
+/// The entry is found by binary searching a list of runs of codepoints with the same entry.
+#[inline]
+pub(crate) const fn lookup_entry(tables: &Tables, cp: u32) -> u8 {
+    if let Some(entry) = supplementary_entry(cp, tables.supplementary_entries) {
+        return entry;
+    }
+    run_entry(tables.width_runs, cp)
+}
+
+/// Like [`lookup_entry`], but for CJK widths.
+/// Where `WIDTH_RUNS_CJK` has no entry of its own, the non-CJK entry applies.
+#[cfg(feature = "cjk")]
+#[inline]
+pub(crate) const fn lookup_entry_cjk(tables: &Tables, cp: u32) -> u8 {
+    if let Some(entry) = supplementary_entry(cp, tables.supplementary_entries_cjk) {
+        return entry;
+    }
+    match run_entry(tables.width_runs_cjk, cp) {
+        0x10 => lookup_entry(tables, cp),
+        entry => entry,
+    }
+}
+
+/// Returns the property bits of `cp` that the width state machine consults.
+#[inline]
+pub(crate) const fn lookup_props(tables: &Tables, cp: u32) -> u8 {
+    lookup_entry(tables, cp) >> 2
+}
+
+/// The tables read by [`lookup_entry`], [`lookup_props`] and the generated property functions.
+///
+/// A `const fn` can't read a `static`, so the lookups take the tables as a parameter:
+/// [`TABLES`] at runtime, and [`CONST_TABLES`] in `const fn`s.
+pub(crate) struct Tables {
+    pub(crate) supplementary_entries: &'static [u8; 16],
+    #[cfg(feature = "cjk")]
+    pub(crate) supplementary_entries_cjk: &'static [u8; 16],
+    pub(crate) width_runs: &'static [u32; 703],
+    #[cfg(feature = "cjk")]
+    pub(crate) width_runs_cjk: &'static [u32; 237],
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    pub(crate) emoji_presentation_leaves:
+        &'static Align128<[[u8; 128]; EMOJI_PRESENTATION_LEAVES_LEN]>,
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    pub(crate) text_presentation_leaves:
+        &'static [&'static [(u8, u8)]; TEXT_PRESENTATION_LEAVES_LEN],
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    pub(crate) emoji_modifier_leaves: &'static [&'static [(u8, u8)]; EMOJI_MODIFIER_LEAVES_LEN],
+}
+
+/// The `static` tables, for lookups at runtime.
+pub(crate) static TABLES: Tables = Tables {
+    supplementary_entries: &SUPPLEMENTARY_ENTRIES,
+    #[cfg(feature = "cjk")]
+    supplementary_entries_cjk: &SUPPLEMENTARY_ENTRIES_CJK,
+    width_runs: &WIDTH_RUNS,
+    #[cfg(feature = "cjk")]
+    width_runs_cjk: &WIDTH_RUNS_CJK,
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    emoji_presentation_leaves: &EMOJI_PRESENTATION_LEAVES,
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    text_presentation_leaves: &TEXT_PRESENTATION_LEAVES,
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    emoji_modifier_leaves: &EMOJI_MODIFIER_LEAVES,
+};
+
+/// The `const` copies of the tables, for `const fn`s.
+pub(crate) const CONST_TABLES: Tables = Tables {
+    supplementary_entries: &SUPPLEMENTARY_ENTRIES_CONST,
+    #[cfg(feature = "cjk")]
+    supplementary_entries_cjk: &SUPPLEMENTARY_ENTRIES_CJK_CONST,
+    width_runs: &WIDTH_RUNS_CONST,
+    #[cfg(feature = "cjk")]
+    width_runs_cjk: &WIDTH_RUNS_CJK_CONST,
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    emoji_presentation_leaves: &EMOJI_PRESENTATION_LEAVES_CONST,
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    text_presentation_leaves: &TEXT_PRESENTATION_LEAVES_CONST,
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    emoji_modifier_leaves: &EMOJI_MODIFIER_LEAVES_CONST,
+};
+
+/// Autogenerated. 703 run(s), each stored as `first_codepoint << 5 | entry`.
+/// Consult [`lookup_entry`] for layout info.
+#[rustfmt::skip]
+pub(crate) static WIDTH_RUNS: [u32; 703] = WIDTH_RUNS_CONST;
+
+/// [`WIDTH_RUNS`], for `const fn`s, which can't read statics.
+#[rustfmt::skip]
+pub(crate) const WIDTH_RUNS_CONST: [u32; 703] = [
+    0x00000001, 0x00000143, 0x00000161, 0x000015A4, 0x000015C1, 0x0000600C, 0x00006684, 0x0000672C,
+    0x00006E01, 0x0000906C, 0x00009104, 0x00009141, 0x0000B22C, 0x0000B7C1, 0x0000B7EC, 0x0000B801,
+    0x0000B82C, 0x0000B861, 0x0000B88C, 0x0000B8C1, 0x0000B8EC, 0x0000B901, 0x0000BB83, 0x0000BBA1,
+    0x0000C0A0, 0x0000C0C1, 0x0000C20C, 0x0000C361, 0x0000C384, 0x0000C3A1, 0x0000C443, 0x0000C481,
+    0x0000C4A3, 0x0000C4C1, 0x0000C4E3, 0x0000C501, 0x0000C96C, 0x0000CC01, 0x0000CE0C, 0x0000CE23,
+    0x0000CE81, 0x0000CEA3, 0x0000CEC1, 0x0000DACC, 0x0000DBA1, 0x0000DBEC, 0x0000DCA1, 0x0000DCEC,
+    0x0000DD21, 0x0000DD4C, 0x0000DDC1, 0x0000E1E4, 0x0000E201, 0x0000E22C, 0x0000E241, 0x0000E60C,
+    0x0000E961, 0x0000EE63, 0x0000EEA1, 0x0000F4C4, 0x0000F621, 0x0000FD6C, 0x0000FE81, 0x0000FFAC,
+    0x0000FFC1, 0x000102CC, 0x00010341, 0x0001036C, 0x00010481, 0x000104AC, 0x00010501, 0x0001052C,
+    0x000105C1, 0x00010B2C, 0x00010B81, 0x00010E03, 0x00011061, 0x00011200, 0x00011241, 0x000112EC,
+    0x00011401, 0x0001194C, 0x00011C40, 0x00011C6C, 0x00012004, 0x00012061, 0x00012744, 0x00012761,
+    0x0001278C, 0x000127A1, 0x00012824, 0x00012921, 0x000129AC, 0x000129C1, 0x00012A2C, 0x00012AA4,
+    0x00012B01, 0x00012C44, 0x00012C81, 0x00013024, 0x00013041, 0x0001378C, 0x000137A1, 0x000137C0,
+    0x000137E1, 0x00013824, 0x000138A1, 0x000139AC, 0x000139C1, 0x00013AE0, 0x00013B01, 0x00013C44,
+    0x00013C81, 0x00013FCC, 0x00013FE1, 0x00014024, 0x00014061, 0x0001478C, 0x000147A1, 0x00014824,
+    0x00014861, 0x000148E4, 0x00014921, 0x00014964, 0x000149AC, 0x000149C1, 0x00014A24, 0x00014A41,
+    0x00014E04, 0x00014E41, 0x00014EA4, 0x00014EC1, 0x00015024, 0x00015061, 0x0001578C, 0x000157A1,
+    0x00015824, 0x000158C1, 0x000158E4, 0x00015921, 0x000159AC, 0x000159C1, 0x00015C44, 0x00015C81,
+    0x00015F44, 0x00016001, 0x00016024, 0x00016041, 0x0001678C, 0x000167A1, 0x000167C0, 0x000167E4,
+    0x00016801, 0x00016824, 0x000168A1, 0x000169AC, 0x000169C1, 0x00016AA4, 0x00016AE0, 0x00016B01,
+    0x00016C44, 0x00016C81, 0x00017044, 0x00017061, 0x000177C0, 0x000177E1, 0x00017804, 0x00017821,
+    0x000179AC, 0x000179C1, 0x00017AE0, 0x00017B01, 0x00018004, 0x00018021, 0x00018084, 0x000180A1,
+    0x0001878C, 0x000187A1, 0x000187C4, 0x00018821, 0x000188C4, 0x00018921, 0x00018944, 0x000189AC,
+    0x000189C1, 0x00018AAC, 0x00018AE1, 0x00018C44, 0x00018C81, 0x00019024, 0x00019041, 0x0001978C,
+    0x000197A1, 0x000197E4, 0x00019800, 0x00019821, 0x00019840, 0x00019861, 0x000198C4, 0x000198E0,
+    0x00019921, 0x00019940, 0x00019984, 0x000199AC, 0x000199C1, 0x00019AA0, 0x00019AE1, 0x00019C44,
+    0x00019C81, 0x0001A004, 0x0001A041, 0x0001A76C, 0x0001A7A1, 0x0001A7C0, 0x0001A7E1, 0x0001A824,
+    0x0001A8A1, 0x0001A9AC, 0x0001A9C0, 0x0001A9E1, 0x0001AAE0, 0x0001AB01, 0x0001AC44, 0x0001AC81,
+    0x0001B024, 0x0001B041, 0x0001B94C, 0x0001B961, 0x0001B9E0, 0x0001BA01, 0x0001BA44, 0x0001BAA1,
+    0x0001BAC4, 0x0001BAE1, 0x0001BBE0, 0x0001BC01, 0x0001C624, 0x0001C641, 0x0001C684, 0x0001C70C,
+    0x0001C761, 0x0001C8E4, 0x0001C90C, 0x0001C984, 0x0001C9E1, 0x0001D624, 0x0001D641, 0x0001D684,
+    0x0001D70C, 0x0001D764, 0x0001D7A1, 0x0001D90C, 0x0001D984, 0x0001D9E1, 0x0001E30C, 0x0001E341,
+    0x0001E6AC, 0x0001E6C1, 0x0001E6EC, 0x0001E701, 0x0001E72C, 0x0001E741, 0x0001EE2C, 0x0001EEC4,
+    0x0001EF4C, 0x0001EFC4, 0x0001EFE1, 0x0001F00C, 0x0001F0A1, 0x0001F0CC, 0x0001F101, 0x0001F1A4,
+    0x0001F301, 0x0001F324, 0x0001F7A1, 0x0001F8CC, 0x0001F8E1, 0x000205A4, 0x00020621, 0x00020644,
+    0x000206EC, 0x00020701, 0x0002072C, 0x00020761, 0x000207A4, 0x000207E1, 0x00020B04, 0x00020B41,
+    0x00020BC4, 0x00020C21, 0x00020E24, 0x00020EA1, 0x00021044, 0x00021061, 0x000210A4, 0x000210E1,
+    0x000211AC, 0x000211C1, 0x000213A4, 0x000213C1, 0x00022002, 0x00022C00, 0x00024001, 0x00026BAC,
+    0x00026C01, 0x0002E244, 0x0002E28C, 0x0002E2A8, 0x0002E2C1, 0x0002E644, 0x0002E688, 0x0002E6A1,
+    0x0002EA44, 0x0002EA81, 0x0002EE44, 0x0002EE81, 0x0002F003, 0x0002F061, 0x0002F083, 0x0002F101,
+    0x0002F123, 0x0002F1A1, 0x0002F1C3, 0x0002F281, 0x0002F2A3, 0x0002F321, 0x0002F363, 0x0002F3C1,
+    0x0002F403, 0x0002F421, 0x0002F443, 0x0002F461, 0x0002F482, 0x0002F4A1, 0x0002F4E3, 0x0002F501,
+    0x0002F563, 0x0002F5A1, 0x0002F5E3, 0x0002F601, 0x0002F68C, 0x0002F6C1, 0x0002F6E4, 0x0002F7C1,
+    0x0002F8C4, 0x0002F8E1, 0x0002F924, 0x0002FA4C, 0x0002FA64, 0x0002FA81, 0x0002FB03, 0x0002FB21,
+    0x0002FBAC, 0x0002FBC1, 0x0003016C, 0x000301C0, 0x000301EC, 0x00030201, 0x000310A4, 0x000310E1,
+    0x0003152C, 0x00031541, 0x00032404, 0x00032461, 0x000324E4, 0x00032521, 0x00032644, 0x00032661,
+    0x0003272C, 0x00032781, 0x00034203, 0x00034221, 0x000342EC, 0x00034321, 0x00034364, 0x00034381,
+    0x00034AC4, 0x00034AE1, 0x00034B04, 0x00034BE1, 0x00034C0C, 0x00034C21, 0x00034C44, 0x00034C61,
+    0x00034CA4, 0x00034DA1, 0x00034E64, 0x00034EAC, 0x00034FA1, 0x00034FEC, 0x00035001, 0x0003560C,
+    0x000357C4, 0x000357EC, 0x00035BC1, 0x00035C0C, 0x00035D81, 0x00036004, 0x00036081, 0x0003668C,
+    0x000366A0, 0x000366C4, 0x00036760, 0x00036784, 0x000367A0, 0x000367C1, 0x00036844, 0x00036860,
+    0x00036888, 0x000368A1, 0x00036D6C, 0x00036E81, 0x00037004, 0x00037041, 0x00037444, 0x000374C1,
+    0x00037504, 0x00037548, 0x0003756C, 0x00037584, 0x000375C1, 0x00037CCC, 0x00037CE1, 0x00037D04,
+    0x00037D41, 0x00037DA4, 0x00037DC1, 0x00037DE4, 0x00037E48, 0x00037E81, 0x00038584, 0x00038681,
+    0x000386C4, 0x000386EC, 0x00038701, 0x00039A0C, 0x00039A61, 0x00039A84, 0x00039AAC, 0x00039C21,
+    0x00039C44, 0x00039D21, 0x00039DAC, 0x00039DC1, 0x00039E8C, 0x00039EA1, 0x00039F0C, 0x00039F41,
+    0x0003B80C, 0x0003C001, 0x00040164, 0x00040180, 0x000401A8, 0x000401C4, 0x00040201, 0x00040544,
+    0x000405E1, 0x00040C04, 0x00040CA0, 0x00040D44, 0x00040E01, 0x00041A0C, 0x00041A44, 0x00041A8C,
+    0x00041B04, 0x00041B6C, 0x00041BA4, 0x00041C2C, 0x00041C44, 0x00041CEC, 0x00041D44, 0x00041D8C,
+    0x00041E21, 0x00046343, 0x00046381, 0x00046522, 0x00046561, 0x00047D23, 0x00047DA1, 0x00047E03,
+    0x00047E21, 0x00047E63, 0x00047E81, 0x0004BFA3, 0x0004BFE1, 0x0004C283, 0x0004C2C1, 0x0004C602,
+    0x0004C701, 0x0004C903, 0x0004CA81, 0x0004CFE3, 0x0004D001, 0x0004D142, 0x0004D201, 0x0004D263,
+    0x0004D281, 0x0004D423, 0x0004D441, 0x0004D543, 0x0004D581, 0x0004D7A3, 0x0004D7E1, 0x0004D883,
+    0x0004D8C1, 0x0004D9C3, 0x0004D9E1, 0x0004DA83, 0x0004DAA1, 0x0004DD43, 0x0004DD61, 0x0004DE43,
+    0x0004DE81, 0x0004DEA3, 0x0004DEC1, 0x0004DF43, 0x0004DF61, 0x0004DFA3, 0x0004DFC1, 0x0004E0A3,
+    0x0004E0C1, 0x0004E143, 0x0004E181, 0x0004E503, 0x0004E521, 0x0004E983, 0x0004E9A1, 0x0004E9C3,
+    0x0004E9E1, 0x0004EA63, 0x0004EAC1, 0x0004EAE3, 0x0004EB01, 0x0004F2A3, 0x0004F301, 0x0004F603,
+    0x0004F621, 0x0004F7E3, 0x0004F801, 0x00056363, 0x000563A1, 0x00056A03, 0x00056A21, 0x00056AA3,
+    0x00056AC1, 0x00059DEC, 0x00059E41, 0x0005A623, 0x0005ACC1, 0x0005ADE3, 0x0005AE01, 0x0005AFE9,
+    0x0005B001, 0x0005BC0C, 0x0005C001, 0x0005D002, 0x0005D341, 0x0005D362, 0x0005DE81, 0x0005E002,
+    0x0005FAC1, 0x0005FE02, 0x0006054C, 0x000605C8, 0x00060602, 0x000607E1, 0x00060822, 0x000612E1,
+    0x0006132C, 0x00061362, 0x00062001, 0x000620A2, 0x00062601, 0x00062622, 0x00062C80, 0x00062CA2,
+    0x000631E1, 0x00063202, 0x00063CC1, 0x00063DE2, 0x000643E1, 0x00064402, 0x00064901, 0x00064A02,
+    0x001491A1, 0x00149202, 0x001498E1, 0x00149F83, 0x00149FC1, 0x0014CDEC, 0x0014CE04, 0x0014CE61,
+    0x0014CE8C, 0x0014CFC1, 0x0014D3CC, 0x0014D401, 0x0014DE0C, 0x0014DE41, 0x00150044, 0x00150061,
+    0x001500CC, 0x001500E1, 0x00150164, 0x00150181, 0x001504A4, 0x001504E1, 0x0015058C, 0x001505A1,
+    0x0015188C, 0x001518A4, 0x001518C1, 0x00151C0C, 0x00151E41, 0x00151F40, 0x00151F61, 0x00151FE4,
+    0x00152001, 0x001524C4, 0x0015256C, 0x001525C1, 0x001528E4, 0x00152A41, 0x00152A68, 0x00152A81,
+    0x00152C02, 0x00152FA1, 0x00153004, 0x00153061, 0x0015366C, 0x00153681, 0x001536C4, 0x00153741,
+    0x00153784, 0x001537C1, 0x00153808, 0x00153821, 0x00153CA4, 0x00153CC1, 0x00154524, 0x001545E1,
+    0x00154624, 0x00154661, 0x001546A4, 0x001546E1, 0x00154864, 0x00154881, 0x00154984, 0x001549A1,
+    0x00154F84, 0x00154FA1, 0x0015560C, 0x00155621, 0x0015564C, 0x001556A1, 0x001556EC, 0x00155721,
+    0x001557CC, 0x00155801, 0x0015582C, 0x00155841, 0x00155D84, 0x00155DC1, 0x00155ECC, 0x00155EE1,
+    0x00157CA4, 0x00157CC1, 0x00157D04, 0x00157D21, 0x00157DAC, 0x00157DC1, 0x00158002, 0x001AF481,
+    0x001AF600, 0x001AF8E1, 0x001AF960, 0x001AFF81, 0x001F2002, 0x001F6001, 0x001F63CC, 0x001F63E1,
+    0x001FC00C, 0x001FC02F, 0x001FC04C, 0x001FC1CF, 0x001FC202, 0x001FC341, 0x001FC40C, 0x001FC602,
+    0x001FCA61, 0x001FCA82, 0x001FCCE1, 0x001FCD02, 0x001FCD81, 0x001FDFE4, 0x001FE001, 0x001FE022,
+    0x001FEC21, 0x001FF400, 0x001FF421, 0x001FFC02, 0x001FFCE1, 0x001FFE00, 0x001FFF21,
+];
+
+/// Autogenerated. 237 run(s), each stored as `first_codepoint << 5 | entry`.
+/// Consult [`lookup_entry`] for layout info.
+#[cfg(feature = "cjk")]
+#[rustfmt::skip]
+pub(crate) static WIDTH_RUNS_CJK: [u32; 237] = WIDTH_RUNS_CJK_CONST;
+
+/// [`WIDTH_RUNS_CJK`], for `const fn`s, which can't read statics.
+#[cfg(feature = "cjk")]
+#[rustfmt::skip]
+pub(crate) const WIDTH_RUNS_CJK_CONST: [u32; 237] = [
+    0x00000010, 0x00001422, 0x00001450, 0x00001482, 0x000014B0, 0x000014E2, 0x00001510, 0x000015C2,
+    0x000015F0, 0x00001602, 0x00001690, 0x000016C2, 0x00001710, 0x00001722, 0x00001750, 0x00001782,
+    0x00001810, 0x00001AE2, 0x00001B10, 0x00001EE2, 0x00001F10, 0x00006707, 0x00006730, 0x000070E2,
+    0x00007110, 0x00040202, 0x00040230, 0x00040262, 0x000402F0, 0x00040302, 0x00040350, 0x00040382,
+    0x000403D0, 0x00040402, 0x00040470, 0x00040482, 0x00040510, 0x00040602, 0x00040630, 0x00040642,
+    0x00040690, 0x000406A2, 0x000406D0, 0x00040762, 0x00040790, 0x000407C2, 0x000407F0, 0x00040E82,
+    0x00040EB0, 0x00041022, 0x000410B0, 0x00041582, 0x000415B0, 0x00042062, 0x00042090, 0x000420A2,
+    0x000420D0, 0x00042122, 0x00042150, 0x000422C2, 0x000422F0, 0x00042422, 0x00042470, 0x00042A02,
+    0x00042BF0, 0x00042C02, 0x00042D90, 0x00042E02, 0x00042F50, 0x00043122, 0x00043150, 0x00043202,
+    0x00043390, 0x000435C2, 0x000435F0, 0x00043702, 0x00043750, 0x000439C2, 0x00043A10, 0x00043A42,
+    0x00043A70, 0x00043A82, 0x00043AB0, 0x00043CE2, 0x00043D10, 0x00044002, 0x00044030, 0x00044042,
+    0x000440B0, 0x000440E2, 0x00044150, 0x00044162, 0x000441B0, 0x000441E2, 0x00044210, 0x00044222,
+    0x00044250, 0x000442A2, 0x000442D0, 0x00044342, 0x00044370, 0x000443A2, 0x00044430, 0x00044462,
+    0x000445B0, 0x000445C2, 0x000445F0, 0x00044682, 0x00044710, 0x00044782, 0x000447D0, 0x00044822,
+    0x00044850, 0x00044902, 0x00044950, 0x00044982, 0x000449B0, 0x00044A42, 0x00044A70, 0x00044C02,
+    0x00044C70, 0x00044C82, 0x00044D10, 0x00044D42, 0x00044D90, 0x00044DC2, 0x00044E50, 0x00045042,
+    0x00045150, 0x000452A2, 0x000452D0, 0x00045322, 0x00045350, 0x000454A2, 0x000454D0, 0x000457E2,
+    0x00045810, 0x00046242, 0x00046270, 0x00048C02, 0x0004A990, 0x0004AA02, 0x0004AEB0, 0x0004B002,
+    0x0004B210, 0x0004B242, 0x0004B2D0, 0x0004B402, 0x0004B450, 0x0004B462, 0x0004B550, 0x0004B642,
+    0x0004B690, 0x0004B6C2, 0x0004B710, 0x0004B782, 0x0004B7D0, 0x0004B802, 0x0004B850, 0x0004B8C2,
+    0x0004B930, 0x0004B962, 0x0004B990, 0x0004B9C2, 0x0004BA50, 0x0004BC42, 0x0004BCD0, 0x0004BDE2,
+    0x0004BE10, 0x0004C0A2, 0x0004C0F0, 0x0004C122, 0x0004C150, 0x0004C1C2, 0x0004C210, 0x0004C2C2,
+    0x0004C310, 0x0004C382, 0x0004C3B0, 0x0004C3C2, 0x0004C3F0, 0x0004C802, 0x0004C830, 0x0004C842,
+    0x0004C870, 0x0004CC02, 0x0004CC50, 0x0004CC62, 0x0004CCD0, 0x0004CCE2, 0x0004CD70, 0x0004CD82,
+    0x0004CDD0, 0x0004CDE2, 0x0004CE10, 0x0004D3C2, 0x0004D410, 0x0004D7E2, 0x0004D810, 0x0004D8C2,
+    0x0004D9D0, 0x0004D9E2, 0x0004DA90, 0x0004DAA2, 0x0004DC50, 0x0004DC62, 0x0004DC90, 0x0004DD02,
+    0x0004DD50, 0x0004DD62, 0x0004DE50, 0x0004DE82, 0x0004DEB0, 0x0004DEC2, 0x0004DF50, 0x0004DF62,
+    0x0004DFB0, 0x0004DFC2, 0x0004E010, 0x0004E7A2, 0x0004E7D0, 0x0004EEC2, 0x0004F290, 0x00056AC2,
+    0x00056B50, 0x00064902, 0x00064A10, 0x001C0002, 0x001F2010, 0x001FC00F, 0x001FC02C, 0x001FC04F,
+    0x001FC070, 0x001FC1CC, 0x001FC1F0, 0x001FFFA2, 0x001FFFD0,
+];
@@ -9,23 +9,20 @@
 // except according to those terms.
 
 // NOTE: The following code was generated by "scripts/unicode.py", do not edit directly
-use crate::tables::*;
-use core::cmp::Ordering;
+#[cfg(not(feature = "no-emoji-sequences"))]
+use crate::layout::Tables;
 
 /// Whether this character has Joining_Group=Lam.
-pub fn is_joining_group_lam(c: char) -> bool {
-    matches!(
-        c,
-        '\u{644}' | '\u{6B5}'..='\u{6B8}' | '\u{76A}' | '\u{8A6}' | '\u{8C7}'
-    )
+pub const fn is_joining_group_lam(cp: u32) -> bool {
+    matches!(cp, 0x644 | 0x6B5..=0x6B8 | 0x76A | 0x8A6 | 0x8C7)
 }
 
 /// Whether this character is a default-ignorable combining mark
 /// or ZWJ. These characters won't interrupt non-Arabic ligatures.
-pub fn is_ligature_transparent(c: char) -> bool {
+pub const fn is_ligature_transparent(cp: u32) -> bool {
     matches!(
-        c,
-        '\u{34F}' | '\u{17B4}'..='\u{17B5}' | '\u{180B}'..='\u{180D}' | '\u{180F}' | '\u{200D}' | '\u{FE00}'..='\u{FE0F}' | '\u{E0100}'..='\u{E01EF}'
+        cp,
+        0x34F | 0x17B4..=0x17B5 | 0x180B..=0x180D | 0x180F | 0x200D | 0xFE00..=0xFE0F | 0xE0100..=0xE01EF
     )
 }
 
@@ -33,9 +30,9 @@ pub fn is_ligature_transparent(c: char) -> bool {
 /// (https://www.unicode.org/reports/tr51/#def_emoji_presentation_sequence)
 /// when followed by `'\u{FEOF}'`.
 /// Emoji presentation sequences are considered to have width 2.
+#[cfg(not(feature = "no-emoji-sequences"))]
 #[inline]
-pub fn starts_emoji_presentation_seq(c: char) -> bool {
-    let cp: u32 = c.into();
+pub(crate) const fn starts_emoji_presentation_seq(tables: &Tables, cp: u32) -> bool {
     // First level of lookup uses all but 10 LSB
     let top_bits = cp >> 10;
     let idx_of_leaf: usize = match top_bits {
@@ -44,81 +41,91 @@ pub fn starts_emoji_presentation_seq(c: char) -> bool {
         0x9 => 2,
         0xA => 3,
         0xC => 4,
+        #[cfg(not(feature = "bmp-only"))]
         0x7C => 5,
+        #[cfg(not(feature = "bmp-only"))]
         0x7D => 6,
         _ => return false,
     };
     // Extract the 3-9th (0-indexed) least significant bits of `cp`,
     // and use them to index into `leaf_row`.
-    let idx_within_leaf = usize::try_from((cp >> 3) & 0x7F).unwrap();
-    let leaf_byte = EMOJI_PRESENTATION_LEAVES.0[idx_of_leaf][idx_within_leaf];
+    let idx_within_leaf = ((cp >> 3) & 0x7F) as usize;
+    let leaf_byte = tables.emoji_presentation_leaves.0[idx_of_leaf][idx_within_leaf];
     // Use the 3 LSB of `cp` to index into `leaf_byte`.
     ((leaf_byte >> (cp & 7)) & 1) == 1
 }
 
-/// Returns `true` if `c` has default emoji presentation, but forms a [text presentation sequence]
+/// Returns `true` if `cp` has default emoji presentation, but forms a [text presentation sequence]
 /// (https://www.unicode.org/reports/tr51/#def_text_presentation_sequence)
 /// when followed by `'\u{FEOE}'`, and is not ideographic.
 /// Such sequences are considered to have width 1.
+#[cfg(not(feature = "no-emoji-sequences"))]
 #[inline]
-pub fn starts_non_ideographic_text_presentation_seq(c: char) -> bool {
-    let cp: u32 = c.into();
+pub(crate) const fn starts_non_ideographic_text_presentation_seq(tables: &Tables, cp: u32) -> bool {
     // First level of lookup uses all but 8 LSB
     let top_bits = cp >> 8;
-    let leaf: &[(u8, u8)] = match top_bits {
-        0x23 => &TEXT_PRESENTATION_LEAF_0,
-        0x25 => &TEXT_PRESENTATION_LEAF_1,
-        0x26 => &TEXT_PRESENTATION_LEAF_2,
-        0x27 => &TEXT_PRESENTATION_LEAF_3,
-        0x2B => &TEXT_PRESENTATION_LEAF_4,
-        0x1F0 => &TEXT_PRESENTATION_LEAF_5,
-        0x1F3 => &TEXT_PRESENTATION_LEAF_6,
-        0x1F4 => &TEXT_PRESENTATION_LEAF_7,
-        0x1F5 => &TEXT_PRESENTATION_LEAF_8,
-        0x1F6 => &TEXT_PRESENTATION_LEAF_9,
+    let idx_of_leaf: usize = match top_bits {
+        0x23 => 0,
+        0x25 => 1,
+        0x26 => 2,
+        0x27 => 3,
+        0x2B => 4,
+        #[cfg(not(feature = "bmp-only"))]
+        0x1F0 => 5,
+        #[cfg(not(feature = "bmp-only"))]
+        0x1F3 => 6,
+        #[cfg(not(feature = "bmp-only"))]
+        0x1F4 => 7,
+        #[cfg(not(feature = "bmp-only"))]
+        0x1F5 => 8,
+        #[cfg(not(feature = "bmp-only"))]
+        0x1F6 => 9,
         _ => return false,
     };
 
-    let bottom_bits = (cp & 0xFF) as u8;
-    leaf.binary_search_by(|&(lo, hi)| {
-        if bottom_bits < lo {
-            Ordering::Greater
-        } else if bottom_bits > hi {
-            Ordering::Less
-        } else {
-            Ordering::Equal
-        }
-    })
-    .is_ok()
+    leaf_contains(
+        tables.text_presentation_leaves[idx_of_leaf],
+        (cp & 0xFF) as u8,
+    )
 }
 
-/// Returns `true` if `c` is an `Emoji_Modifier_Base`.
+/// Returns `true` if `cp` is an `Emoji_Modifier_Base`.
+#[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
 #[inline]
-pub fn is_emoji_modifier_base(c: char) -> bool {
-    let cp: u32 = c.into();
+pub(crate) const fn is_emoji_modifier_base(tables: &Tables, cp: u32) -> bool {
     // First level of lookup uses all but 8 LSB
     let top_bits = cp >> 8;
-    let leaf: &[(u8, u8)] = match top_bits {
-        0x26 => &EMOJI_MODIFIER_LEAF_0,
-        0x27 => &EMOJI_MODIFIER_LEAF_1,
-        0x1F3 => &EMOJI_MODIFIER_LEAF_2,
-        0x1F4 => &EMOJI_MODIFIER_LEAF_3,
-        0x1F5 => &EMOJI_MODIFIER_LEAF_4,
-        0x1F6 => &EMOJI_MODIFIER_LEAF_5,
-        0x1F9 => &EMOJI_MODIFIER_LEAF_6,
-        0x1FA => &EMOJI_MODIFIER_LEAF_7,
+    let idx_of_leaf: usize = match top_bits {
+        0x26 => 0,
+        0x27 => 1,
+        0x1F3 => 2,
+        0x1F4 => 3,
+        0x1F5 => 4,
+        0x1F6 => 5,
+        0x1F9 => 6,
+        0x1FA => 7,
         _ => return false,
     };
 
-    let bottom_bits = (cp & 0xFF) as u8;
-    leaf.binary_search_by(|&(lo, hi)| {
+    leaf_contains(tables.emoji_modifier_leaves[idx_of_leaf], (cp & 0xFF) as u8)
+}
+
+/// Binary searches a sorted list of inclusive `(lo, hi)` ranges for `bottom_bits`.
+/// Written as a loop (rather than with `binary_search_by`) so it can be a `const fn`.
+#[cfg(not(feature = "no-emoji-sequences"))]
+#[inline]
+const fn leaf_contains(leaf: &[(u8, u8)], bottom_bits: u8) -> bool {
+    let (mut lo_idx, mut hi_idx) = (0, leaf.len());
+    while lo_idx < hi_idx {
+        let mid = lo_idx + (hi_idx - lo_idx) / 2;
+        let (lo, hi) = leaf[mid];
         if bottom_bits < lo {
-            Ordering::Greater
+            hi_idx = mid;
         } else if bottom_bits > hi {
-            Ordering::Less
+            lo_idx = mid + 1;
         } else {
-            Ordering::Equal
+            return true;
         }
-    })
-    .is_ok()
+    }
+    false
 }
@@ -14,1463 +14,32 @@
 /// that this version of unicode-width is based on.
 pub const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
 
+// Not every table layout uses every alignment.
+#[allow(dead_code)]
 #[repr(align(32))]
 pub(crate) struct Align32<T>(pub(crate) T);
 
+#[allow(dead_code)]
 #[repr(align(64))]
 pub(crate) struct Align64<T>(pub(crate) T);
 
+#[allow(dead_code)]
 #[repr(align(128))]
 pub(crate) struct Align128<T>(pub(crate) T);
-/// Autogenerated. 1 sub-table(s). Consult [`lookup_width`] for layout info.)
-pub(crate) static WIDTH_ROOT: Align128<[u8; 256]> = Align128([
-    0x00, 0x01, 0x02, 0x02, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
-    0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x0E, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x0E,
-    0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08,
-    0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08,
-    0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08,
-    0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08,
-    0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08,
-    0x0F, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08,
-    0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-]);
-/// Autogenerated. 16 sub-table(s). Consult [`lookup_width`] for layout info.)
-#[cfg(feature = "cjk")]
-pub(crate) static WIDTH_ROOT_CJK: Align128<[u8; 256]> = Align128([
-    0x10, 0x11, 0x02, 0x02, 0x02, 0x03, 0x04, 0x12, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x13,
-    0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x0E, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x0E,
-    0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08,
-    0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08,
-    0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08,
-    0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08,
-    0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08,
-    0x0F, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x0E,
-    0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x0E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-]);
-
-#[cfg(feature = "cjk")]
-pub(crate) const WIDTH_MIDDLE_LEN: usize = 20;
-#[cfg(not(feature = "cjk"))]
-pub(crate) const WIDTH_MIDDLE_LEN: usize = 16;
-/// Autogenerated. 4 sub-table(s). Consult [`lookup_width`] for layout info.
-pub(crate) static WIDTH_MIDDLE: Align64<[[u8; 64]; WIDTH_MIDDLE_LEN]> = Align64([
-    [
-        0x00, 0x01, 0x02, 0x02, 0x02, 0x02, 0x03, 0x02, 0x02, 0x04, 0x02, 0x05, 0x06, 0x07, 0x08,
-        0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
-        0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x02, 0x02, 0x1E, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x1F, 0x20, 0x21, 0x22, 0x23, 0x02, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x02, 0x2A,
-        0x02, 0x02, 0x02, 0x02,
-    ],
-    [
-        0x2B, 0x2C, 0x02, 0x02, 0x02, 0x02, 0x2D, 0x2E, 0x02, 0x02, 0x02, 0x2F, 0x30, 0x31, 0x32,
-        0x33, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x34, 0x02, 0x02, 0x35, 0x36, 0x37, 0x02, 0x38,
-        0x39, 0x3A, 0x3B, 0x3C, 0x3D, 0x3E, 0x3F, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39,
-    ],
-    [
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39,
-    ],
-    [
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x40, 0x02, 0x02, 0x41, 0x42, 0x02,
-        0x02, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x02, 0x49, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39,
-    ],
-    [
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x4A, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02,
-    ],
-    [
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x39, 0x39, 0x39, 0x39, 0x4B, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x4C, 0x4D, 0x4E, 0x4F,
-    ],
-    [
-        0x02, 0x02, 0x02, 0x50, 0x02, 0x51, 0x52, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x53, 0x54, 0x02, 0x02, 0x55, 0x02, 0x56, 0x02, 0x02, 0x57,
-        0x58, 0x59, 0x5A, 0x5B, 0x5C, 0x5D, 0x5E, 0x5F, 0x60, 0x61, 0x62, 0x63, 0x02, 0x64, 0x65,
-        0x66, 0x67, 0x02, 0x68, 0x02, 0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x02, 0x6E, 0x6F, 0x70, 0x71,
-        0x02, 0x72, 0x73, 0x02,
-    ],
-    [
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x74, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02,
-    ],
-    [
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02,
-    ],
-    [
-        0x02, 0x02, 0x75, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x76, 0x77, 0x02, 0x02, 0x02, 0x78, 0x02, 0x02, 0x02,
-        0x79, 0x7A, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39,
-    ],
-    [
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x7B, 0x7C, 0x7D, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02,
-    ],
-    [
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x7E, 0x39, 0x39, 0x7F, 0x39, 0x39, 0x80, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x81, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02,
-    ],
-    [
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x82, 0x02, 0x02, 0x02, 0x83, 0x84, 0x85, 0x02, 0x86, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x87, 0x88, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02,
-    ],
-    [
-        0x89, 0x8A, 0x77, 0x02, 0x02, 0x8B, 0x02, 0x02, 0x02, 0x8C, 0x02, 0x8D, 0x02, 0x8E, 0x02,
-        0x02, 0x02, 0x8F, 0x90, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x91, 0x92, 0x02, 0x93, 0x94, 0x02, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9A, 0x9B,
-        0x9C, 0x02, 0x9D, 0x02, 0x02, 0x9E, 0x9F, 0xA0, 0xA1, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02,
-    ],
-    [
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0xA2,
-    ],
-    [
-        0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D,
-        0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D, 0x1D,
-        0x1D, 0x1D, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0x00, 0xA3, 0x02, 0x02, 0x02, 0x02, 0xA4, 0xA5, 0x02, 0x04, 0x02, 0x05, 0x06, 0x07, 0x08,
-        0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
-        0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x02, 0x02, 0x1E, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x1F, 0x20, 0x21, 0x22, 0x23, 0x02, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x02, 0x2A,
-        0x02, 0x02, 0x02, 0x02,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0xA6, 0xA7, 0xA8, 0xA9, 0xAA, 0xAB, 0xAC, 0x2E, 0xAD, 0x39, 0xAE, 0xAF, 0xB0, 0xB1, 0xB2,
-        0xB3, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0xB4, 0x02, 0x02, 0x35, 0x36, 0x37, 0x02, 0x38,
-        0x39, 0x3A, 0x3B, 0x3C, 0x3D, 0x3E, 0xB5, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39,
-        0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x39, 0x4B, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0xB6, 0x4D, 0x4E, 0xB7,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0x89, 0x8A, 0x77, 0x02, 0x02, 0x8B, 0x02, 0x02, 0x02, 0x8C, 0x02, 0x8D, 0x02, 0x8E, 0x02,
-        0x02, 0x02, 0x8F, 0x90, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x91, 0x92, 0xB8, 0xB9, 0x94, 0x02, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9A, 0x9B,
-        0x9C, 0x02, 0x9D, 0x02, 0x02, 0x9E, 0x9F, 0xA0, 0xA1, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-        0x02, 0x02, 0x02, 0x02,
-    ],
-]);
-
-#[cfg(feature = "cjk")]
-pub(crate) const WIDTH_LEAVES_LEN: usize = 186;
-#[cfg(not(feature = "cjk"))]
-pub(crate) const WIDTH_LEAVES_LEN: usize = 163;
-/// Autogenerated. 186 sub-table(s). Consult [`lookup_width`] for layout info.
-pub(crate) static WIDTH_LEAVES: Align32<[[u8; 32]; WIDTH_LEAVES_LEN]> = Align32([
-    [
-        0x55, 0x55, 0x75, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x15, 0x00, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x10, 0x41, 0x10, 0x55, 0x55, 0x55, 0x55, 0x55, 0x57, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x51, 0x55, 0x55, 0x00, 0x00, 0x40, 0x54, 0xF5, 0xDD, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55, 0xFC, 0x5D,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x00, 0x14, 0x00, 0x14, 0x04, 0x50, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x15, 0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xD5, 0x57,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x00, 0x00, 0x54, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x00, 0x00, 0x55,
-        0x55, 0x51,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x10, 0x00, 0x00, 0x01, 0x01, 0x50, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0xFF, 0xFF,
-        0xFF, 0xFF,
-    ],
-    [
-        0x7F, 0x55, 0x55, 0x55, 0x50, 0x15, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00,
-    ],
-    [
-        0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45,
-        0x54, 0x01, 0x00, 0x54, 0x51, 0x01, 0x00, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x44, 0x01, 0x54, 0x55, 0x51, 0x55, 0x15, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x45,
-    ],
-    [
-        0x41, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x54, 0x41, 0x15, 0x14, 0x50, 0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x50, 0x51,
-        0x55, 0x55,
-    ],
-    [
-        0x41, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x54, 0x01, 0x10, 0x54, 0x51, 0x55, 0x55, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x05, 0x00,
-    ],
-    [
-        0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x04, 0x01, 0x54, 0x55, 0x51, 0x55, 0x01, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x45, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x45, 0x54, 0x55, 0x55, 0x51, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x54, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x04, 0x54, 0x05, 0x04, 0x50, 0x55, 0x41, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x14, 0x44, 0x05, 0x04, 0x50, 0x55, 0x41, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15,
-        0x44, 0x01, 0x54, 0x55, 0x41, 0x55, 0x15, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x45, 0x15, 0x05, 0x44, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x00, 0x40,
-        0x55, 0x55, 0x15, 0x00, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x00, 0x00,
-        0x54, 0x55, 0x55, 0x00, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x11, 0x51,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x01, 0x00,
-        0x00, 0x40,
-    ],
-    [
-        0x00, 0x04, 0x55, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x54, 0x55, 0x45, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x01, 0x04, 0x00, 0x41,
-        0x41, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x50, 0x05, 0x54, 0x55, 0x55, 0x55, 0x01, 0x54,
-        0x55, 0x55,
-    ],
-    [
-        0x45, 0x41, 0x55, 0x51, 0x55, 0x55, 0x55, 0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00,
-    ],
-    [
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x05, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x54, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x7F, 0xFF, 0xFD, 0xF7, 0xFF, 0xFD, 0xD7, 0x5F, 0x77, 0xD6, 0xD5, 0xD7, 0x55, 0x10, 0x00,
-        0x50, 0x55, 0x45, 0x01, 0x00, 0x00, 0x55, 0x57, 0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x15, 0x00, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x41, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x40, 0x15, 0x54, 0x55, 0x45, 0x55, 0x01,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x57, 0x15, 0x14, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45, 0x00, 0x40, 0x44, 0x01, 0x00, 0x54, 0x15, 0x00,
-        0x00, 0x14,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x50, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x00, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00,
-        0x50, 0x05, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x00, 0x00, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x50, 0x00, 0x50, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45, 0x50, 0x11, 0x00, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x05, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x40, 0x00, 0x00, 0x00, 0x04, 0x00, 0x54, 0x51, 0x55, 0x54,
-        0x50, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00,
-    ],
-    [
-        0x55, 0x55, 0x15, 0x00, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x40, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x54, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xF5, 0x55, 0x55, 0x55, 0x69, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xFD, 0x57, 0xD7, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x7D,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x5F, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xAA, 0xAA, 0x55,
-        0x55, 0x55, 0x55, 0xFF, 0xFF, 0xFF, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0xD5,
-    ],
-    [
-        0x55, 0x55, 0xA5, 0xAA, 0xD5, 0x55, 0x55, 0x55, 0x5D, 0x55, 0xF5, 0x55, 0x55, 0x55, 0x55,
-        0x7D, 0x55, 0x5F, 0x55, 0x75, 0x55, 0x57, 0x55, 0x55, 0x55, 0x55, 0x75, 0x55, 0xF5, 0x5D,
-        0x75, 0x5D,
-    ],
-    [
-        0x55, 0x5D, 0xF5, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x57, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x77, 0xD5, 0xDF, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0xFD, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x57, 0x55, 0x55,
-        0xD5, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xD5, 0x57, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x57, 0x5D, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x50, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xFD, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x5F, 0x55, 0xD5, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00,
-    ],
-    [
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x9A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA,
-    ],
-    [
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x5A, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xAA, 0xAA,
-        0xAA, 0xAA,
-    ],
-    [
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x0A, 0x00, 0xAA, 0xAA, 0xAA,
-        0x6A, 0xA9, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA,
-    ],
-    [
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A, 0x81, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA,
-    ],
-    [
-        0x55, 0xA9, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA9, 0xAA, 0xAA,
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA8, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA,
-    ],
-    [
-        0xAA, 0xAA, 0xAA, 0x6A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x5A, 0x55, 0x95, 0xAA, 0xAA,
-        0xAA, 0xAA,
-    ],
-    [
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA, 0xAA, 0x55, 0x55, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA,
-    ],
-    [
-        0xAA, 0xAA, 0xAA, 0x56, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA, 0x6A, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x5F,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x40, 0x00,
-        0x00, 0x50,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x50, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x45, 0x45, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x41, 0x55, 0x54, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x50, 0x55,
-        0x45, 0x15,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x00, 0x50, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x15, 0x00, 0x00, 0x10, 0x55, 0x55, 0x55, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0x56,
-    ],
-    [
-        0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x05, 0x50,
-        0x50, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x01, 0x40, 0x41, 0x41, 0x55,
-        0x55, 0x15, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x54,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x04, 0x14, 0x54,
-        0x05, 0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x50, 0x55, 0x45,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x54, 0x51, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x40, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x0C, 0x00, 0x00, 0xF0, 0xAA, 0xAA, 0x5A, 0x55, 0x00, 0x00, 0x00, 0x00, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A, 0xAA, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x15,
-    ],
-    [
-        0xA9, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x56, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xAA, 0x6A, 0x55, 0x55, 0x00, 0x00,
-        0x54, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x51,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05,
-        0x40, 0x55,
-    ],
-    [
-        0x01, 0x41, 0x55, 0x00, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x40,
-        0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x41, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0xD5, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x01, 0x50, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x54, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x05, 0x00,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x05, 0x00, 0x00, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x05, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00,
-        0x00, 0x00, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x14, 0x54,
-        0x55, 0x15,
-    ],
-    [
-        0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x40, 0x41,
-        0x55, 0x45, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x00, 0x01, 0x00, 0x54, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x00,
-        0x40, 0x04, 0x55, 0x01, 0x14, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x50, 0x00, 0x55,
-        0x45, 0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x15, 0x00, 0x40, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15,
-        0x44, 0x54, 0x55, 0x55, 0x51, 0x55, 0x15, 0x55, 0x55, 0x55, 0x05, 0x00, 0x54, 0x00, 0x54,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x14,
-        0x00, 0x44, 0x11, 0x50, 0x05, 0x40, 0x55, 0x55, 0x55, 0x41, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00,
-        0x00, 0x05, 0x44, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x14, 0x00, 0x44,
-        0x11, 0x04, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x05, 0x50, 0x55,
-        0x10, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x00, 0x40,
-        0x11, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x51, 0x00, 0x00, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x11, 0x05, 0x10, 0x00, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x00, 0x00, 0x41,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0x55, 0x15,
-        0x00, 0x11, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x05, 0x55, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x01, 0x00, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x00, 0x14,
-        0x40, 0x55, 0x15, 0x55, 0x55, 0x01, 0x40, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x00, 0x00, 0x00, 0x00, 0x40, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x04, 0x44, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x40, 0x00,
-        0x10, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x04, 0x41, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x01, 0x40, 0x45,
-        0x10, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x50, 0x11, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x54,
-        0x55, 0x55,
-    ],
-    [
-        0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x40,
-        0x55, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x54, 0x15, 0x00, 0x00, 0x00, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x00, 0x00, 0x50, 0x01, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x54,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x40, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xD5, 0x57, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x15, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xAA, 0x54, 0x55, 0x55, 0xA0, 0x6A,
-        0x55, 0x55,
-    ],
-    [
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x5A, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x95,
-    ],
-    [
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xAA, 0xA9,
-        0xAA, 0x69,
-    ],
-    [
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A, 0x55, 0x55, 0x55, 0x65, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x6A, 0x59, 0x55, 0x55, 0x55, 0xAA, 0x55, 0x55, 0xAA, 0xAA,
-        0xAA, 0xAA,
-    ],
-    [
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x41, 0x00, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x50, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x01, 0x50, 0x01, 0x00, 0x00,
-        0x00, 0x00,
-    ],
-    [
-        0x40, 0x01, 0x00, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x50, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x05, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A, 0x55, 0x55, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A,
-        0x55, 0x55,
-    ],
-    [
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x15,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x54, 0x55, 0x51,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x54, 0x55, 0x55, 0x55, 0x55, 0x15, 0x00, 0x01, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x10, 0x04, 0x40, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x45, 0x55, 0x05, 0x55, 0x51,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x00, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x57, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0xD5, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x75, 0xFD, 0xFF, 0x7F, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xF5, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF,
-    ],
-    [
-        0x6E, 0x55, 0x55, 0x55, 0xAA, 0xAA, 0xBA, 0xAA, 0xAA, 0xAA, 0xAA, 0xEA, 0xFA, 0xBF, 0xBF,
-        0x55, 0xAA, 0xAA, 0x56, 0x55, 0x5F, 0x55, 0x55, 0x55, 0xAA, 0x5A, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x57, 0x55, 0x55, 0xFD, 0xFF, 0xDF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xF7,
-    ],
-    [
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x55, 0x55, 0x55, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0x7F, 0xD5, 0xFF, 0x55, 0x55, 0x55, 0xFF, 0xFF, 0xFF, 0xFF, 0x57, 0x57,
-        0xFF, 0xFF,
-    ],
-    [
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0x7F, 0xF7, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF,
-    ],
-    [
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xD7,
-    ],
-    [
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0x5F, 0x55, 0x55, 0xD5, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x55, 0x55, 0x55, 0x55,
-        0x75, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x7D, 0x55, 0x55, 0x55, 0x57, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0xD5, 0xFF,
-    ],
-    [
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0x5F, 0x55, 0x57, 0x7F, 0xFD, 0x57, 0xFF, 0x55, 0x55, 0xD5, 0x57, 0x55, 0xFF,
-        0xFF, 0x57,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xFF, 0xFF, 0xFF, 0x55, 0x57, 0x55,
-        0x55, 0x55,
-    ],
-    [
-        0x55, 0x55, 0x55, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F,
-        0xFF, 0xFF, 0xDF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF,
-    ],
-    [
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF,
-    ],
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xFF, 0xFF,
-        0xFF, 0x57,
-    ],
-    [
-        0xFF, 0xFF, 0x7F, 0xF5, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF, 0x7F, 0x57, 0xFD, 0xFF, 0xFF, 0xFF, 0xD7, 0xFF, 0xFF, 0x7F, 0xD5, 0xFF, 0xFF,
-        0x57, 0x55,
-    ],
-    [
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0x5A,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59, 0x96, 0x55, 0x61, 0xAA, 0xA5, 0x59,
-        0xAA, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95,
-        0x55, 0x55,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0x55, 0x55, 0x15, 0x00, 0x96, 0x6A, 0x5A, 0x5A, 0x6A, 0xAA, 0x05, 0x40, 0xA6, 0x59, 0x95,
-        0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x55, 0x56,
-        0x55, 0x55,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0xA9, 0x56, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x54, 0x55,
-        0x55, 0x55,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0x95, 0x59, 0x59, 0x55, 0x55, 0x65, 0x55, 0x55, 0x69, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0xAA, 0xAA, 0xAA, 0x6A, 0xAA, 0xAA, 0xAA, 0x55, 0xAA, 0xAA,
-        0x5A, 0x55,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0x55, 0x55, 0x59, 0x55, 0xAA, 0xAA, 0xAA, 0x55, 0x55, 0x55, 0x55, 0x65, 0x55, 0x55, 0x5A,
-        0x55, 0x55, 0x55, 0x55, 0xA5, 0x65, 0x56, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0xA6, 0x96, 0x9A, 0x96, 0x59, 0x59, 0x65, 0xA9, 0x96, 0xAA, 0xAA, 0x66, 0x55, 0xAA, 0x55,
-        0x5A, 0x59, 0x55, 0x5A, 0x56, 0x65, 0x55, 0x55, 0x55, 0x6A, 0xAA, 0xA5, 0xA5, 0x5A, 0x55,
-        0x55, 0x55,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0xA5, 0xAA, 0x5A, 0x55, 0x55, 0x59, 0x59, 0x55, 0x55, 0x59, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0x55, 0x55, 0x55, 0x55, 0x65, 0x55, 0xF5, 0x55, 0x55, 0x55, 0x69, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA, 0xAA, 0xAA, 0x55, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x56,
-        0x55, 0x55,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0xAA, 0xAA, 0xAA, 0xAA, 0xA5, 0x5A, 0x55, 0x55, 0x9A, 0xAA, 0x5A, 0x55, 0xA5, 0xA5, 0x55,
-        0x5A, 0x5A, 0xA5, 0x96, 0xA5, 0x5A, 0x55, 0x55, 0x55, 0xA5, 0x5A, 0x55, 0x95, 0x55, 0x55,
-        0x55, 0x7D,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0x55, 0x69, 0x59, 0xA5, 0x55, 0xAF, 0x55, 0x66, 0x55, 0x55, 0x55, 0x55, 0xAA, 0xAA, 0x55,
-        0x55, 0x66, 0x55, 0xFF, 0xFF, 0xFF, 0x55, 0x55, 0x55, 0x9A, 0x9A, 0x6A, 0x9A, 0x55, 0x55,
-        0x55, 0xD5,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0x55, 0x55, 0xA5, 0xAA, 0xD5, 0x55, 0x55, 0xA5, 0x5D, 0x55, 0xF5, 0x55, 0x55, 0x55, 0x55,
-        0xBD, 0x55, 0xAF, 0xAA, 0xBA, 0xAA, 0xAB, 0xAA, 0xAA, 0x9A, 0x55, 0xBA, 0xAA, 0xFA, 0xAE,
-        0xBA, 0xAE,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0x55, 0x5D, 0xF5, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x57, 0x55, 0x55, 0x55, 0x55,
-        0x59, 0x55, 0x55, 0x55, 0x77, 0xD5, 0xDF, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xA5,
-        0xAA, 0xAA,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFD, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x57, 0x55, 0x55,
-        0xD5, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xD5, 0x57, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x57, 0xAD, 0x5A, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0x33, 0x00, 0x00, 0xC0, 0xAA, 0xAA, 0x5A, 0x55, 0x00, 0x00, 0x00, 0x00, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A, 0xAA, 0x55, 0x55, 0x55,
-        0x55, 0x55,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xAA, 0x6A, 0x55, 0x55, 0x00, 0x00,
-        0x54, 0x59,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0xAA, 0xAA, 0xAA, 0x56, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x5A, 0xAA, 0xAA, 0xAA,
-        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x5A, 0x55, 0xAA, 0xAA,
-        0xAA, 0xAA,
-    ],
-    #[cfg(feature = "cjk")]
-    [
-        0xAA, 0xAA, 0xAA, 0xBA, 0xFE, 0xFF, 0xBF, 0xAA, 0xAA, 0xAA, 0xAA, 0x56, 0x55, 0x55, 0x55,
-        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xF5, 0xFF, 0xFF, 0xFF, 0xFF,
-        0xFF, 0xFF,
-    ],
-]);
-
-/// Sorted list of codepoint ranges (inclusive)
-/// that are zero-width but not `Joining_Type=Transparent`
-/// FIXME: can we get better compression?
-pub(crate) static NON_TRANSPARENT_ZERO_WIDTHS: [([u8; 3], [u8; 3]); 71] = [
-    ([0x05, 0x06, 0x00], [0x05, 0x06, 0x00]),
-    ([0x90, 0x08, 0x00], [0x91, 0x08, 0x00]),
-    ([0xE2, 0x08, 0x00], [0xE2, 0x08, 0x00]),
-    ([0xBE, 0x09, 0x00], [0xBE, 0x09, 0x00]),
-    ([0xD7, 0x09, 0x00], [0xD7, 0x09, 0x00]),
-    ([0x3E, 0x0B, 0x00], [0x3E, 0x0B, 0x00]),
-    ([0x57, 0x0B, 0x00], [0x57, 0x0B, 0x00]),
-    ([0xBE, 0x0B, 0x00], [0xBE, 0x0B, 0x00]),
-    ([0xD7, 0x0B, 0x00], [0xD7, 0x0B, 0x00]),
-    ([0xC0, 0x0C, 0x00], [0xC0, 0x0C, 0x00]),
-    ([0xC2, 0x0C, 0x00], [0xC2, 0x0C, 0x00]),
-    ([0xC7, 0x0C, 0x00], [0xC8, 0x0C, 0x00]),
-    ([0xCA, 0x0C, 0x00], [0xCB, 0x0C, 0x00]),
-    ([0xD5, 0x0C, 0x00], [0xD6, 0x0C, 0x00]),
-    ([0x3E, 0x0D, 0x00], [0x3E, 0x0D, 0x00]),
-    ([0x4E, 0x0D, 0x00], [0x4E, 0x0D, 0x00]),
-    ([0x57, 0x0D, 0x00], [0x57, 0x0D, 0x00]),
-    ([0xCF, 0x0D, 0x00], [0xCF, 0x0D, 0x00]),
-    ([0xDF, 0x0D, 0x00], [0xDF, 0x0D, 0x00]),
-    ([0x60, 0x11, 0x00], [0xFF, 0x11, 0x00]),
-    ([0x15, 0x17, 0x00], [0x15, 0x17, 0x00]),
-    ([0x34, 0x17, 0x00], [0x34, 0x17, 0x00]),
-    ([0x0E, 0x18, 0x00], [0x0E, 0x18, 0x00]),
-    ([0x35, 0x1B, 0x00], [0x35, 0x1B, 0x00]),
-    ([0x3B, 0x1B, 0x00], [0x3B, 0x1B, 0x00]),
-    ([0x3D, 0x1B, 0x00], [0x3D, 0x1B, 0x00]),
-    ([0x43, 0x1B, 0x00], [0x44, 0x1B, 0x00]),
-    ([0xAA, 0x1B, 0x00], [0xAA, 0x1B, 0x00]),
-    ([0xF2, 0x1B, 0x00], [0xF3, 0x1B, 0x00]),
-    ([0x0C, 0x20, 0x00], [0x0D, 0x20, 0x00]),
-    ([0x65, 0x20, 0x00], [0x69, 0x20, 0x00]),
-    ([0x2E, 0x30, 0x00], [0x2F, 0x30, 0x00]),
-    ([0x64, 0x31, 0x00], [0x64, 0x31, 0x00]),
-    ([0xFA, 0xA8, 0x00], [0xFA, 0xA8, 0x00]),
-    ([0x53, 0xA9, 0x00], [0x53, 0xA9, 0x00]),
-    ([0xC0, 0xA9, 0x00], [0xC0, 0xA9, 0x00]),
-    ([0xB0, 0xD7, 0x00], [0xC6, 0xD7, 0x00]),
-    ([0xCB, 0xD7, 0x00], [0xFB, 0xD7, 0x00]),
-    ([0xA0, 0xFF, 0x00], [0xA0, 0xFF, 0x00]),
-    ([0xF0, 0xFF, 0x00], [0xF8, 0xFF, 0x00]),
-    ([0xC0, 0x11, 0x01], [0xC0, 0x11, 0x01]),
-    ([0xC2, 0x11, 0x01], [0xC3, 0x11, 0x01]),
-    ([0x35, 0x12, 0x01], [0x35, 0x12, 0x01]),
-    ([0x3E, 0x13, 0x01], [0x3E, 0x13, 0x01]),
-    ([0x4D, 0x13, 0x01], [0x4D, 0x13, 0x01]),
-    ([0x57, 0x13, 0x01], [0x57, 0x13, 0x01]),
-    ([0xB8, 0x13, 0x01], [0xB8, 0x13, 0x01]),
-    ([0xC2, 0x13, 0x01], [0xC2, 0x13, 0x01]),
-    ([0xC5, 0x13, 0x01], [0xC5, 0x13, 0x01]),
-    ([0xC7, 0x13, 0x01], [0xC9, 0x13, 0x01]),
-    ([0xCF, 0x13, 0x01], [0xCF, 0x13, 0x01]),
-    ([0xD1, 0x13, 0x01], [0xD1, 0x13, 0x01]),
-    ([0xB0, 0x14, 0x01], [0xB0, 0x14, 0x01]),
-    ([0xBD, 0x14, 0x01], [0xBD, 0x14, 0x01]),
-    ([0xAF, 0x15, 0x01], [0xAF, 0x15, 0x01]),
-    ([0xB6, 0x16, 0x01], [0xB6, 0x16, 0x01]),
-    ([0x30, 0x19, 0x01], [0x30, 0x19, 0x01]),
-    ([0x3D, 0x19, 0x01], [0x3D, 0x19, 0x01]),
-    ([0x3F, 0x19, 0x01], [0x3F, 0x19, 0x01]),
-    ([0x41, 0x19, 0x01], [0x41, 0x19, 0x01]),
-    ([0x84, 0x1A, 0x01], [0x89, 0x1A, 0x01]),
-    ([0x46, 0x1D, 0x01], [0x46, 0x1D, 0x01]),
-    ([0x02, 0x1F, 0x01], [0x02, 0x1F, 0x01]),
-    ([0x41, 0x1F, 0x01], [0x41, 0x1F, 0x01]),
-    ([0xF0, 0x6F, 0x01], [0xF1, 0x6F, 0x01]),
-    ([0x65, 0xD1, 0x01], [0x66, 0xD1, 0x01]),
-    ([0x6D, 0xD1, 0x01], [0x72, 0xD1, 0x01]),
-    ([0x00, 0x00, 0x0E], [0x00, 0x00, 0x0E]),
-    ([0x02, 0x00, 0x0E], [0x1F, 0x00, 0x0E]),
-    ([0x80, 0x00, 0x0E], [0xFF, 0x00, 0x0E]),
-    ([0xF0, 0x01, 0x0E], [0xFF, 0x0F, 0x0E]),
-];
-
-/// Sorted list of codepoint ranges (inclusive)
-/// that don't affect how the combining solidus applies
-/// (mostly ccc > 1).
-/// FIXME: can we get better compression?
-#[cfg(feature = "cjk")]
-pub(crate) static SOLIDUS_TRANSPARENT: [([u8; 3], [u8; 3]); 208] = [
-    ([0x00, 0x03, 0x00], [0x33, 0x03, 0x00]),
-    ([0x39, 0x03, 0x00], [0x6F, 0x03, 0x00]),
-    ([0x83, 0x04, 0x00], [0x87, 0x04, 0x00]),
-    ([0x91, 0x05, 0x00], [0xBD, 0x05, 0x00]),
-    ([0xBF, 0x05, 0x00], [0xBF, 0x05, 0x00]),
-    ([0xC1, 0x05, 0x00], [0xC2, 0x05, 0x00]),
-    ([0xC4, 0x05, 0x00], [0xC5, 0x05, 0x00]),
-    ([0xC7, 0x05, 0x00], [0xC7, 0x05, 0x00]),
-    ([0x10, 0x06, 0x00], [0x1A, 0x06, 0x00]),
-    ([0x4B, 0x06, 0x00], [0x5F, 0x06, 0x00]),
-    ([0x70, 0x06, 0x00], [0x70, 0x06, 0x00]),
-    ([0xD6, 0x06, 0x00], [0xDC, 0x06, 0x00]),
-    ([0xDF, 0x06, 0x00], [0xE4, 0x06, 0x00]),
-    ([0xE7, 0x06, 0x00], [0xE8, 0x06, 0x00]),
-    ([0xEA, 0x06, 0x00], [0xED, 0x06, 0x00]),
-    ([0x11, 0x07, 0x00], [0x11, 0x07, 0x00]),
-    ([0x30, 0x07, 0x00], [0x4A, 0x07, 0x00]),
-    ([0xEB, 0x07, 0x00], [0xF3, 0x07, 0x00]),
-    ([0xFD, 0x07, 0x00], [0xFD, 0x07, 0x00]),
-    ([0x16, 0x08, 0x00], [0x19, 0x08, 0x00]),
-    ([0x1B, 0x08, 0x00], [0x23, 0x08, 0x00]),
-    ([0x25, 0x08, 0x00], [0x27, 0x08, 0x00]),
-    ([0x29, 0x08, 0x00], [0x2D, 0x08, 0x00]),
-    ([0x59, 0x08, 0x00], [0x5B, 0x08, 0x00]),
-    ([0x97, 0x08, 0x00], [0x9F, 0x08, 0x00]),
-    ([0xCA, 0x08, 0x00], [0xE1, 0x08, 0x00]),
-    ([0xE3, 0x08, 0x00], [0xFF, 0x08, 0x00]),
-    ([0x3C, 0x09, 0x00], [0x3C, 0x09, 0x00]),
-    ([0x4D, 0x09, 0x00], [0x4D, 0x09, 0x00]),
-    ([0x51, 0x09, 0x00], [0x54, 0x09, 0x00]),
-    ([0xBC, 0x09, 0x00], [0xBC, 0x09, 0x00]),
-    ([0xCD, 0x09, 0x00], [0xCD, 0x09, 0x00]),
-    ([0xFE, 0x09, 0x00], [0xFE, 0x09, 0x00]),
-    ([0x3C, 0x0A, 0x00], [0x3C, 0x0A, 0x00]),
-    ([0x4D, 0x0A, 0x00], [0x4D, 0x0A, 0x00]),
-    ([0xBC, 0x0A, 0x00], [0xBC, 0x0A, 0x00]),
-    ([0xCD, 0x0A, 0x00], [0xCD, 0x0A, 0x00]),
-    ([0x3C, 0x0B, 0x00], [0x3C, 0x0B, 0x00]),
-    ([0x4D, 0x0B, 0x00], [0x4D, 0x0B, 0x00]),
-    ([0xCD, 0x0B, 0x00], [0xCD, 0x0B, 0x00]),
-    ([0x3C, 0x0C, 0x00], [0x3C, 0x0C, 0x00]),
-    ([0x4D, 0x0C, 0x00], [0x4D, 0x0C, 0x00]),
-    ([0x55, 0x0C, 0x00], [0x56, 0x0C, 0x00]),
-    ([0xBC, 0x0C, 0x00], [0xBC, 0x0C, 0x00]),
-    ([0xCD, 0x0C, 0x00], [0xCD, 0x0C, 0x00]),
-    ([0x3B, 0x0D, 0x00], [0x3C, 0x0D, 0x00]),
-    ([0x4D, 0x0D, 0x00], [0x4D, 0x0D, 0x00]),
-    ([0xCA, 0x0D, 0x00], [0xCA, 0x0D, 0x00]),
-    ([0x38, 0x0E, 0x00], [0x3A, 0x0E, 0x00]),
-    ([0x48, 0x0E, 0x00], [0x4B, 0x0E, 0x00]),
-    ([0xB8, 0x0E, 0x00], [0xBA, 0x0E, 0x00]),
-    ([0xC8, 0x0E, 0x00], [0xCB, 0x0E, 0x00]),
-    ([0x18, 0x0F, 0x00], [0x19, 0x0F, 0x00]),
-    ([0x35, 0x0F, 0x00], [0x35, 0x0F, 0x00]),
-    ([0x37, 0x0F, 0x00], [0x37, 0x0F, 0x00]),
-    ([0x39, 0x0F, 0x00], [0x39, 0x0F, 0x00]),
-    ([0x71, 0x0F, 0x00], [0x75, 0x0F, 0x00]),
-    ([0x7A, 0x0F, 0x00], [0x7D, 0x0F, 0x00]),
-    ([0x80, 0x0F, 0x00], [0x84, 0x0F, 0x00]),
-    ([0x86, 0x0F, 0x00], [0x87, 0x0F, 0x00]),
-    ([0xC6, 0x0F, 0x00], [0xC6, 0x0F, 0x00]),
-    ([0x37, 0x10, 0x00], [0x37, 0x10, 0x00]),
-    ([0x39, 0x10, 0x00], [0x3A, 0x10, 0x00]),
-    ([0x8D, 0x10, 0x00], [0x8D, 0x10, 0x00]),
-    ([0x5D, 0x13, 0x00], [0x5F, 0x13, 0x00]),
-    ([0x14, 0x17, 0x00], [0x15, 0x17, 0x00]),
-    ([0x34, 0x17, 0x00], [0x34, 0x17, 0x00]),
-    ([0xD2, 0x17, 0x00], [0xD2, 0x17, 0x00]),
-    ([0xDD, 0x17, 0x00], [0xDD, 0x17, 0x00]),
-    ([0xA9, 0x18, 0x00], [0xA9, 0x18, 0x00]),
-    ([0x39, 0x19, 0x00], [0x3B, 0x19, 0x00]),
-    ([0x17, 0x1A, 0x00], [0x18, 0x1A, 0x00]),
-    ([0x60, 0x1A, 0x00], [0x60, 0x1A, 0x00]),
-    ([0x75, 0x1A, 0x00], [0x7C, 0x1A, 0x00]),
-    ([0x7F, 0x1A, 0x00], [0x7F, 0x1A, 0x00]),
-    ([0xB0, 0x1A, 0x00], [0xBD, 0x1A, 0x00]),
-    ([0xBF, 0x1A, 0x00], [0xDD, 0x1A, 0x00]),
-    ([0xE0, 0x1A, 0x00], [0xEB, 0x1A, 0x00]),
-    ([0x34, 0x1B, 0x00], [0x34, 0x1B, 0x00]),
-    ([0x44, 0x1B, 0x00], [0x44, 0x1B, 0x00]),
-    ([0x6B, 0x1B, 0x00], [0x73, 0x1B, 0x00]),
-    ([0xAA, 0x1B, 0x00], [0xAB, 0x1B, 0x00]),
-    ([0xE6, 0x1B, 0x00], [0xE6, 0x1B, 0x00]),
-    ([0xF2, 0x1B, 0x00], [0xF3, 0x1B, 0x00]),
-    ([0x37, 0x1C, 0x00], [0x37, 0x1C, 0x00]),
-    ([0xD0, 0x1C, 0x00], [0xD2, 0x1C, 0x00]),
-    ([0xD5, 0x1C, 0x00], [0xE0, 0x1C, 0x00]),
-    ([0xED, 0x1C, 0x00], [0xED, 0x1C, 0x00]),
-    ([0xF4, 0x1C, 0x00], [0xF4, 0x1C, 0x00]),
-    ([0xF8, 0x1C, 0x00], [0xF9, 0x1C, 0x00]),
-    ([0xC0, 0x1D, 0x00], [0xFF, 0x1D, 0x00]),
-    ([0xD0, 0x20, 0x00], [0xD1, 0x20, 0x00]),
-    ([0xD4, 0x20, 0x00], [0xD7, 0x20, 0x00]),
-    ([0xDB, 0x20, 0x00], [0xDC, 0x20, 0x00]),
-    ([0xE1, 0x20, 0x00], [0xE1, 0x20, 0x00]),
-    ([0xE7, 0x20, 0x00], [0xE9, 0x20, 0x00]),
-    ([0xEC, 0x20, 0x00], [0xF0, 0x20, 0x00]),
-    ([0xEF, 0x2C, 0x00], [0xF1, 0x2C, 0x00]),
-    ([0x7F, 0x2D, 0x00], [0x7F, 0x2D, 0x00]),
-    ([0xE0, 0x2D, 0x00], [0xFF, 0x2D, 0x00]),
-    ([0x2A, 0x30, 0x00], [0x2F, 0x30, 0x00]),
-    ([0x99, 0x30, 0x00], [0x9A, 0x30, 0x00]),
-    ([0x6F, 0xA6, 0x00], [0x6F, 0xA6, 0x00]),
-    ([0x74, 0xA6, 0x00], [0x7D, 0xA6, 0x00]),
-    ([0x9E, 0xA6, 0x00], [0x9F, 0xA6, 0x00]),
-    ([0xF0, 0xA6, 0x00], [0xF1, 0xA6, 0x00]),
-    ([0x06, 0xA8, 0x00], [0x06, 0xA8, 0x00]),
-    ([0x2C, 0xA8, 0x00], [0x2C, 0xA8, 0x00]),
-    ([0xC4, 0xA8, 0x00], [0xC4, 0xA8, 0x00]),
-    ([0xE0, 0xA8, 0x00], [0xF1, 0xA8, 0x00]),
-    ([0x2B, 0xA9, 0x00], [0x2D, 0xA9, 0x00]),
-    ([0x53, 0xA9, 0x00], [0x53, 0xA9, 0x00]),
-    ([0xB3, 0xA9, 0x00], [0xB3, 0xA9, 0x00]),
-    ([0xC0, 0xA9, 0x00], [0xC0, 0xA9, 0x00]),
-    ([0xB0, 0xAA, 0x00], [0xB0, 0xAA, 0x00]),
-    ([0xB2, 0xAA, 0x00], [0xB4, 0xAA, 0x00]),
-    ([0xB7, 0xAA, 0x00], [0xB8, 0xAA, 0x00]),
-    ([0xBE, 0xAA, 0x00], [0xBF, 0xAA, 0x00]),
-    ([0xC1, 0xAA, 0x00], [0xC1, 0xAA, 0x00]),
-    ([0xF6, 0xAA, 0x00], [0xF6, 0xAA, 0x00]),
-    ([0xED, 0xAB, 0x00], [0xED, 0xAB, 0x00]),
-    ([0x1E, 0xFB, 0x00], [0x1E, 0xFB, 0x00]),
-    ([0x20, 0xFE, 0x00], [0x2F, 0xFE, 0x00]),
-    ([0x1B, 0x01, 0x01], [0x1B, 0x01, 0x01]),
-    ([0x6B, 0x01, 0x01], [0x6B, 0x01, 0x01]),
-    ([0xFD, 0x01, 0x01], [0xFD, 0x01, 0x01]),
-    ([0xE0, 0x02, 0x01], [0xE0, 0x02, 0x01]),
-    ([0xF5, 0x02, 0x01], [0xF5, 0x02, 0x01]),
-    ([0x76, 0x03, 0x01], [0x7A, 0x03, 0x01]),
-    ([0xD4, 0x09, 0x01], [0xD4, 0x09, 0x01]),
-    ([0x0D, 0x0A, 0x01], [0x0D, 0x0A, 0x01]),
-    ([0x0F, 0x0A, 0x01], [0x0F, 0x0A, 0x01]),
-    ([0x38, 0x0A, 0x01], [0x38, 0x0A, 0x01]),
-    ([0x3A, 0x0A, 0x01], [0x3A, 0x0A, 0x01]),
-    ([0x3F, 0x0A, 0x01], [0x3F, 0x0A, 0x01]),
-    ([0xE5, 0x0A, 0x01], [0xE6, 0x0A, 0x01]),
-    ([0x24, 0x0D, 0x01], [0x27, 0x0D, 0x01]),
-    ([0x69, 0x0D, 0x01], [0x6D, 0x0D, 0x01]),
-    ([0x74, 0x0E, 0x01], [0x74, 0x0E, 0x01]),
-    ([0xAB, 0x0E, 0x01], [0xAC, 0x0E, 0x01]),
-    ([0xFA, 0x0E, 0x01], [0xFB, 0x0E, 0x01]),
-    ([0xFD, 0x0E, 0x01], [0xFF, 0x0E, 0x01]),
-    ([0x46, 0x0F, 0x01], [0x50, 0x0F, 0x01]),
-    ([0x82, 0x0F, 0x01], [0x85, 0x0F, 0x01]),
-    ([0x46, 0x10, 0x01], [0x46, 0x10, 0x01]),
-    ([0x70, 0x10, 0x01], [0x70, 0x10, 0x01]),
-    ([0x7F, 0x10, 0x01], [0x7F, 0x10, 0x01]),
-    ([0xB9, 0x10, 0x01], [0xBA, 0x10, 0x01]),
-    ([0x00, 0x11, 0x01], [0x02, 0x11, 0x01]),
-    ([0x33, 0x11, 0x01], [0x34, 0x11, 0x01]),
-    ([0x73, 0x11, 0x01], [0x73, 0x11, 0x01]),
-    ([0xC0, 0x11, 0x01], [0xC0, 0x11, 0x01]),
-    ([0xCA, 0x11, 0x01], [0xCA, 0x11, 0x01]),
-    ([0x35, 0x12, 0x01], [0x36, 0x12, 0x01]),
-    ([0xE9, 0x12, 0x01], [0xEA, 0x12, 0x01]),
-    ([0x3B, 0x13, 0x01], [0x3C, 0x13, 0x01]),
-    ([0x4D, 0x13, 0x01], [0x4D, 0x13, 0x01]),
-    ([0x66, 0x13, 0x01], [0x6C, 0x13, 0x01]),
-    ([0x70, 0x13, 0x01], [0x74, 0x13, 0x01]),
-    ([0xCE, 0x13, 0x01], [0xD0, 0x13, 0x01]),
-    ([0x42, 0x14, 0x01], [0x42, 0x14, 0x01]),
-    ([0x46, 0x14, 0x01], [0x46, 0x14, 0x01]),
-    ([0x5E, 0x14, 0x01], [0x5E, 0x14, 0x01]),
-    ([0xC2, 0x14, 0x01], [0xC3, 0x14, 0x01]),
-    ([0xBF, 0x15, 0x01], [0xC0, 0x15, 0x01]),
-    ([0x3F, 0x16, 0x01], [0x3F, 0x16, 0x01]),
-    ([0xB6, 0x16, 0x01], [0xB7, 0x16, 0x01]),
-    ([0x2B, 0x17, 0x01], [0x2B, 0x17, 0x01]),
-    ([0x39, 0x18, 0x01], [0x3A, 0x18, 0x01]),
-    ([0x3D, 0x19, 0x01], [0x3E, 0x19, 0x01]),
-    ([0x43, 0x19, 0x01], [0x43, 0x19, 0x01]),
-    ([0xE0, 0x19, 0x01], [0xE0, 0x19, 0x01]),
-    ([0x34, 0x1A, 0x01], [0x34, 0x1A, 0x01]),
-    ([0x47, 0x1A, 0x01], [0x47, 0x1A, 0x01]),
-    ([0x99, 0x1A, 0x01], [0x99, 0x1A, 0x01]),
-    ([0x3F, 0x1C, 0x01], [0x3F, 0x1C, 0x01]),
-    ([0x42, 0x1D, 0x01], [0x42, 0x1D, 0x01]),
-    ([0x44, 0x1D, 0x01], [0x45, 0x1D, 0x01]),
-    ([0x97, 0x1D, 0x01], [0x97, 0x1D, 0x01]),
-    ([0x41, 0x1F, 0x01], [0x42, 0x1F, 0x01]),
-    ([0x2F, 0x61, 0x01], [0x2F, 0x61, 0x01]),
-    ([0x30, 0x6B, 0x01], [0x36, 0x6B, 0x01]),
-    ([0xF0, 0x6F, 0x01], [0xF1, 0x6F, 0x01]),
-    ([0x65, 0xD1, 0x01], [0x66, 0xD1, 0x01]),
-    ([0x6D, 0xD1, 0x01], [0x72, 0xD1, 0x01]),
-    ([0x7B, 0xD1, 0x01], [0x82, 0xD1, 0x01]),
-    ([0x85, 0xD1, 0x01], [0x8B, 0xD1, 0x01]),
-    ([0xAA, 0xD1, 0x01], [0xAD, 0xD1, 0x01]),
-    ([0x42, 0xD2, 0x01], [0x44, 0xD2, 0x01]),
-    ([0x00, 0xE0, 0x01], [0x06, 0xE0, 0x01]),
-    ([0x08, 0xE0, 0x01], [0x18, 0xE0, 0x01]),
-    ([0x1B, 0xE0, 0x01], [0x21, 0xE0, 0x01]),
-    ([0x23, 0xE0, 0x01], [0x24, 0xE0, 0x01]),
-    ([0x26, 0xE0, 0x01], [0x2A, 0xE0, 0x01]),
-    ([0x8F, 0xE0, 0x01], [0x8F, 0xE0, 0x01]),
-    ([0x30, 0xE1, 0x01], [0x36, 0xE1, 0x01]),
-    ([0xAE, 0xE2, 0x01], [0xAE, 0xE2, 0x01]),
-    ([0xEC, 0xE2, 0x01], [0xEF, 0xE2, 0x01]),
-    ([0xEC, 0xE4, 0x01], [0xEF, 0xE4, 0x01]),
-    ([0xEE, 0xE5, 0x01], [0xEF, 0xE5, 0x01]),
-    ([0xE3, 0xE6, 0x01], [0xE3, 0xE6, 0x01]),
-    ([0xE6, 0xE6, 0x01], [0xE6, 0xE6, 0x01]),
-    ([0xEE, 0xE6, 0x01], [0xEF, 0xE6, 0x01]),
-    ([0xF5, 0xE6, 0x01], [0xF5, 0xE6, 0x01]),
-    ([0xD0, 0xE8, 0x01], [0xD6, 0xE8, 0x01]),
-    ([0x44, 0xE9, 0x01], [0x4A, 0xE9, 0x01]),
-    ([0x85, 0xEC, 0x01], [0x85, 0xEC, 0x01]),
-    ([0x15, 0xED, 0x01], [0x15, 0xED, 0x01]),
-];
 
+#[cfg(all(not(feature = "no-emoji-sequences"), not(feature = "bmp-only")))]
+pub(crate) const EMOJI_PRESENTATION_LEAVES_LEN: usize = 7;
+#[cfg(all(not(feature = "no-emoji-sequences"), feature = "bmp-only"))]
+pub(crate) const EMOJI_PRESENTATION_LEAVES_LEN: usize = 5;
 /// Array of 1024-bit bitmaps. Index into the correct bitmap with the 10 LSB of your codepoint
 /// to get whether it can start an emoji presentation sequence.
-pub(crate) static EMOJI_PRESENTATION_LEAVES: Align128<[[u8; 128]; 7]> = Align128([
+#[cfg(not(feature = "no-emoji-sequences"))]
+pub(crate) static EMOJI_PRESENTATION_LEAVES: Align128<[[u8; 128]; EMOJI_PRESENTATION_LEAVES_LEN]> =
+    EMOJI_PRESENTATION_LEAVES_CONST;
+/// [`EMOJI_PRESENTATION_LEAVES`], for `const fn`s, which can't read statics.
+#[cfg(not(feature = "no-emoji-sequences"))]
+#[rustfmt::skip]
+pub(crate) const EMOJI_PRESENTATION_LEAVES_CONST: Align128<[[u8; 128]; EMOJI_PRESENTATION_LEAVES_LEN]> = Align128([
     [
         0x00, 0x00, 0x00, 0x00, 0x08, 0x04, 0xFF, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x42, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -1526,6 +95,7 @@ pub(crate) static EMOJI_PRESENTATION_LEAVES: Align128<[[u8; 128]; 7]> = Align128
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
     ],
+    #[cfg(not(feature = "bmp-only"))]
     [
         0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -1537,6 +107,7 @@ pub(crate) static EMOJI_PRESENTATION_LEAVES: Align128<[[u8; 128]; 7]> = Align128
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0xC8, 0xCE, 0x80, 0x70, 0x00, 0x00,
         0x54, 0x7C, 0xF0, 0xFF, 0x01, 0x20, 0xA8, 0x00,
     ],
+    #[cfg(not(feature = "bmp-only"))]
     [
         0x00, 0x01, 0x20, 0x80, 0x40, 0x00, 0x00, 0x80, 0xC6, 0x63, 0x08, 0x00, 0x00, 0x04, 0x00,
         0x20, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x09, 0x88, 0x00, 0x08, 0x00, 0x84, 0x70, 0x3C,
@@ -1550,197 +121,5371 @@ pub(crate) static EMOJI_PRESENTATION_LEAVES: Align128<[[u8; 128]; 7]> = Align128
     ],
 ]);
 
+#[cfg(all(not(feature = "no-emoji-sequences"), not(feature = "bmp-only")))]
+pub(crate) const TEXT_PRESENTATION_LEAVES_LEN: usize = 10;
+#[cfg(all(not(feature = "no-emoji-sequences"), feature = "bmp-only"))]
+pub(crate) const TEXT_PRESENTATION_LEAVES_LEN: usize = 5;
+/// Sorted lists of inclusive ranges of the 8 LSB of text presentation sequence starters.
+/// There is one list for each value of the other bits that has any.
+#[cfg(not(feature = "no-emoji-sequences"))]
+pub(crate) static TEXT_PRESENTATION_LEAVES: [&[(u8, u8)]; TEXT_PRESENTATION_LEAVES_LEN] =
+    TEXT_PRESENTATION_LEAVES_CONST;
+/// [`TEXT_PRESENTATION_LEAVES`], for `const fn`s, which can't read statics.
+#[cfg(not(feature = "no-emoji-sequences"))]
 #[rustfmt::skip]
-pub(crate) static TEXT_PRESENTATION_LEAF_0: [(u8, u8); 4] = [
-    (0x1A, 0x1B),
-    (0xE9, 0xEC),
-    (0xF0, 0xF0),
-    (0xF3, 0xF3),
-];
-
-#[rustfmt::skip]
-pub(crate) static TEXT_PRESENTATION_LEAF_1: [(u8, u8); 1] = [
-    (0xFD, 0xFE),
-];
-
-#[rustfmt::skip]
-pub(crate) static TEXT_PRESENTATION_LEAF_2: [(u8, u8); 15] = [
-    (0x14, 0x15),
-    (0x48, 0x53),
-    (0x7F, 0x7F),
-    (0x93, 0x93),
-    (0xA1, 0xA1),
-    (0xAA, 0xAB),
-    (0xBD, 0xBE),
-    (0xC4, 0xC5),
-    (0xCE, 0xCE),
-    (0xD4, 0xD4),
-    (0xEA, 0xEA),
-    (0xF2, 0xF3),
-    (0xF5, 0xF5),
-    (0xFA, 0xFA),
-    (0xFD, 0xFD),
-];
-
-#[rustfmt::skip]
-pub(crate) static TEXT_PRESENTATION_LEAF_3: [(u8, u8); 10] = [
-    (0x05, 0x05),
-    (0x0A, 0x0B),
-    (0x28, 0x28),
-    (0x4C, 0x4C),
-    (0x4E, 0x4E),
-    (0x53, 0x55),
-    (0x57, 0x57),
-    (0x95, 0x97),
-    (0xB0, 0xB0),
-    (0xBF, 0xBF),
-];
-
-#[rustfmt::skip]
-pub(crate) static TEXT_PRESENTATION_LEAF_4: [(u8, u8); 3] = [
-    (0x1B, 0x1C),
-    (0x50, 0x50),
-    (0x55, 0x55),
-];
-
-#[rustfmt::skip]
-pub(crate) static TEXT_PRESENTATION_LEAF_5: [(u8, u8); 1] = [
-    (0x04, 0x04),
-];
-
-#[rustfmt::skip]
-pub(crate) static TEXT_PRESENTATION_LEAF_6: [(u8, u8); 13] = [
-    (0x0D, 0x0F),
-    (0x15, 0x15),
-    (0x1C, 0x1C),
-    (0x78, 0x78),
-    (0x93, 0x93),
-    (0xA7, 0xA7),
-    (0xAC, 0xAE),
-    (0xC2, 0xC2),
-    (0xC4, 0xC4),
-    (0xC6, 0xC6),
-    (0xCA, 0xCA),
-    (0xE0, 0xE0),
-    (0xED, 0xED),
-];
-
-#[rustfmt::skip]
-pub(crate) static TEXT_PRESENTATION_LEAF_7: [(u8, u8); 22] = [
-    (0x08, 0x08),
-    (0x15, 0x15),
-    (0x1F, 0x1F),
-    (0x26, 0x26),
-    (0x42, 0x42),
-    (0x46, 0x49),
-    (0x4D, 0x4E),
-    (0x53, 0x53),
-    (0x6A, 0x6A),
-    (0x7D, 0x7D),
-    (0xA3, 0xA3),
-    (0xB0, 0xB0),
-    (0xB3, 0xB3),
-    (0xBB, 0xBB),
-    (0xBF, 0xBF),
-    (0xCB, 0xCB),
-    (0xDA, 0xDA),
-    (0xDF, 0xDF),
-    (0xE4, 0xE6),
-    (0xEA, 0xED),
-    (0xF7, 0xF7),
-    (0xF9, 0xFB),
-];
-
-#[rustfmt::skip]
-pub(crate) static TEXT_PRESENTATION_LEAF_8: [(u8, u8); 4] = [
-    (0x08, 0x08),
-    (0x0D, 0x0D),
-    (0x12, 0x13),
-    (0x50, 0x67),
-];
-
-#[rustfmt::skip]
-pub(crate) static TEXT_PRESENTATION_LEAF_9: [(u8, u8); 10] = [
-    (0x10, 0x10),
-    (0x87, 0x87),
-    (0x8D, 0x8D),
-    (0x91, 0x91),
-    (0x94, 0x94),
-    (0x98, 0x98),
-    (0xAD, 0xAD),
-    (0xB2, 0xB2),
-    (0xB9, 0xBA),
-    (0xBC, 0xBC),
+pub(crate) const TEXT_PRESENTATION_LEAVES_CONST: [&[(u8, u8)]; TEXT_PRESENTATION_LEAVES_LEN] = [
+    &[
+        (0x1A, 0x1B),
+        (0xE9, 0xEC),
+        (0xF0, 0xF0),
+        (0xF3, 0xF3),
+    ],
+    &[
+        (0xFD, 0xFE),
+    ],
+    &[
+        (0x14, 0x15),
+        (0x48, 0x53),
+        (0x7F, 0x7F),
+        (0x93, 0x93),
+        (0xA1, 0xA1),
+        (0xAA, 0xAB),
+        (0xBD, 0xBE),
+        (0xC4, 0xC5),
+        (0xCE, 0xCE),
+        (0xD4, 0xD4),
+        (0xEA, 0xEA),
+        (0xF2, 0xF3),
+        (0xF5, 0xF5),
+        (0xFA, 0xFA),
+        (0xFD, 0xFD),
+    ],
+    &[
+        (0x05, 0x05),
+        (0x0A, 0x0B),
+        (0x28, 0x28),
+        (0x4C, 0x4C),
+        (0x4E, 0x4E),
+        (0x53, 0x55),
+        (0x57, 0x57),
+        (0x95, 0x97),
+        (0xB0, 0xB0),
+        (0xBF, 0xBF),
+    ],
+    &[
+        (0x1B, 0x1C),
+        (0x50, 0x50),
+        (0x55, 0x55),
+    ],
+    #[cfg(not(feature = "bmp-only"))]
+    &[
+        (0x04, 0x04),
+    ],
+    #[cfg(not(feature = "bmp-only"))]
+    &[
+        (0x0D, 0x0F),
+        (0x15, 0x15),
+        (0x1C, 0x1C),
+        (0x78, 0x78),
+        (0x93, 0x93),
+        (0xA7, 0xA7),
+        (0xAC, 0xAE),
+        (0xC2, 0xC2),
+        (0xC4, 0xC4),
+        (0xC6, 0xC6),
+        (0xCA, 0xCA),
+        (0xE0, 0xE0),
+        (0xED, 0xED),
+    ],
+    #[cfg(not(feature = "bmp-only"))]
+    &[
+        (0x08, 0x08),
+        (0x15, 0x15),
+        (0x1F, 0x1F),
+        (0x26, 0x26),
+        (0x42, 0x42),
+        (0x46, 0x49),
+        (0x4D, 0x4E),
+        (0x53, 0x53),
+        (0x6A, 0x6A),
+        (0x7D, 0x7D),
+        (0xA3, 0xA3),
+        (0xB0, 0xB0),
+        (0xB3, 0xB3),
+        (0xBB, 0xBB),
+        (0xBF, 0xBF),
+        (0xCB, 0xCB),
+        (0xDA, 0xDA),
+        (0xDF, 0xDF),
+        (0xE4, 0xE6),
+        (0xEA, 0xED),
+        (0xF7, 0xF7),
+        (0xF9, 0xFB),
+    ],
+    #[cfg(not(feature = "bmp-only"))]
+    &[
+        (0x08, 0x08),
+        (0x0D, 0x0D),
+        (0x12, 0x13),
+        (0x50, 0x67),
+    ],
+    #[cfg(not(feature = "bmp-only"))]
+    &[
+        (0x10, 0x10),
+        (0x87, 0x87),
+        (0x8D, 0x8D),
+        (0x91, 0x91),
+        (0x94, 0x94),
+        (0x98, 0x98),
+        (0xAD, 0xAD),
+        (0xB2, 0xB2),
+        (0xB9, 0xBA),
+        (0xBC, 0xBC),
+    ],
 ];
 
+#[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+pub(crate) const EMOJI_MODIFIER_LEAVES_LEN: usize = 8;
+/// Sorted lists of inclusive ranges of the 8 LSB of `Emoji_Modifier_Base` codepoints.
+/// There is one list for each value of the other bits that has any.
+#[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+pub(crate) static EMOJI_MODIFIER_LEAVES: [&[(u8, u8)]; EMOJI_MODIFIER_LEAVES_LEN] =
+    EMOJI_MODIFIER_LEAVES_CONST;
+/// [`EMOJI_MODIFIER_LEAVES`], for `const fn`s, which can't read statics.
+#[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
 #[rustfmt::skip]
-pub(crate) static EMOJI_MODIFIER_LEAF_0: [(u8, u8); 2] = [
-    (0x1D, 0x1D),
-    (0xF9, 0xF9),
+pub(crate) const EMOJI_MODIFIER_LEAVES_CONST: [&[(u8, u8)]; EMOJI_MODIFIER_LEAVES_LEN] = [
+    &[
+        (0x1D, 0x1D),
+        (0xF9, 0xF9),
+    ],
+    &[
+        (0x0A, 0x0D),
+    ],
+    &[
+        (0x85, 0x85),
+        (0xC2, 0xC4),
+        (0xC7, 0xC7),
+        (0xCA, 0xCC),
+    ],
+    &[
+        (0x42, 0x43),
+        (0x46, 0x50),
+        (0x66, 0x78),
+        (0x7C, 0x7C),
+        (0x81, 0x83),
+        (0x85, 0x87),
+        (0x8F, 0x8F),
+        (0x91, 0x91),
+        (0xAA, 0xAA),
+    ],
+    &[
+        (0x74, 0x75),
+        (0x7A, 0x7A),
+        (0x90, 0x90),
+        (0x95, 0x96),
+    ],
+    &[
+        (0x45, 0x47),
+        (0x4B, 0x4F),
+        (0xA3, 0xA3),
+        (0xB4, 0xB6),
+        (0xC0, 0xC0),
+        (0xCC, 0xCC),
+    ],
+    &[
+        (0x0C, 0x0C),
+        (0x0F, 0x0F),
+        (0x18, 0x1F),
+        (0x26, 0x26),
+        (0x30, 0x39),
+        (0x3C, 0x3E),
+        (0x77, 0x77),
+        (0xB5, 0xB6),
+        (0xB8, 0xB9),
+        (0xBB, 0xBB),
+        (0xCD, 0xCF),
+        (0xD1, 0xDD),
+    ],
+    &[
+        (0xC3, 0xC5),
+        (0xF0, 0xF8),
+    ],
 ];
 
+/// Sorted list of the inclusive ranges of unassigned codepoints in the BMP,
+/// including noncharacters.
 #[rustfmt::skip]
-pub(crate) static EMOJI_MODIFIER_LEAF_1: [(u8, u8); 1] = [
-    (0x0A, 0x0D),
+pub(crate) static UNASSIGNED_BMP: [(u16, u16); 330] = [
+    (0x0378, 0x0379),
+    (0x0380, 0x0383),
+    (0x038B, 0x038B),
+    (0x038D, 0x038D),
+    (0x03A2, 0x03A2),
+    (0x0530, 0x0530),
+    (0x0557, 0x0558),
+    (0x058B, 0x058C),
+    (0x0590, 0x0590),
+    (0x05C8, 0x05CF),
+    (0x05EB, 0x05EE),
+    (0x05F5, 0x05FF),
+    (0x070E, 0x070E),
+    (0x074B, 0x074C),
+    (0x07B2, 0x07BF),
+    (0x07FB, 0x07FC),
+    (0x082E, 0x082F),
+    (0x083F, 0x083F),
+    (0x085C, 0x085D),
+    (0x085F, 0x085F),
+    (0x086B, 0x086F),
+    (0x0892, 0x0896),
+    (0x0984, 0x0984),
+    (0x098D, 0x098E),
+    (0x0991, 0x0992),
+    (0x09A9, 0x09A9),
+    (0x09B1, 0x09B1),
+    (0x09B3, 0x09B5),
+    (0x09BA, 0x09BB),
+    (0x09C5, 0x09C6),
+    (0x09C9, 0x09CA),
+    (0x09CF, 0x09D6),
+    (0x09D8, 0x09DB),
+    (0x09DE, 0x09DE),
+    (0x09E4, 0x09E5),
+    (0x09FF, 0x0A00),
+    (0x0A04, 0x0A04),
+    (0x0A0B, 0x0A0E),
+    (0x0A11, 0x0A12),
+    (0x0A29, 0x0A29),
+    (0x0A31, 0x0A31),
+    (0x0A34, 0x0A34),
+    (0x0A37, 0x0A37),
+    (0x0A3A, 0x0A3B),
+    (0x0A3D, 0x0A3D),
+    (0x0A43, 0x0A46),
+    (0x0A49, 0x0A4A),
+    (0x0A4E, 0x0A50),
+    (0x0A52, 0x0A58),
+    (0x0A5D, 0x0A5D),
+    (0x0A5F, 0x0A65),
+    (0x0A77, 0x0A80),
+    (0x0A84, 0x0A84),
+    (0x0A8E, 0x0A8E),
+    (0x0A92, 0x0A92),
+    (0x0AA9, 0x0AA9),
+    (0x0AB1, 0x0AB1),
+    (0x0AB4, 0x0AB4),
+    (0x0ABA, 0x0ABB),
+    (0x0AC6, 0x0AC6),
+    (0x0ACA, 0x0ACA),
+    (0x0ACE, 0x0ACF),
+    (0x0AD1, 0x0ADF),
+    (0x0AE4, 0x0AE5),
+    (0x0AF2, 0x0AF8),
+    (0x0B00, 0x0B00),
+    (0x0B04, 0x0B04),
+    (0x0B0D, 0x0B0E),
+    (0x0B11, 0x0B12),
+    (0x0B29, 0x0B29),
+    (0x0B31, 0x0B31),
+    (0x0B34, 0x0B34),
+    (0x0B3A, 0x0B3B),
+    (0x0B45, 0x0B46),
+    (0x0B49, 0x0B4A),
+    (0x0B4E, 0x0B54),
+    (0x0B58, 0x0B5B),
+    (0x0B5E, 0x0B5E),
+    (0x0B64, 0x0B65),
+    (0x0B78, 0x0B81),
+    (0x0B84, 0x0B84),
+    (0x0B8B, 0x0B8D),
+    (0x0B91, 0x0B91),
+    (0x0B96, 0x0B98),
+    (0x0B9B, 0x0B9B),
+    (0x0B9D, 0x0B9D),
+    (0x0BA0, 0x0BA2),
+    (0x0BA5, 0x0BA7),
+    (0x0BAB, 0x0BAD),
+    (0x0BBA, 0x0BBD),
+    (0x0BC3, 0x0BC5),
+    (0x0BC9, 0x0BC9),
+    (0x0BCE, 0x0BCF),
+    (0x0BD1, 0x0BD6),
+    (0x0BD8, 0x0BE5),
+    (0x0BFB, 0x0BFF),
+    (0x0C0D, 0x0C0D),
+    (0x0C11, 0x0C11),
+    (0x0C29, 0x0C29),
+    (0x0C3A, 0x0C3B),
+    (0x0C45, 0x0C45),
+    (0x0C49, 0x0C49),
+    (0x0C4E, 0x0C54),
+    (0x0C57, 0x0C57),
+    (0x0C5B, 0x0C5B),
+    (0x0C5E, 0x0C5F),
+    (0x0C64, 0x0C65),
+    (0x0C70, 0x0C76),
+    (0x0C8D, 0x0C8D),
+    (0x0C91, 0x0C91),
+    (0x0CA9, 0x0CA9),
+    (0x0CB4, 0x0CB4),
+    (0x0CBA, 0x0CBB),
+    (0x0CC5, 0x0CC5),
+    (0x0CC9, 0x0CC9),
+    (0x0CCE, 0x0CD4),
+    (0x0CD7, 0x0CDB),
+    (0x0CDF, 0x0CDF),
+    (0x0CE4, 0x0CE5),
+    (0x0CF0, 0x0CF0),
+    (0x0CF4, 0x0CFF),
+    (0x0D0D, 0x0D0D),
+    (0x0D11, 0x0D11),
+    (0x0D45, 0x0D45),
+    (0x0D49, 0x0D49),
+    (0x0D50, 0x0D53),
+    (0x0D64, 0x0D65),
+    (0x0D80, 0x0D80),
+    (0x0D84, 0x0D84),
+    (0x0D97, 0x0D99),
+    (0x0DB2, 0x0DB2),
+    (0x0DBC, 0x0DBC),
+    (0x0DBE, 0x0DBF),
+    (0x0DC7, 0x0DC9),
+    (0x0DCB, 0x0DCE),
+    (0x0DD5, 0x0DD5),
+    (0x0DD7, 0x0DD7),
+    (0x0DE0, 0x0DE5),
+    (0x0DF0, 0x0DF1),
+    (0x0DF5, 0x0E00),
+    (0x0E3B, 0x0E3E),
+    (0x0E5C, 0x0E80),
+    (0x0E83, 0x0E83),
+    (0x0E85, 0x0E85),
+    (0x0E8B, 0x0E8B),
+    (0x0EA4, 0x0EA4),
+    (0x0EA6, 0x0EA6),
+    (0x0EBE, 0x0EBF),
+    (0x0EC5, 0x0EC5),
+    (0x0EC7, 0x0EC7),
+    (0x0ECF, 0x0ECF),
+    (0x0EDA, 0x0EDB),
+    (0x0EE0, 0x0EFF),
+    (0x0F48, 0x0F48),
+    (0x0F6D, 0x0F70),
+    (0x0F98, 0x0F98),
+    (0x0FBD, 0x0FBD),
+    (0x0FCD, 0x0FCD),
+    (0x0FDB, 0x0FFF),
+    (0x10C6, 0x10C6),
+    (0x10C8, 0x10CC),
+    (0x10CE, 0x10CF),
+    (0x1249, 0x1249),
+    (0x124E, 0x124F),
+    (0x1257, 0x1257),
+    (0x1259, 0x1259),
+    (0x125E, 0x125F),
+    (0x1289, 0x1289),
+    (0x128E, 0x128F),
+    (0x12B1, 0x12B1),
+    (0x12B6, 0x12B7),
+    (0x12BF, 0x12BF),
+    (0x12C1, 0x12C1),
+    (0x12C6, 0x12C7),
+    (0x12D7, 0x12D7),
+    (0x1311, 0x1311),
+    (0x1316, 0x1317),
+    (0x135B, 0x135C),
+    (0x137D, 0x137F),
+    (0x139A, 0x139F),
+    (0x13F6, 0x13F7),
+    (0x13FE, 0x13FF),
+    (0x169D, 0x169F),
+    (0x16F9, 0x16FF),
+    (0x1716, 0x171E),
+    (0x1737, 0x173F),
+    (0x1754, 0x175F),
+    (0x176D, 0x176D),
+    (0x1771, 0x1771),
+    (0x1774, 0x177F),
+    (0x17DE, 0x17DF),
+    (0x17EA, 0x17EF),
+    (0x17FA, 0x17FF),
+    (0x181A, 0x181F),
+    (0x1879, 0x187F),
+    (0x18AB, 0x18AF),
+    (0x18F6, 0x18FF),
+    (0x191F, 0x191F),
+    (0x192C, 0x192F),
+    (0x193C, 0x193F),
+    (0x1941, 0x1943),
+    (0x196E, 0x196F),
+    (0x1975, 0x197F),
+    (0x19AC, 0x19AF),
+    (0x19CA, 0x19CF),
+    (0x19DB, 0x19DD),
+    (0x1A1C, 0x1A1D),
+    (0x1A5F, 0x1A5F),
+    (0x1A7D, 0x1A7E),
+    (0x1A8A, 0x1A8F),
+    (0x1A9A, 0x1A9F),
+    (0x1AAE, 0x1AAF),
+    (0x1ADE, 0x1ADF),
+    (0x1AEC, 0x1AFF),
+    (0x1B4D, 0x1B4D),
+    (0x1BF4, 0x1BFB),
+    (0x1C38, 0x1C3A),
+    (0x1C4A, 0x1C4C),
+    (0x1C8B, 0x1C8F),
+    (0x1CBB, 0x1CBC),
+    (0x1CC8, 0x1CCF),
+    (0x1CFB, 0x1CFF),
+    (0x1F16, 0x1F17),
+    (0x1F1E, 0x1F1F),
+    (0x1F46, 0x1F47),
+    (0x1F4E, 0x1F4F),
+    (0x1F58, 0x1F58),
+    (0x1F5A, 0x1F5A),
+    (0x1F5C, 0x1F5C),
+    (0x1F5E, 0x1F5E),
+    (0x1F7E, 0x1F7F),
+    (0x1FB5, 0x1FB5),
+    (0x1FC5, 0x1FC5),
+    (0x1FD4, 0x1FD5),
+    (0x1FDC, 0x1FDC),
+    (0x1FF0, 0x1FF1),
+    (0x1FF5, 0x1FF5),
+    (0x1FFF, 0x1FFF),
+    (0x2065, 0x2065),
+    (0x2072, 0x2073),
+    (0x208F, 0x208F),
+    (0x209D, 0x209F),
+    (0x20C2, 0x20CF),
+    (0x20F1, 0x20FF),
+    (0x218C, 0x218F),
+    (0x242A, 0x243F),
+    (0x244B, 0x245F),
+    (0x2B74, 0x2B75),
+    (0x2CF4, 0x2CF8),
+    (0x2D26, 0x2D26),
+    (0x2D28, 0x2D2C),
+    (0x2D2E, 0x2D2F),
+    (0x2D68, 0x2D6E),
+    (0x2D71, 0x2D7E),
+    (0x2D97, 0x2D9F),
+    (0x2DA7, 0x2DA7),
+    (0x2DAF, 0x2DAF),
+    (0x2DB7, 0x2DB7),
+    (0x2DBF, 0x2DBF),
+    (0x2DC7, 0x2DC7),
+    (0x2DCF, 0x2DCF),
+    (0x2DD7, 0x2DD7),
+    (0x2DDF, 0x2DDF),
+    (0x2E5E, 0x2E7F),
+    (0x2E9A, 0x2E9A),
+    (0x2EF4, 0x2EFF),
+    (0x2FD6, 0x2FEF),
+    (0x3040, 0x3040),
+    (0x3097, 0x3098),
+    (0x3100, 0x3104),
+    (0x3130, 0x3130),
+    (0x318F, 0x318F),
+    (0x31E6, 0x31EE),
+    (0x321F, 0x321F),
+    (0xA48D, 0xA48F),
+    (0xA4C7, 0xA4CF),
+    (0xA62C, 0xA63F),
+    (0xA6F8, 0xA6FF),
+    (0xA7DD, 0xA7F0),
+    (0xA82D, 0xA82F),
+    (0xA83A, 0xA83F),
+    (0xA878, 0xA87F),
+    (0xA8C6, 0xA8CD),
+    (0xA8DA, 0xA8DF),
+    (0xA954, 0xA95E),
+    (0xA97D, 0xA97F),
+    (0xA9CE, 0xA9CE),
+    (0xA9DA, 0xA9DD),
+    (0xA9FF, 0xA9FF),
+    (0xAA37, 0xAA3F),
+    (0xAA4E, 0xAA4F),
+    (0xAA5A, 0xAA5B),
+    (0xAAC3, 0xAADA),
+    (0xAAF7, 0xAB00),
+    (0xAB07, 0xAB08),
+    (0xAB0F, 0xAB10),
+    (0xAB17, 0xAB1F),
+    (0xAB27, 0xAB27),
+    (0xAB2F, 0xAB2F),
+    (0xAB6C, 0xAB6F),
+    (0xABEE, 0xABEF),
+    (0xABFA, 0xABFF),
+    (0xD7A4, 0xD7AF),
+    (0xD7C7, 0xD7CA),
+    (0xD7FC, 0xD7FF),
+    (0xFA6E, 0xFA6F),
+    (0xFADA, 0xFAFF),
+    (0xFB07, 0xFB12),
+    (0xFB18, 0xFB1C),
+    (0xFB37, 0xFB37),
+    (0xFB3D, 0xFB3D),
+    (0xFB3F, 0xFB3F),
+    (0xFB42, 0xFB42),
+    (0xFB45, 0xFB45),
+    (0xFDD0, 0xFDEF),
+    (0xFE1A, 0xFE1F),
+    (0xFE53, 0xFE53),
+    (0xFE67, 0xFE67),
+    (0xFE6C, 0xFE6F),
+    (0xFE75, 0xFE75),
+    (0xFEFD, 0xFEFE),
+    (0xFF00, 0xFF00),
+    (0xFFBF, 0xFFC1),
+    (0xFFC8, 0xFFC9),
+    (0xFFD0, 0xFFD1),
+    (0xFFD8, 0xFFD9),
+    (0xFFDD, 0xFFDF),
+    (0xFFE7, 0xFFE7),
+    (0xFFEF, 0xFFF8),
+    (0xFFFE, 0xFFFF),
 ];
 
+/// Sorted list of the inclusive ranges of unassigned codepoints outside of the BMP,
+/// including noncharacters.
+#[cfg(not(feature = "bmp-only"))]
 #[rustfmt::skip]
-pub(crate) static EMOJI_MODIFIER_LEAF_2: [(u8, u8); 4] = [
-    (0x85, 0x85),
-    (0xC2, 0xC4),
-    (0xC7, 0xC7),
-    (0xCA, 0xCC),
+pub(crate) static UNASSIGNED_SUPPLEMENTARY: [(u32, u32); 405] = [
+    (0x01000C, 0x01000C),
+    (0x010027, 0x010027),
+    (0x01003B, 0x01003B),
+    (0x01003E, 0x01003E),
+    (0x01004E, 0x01004F),
+    (0x01005E, 0x01007F),
+    (0x0100FB, 0x0100FF),
+    (0x010103, 0x010106),
+    (0x010134, 0x010136),
+    (0x01018F, 0x01018F),
+    (0x01019D, 0x01019F),
+    (0x0101A1, 0x0101CF),
+    (0x0101FE, 0x01027F),
+    (0x01029D, 0x01029F),
+    (0x0102D1, 0x0102DF),
+    (0x0102FC, 0x0102FF),
+    (0x010324, 0x01032C),
+    (0x01034B, 0x01034F),
+    (0x01037B, 0x01037F),
+    (0x01039E, 0x01039E),
+    (0x0103C4, 0x0103C7),
+    (0x0103D6, 0x0103FF),
+    (0x01049E, 0x01049F),
+    (0x0104AA, 0x0104AF),
+    (0x0104D4, 0x0104D7),
+    (0x0104FC, 0x0104FF),
+    (0x010528, 0x01052F),
+    (0x010564, 0x01056E),
+    (0x01057B, 0x01057B),
+    (0x01058B, 0x01058B),
+    (0x010593, 0x010593),
+    (0x010596, 0x010596),
+    (0x0105A2, 0x0105A2),
+    (0x0105B2, 0x0105B2),
+    (0x0105BA, 0x0105BA),
+    (0x0105BD, 0x0105BF),
+    (0x0105F4, 0x0105FF),
+    (0x010737, 0x01073F),
+    (0x010756, 0x01075F),
+    (0x010768, 0x01077F),
+    (0x010786, 0x010786),
+    (0x0107B1, 0x0107B1),
+    (0x0107BB, 0x0107FF),
+    (0x010806, 0x010807),
+    (0x010809, 0x010809),
+    (0x010836, 0x010836),
+    (0x010839, 0x01083B),
+    (0x01083D, 0x01083E),
+    (0x010856, 0x010856),
+    (0x01089F, 0x0108A6),
+    (0x0108B0, 0x0108DF),
+    (0x0108F3, 0x0108F3),
+    (0x0108F6, 0x0108FA),
+    (0x01091C, 0x01091E),
+    (0x01093A, 0x01093E),
+    (0x01095A, 0x01097F),
+    (0x0109B8, 0x0109BB),
+    (0x0109D0, 0x0109D1),
+    (0x010A04, 0x010A04),
+    (0x010A07, 0x010A0B),
+    (0x010A14, 0x010A14),
+    (0x010A18, 0x010A18),
+    (0x010A36, 0x010A37),
+    (0x010A3B, 0x010A3E),
+    (0x010A49, 0x010A4F),
+    (0x010A59, 0x010A5F),
+    (0x010AA0, 0x010ABF),
+    (0x010AE7, 0x010AEA),
+    (0x010AF7, 0x010AFF),
+    (0x010B36, 0x010B38),
+    (0x010B56, 0x010B57),
+    (0x010B73, 0x010B77),
+    (0x010B92, 0x010B98),
+    (0x010B9D, 0x010BA8),
+    (0x010BB0, 0x010BFF),
+    (0x010C49, 0x010C7F),
+    (0x010CB3, 0x010CBF),
+    (0x010CF3, 0x010CF9),
+    (0x010D28, 0x010D2F),
+    (0x010D3A, 0x010D3F),
+    (0x010D66, 0x010D68),
+    (0x010D86, 0x010D8D),
+    (0x010D90, 0x010E5F),
+    (0x010E7F, 0x010E7F),
+    (0x010EAA, 0x010EAA),
+    (0x010EAE, 0x010EAF),
+    (0x010EB2, 0x010EC1),
+    (0x010EC8, 0x010ECF),
+    (0x010ED9, 0x010EF9),
+    (0x010F28, 0x010F2F),
+    (0x010F5A, 0x010F6F),
+    (0x010F8A, 0x010FAF),
+    (0x010FCC, 0x010FDF),
+    (0x010FF7, 0x010FFF),
+    (0x01104E, 0x011051),
+    (0x011076, 0x01107E),
+    (0x0110C3, 0x0110CC),
+    (0x0110CE, 0x0110CF),
+    (0x0110E9, 0x0110EF),
+    (0x0110FA, 0x0110FF),
+    (0x011135, 0x011135),
+    (0x011148, 0x01114F),
+    (0x011177, 0x01117F),
+    (0x0111E0, 0x0111E0),
+    (0x0111F5, 0x0111FF),
+    (0x011212, 0x011212),
+    (0x011242, 0x01127F),
+    (0x011287, 0x011287),
+    (0x011289, 0x011289),
+    (0x01128E, 0x01128E),
+    (0x01129E, 0x01129E),
+    (0x0112AA, 0x0112AF),
+    (0x0112EB, 0x0112EF),
+    (0x0112FA, 0x0112FF),
+    (0x011304, 0x011304),
+    (0x01130D, 0x01130E),
+    (0x011311, 0x011312),
+    (0x011329, 0x011329),
+    (0x011331, 0x011331),
+    (0x011334, 0x011334),
+    (0x01133A, 0x01133A),
+    (0x011345, 0x011346),
+    (0x011349, 0x01134A),
+    (0x01134E, 0x01134F),
+    (0x011351, 0x011356),
+    (0x011358, 0x01135C),
+    (0x011364, 0x011365),
+    (0x01136D, 0x01136F),
+    (0x011375, 0x01137F),
+    (0x01138A, 0x01138A),
+    (0x01138C, 0x01138D),
+    (0x01138F, 0x01138F),
+    (0x0113B6, 0x0113B6),
+    (0x0113C1, 0x0113C1),
+    (0x0113C3, 0x0113C4),
+    (0x0113C6, 0x0113C6),
+    (0x0113CB, 0x0113CB),
+    (0x0113D6, 0x0113D6),
+    (0x0113D9, 0x0113E0),
+    (0x0113E3, 0x0113FF),
+    (0x01145C, 0x01145C),
+    (0x011462, 0x01147F),
+    (0x0114C8, 0x0114CF),
+    (0x0114DA, 0x01157F),
+    (0x0115B6, 0x0115B7),
+    (0x0115DE, 0x0115FF),
+    (0x011645, 0x01164F),
+    (0x01165A, 0x01165F),
+    (0x01166D, 0x01167F),
+    (0x0116BA, 0x0116BF),
+    (0x0116CA, 0x0116CF),
+    (0x0116E4, 0x0116FF),
+    (0x01171B, 0x01171C),
+    (0x01172C, 0x01172F),
+    (0x011747, 0x0117FF),
+    (0x01183C, 0x01189F),
+    (0x0118F3, 0x0118FE),
+    (0x011907, 0x011908),
+    (0x01190A, 0x01190B),
+    (0x011914, 0x011914),
+    (0x011917, 0x011917),
+    (0x011936, 0x011936),
+    (0x011939, 0x01193A),
+    (0x011947, 0x01194F),
+    (0x01195A, 0x01199F),
+    (0x0119A8, 0x0119A9),
+    (0x0119D8, 0x0119D9),
+    (0x0119E5, 0x0119FF),
+    (0x011A48, 0x011A4F),
+    (0x011AA3, 0x011AAF),
+    (0x011AF9, 0x011AFF),
+    (0x011B0A, 0x011B5F),
+    (0x011B68, 0x011BBF),
+    (0x011BE2, 0x011BEF),
+    (0x011BFA, 0x011BFF),
+    (0x011C09, 0x011C09),
+    (0x011C37, 0x011C37),
+    (0x011C46, 0x011C4F),
+    (0x011C6D, 0x011C6F),
+    (0x011C90, 0x011C91),
+    (0x011CA8, 0x011CA8),
+    (0x011CB7, 0x011CFF),
+    (0x011D07, 0x011D07),
+    (0x011D0A, 0x011D0A),
+    (0x011D37, 0x011D39),
+    (0x011D3B, 0x011D3B),
+    (0x011D3E, 0x011D3E),
+    (0x011D48, 0x011D4F),
+    (0x011D5A, 0x011D5F),
+    (0x011D66, 0x011D66),
+    (0x011D69, 0x011D69),
+    (0x011D8F, 0x011D8F),
+    (0x011D92, 0x011D92),
+    (0x011D99, 0x011D9F),
+    (0x011DAA, 0x011DAF),
+    (0x011DDC, 0x011DDF),
+    (0x011DEA, 0x011EDF),
+    (0x011EF9, 0x011EFF),
+    (0x011F11, 0x011F11),
+    (0x011F3B, 0x011F3D),
+    (0x011F5B, 0x011FAF),
+    (0x011FB1, 0x011FBF),
+    (0x011FF2, 0x011FFE),
+    (0x01239A, 0x0123FF),
+    (0x01246F, 0x01246F),
+    (0x012475, 0x01247F),
+    (0x012544, 0x012F8F),
+    (0x012FF3, 0x012FFF),
+    (0x013456, 0x01345F),
+    (0x0143FB, 0x0143FF),
+    (0x014647, 0x0160FF),
+    (0x01613A, 0x0167FF),
+    (0x016A39, 0x016A3F),
+    (0x016A5F, 0x016A5F),
+    (0x016A6A, 0x016A6D),
+    (0x016ABF, 0x016ABF),
+    (0x016ACA, 0x016ACF),
+    (0x016AEE, 0x016AEF),
+    (0x016AF6, 0x016AFF),
+    (0x016B46, 0x016B4F),
+    (0x016B5A, 0x016B5A),
+    (0x016B62, 0x016B62),
+    (0x016B78, 0x016B7C),
+    (0x016B90, 0x016D3F),
+    (0x016D7A, 0x016E3F),
+    (0x016E9B, 0x016E9F),
+    (0x016EB9, 0x016EBA),
+    (0x016ED4, 0x016EFF),
+    (0x016F4B, 0x016F4E),
+    (0x016F88, 0x016F8E),
+    (0x016FA0, 0x016FDF),
+    (0x016FE5, 0x016FEF),
+    (0x016FF7, 0x016FFF),
+    (0x018CD6, 0x018CFE),
+    (0x018D1F, 0x018D7F),
+    (0x018DF3, 0x01AFEF),
+    (0x01AFF4, 0x01AFF4),
+    (0x01AFFC, 0x01AFFC),
+    (0x01AFFF, 0x01AFFF),
+    (0x01B123, 0x01B131),
+    (0x01B133, 0x01B14F),
+    (0x01B153, 0x01B154),
+    (0x01B156, 0x01B163),
+    (0x01B168, 0x01B16F),
+    (0x01B2FC, 0x01BBFF),
+    (0x01BC6B, 0x01BC6F),
+    (0x01BC7D, 0x01BC7F),
+    (0x01BC89, 0x01BC8F),
+    (0x01BC9A, 0x01BC9B),
+    (0x01BCA4, 0x01CBFF),
+    (0x01CCFD, 0x01CCFF),
+    (0x01CEB4, 0x01CEB9),
+    (0x01CED1, 0x01CEDF),
+    (0x01CEF1, 0x01CEFF),
+    (0x01CF2E, 0x01CF2F),
+    (0x01CF47, 0x01CF4F),
+    (0x01CFC4, 0x01CFFF),
+    (0x01D0F6, 0x01D0FF),
+    (0x01D127, 0x01D128),
+    (0x01D1EB, 0x01D1FF),
+    (0x01D246, 0x01D2BF),
+    (0x01D2D4, 0x01D2DF),
+    (0x01D2F4, 0x01D2FF),
+    (0x01D357, 0x01D35F),
+    (0x01D379, 0x01D3FF),
+    (0x01D455, 0x01D455),
+    (0x01D49D, 0x01D49D),
+    (0x01D4A0, 0x01D4A1),
+    (0x01D4A3, 0x01D4A4),
+    (0x01D4A7, 0x01D4A8),
+    (0x01D4AD, 0x01D4AD),
+    (0x01D4BA, 0x01D4BA),
+    (0x01D4BC, 0x01D4BC),
+    (0x01D4C4, 0x01D4C4),
+    (0x01D506, 0x01D506),
+    (0x01D50B, 0x01D50C),
+    (0x01D515, 0x01D515),
+    (0x01D51D, 0x01D51D),
+    (0x01D53A, 0x01D53A),
+    (0x01D53F, 0x01D53F),
+    (0x01D545, 0x01D545),
+    (0x01D547, 0x01D549),
+    (0x01D551, 0x01D551),
+    (0x01D6A6, 0x01D6A7),
+    (0x01D7CC, 0x01D7CD),
+    (0x01DA8C, 0x01DA9A),
+    (0x01DAA0, 0x01DAA0),
+    (0x01DAB0, 0x01DEFF),
+    (0x01DF1F, 0x01DF24),
+    (0x01DF2B, 0x01DFFF),
+    (0x01E007, 0x01E007),
+    (0x01E019, 0x01E01A),
+    (0x01E022, 0x01E022),
+    (0x01E025, 0x01E025),
+    (0x01E02B, 0x01E02F),
+    (0x01E06E, 0x01E08E),
+    (0x01E090, 0x01E0FF),
+    (0x01E12D, 0x01E12F),
+    (0x01E13E, 0x01E13F),
+    (0x01E14A, 0x01E14D),
+    (0x01E150, 0x01E28F),
+    (0x01E2AF, 0x01E2BF),
+    (0x01E2FA, 0x01E2FE),
+    (0x01E300, 0x01E4CF),
+    (0x01E4FA, 0x01E5CF),
+    (0x01E5FB, 0x01E5FE),
+    (0x01E600, 0x01E6BF),
+    (0x01E6DF, 0x01E6DF),
+    (0x01E6F6, 0x01E6FD),
+    (0x01E700, 0x01E7DF),
+    (0x01E7E7, 0x01E7E7),
+    (0x01E7EC, 0x01E7EC),
+    (0x01E7EF, 0x01E7EF),
+    (0x01E7FF, 0x01E7FF),
+    (0x01E8C5, 0x01E8C6),
+    (0x01E8D7, 0x01E8FF),
+    (0x01E94C, 0x01E94F),
+    (0x01E95A, 0x01E95D),
+    (0x01E960, 0x01EC70),
+    (0x01ECB5, 0x01ED00),
+    (0x01ED3E, 0x01EDFF),
+    (0x01EE04, 0x01EE04),
+    (0x01EE20, 0x01EE20),
+    (0x01EE23, 0x01EE23),
+    (0x01EE25, 0x01EE26),
+    (0x01EE28, 0x01EE28),
+    (0x01EE33, 0x01EE33),
+    (0x01EE38, 0x01EE38),
+    (0x01EE3A, 0x01EE3A),
+    (0x01EE3C, 0x01EE41),
+    (0x01EE43, 0x01EE46),
+    (0x01EE48, 0x01EE48),
+    (0x01EE4A, 0x01EE4A),
+    (0x01EE4C, 0x01EE4C),
+    (0x01EE50, 0x01EE50),
+    (0x01EE53, 0x01EE53),
+    (0x01EE55, 0x01EE56),
+    (0x01EE58, 0x01EE58),
+    (0x01EE5A, 0x01EE5A),
+    (0x01EE5C, 0x01EE5C),
+    (0x01EE5E, 0x01EE5E),
+    (0x01EE60, 0x01EE60),
+    (0x01EE63, 0x01EE63),
+    (0x01EE65, 0x01EE66),
+    (0x01EE6B, 0x01EE6B),
+    (0x01EE73, 0x01EE73),
+    (0x01EE78, 0x01EE78),
+    (0x01EE7D, 0x01EE7D),
+    (0x01EE7F, 0x01EE7F),
+    (0x01EE8A, 0x01EE8A),
+    (0x01EE9C, 0x01EEA0),
+    (0x01EEA4, 0x01EEA4),
+    (0x01EEAA, 0x01EEAA),
+    (0x01EEBC, 0x01EEEF),
+    (0x01EEF2, 0x01EFFF),
+    (0x01F02C, 0x01F02F),
+    (0x01F094, 0x01F09F),
+    (0x01F0AF, 0x01F0B0),
+    (0x01F0C0, 0x01F0C0),
+    (0x01F0D0, 0x01F0D0),
+    (0x01F0F6, 0x01F0FF),
+    (0x01F1AE, 0x01F1E5),
+    (0x01F203, 0x01F20F),
+    (0x01F23C, 0x01F23F),
+    (0x01F249, 0x01F24F),
+    (0x01F252, 0x01F25F),
+    (0x01F266, 0x01F2FF),
+    (0x01F6D9, 0x01F6DB),
+    (0x01F6ED, 0x01F6EF),
+    (0x01F6FD, 0x01F6FF),
+    (0x01F7DA, 0x01F7DF),
+    (0x01F7EC, 0x01F7EF),
+    (0x01F7F1, 0x01F7FF),
+    (0x01F80C, 0x01F80F),
+    (0x01F848, 0x01F84F),
+    (0x01F85A, 0x01F85F),
+    (0x01F888, 0x01F88F),
+    (0x01F8AE, 0x01F8AF),
+    (0x01F8BC, 0x01F8BF),
+    (0x01F8C2, 0x01F8CF),
+    (0x01F8D9, 0x01F8FF),
+    (0x01FA58, 0x01FA5F),
+    (0x01FA6E, 0x01FA6F),
+    (0x01FA7D, 0x01FA7F),
+    (0x01FA8B, 0x01FA8D),
+    (0x01FAC7, 0x01FAC7),
+    (0x01FAC9, 0x01FACC),
+    (0x01FADD, 0x01FADE),
+    (0x01FAEB, 0x01FAEE),
+    (0x01FAF9, 0x01FAFF),
+    (0x01FB93, 0x01FB93),
+    (0x01FBFB, 0x01FFFF),
+    (0x02A6E0, 0x02A6FF),
+    (0x02B81E, 0x02B81F),
+    (0x02CEAE, 0x02CEAF),
+    (0x02EBE1, 0x02EBEF),
+    (0x02EE5E, 0x02F7FF),
+    (0x02FA1E, 0x02FFFF),
+    (0x03134B, 0x03134F),
+    (0x03347A, 0x0E0000),
+    (0x0E0002, 0x0E001F),
+    (0x0E0080, 0x0E00FF),
+    (0x0E01F0, 0x0EFFFF),
+    (0x0FFFFE, 0x0FFFFF),
+    (0x10FFFE, 0x10FFFF),
 ];
 
+/// Every RGI emoji sequence, sorted by their UTF-8 encoding, one after the other.
+#[cfg(feature = "rgi-emoji")]
 #[rustfmt::skip]
-pub(crate) static EMOJI_MODIFIER_LEAF_3: [(u8, u8); 9] = [
-    (0x42, 0x43),
-    (0x46, 0x50),
-    (0x66, 0x78),
-    (0x7C, 0x7C),
-    (0x81, 0x83),
-    (0x85, 0x87),
-    (0x8F, 0x8F),
-    (0x91, 0x91),
-    (0xAA, 0xAA),
-];
+pub(crate) static RGI_EMOJI: &str = concat!(
+    "\u{23}\u{FE0F}\u{20E3}",
+    "\u{2A}\u{FE0F}\u{20E3}",
+    "\u{30}\u{FE0F}\u{20E3}",
+    "\u{31}\u{FE0F}\u{20E3}",
+    "\u{32}\u{FE0F}\u{20E3}",
+    "\u{33}\u{FE0F}\u{20E3}",
+    "\u{34}\u{FE0F}\u{20E3}",
+    "\u{35}\u{FE0F}\u{20E3}",
+    "\u{36}\u{FE0F}\u{20E3}",
+    "\u{37}\u{FE0F}\u{20E3}",
+    "\u{38}\u{FE0F}\u{20E3}",
+    "\u{39}\u{FE0F}\u{20E3}",
+    "\u{A9}\u{FE0F}",
+    "\u{AE}\u{FE0F}",
+    "\u{203C}\u{FE0F}",
+    "\u{2049}\u{FE0F}",
+    "\u{2122}\u{FE0F}",
+    "\u{2139}\u{FE0F}",
+    "\u{2194}\u{FE0F}",
+    "\u{2195}\u{FE0F}",
+    "\u{2196}\u{FE0F}",
+    "\u{2197}\u{FE0F}",
+    "\u{2198}\u{FE0F}",
+    "\u{2199}\u{FE0F}",
+    "\u{21A9}\u{FE0F}",
+    "\u{21AA}\u{FE0F}",
+    "\u{231A}",
+    "\u{231B}",
+    "\u{2328}\u{FE0F}",
+    "\u{23CF}\u{FE0F}",
+    "\u{23E9}",
+    "\u{23EA}",
+    "\u{23EB}",
+    "\u{23EC}",
+    "\u{23ED}\u{FE0F}",
+    "\u{23EE}\u{FE0F}",
+    "\u{23EF}\u{FE0F}",
+    "\u{23F0}",
+    "\u{23F1}\u{FE0F}",
+    "\u{23F2}\u{FE0F}",
+    "\u{23F3}",
+    "\u{23F8}\u{FE0F}",
+    "\u{23F9}\u{FE0F}",
+    "\u{23FA}\u{FE0F}",
+    "\u{24C2}\u{FE0F}",
+    "\u{25AA}\u{FE0F}",
+    "\u{25AB}\u{FE0F}",
+    "\u{25B6}\u{FE0F}",
+    "\u{25C0}\u{FE0F}",
+    "\u{25FB}\u{FE0F}",
+    "\u{25FC}\u{FE0F}",
+    "\u{25FD}",
+    "\u{25FE}",
+    "\u{2600}\u{FE0F}",
+    "\u{2601}\u{FE0F}",
+    "\u{2602}\u{FE0F}",
+    "\u{2603}\u{FE0F}",
+    "\u{2604}\u{FE0F}",
+    "\u{260E}\u{FE0F}",
+    "\u{2611}\u{FE0F}",
+    "\u{2614}",
+    "\u{2615}",
+    "\u{2618}\u{FE0F}",
+    "\u{261D}\u{FE0F}",
+    "\u{261D}\u{1F3FB}",
+    "\u{261D}\u{1F3FC}",
+    "\u{261D}\u{1F3FD}",
+    "\u{261D}\u{1F3FE}",
+    "\u{261D}\u{1F3FF}",
+    "\u{2620}\u{FE0F}",
+    "\u{2622}\u{FE0F}",
+    "\u{2623}\u{FE0F}",
+    "\u{2626}\u{FE0F}",
+    "\u{262A}\u{FE0F}",
+    "\u{262E}\u{FE0F}",
+    "\u{262F}\u{FE0F}",
+    "\u{2638}\u{FE0F}",
+    "\u{2639}\u{FE0F}",
+    "\u{263A}\u{FE0F}",
+    "\u{2640}\u{FE0F}",
+    "\u{2642}\u{FE0F}",
+    "\u{2648}",
+    "\u{2649}",
+    "\u{264A}",
+    "\u{264B}",
+    "\u{264C}",
+    "\u{264D}",
+    "\u{264E}",
+    "\u{264F}",
+    "\u{2650}",
+    "\u{2651}",
+    "\u{2652}",
+    "\u{2653}",
+    "\u{265F}\u{FE0F}",
+    "\u{2660}\u{FE0F}",
+    "\u{2663}\u{FE0F}",
+    "\u{2665}\u{FE0F}",
+    "\u{2666}\u{FE0F}",
+    "\u{2668}\u{FE0F}",
+    "\u{267B}\u{FE0F}",
+    "\u{267E}\u{FE0F}",
+    "\u{267F}",
+    "\u{2692}\u{FE0F}",
+    "\u{2693}",
+    "\u{2694}\u{FE0F}",
+    "\u{2695}\u{FE0F}",
+    "\u{2696}\u{FE0F}",
+    "\u{2697}\u{FE0F}",
+    "\u{2699}\u{FE0F}",
+    "\u{269B}\u{FE0F}",
+    "\u{269C}\u{FE0F}",
+    "\u{26A0}\u{FE0F}",
+    "\u{26A1}",
+    "\u{26A7}\u{FE0F}",
+    "\u{26AA}",
+    "\u{26AB}",
+    "\u{26B0}\u{FE0F}",
+    "\u{26B1}\u{FE0F}",
+    "\u{26BD}",
+    "\u{26BE}",
+    "\u{26C4}",
+    "\u{26C5}",
+    "\u{26C8}\u{FE0F}",
+    "\u{26CE}",
+    "\u{26CF}\u{FE0F}",
+    "\u{26D1}\u{FE0F}",
+    "\u{26D3}\u{FE0F}",
+    "\u{26D3}\u{FE0F}\u{200D}\u{1F4A5}",
+    "\u{26D4}",
+    "\u{26E9}\u{FE0F}",
+    "\u{26EA}",
+    "\u{26F0}\u{FE0F}",
+    "\u{26F1}\u{FE0F}",
+    "\u{26F2}",
+    "\u{26F3}",
+    "\u{26F4}\u{FE0F}",
+    "\u{26F5}",
+    "\u{26F7}\u{FE0F}",
+    "\u{26F8}\u{FE0F}",
+    "\u{26F9}\u{FE0F}",
+    "\u{26F9}\u{FE0F}\u{200D}\u{2640}\u{FE0F}",
+    "\u{26F9}\u{FE0F}\u{200D}\u{2642}\u{FE0F}",
+    "\u{26F9}\u{1F3FB}",
+    "\u{26F9}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{26F9}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{26F9}\u{1F3FC}",
+    "\u{26F9}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{26F9}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{26F9}\u{1F3FD}",
+    "\u{26F9}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{26F9}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{26F9}\u{1F3FE}",
+    "\u{26F9}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{26F9}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{26F9}\u{1F3FF}",
+    "\u{26F9}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{26F9}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{26FA}",
+    "\u{26FD}",
+    "\u{2702}\u{FE0F}",
+    "\u{2705}",
+    "\u{2708}\u{FE0F}",
+    "\u{2709}\u{FE0F}",
+    "\u{270A}",
+    "\u{270A}\u{1F3FB}",
+    "\u{270A}\u{1F3FC}",
+    "\u{270A}\u{1F3FD}",
+    "\u{270A}\u{1F3FE}",
+    "\u{270A}\u{1F3FF}",
+    "\u{270B}",
+    "\u{270B}\u{1F3FB}",
+    "\u{270B}\u{1F3FC}",
+    "\u{270B}\u{1F3FD}",
+    "\u{270B}\u{1F3FE}",
+    "\u{270B}\u{1F3FF}",
+    "\u{270C}\u{FE0F}",
+    "\u{270C}\u{1F3FB}",
+    "\u{270C}\u{1F3FC}",
+    "\u{270C}\u{1F3FD}",
+    "\u{270C}\u{1F3FE}",
+    "\u{270C}\u{1F3FF}",
+    "\u{270D}\u{FE0F}",
+    "\u{270D}\u{1F3FB}",
+    "\u{270D}\u{1F3FC}",
+    "\u{270D}\u{1F3FD}",
+    "\u{270D}\u{1F3FE}",
+    "\u{270D}\u{1F3FF}",
+    "\u{270F}\u{FE0F}",
+    "\u{2712}\u{FE0F}",
+    "\u{2714}\u{FE0F}",
+    "\u{2716}\u{FE0F}",
+    "\u{271D}\u{FE0F}",
+    "\u{2721}\u{FE0F}",
+    "\u{2728}",
+    "\u{2733}\u{FE0F}",
+    "\u{2734}\u{FE0F}",
+    "\u{2744}\u{FE0F}",
+    "\u{2747}\u{FE0F}",
+    "\u{274C}",
+    "\u{274E}",
+    "\u{2753}",
+    "\u{2754}",
+    "\u{2755}",
+    "\u{2757}",
+    "\u{2763}\u{FE0F}",
+    "\u{2764}\u{FE0F}",
+    "\u{2764}\u{FE0F}\u{200D}\u{1F525}",
+    "\u{2764}\u{FE0F}\u{200D}\u{1FA79}",
+    "\u{2795}",
+    "\u{2796}",
+    "\u{2797}",
+    "\u{27A1}\u{FE0F}",
+    "\u{27B0}",
+    "\u{27BF}",
+    "\u{2934}\u{FE0F}",
+    "\u{2935}\u{FE0F}",
+    "\u{2B05}\u{FE0F}",
+    "\u{2B06}\u{FE0F}",
+    "\u{2B07}\u{FE0F}",
+    "\u{2B1B}",
+    "\u{2B1C}",
+    "\u{2B50}",
+    "\u{2B55}",
+    "\u{3030}\u{FE0F}",
+    "\u{303D}\u{FE0F}",
+    "\u{3297}\u{FE0F}",
+    "\u{3299}\u{FE0F}",
+    "\u{1F004}",
+    "\u{1F0CF}",
+    "\u{1F170}\u{FE0F}",
+    "\u{1F171}\u{FE0F}",
+    "\u{1F17E}\u{FE0F}",
+    "\u{1F17F}\u{FE0F}",
+    "\u{1F18E}",
+    "\u{1F191}",
+    "\u{1F192}",
+    "\u{1F193}",
+    "\u{1F194}",
+    "\u{1F195}",
+    "\u{1F196}",
+    "\u{1F197}",
+    "\u{1F198}",
+    "\u{1F199}",
+    "\u{1F19A}",
+    "\u{1F1E6}\u{1F1E8}",
+    "\u{1F1E6}\u{1F1E9}",
+    "\u{1F1E6}\u{1F1EA}",
+    "\u{1F1E6}\u{1F1EB}",
+    "\u{1F1E6}\u{1F1EC}",
+    "\u{1F1E6}\u{1F1EE}",
+    "\u{1F1E6}\u{1F1F1}",
+    "\u{1F1E6}\u{1F1F2}",
+    "\u{1F1E6}\u{1F1F4}",
+    "\u{1F1E6}\u{1F1F6}",
+    "\u{1F1E6}\u{1F1F7}",
+    "\u{1F1E6}\u{1F1F8}",
+    "\u{1F1E6}\u{1F1F9}",
+    "\u{1F1E6}\u{1F1FA}",
+    "\u{1F1E6}\u{1F1FC}",
+    "\u{1F1E6}\u{1F1FD}",
+    "\u{1F1E6}\u{1F1FF}",
+    "\u{1F1E7}\u{1F1E6}",
+    "\u{1F1E7}\u{1F1E7}",
+    "\u{1F1E7}\u{1F1E9}",
+    "\u{1F1E7}\u{1F1EA}",
+    "\u{1F1E7}\u{1F1EB}",
+    "\u{1F1E7}\u{1F1EC}",
+    "\u{1F1E7}\u{1F1ED}",
+    "\u{1F1E7}\u{1F1EE}",
+    "\u{1F1E7}\u{1F1EF}",
+    "\u{1F1E7}\u{1F1F1}",
+    "\u{1F1E7}\u{1F1F2}",
+    "\u{1F1E7}\u{1F1F3}",
+    "\u{1F1E7}\u{1F1F4}",
+    "\u{1F1E7}\u{1F1F6}",
+    "\u{1F1E7}\u{1F1F7}",
+    "\u{1F1E7}\u{1F1F8}",
+    "\u{1F1E7}\u{1F1F9}",
+    "\u{1F1E7}\u{1F1FB}",
+    "\u{1F1E7}\u{1F1FC}",
+    "\u{1F1E7}\u{1F1FE}",
+    "\u{1F1E7}\u{1F1FF}",
+    "\u{1F1E8}\u{1F1E6}",
+    "\u{1F1E8}\u{1F1E8}",
+    "\u{1F1E8}\u{1F1E9}",
+    "\u{1F1E8}\u{1F1EB}",
+    "\u{1F1E8}\u{1F1EC}",
+    "\u{1F1E8}\u{1F1ED}",
+    "\u{1F1E8}\u{1F1EE}",
+    "\u{1F1E8}\u{1F1F0}",
+    "\u{1F1E8}\u{1F1F1}",
+    "\u{1F1E8}\u{1F1F2}",
+    "\u{1F1E8}\u{1F1F3}",
+    "\u{1F1E8}\u{1F1F4}",
+    "\u{1F1E8}\u{1F1F5}",
+    "\u{1F1E8}\u{1F1F6}",
+    "\u{1F1E8}\u{1F1F7}",
+    "\u{1F1E8}\u{1F1FA}",
+    "\u{1F1E8}\u{1F1FB}",
+    "\u{1F1E8}\u{1F1FC}",
+    "\u{1F1E8}\u{1F1FD}",
+    "\u{1F1E8}\u{1F1FE}",
+    "\u{1F1E8}\u{1F1FF}",
+    "\u{1F1E9}\u{1F1EA}",
+    "\u{1F1E9}\u{1F1EC}",
+    "\u{1F1E9}\u{1F1EF}",
+    "\u{1F1E9}\u{1F1F0}",
+    "\u{1F1E9}\u{1F1F2}",
+    "\u{1F1E9}\u{1F1F4}",
+    "\u{1F1E9}\u{1F1FF}",
+    "\u{1F1EA}\u{1F1E6}",
+    "\u{1F1EA}\u{1F1E8}",
+    "\u{1F1EA}\u{1F1EA}",
+    "\u{1F1EA}\u{1F1EC}",
+    "\u{1F1EA}\u{1F1ED}",
+    "\u{1F1EA}\u{1F1F7}",
+    "\u{1F1EA}\u{1F1F8}",
+    "\u{1F1EA}\u{1F1F9}",
+    "\u{1F1EA}\u{1F1FA}",
+    "\u{1F1EB}\u{1F1EE}",
+    "\u{1F1EB}\u{1F1EF}",
+    "\u{1F1EB}\u{1F1F0}",
+    "\u{1F1EB}\u{1F1F2}",
+    "\u{1F1EB}\u{1F1F4}",
+    "\u{1F1EB}\u{1F1F7}",
+    "\u{1F1EC}\u{1F1E6}",
+    "\u{1F1EC}\u{1F1E7}",
+    "\u{1F1EC}\u{1F1E9}",
+    "\u{1F1EC}\u{1F1EA}",
+    "\u{1F1EC}\u{1F1EB}",
+    "\u{1F1EC}\u{1F1EC}",
+    "\u{1F1EC}\u{1F1ED}",
+    "\u{1F1EC}\u{1F1EE}",
+    "\u{1F1EC}\u{1F1F1}",
+    "\u{1F1EC}\u{1F1F2}",
+    "\u{1F1EC}\u{1F1F3}",
+    "\u{1F1EC}\u{1F1F5}",
+    "\u{1F1EC}\u{1F1F6}",
+    "\u{1F1EC}\u{1F1F7}",
+    "\u{1F1EC}\u{1F1F8}",
+    "\u{1F1EC}\u{1F1F9}",
+    "\u{1F1EC}\u{1F1FA}",
+    "\u{1F1EC}\u{1F1FC}",
+    "\u{1F1EC}\u{1F1FE}",
+    "\u{1F1ED}\u{1F1F0}",
+    "\u{1F1ED}\u{1F1F2}",
+    "\u{1F1ED}\u{1F1F3}",
+    "\u{1F1ED}\u{1F1F7}",
+    "\u{1F1ED}\u{1F1F9}",
+    "\u{1F1ED}\u{1F1FA}",
+    "\u{1F1EE}\u{1F1E8}",
+    "\u{1F1EE}\u{1F1E9}",
+    "\u{1F1EE}\u{1F1EA}",
+    "\u{1F1EE}\u{1F1F1}",
+    "\u{1F1EE}\u{1F1F2}",
+    "\u{1F1EE}\u{1F1F3}",
+    "\u{1F1EE}\u{1F1F4}",
+    "\u{1F1EE}\u{1F1F6}",
+    "\u{1F1EE}\u{1F1F7}",
+    "\u{1F1EE}\u{1F1F8}",
+    "\u{1F1EE}\u{1F1F9}",
+    "\u{1F1EF}\u{1F1EA}",
+    "\u{1F1EF}\u{1F1F2}",
+    "\u{1F1EF}\u{1F1F4}",
+    "\u{1F1EF}\u{1F1F5}",
+    "\u{1F1F0}\u{1F1EA}",
+    "\u{1F1F0}\u{1F1EC}",
+    "\u{1F1F0}\u{1F1ED}",
+    "\u{1F1F0}\u{1F1EE}",
+    "\u{1F1F0}\u{1F1F2}",
+    "\u{1F1F0}\u{1F1F3}",
+    "\u{1F1F0}\u{1F1F5}",
+    "\u{1F1F0}\u{1F1F7}",
+    "\u{1F1F0}\u{1F1FC}",
+    "\u{1F1F0}\u{1F1FE}",
+    "\u{1F1F0}\u{1F1FF}",
+    "\u{1F1F1}\u{1F1E6}",
+    "\u{1F1F1}\u{1F1E7}",
+    "\u{1F1F1}\u{1F1E8}",
+    "\u{1F1F1}\u{1F1EE}",
+    "\u{1F1F1}\u{1F1F0}",
+    "\u{1F1F1}\u{1F1F7}",
+    "\u{1F1F1}\u{1F1F8}",
+    "\u{1F1F1}\u{1F1F9}",
+    "\u{1F1F1}\u{1F1FA}",
+    "\u{1F1F1}\u{1F1FB}",
+    "\u{1F1F1}\u{1F1FE}",
+    "\u{1F1F2}\u{1F1E6}",
+    "\u{1F1F2}\u{1F1E8}",
+    "\u{1F1F2}\u{1F1E9}",
+    "\u{1F1F2}\u{1F1EA}",
+    "\u{1F1F2}\u{1F1EB}",
+    "\u{1F1F2}\u{1F1EC}",
+    "\u{1F1F2}\u{1F1ED}",
+    "\u{1F1F2}\u{1F1F0}",
+    "\u{1F1F2}\u{1F1F1}",
+    "\u{1F1F2}\u{1F1F2}",
+    "\u{1F1F2}\u{1F1F3}",
+    "\u{1F1F2}\u{1F1F4}",
+    "\u{1F1F2}\u{1F1F5}",
+    "\u{1F1F2}\u{1F1F6}",
+    "\u{1F1F2}\u{1F1F7}",
+    "\u{1F1F2}\u{1F1F8}",
+    "\u{1F1F2}\u{1F1F9}",
+    "\u{1F1F2}\u{1F1FA}",
+    "\u{1F1F2}\u{1F1FB}",
+    "\u{1F1F2}\u{1F1FC}",
+    "\u{1F1F2}\u{1F1FD}",
+    "\u{1F1F2}\u{1F1FE}",
+    "\u{1F1F2}\u{1F1FF}",
+    "\u{1F1F3}\u{1F1E6}",
+    "\u{1F1F3}\u{1F1E8}",
+    "\u{1F1F3}\u{1F1EA}",
+    "\u{1F1F3}\u{1F1EB}",
+    "\u{1F1F3}\u{1F1EC}",
+    "\u{1F1F3}\u{1F1EE}",
+    "\u{1F1F3}\u{1F1F1}",
+    "\u{1F1F3}\u{1F1F4}",
+    "\u{1F1F3}\u{1F1F5}",
+    "\u{1F1F3}\u{1F1F7}",
+    "\u{1F1F3}\u{1F1FA}",
+    "\u{1F1F3}\u{1F1FF}",
+    "\u{1F1F4}\u{1F1F2}",
+    "\u{1F1F5}\u{1F1E6}",
+    "\u{1F1F5}\u{1F1EA}",
+    "\u{1F1F5}\u{1F1EB}",
+    "\u{1F1F5}\u{1F1EC}",
+    "\u{1F1F5}\u{1F1ED}",
+    "\u{1F1F5}\u{1F1F0}",
+    "\u{1F1F5}\u{1F1F1}",
+    "\u{1F1F5}\u{1F1F2}",
+    "\u{1F1F5}\u{1F1F3}",
+    "\u{1F1F5}\u{1F1F7}",
+    "\u{1F1F5}\u{1F1F8}",
+    "\u{1F1F5}\u{1F1F9}",
+    "\u{1F1F5}\u{1F1FC}",
+    "\u{1F1F5}\u{1F1FE}",
+    "\u{1F1F6}\u{1F1E6}",
+    "\u{1F1F7}\u{1F1EA}",
+    "\u{1F1F7}\u{1F1F4}",
+    "\u{1F1F7}\u{1F1F8}",
+    "\u{1F1F7}\u{1F1FA}",
+    "\u{1F1F7}\u{1F1FC}",
+    "\u{1F1F8}\u{1F1E6}",
+    "\u{1F1F8}\u{1F1E7}",
+    "\u{1F1F8}\u{1F1E8}",
+    "\u{1F1F8}\u{1F1E9}",
+    "\u{1F1F8}\u{1F1EA}",
+    "\u{1F1F8}\u{1F1EC}",
+    "\u{1F1F8}\u{1F1ED}",
+    "\u{1F1F8}\u{1F1EE}",
+    "\u{1F1F8}\u{1F1EF}",
+    "\u{1F1F8}\u{1F1F0}",
+    "\u{1F1F8}\u{1F1F1}",
+    "\u{1F1F8}\u{1F1F2}",
+    "\u{1F1F8}\u{1F1F3}",
+    "\u{1F1F8}\u{1F1F4}",
+    "\u{1F1F8}\u{1F1F7}",
+    "\u{1F1F8}\u{1F1F8}",
+    "\u{1F1F8}\u{1F1F9}",
+    "\u{1F1F8}\u{1F1FB}",
+    "\u{1F1F8}\u{1F1FD}",
+    "\u{1F1F8}\u{1F1FE}",
+    "\u{1F1F8}\u{1F1FF}",
+    "\u{1F1F9}\u{1F1E6}",
+    "\u{1F1F9}\u{1F1E8}",
+    "\u{1F1F9}\u{1F1E9}",
+    "\u{1F1F9}\u{1F1EB}",
+    "\u{1F1F9}\u{1F1EC}",
+    "\u{1F1F9}\u{1F1ED}",
+    "\u{1F1F9}\u{1F1EF}",
+    "\u{1F1F9}\u{1F1F0}",
+    "\u{1F1F9}\u{1F1F1}",
+    "\u{1F1F9}\u{1F1F2}",
+    "\u{1F1F9}\u{1F1F3}",
+    "\u{1F1F9}\u{1F1F4}",
+    "\u{1F1F9}\u{1F1F7}",
+    "\u{1F1F9}\u{1F1F9}",
+    "\u{1F1F9}\u{1F1FB}",
+    "\u{1F1F9}\u{1F1FC}",
+    "\u{1F1F9}\u{1F1FF}",
+    "\u{1F1FA}\u{1F1E6}",
+    "\u{1F1FA}\u{1F1EC}",
+    "\u{1F1FA}\u{1F1F2}",
+    "\u{1F1FA}\u{1F1F3}",
+    "\u{1F1FA}\u{1F1F8}",
+    "\u{1F1FA}\u{1F1FE}",
+    "\u{1F1FA}\u{1F1FF}",
+    "\u{1F1FB}\u{1F1E6}",
+    "\u{1F1FB}\u{1F1E8}",
+    "\u{1F1FB}\u{1F1EA}",
+    "\u{1F1FB}\u{1F1EC}",
+    "\u{1F1FB}\u{1F1EE}",
+    "\u{1F1FB}\u{1F1F3}",
+    "\u{1F1FB}\u{1F1FA}",
+    "\u{1F1FC}\u{1F1EB}",
+    "\u{1F1FC}\u{1F1F8}",
+    "\u{1F1FD}\u{1F1F0}",
+    "\u{1F1FE}\u{1F1EA}",
+    "\u{1F1FE}\u{1F1F9}",
+    "\u{1F1FF}\u{1F1E6}",
+    "\u{1F1FF}\u{1F1F2}",
+    "\u{1F1FF}\u{1F1FC}",
+    "\u{1F201}",
+    "\u{1F202}\u{FE0F}",
+    "\u{1F21A}",
+    "\u{1F22F}",
+    "\u{1F232}",
+    "\u{1F233}",
+    "\u{1F234}",
+    "\u{1F235}",
+    "\u{1F236}",
+    "\u{1F237}\u{FE0F}",
+    "\u{1F238}",
+    "\u{1F239}",
+    "\u{1F23A}",
+    "\u{1F250}",
+    "\u{1F251}",
+    "\u{1F300}",
+    "\u{1F301}",
+    "\u{1F302}",
+    "\u{1F303}",
+    "\u{1F304}",
+    "\u{1F305}",
+    "\u{1F306}",
+    "\u{1F307}",
+    "\u{1F308}",
+    "\u{1F309}",
+    "\u{1F30A}",
+    "\u{1F30B}",
+    "\u{1F30C}",
+    "\u{1F30D}",
+    "\u{1F30E}",
+    "\u{1F30F}",
+    "\u{1F310}",
+    "\u{1F311}",
+    "\u{1F312}",
+    "\u{1F313}",
+    "\u{1F314}",
+    "\u{1F315}",
+    "\u{1F316}",
+    "\u{1F317}",
+    "\u{1F318}",
+    "\u{1F319}",
+    "\u{1F31A}",
+    "\u{1F31B}",
+    "\u{1F31C}",
+    "\u{1F31D}",
+    "\u{1F31E}",
+    "\u{1F31F}",
+    "\u{1F320}",
+    "\u{1F321}\u{FE0F}",
+    "\u{1F324}\u{FE0F}",
+    "\u{1F325}\u{FE0F}",
+    "\u{1F326}\u{FE0F}",
+    "\u{1F327}\u{FE0F}",
+    "\u{1F328}\u{FE0F}",
+    "\u{1F329}\u{FE0F}",
+    "\u{1F32A}\u{FE0F}",
+    "\u{1F32B}\u{FE0F}",
+    "\u{1F32C}\u{FE0F}",
+    "\u{1F32D}",
+    "\u{1F32E}",
+    "\u{1F32F}",
+    "\u{1F330}",
+    "\u{1F331}",
+    "\u{1F332}",
+    "\u{1F333}",
+    "\u{1F334}",
+    "\u{1F335}",
+    "\u{1F336}\u{FE0F}",
+    "\u{1F337}",
+    "\u{1F338}",
+    "\u{1F339}",
+    "\u{1F33A}",
+    "\u{1F33B}",
+    "\u{1F33C}",
+    "\u{1F33D}",
+    "\u{1F33E}",
+    "\u{1F33F}",
+    "\u{1F340}",
+    "\u{1F341}",
+    "\u{1F342}",
+    "\u{1F343}",
+    "\u{1F344}",
+    "\u{1F344}\u{200D}\u{1F7EB}",
+    "\u{1F345}",
+    "\u{1F346}",
+    "\u{1F347}",
+    "\u{1F348}",
+    "\u{1F349}",
+    "\u{1F34A}",
+    "\u{1F34B}",
+    "\u{1F34B}\u{200D}\u{1F7E9}",
+    "\u{1F34C}",
+    "\u{1F34D}",
+    "\u{1F34E}",
+    "\u{1F34F}",
+    "\u{1F350}",
+    "\u{1F351}",
+    "\u{1F352}",
+    "\u{1F353}",
+    "\u{1F354}",
+    "\u{1F355}",
+    "\u{1F356}",
+    "\u{1F357}",
+    "\u{1F358}",
+    "\u{1F359}",
+    "\u{1F35A}",
+    "\u{1F35B}",
+    "\u{1F35C}",
+    "\u{1F35D}",
+    "\u{1F35E}",
+    "\u{1F35F}",
+    "\u{1F360}",
+    "\u{1F361}",
+    "\u{1F362}",
+    "\u{1F363}",
+    "\u{1F364}",
+    "\u{1F365}",
+    "\u{1F366}",
+    "\u{1F367}",
+    "\u{1F368}",
+    "\u{1F369}",
+    "\u{1F36A}",
+    "\u{1F36B}",
+    "\u{1F36C}",
+    "\u{1F36D}",
+    "\u{1F36E}",
+    "\u{1F36F}",
+    "\u{1F370}",
+    "\u{1F371}",
+    "\u{1F372}",
+    "\u{1F373}",
+    "\u{1F374}",
+    "\u{1F375}",
+    "\u{1F376}",
+    "\u{1F377}",
+    "\u{1F378}",
+    "\u{1F379}",
+    "\u{1F37A}",
+    "\u{1F37B}",
+    "\u{1F37C}",
+    "\u{1F37D}\u{FE0F}",
+    "\u{1F37E}",
+    "\u{1F37F}",
+    "\u{1F380}",
+    "\u{1F381}",
+    "\u{1F382}",
+    "\u{1F383}",
+    "\u{1F384}",
+    "\u{1F385}",
+    "\u{1F385}\u{1F3FB}",
+    "\u{1F385}\u{1F3FC}",
+    "\u{1F385}\u{1F3FD}",
+    "\u{1F385}\u{1F3FE}",
+    "\u{1F385}\u{1F3FF}",
+    "\u{1F386}",
+    "\u{1F387}",
+    "\u{1F388}",
+    "\u{1F389}",
+    "\u{1F38A}",
+    "\u{1F38B}",
+    "\u{1F38C}",
+    "\u{1F38D}",
+    "\u{1F38E}",
+    "\u{1F38F}",
+    "\u{1F390}",
+    "\u{1F391}",
+    "\u{1F392}",
+    "\u{1F393}",
+    "\u{1F396}\u{FE0F}",
+    "\u{1F397}\u{FE0F}",
+    "\u{1F399}\u{FE0F}",
+    "\u{1F39A}\u{FE0F}",
+    "\u{1F39B}\u{FE0F}",
+    "\u{1F39E}\u{FE0F}",
+    "\u{1F39F}\u{FE0F}",
+    "\u{1F3A0}",
+    "\u{1F3A1}",
+    "\u{1F3A2}",
+    "\u{1F3A3}",
+    "\u{1F3A4}",
+    "\u{1F3A5}",
+    "\u{1F3A6}",
+    "\u{1F3A7}",
+    "\u{1F3A8}",
+    "\u{1F3A9}",
+    "\u{1F3AA}",
+    "\u{1F3AB}",
+    "\u{1F3AC}",
+    "\u{1F3AD}",
+    "\u{1F3AE}",
+    "\u{1F3AF}",
+    "\u{1F3B0}",
+    "\u{1F3B1}",
+    "\u{1F3B2}",
+    "\u{1F3B3}",
+    "\u{1F3B4}",
+    "\u{1F3B5}",
+    "\u{1F3B6}",
+    "\u{1F3B7}",
+    "\u{1F3B8}",
+    "\u{1F3B9}",
+    "\u{1F3BA}",
+    "\u{1F3BB}",
+    "\u{1F3BC}",
+    "\u{1F3BD}",
+    "\u{1F3BE}",
+    "\u{1F3BF}",
+    "\u{1F3C0}",
+    "\u{1F3C1}",
+    "\u{1F3C2}",
+    "\u{1F3C2}\u{1F3FB}",
+    "\u{1F3C2}\u{1F3FC}",
+    "\u{1F3C2}\u{1F3FD}",
+    "\u{1F3C2}\u{1F3FE}",
+    "\u{1F3C2}\u{1F3FF}",
+    "\u{1F3C3}",
+    "\u{1F3C3}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3C3}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F3C3}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3C3}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F3C3}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FB}",
+    "\u{1F3C3}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FB}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FC}",
+    "\u{1F3C3}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FD}",
+    "\u{1F3C3}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FE}",
+    "\u{1F3C3}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FE}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FF}",
+    "\u{1F3C3}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F3C3}\u{1F3FF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F3C4}",
+    "\u{1F3C4}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3C4}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3C4}\u{1F3FB}",
+    "\u{1F3C4}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3C4}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3C4}\u{1F3FC}",
+    "\u{1F3C4}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3C4}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3C4}\u{1F3FD}",
+    "\u{1F3C4}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3C4}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3C4}\u{1F3FE}",
+    "\u{1F3C4}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3C4}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3C4}\u{1F3FF}",
+    "\u{1F3C4}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3C4}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3C5}",
+    "\u{1F3C6}",
+    "\u{1F3C7}",
+    "\u{1F3C7}\u{1F3FB}",
+    "\u{1F3C7}\u{1F3FC}",
+    "\u{1F3C7}\u{1F3FD}",
+    "\u{1F3C7}\u{1F3FE}",
+    "\u{1F3C7}\u{1F3FF}",
+    "\u{1F3C8}",
+    "\u{1F3C9}",
+    "\u{1F3CA}",
+    "\u{1F3CA}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3CA}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3CA}\u{1F3FB}",
+    "\u{1F3CA}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3CA}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3CA}\u{1F3FC}",
+    "\u{1F3CA}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3CA}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3CA}\u{1F3FD}",
+    "\u{1F3CA}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3CA}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3CA}\u{1F3FE}",
+    "\u{1F3CA}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3CA}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3CA}\u{1F3FF}",
+    "\u{1F3CA}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3CA}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3CB}\u{FE0F}",
+    "\u{1F3CB}\u{FE0F}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3CB}\u{FE0F}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3CB}\u{1F3FB}",
+    "\u{1F3CB}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3CB}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3CB}\u{1F3FC}",
+    "\u{1F3CB}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3CB}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3CB}\u{1F3FD}",
+    "\u{1F3CB}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3CB}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3CB}\u{1F3FE}",
+    "\u{1F3CB}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3CB}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3CB}\u{1F3FF}",
+    "\u{1F3CB}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3CB}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3CC}\u{FE0F}",
+    "\u{1F3CC}\u{FE0F}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3CC}\u{FE0F}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3CC}\u{1F3FB}",
+    "\u{1F3CC}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3CC}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3CC}\u{1F3FC}",
+    "\u{1F3CC}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3CC}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3CC}\u{1F3FD}",
+    "\u{1F3CC}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3CC}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3CC}\u{1F3FE}",
+    "\u{1F3CC}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3CC}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3CC}\u{1F3FF}",
+    "\u{1F3CC}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F3CC}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F3CD}\u{FE0F}",
+    "\u{1F3CE}\u{FE0F}",
+    "\u{1F3CF}",
+    "\u{1F3D0}",
+    "\u{1F3D1}",
+    "\u{1F3D2}",
+    "\u{1F3D3}",
+    "\u{1F3D4}\u{FE0F}",
+    "\u{1F3D5}\u{FE0F}",
+    "\u{1F3D6}\u{FE0F}",
+    "\u{1F3D7}\u{FE0F}",
+    "\u{1F3D8}\u{FE0F}",
+    "\u{1F3D9}\u{FE0F}",
+    "\u{1F3DA}\u{FE0F}",
+    "\u{1F3DB}\u{FE0F}",
+    "\u{1F3DC}\u{FE0F}",
+    "\u{1F3DD}\u{FE0F}",
+    "\u{1F3DE}\u{FE0F}",
+    "\u{1F3DF}\u{FE0F}",
+    "\u{1F3E0}",
+    "\u{1F3E1}",
+    "\u{1F3E2}",
+    "\u{1F3E3}",
+    "\u{1F3E4}",
+    "\u{1F3E5}",
+    "\u{1F3E6}",
+    "\u{1F3E7}",
+    "\u{1F3E8}",
+    "\u{1F3E9}",
+    "\u{1F3EA}",
+    "\u{1F3EB}",
+    "\u{1F3EC}",
+    "\u{1F3ED}",
+    "\u{1F3EE}",
+    "\u{1F3EF}",
+    "\u{1F3F0}",
+    "\u{1F3F3}\u{FE0F}",
+    "\u{1F3F3}\u{FE0F}\u{200D}\u{26A7}\u{FE0F}",
+    "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}",
+    "\u{1F3F4}",
+    "\u{1F3F4}\u{200D}\u{2620}\u{FE0F}",
+    "\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}",
+    "\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}",
+    "\u{1F3F4}\u{E0067}\u{E0062}\u{E0077}\u{E006C}\u{E0073}\u{E007F}",
+    "\u{1F3F5}\u{FE0F}",
+    "\u{1F3F7}\u{FE0F}",
+    "\u{1F3F8}",
+    "\u{1F3F9}",
+    "\u{1F3FA}",
+    "\u{1F3FB}",
+    "\u{1F3FC}",
+    "\u{1F3FD}",
+    "\u{1F3FE}",
+    "\u{1F3FF}",
+    "\u{1F400}",
+    "\u{1F401}",
+    "\u{1F402}",
+    "\u{1F403}",
+    "\u{1F404}",
+    "\u{1F405}",
+    "\u{1F406}",
+    "\u{1F407}",
+    "\u{1F408}",
+    "\u{1F408}\u{200D}\u{2B1B}",
+    "\u{1F409}",
+    "\u{1F40A}",
+    "\u{1F40B}",
+    "\u{1F40C}",
+    "\u{1F40D}",
+    "\u{1F40E}",
+    "\u{1F40F}",
+    "\u{1F410}",
+    "\u{1F411}",
+    "\u{1F412}",
+    "\u{1F413}",
+    "\u{1F414}",
+    "\u{1F415}",
+    "\u{1F415}\u{200D}\u{1F9BA}",
+    "\u{1F416}",
+    "\u{1F417}",
+    "\u{1F418}",
+    "\u{1F419}",
+    "\u{1F41A}",
+    "\u{1F41B}",
+    "\u{1F41C}",
+    "\u{1F41D}",
+    "\u{1F41E}",
+    "\u{1F41F}",
+    "\u{1F420}",
+    "\u{1F421}",
+    "\u{1F422}",
+    "\u{1F423}",
+    "\u{1F424}",
+    "\u{1F425}",
+    "\u{1F426}",
+    "\u{1F426}\u{200D}\u{2B1B}",
+    "\u{1F426}\u{200D}\u{1F525}",
+    "\u{1F427}",
+    "\u{1F428}",
+    "\u{1F429}",
+    "\u{1F42A}",
+    "\u{1F42B}",
+    "\u{1F42C}",
+    "\u{1F42D}",
+    "\u{1F42E}",
+    "\u{1F42F}",
+    "\u{1F430}",
+    "\u{1F431}",
+    "\u{1F432}",
+    "\u{1F433}",
+    "\u{1F434}",
+    "\u{1F435}",
+    "\u{1F436}",
+    "\u{1F437}",
+    "\u{1F438}",
+    "\u{1F439}",
+    "\u{1F43A}",
+    "\u{1F43B}",
+    "\u{1F43B}\u{200D}\u{2744}\u{FE0F}",
+    "\u{1F43C}",
+    "\u{1F43D}",
+    "\u{1F43E}",
+    "\u{1F43F}\u{FE0F}",
+    "\u{1F440}",
+    "\u{1F441}\u{FE0F}",
+    "\u{1F441}\u{FE0F}\u{200D}\u{1F5E8}\u{FE0F}",
+    "\u{1F442}",
+    "\u{1F442}\u{1F3FB}",
+    "\u{1F442}\u{1F3FC}",
+    "\u{1F442}\u{1F3FD}",
+    "\u{1F442}\u{1F3FE}",
+    "\u{1F442}\u{1F3FF}",
+    "\u{1F443}",
+    "\u{1F443}\u{1F3FB}",
+    "\u{1F443}\u{1F3FC}",
+    "\u{1F443}\u{1F3FD}",
+    "\u{1F443}\u{1F3FE}",
+    "\u{1F443}\u{1F3FF}",
+    "\u{1F444}",
+    "\u{1F445}",
+    "\u{1F446}",
+    "\u{1F446}\u{1F3FB}",
+    "\u{1F446}\u{1F3FC}",
+    "\u{1F446}\u{1F3FD}",
+    "\u{1F446}\u{1F3FE}",
+    "\u{1F446}\u{1F3FF}",
+    "\u{1F447}",
+    "\u{1F447}\u{1F3FB}",
+    "\u{1F447}\u{1F3FC}",
+    "\u{1F447}\u{1F3FD}",
+    "\u{1F447}\u{1F3FE}",
+    "\u{1F447}\u{1F3FF}",
+    "\u{1F448}",
+    "\u{1F448}\u{1F3FB}",
+    "\u{1F448}\u{1F3FC}",
+    "\u{1F448}\u{1F3FD}",
+    "\u{1F448}\u{1F3FE}",
+    "\u{1F448}\u{1F3FF}",
+    "\u{1F449}",
+    "\u{1F449}\u{1F3FB}",
+    "\u{1F449}\u{1F3FC}",
+    "\u{1F449}\u{1F3FD}",
+    "\u{1F449}\u{1F3FE}",
+    "\u{1F449}\u{1F3FF}",
+    "\u{1F44A}",
+    "\u{1F44A}\u{1F3FB}",
+    "\u{1F44A}\u{1F3FC}",
+    "\u{1F44A}\u{1F3FD}",
+    "\u{1F44A}\u{1F3FE}",
+    "\u{1F44A}\u{1F3FF}",
+    "\u{1F44B}",
+    "\u{1F44B}\u{1F3FB}",
+    "\u{1F44B}\u{1F3FC}",
+    "\u{1F44B}\u{1F3FD}",
+    "\u{1F44B}\u{1F3FE}",
+    "\u{1F44B}\u{1F3FF}",
+    "\u{1F44C}",
+    "\u{1F44C}\u{1F3FB}",
+    "\u{1F44C}\u{1F3FC}",
+    "\u{1F44C}\u{1F3FD}",
+    "\u{1F44C}\u{1F3FE}",
+    "\u{1F44C}\u{1F3FF}",
+    "\u{1F44D}",
+    "\u{1F44D}\u{1F3FB}",
+    "\u{1F44D}\u{1F3FC}",
+    "\u{1F44D}\u{1F3FD}",
+    "\u{1F44D}\u{1F3FE}",
+    "\u{1F44D}\u{1F3FF}",
+    "\u{1F44E}",
+    "\u{1F44E}\u{1F3FB}",
+    "\u{1F44E}\u{1F3FC}",
+    "\u{1F44E}\u{1F3FD}",
+    "\u{1F44E}\u{1F3FE}",
+    "\u{1F44E}\u{1F3FF}",
+    "\u{1F44F}",
+    "\u{1F44F}\u{1F3FB}",
+    "\u{1F44F}\u{1F3FC}",
+    "\u{1F44F}\u{1F3FD}",
+    "\u{1F44F}\u{1F3FE}",
+    "\u{1F44F}\u{1F3FF}",
+    "\u{1F450}",
+    "\u{1F450}\u{1F3FB}",
+    "\u{1F450}\u{1F3FC}",
+    "\u{1F450}\u{1F3FD}",
+    "\u{1F450}\u{1F3FE}",
+    "\u{1F450}\u{1F3FF}",
+    "\u{1F451}",
+    "\u{1F452}",
+    "\u{1F453}",
+    "\u{1F454}",
+    "\u{1F455}",
+    "\u{1F456}",
+    "\u{1F457}",
+    "\u{1F458}",
+    "\u{1F459}",
+    "\u{1F45A}",
+    "\u{1F45B}",
+    "\u{1F45C}",
+    "\u{1F45D}",
+    "\u{1F45E}",
+    "\u{1F45F}",
+    "\u{1F460}",
+    "\u{1F461}",
+    "\u{1F462}",
+    "\u{1F463}",
+    "\u{1F464}",
+    "\u{1F465}",
+    "\u{1F466}",
+    "\u{1F466}\u{1F3FB}",
+    "\u{1F466}\u{1F3FC}",
+    "\u{1F466}\u{1F3FD}",
+    "\u{1F466}\u{1F3FE}",
+    "\u{1F466}\u{1F3FF}",
+    "\u{1F467}",
+    "\u{1F467}\u{1F3FB}",
+    "\u{1F467}\u{1F3FC}",
+    "\u{1F467}\u{1F3FD}",
+    "\u{1F467}\u{1F3FE}",
+    "\u{1F467}\u{1F3FF}",
+    "\u{1F468}",
+    "\u{1F468}\u{200D}\u{2695}\u{FE0F}",
+    "\u{1F468}\u{200D}\u{2696}\u{FE0F}",
+    "\u{1F468}\u{200D}\u{2708}\u{FE0F}",
+    "\u{1F468}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}",
+    "\u{1F468}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}",
+    "\u{1F468}\u{200D}\u{1F33E}",
+    "\u{1F468}\u{200D}\u{1F373}",
+    "\u{1F468}\u{200D}\u{1F37C}",
+    "\u{1F468}\u{200D}\u{1F393}",
+    "\u{1F468}\u{200D}\u{1F3A4}",
+    "\u{1F468}\u{200D}\u{1F3A8}",
+    "\u{1F468}\u{200D}\u{1F3EB}",
+    "\u{1F468}\u{200D}\u{1F3ED}",
+    "\u{1F468}\u{200D}\u{1F466}",
+    "\u{1F468}\u{200D}\u{1F466}\u{200D}\u{1F466}",
+    "\u{1F468}\u{200D}\u{1F467}",
+    "\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F466}",
+    "\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F467}",
+    "\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F466}",
+    "\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F466}\u{200D}\u{1F466}",
+    "\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}",
+    "\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F466}",
+    "\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F467}",
+    "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}",
+    "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}",
+    "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+    "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}",
+    "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}",
+    "\u{1F468}\u{200D}\u{1F4BB}",
+    "\u{1F468}\u{200D}\u{1F4BC}",
+    "\u{1F468}\u{200D}\u{1F527}",
+    "\u{1F468}\u{200D}\u{1F52C}",
+    "\u{1F468}\u{200D}\u{1F680}",
+    "\u{1F468}\u{200D}\u{1F692}",
+    "\u{1F468}\u{200D}\u{1F9AF}",
+    "\u{1F468}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F468}\u{200D}\u{1F9B0}",
+    "\u{1F468}\u{200D}\u{1F9B1}",
+    "\u{1F468}\u{200D}\u{1F9B2}",
+    "\u{1F468}\u{200D}\u{1F9B3}",
+    "\u{1F468}\u{200D}\u{1F9BC}",
+    "\u{1F468}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F468}\u{200D}\u{1F9BD}",
+    "\u{1F468}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2695}\u{FE0F}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2696}\u{FE0F}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2708}\u{FE0F}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F33E}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F373}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F37C}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F393}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F3A4}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F3A8}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F3EB}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F3ED}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F4BB}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F4BC}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F527}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F52C}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F680}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F692}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9AF}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9B0}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9B1}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9B2}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9B3}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9BC}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9BD}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FB}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2695}\u{FE0F}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2696}\u{FE0F}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2708}\u{FE0F}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F33E}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F373}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F37C}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F393}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F3A4}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F3A8}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F3EB}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F3ED}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F4BB}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F4BC}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F527}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F52C}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F680}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F692}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9AF}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9B0}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9B1}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9B2}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9B3}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9BC}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9BD}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FC}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2695}\u{FE0F}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2696}\u{FE0F}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2708}\u{FE0F}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F33E}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F373}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F37C}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F393}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F3A4}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F3A8}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F3EB}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F3ED}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F4BB}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F4BC}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F527}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F52C}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F680}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F692}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9AF}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9B0}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9B1}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9B2}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9B3}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9BC}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9BD}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FD}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2695}\u{FE0F}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2696}\u{FE0F}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2708}\u{FE0F}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F33E}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F373}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F37C}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F393}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F3A4}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F3A8}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F3EB}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F3ED}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F4BB}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F4BC}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F527}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F52C}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F680}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F692}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9AF}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9B0}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9B1}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9B2}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9B3}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9BC}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9BD}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FE}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2695}\u{FE0F}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2696}\u{FE0F}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2708}\u{FE0F}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F33E}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F373}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F37C}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F393}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F3A4}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F3A8}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F3EB}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F3ED}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F430}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F4BB}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F4BC}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F527}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F52C}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F680}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F692}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9AF}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9B0}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9B1}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9B2}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9B3}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9BC}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9BD}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F468}\u{1F3FF}\u{200D}\u{1FAEF}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}",
+    "\u{1F469}\u{200D}\u{2695}\u{FE0F}",
+    "\u{1F469}\u{200D}\u{2696}\u{FE0F}",
+    "\u{1F469}\u{200D}\u{2708}\u{FE0F}",
+    "\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}",
+    "\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}",
+    "\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}",
+    "\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}",
+    "\u{1F469}\u{200D}\u{1F33E}",
+    "\u{1F469}\u{200D}\u{1F373}",
+    "\u{1F469}\u{200D}\u{1F37C}",
+    "\u{1F469}\u{200D}\u{1F393}",
+    "\u{1F469}\u{200D}\u{1F3A4}",
+    "\u{1F469}\u{200D}\u{1F3A8}",
+    "\u{1F469}\u{200D}\u{1F3EB}",
+    "\u{1F469}\u{200D}\u{1F3ED}",
+    "\u{1F469}\u{200D}\u{1F466}",
+    "\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}",
+    "\u{1F469}\u{200D}\u{1F467}",
+    "\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}",
+    "\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}",
+    "\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F466}",
+    "\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}",
+    "\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+    "\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}",
+    "\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}",
+    "\u{1F469}\u{200D}\u{1F4BB}",
+    "\u{1F469}\u{200D}\u{1F4BC}",
+    "\u{1F469}\u{200D}\u{1F527}",
+    "\u{1F469}\u{200D}\u{1F52C}",
+    "\u{1F469}\u{200D}\u{1F680}",
+    "\u{1F469}\u{200D}\u{1F692}",
+    "\u{1F469}\u{200D}\u{1F9AF}",
+    "\u{1F469}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F469}\u{200D}\u{1F9B0}",
+    "\u{1F469}\u{200D}\u{1F9B1}",
+    "\u{1F469}\u{200D}\u{1F9B2}",
+    "\u{1F469}\u{200D}\u{1F9B3}",
+    "\u{1F469}\u{200D}\u{1F9BC}",
+    "\u{1F469}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F469}\u{200D}\u{1F9BD}",
+    "\u{1F469}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2695}\u{FE0F}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2696}\u{FE0F}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2708}\u{FE0F}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F33E}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F373}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F37C}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F393}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F3A4}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F3A8}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F3EB}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F3ED}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F4BB}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F4BC}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F527}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F52C}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F680}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F692}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9AF}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9B0}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9B1}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9B2}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9B3}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9BC}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9BD}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FB}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2695}\u{FE0F}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2696}\u{FE0F}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2708}\u{FE0F}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F33E}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F373}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F37C}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F393}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F3A4}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F3A8}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F3EB}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F3ED}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F4BB}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F4BC}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F527}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F52C}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F680}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F692}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9AF}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9B0}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9B1}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9B2}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9B3}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9BC}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9BD}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FC}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2695}\u{FE0F}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2696}\u{FE0F}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2708}\u{FE0F}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F33E}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F373}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F37C}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F393}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F3A4}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F3A8}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F3EB}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F3ED}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F4BB}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F4BC}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F527}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F52C}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F680}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F692}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9AF}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9B0}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9B1}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9B2}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9B3}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9BC}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9BD}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FD}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2695}\u{FE0F}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2696}\u{FE0F}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2708}\u{FE0F}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F33E}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F373}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F37C}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F393}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F3A4}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F3A8}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F3EB}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F3ED}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F4BB}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F4BC}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F527}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F52C}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F680}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F692}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9AF}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9B0}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9B1}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9B2}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9B3}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9BC}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9BD}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FE}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2695}\u{FE0F}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2696}\u{FE0F}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2708}\u{FE0F}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FF}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F33E}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F373}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F37C}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F393}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F3A4}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F3A8}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F3EB}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F3ED}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F430}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F4BB}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F4BC}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F527}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F52C}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F680}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F692}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9AF}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9B0}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9B1}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9B2}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9B3}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9BC}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9BD}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FB}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FC}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FD}",
+    "\u{1F469}\u{1F3FF}\u{200D}\u{1FAEF}\u{200D}\u{1F469}\u{1F3FE}",
+    "\u{1F46A}",
+    "\u{1F46B}",
+    "\u{1F46B}\u{1F3FB}",
+    "\u{1F46B}\u{1F3FC}",
+    "\u{1F46B}\u{1F3FD}",
+    "\u{1F46B}\u{1F3FE}",
+    "\u{1F46B}\u{1F3FF}",
+    "\u{1F46C}",
+    "\u{1F46C}\u{1F3FB}",
+    "\u{1F46C}\u{1F3FC}",
+    "\u{1F46C}\u{1F3FD}",
+    "\u{1F46C}\u{1F3FE}",
+    "\u{1F46C}\u{1F3FF}",
+    "\u{1F46D}",
+    "\u{1F46D}\u{1F3FB}",
+    "\u{1F46D}\u{1F3FC}",
+    "\u{1F46D}\u{1F3FD}",
+    "\u{1F46D}\u{1F3FE}",
+    "\u{1F46D}\u{1F3FF}",
+    "\u{1F46E}",
+    "\u{1F46E}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F46E}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F46E}\u{1F3FB}",
+    "\u{1F46E}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F46E}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F46E}\u{1F3FC}",
+    "\u{1F46E}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F46E}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F46E}\u{1F3FD}",
+    "\u{1F46E}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F46E}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F46E}\u{1F3FE}",
+    "\u{1F46E}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F46E}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F46E}\u{1F3FF}",
+    "\u{1F46E}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F46E}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F46F}",
+    "\u{1F46F}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F46F}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F46F}\u{1F3FB}",
+    "\u{1F46F}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F46F}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F46F}\u{1F3FC}",
+    "\u{1F46F}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F46F}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F46F}\u{1F3FD}",
+    "\u{1F46F}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F46F}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F46F}\u{1F3FE}",
+    "\u{1F46F}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F46F}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F46F}\u{1F3FF}",
+    "\u{1F46F}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F46F}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F470}",
+    "\u{1F470}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F470}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F470}\u{1F3FB}",
+    "\u{1F470}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F470}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F470}\u{1F3FC}",
+    "\u{1F470}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F470}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F470}\u{1F3FD}",
+    "\u{1F470}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F470}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F470}\u{1F3FE}",
+    "\u{1F470}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F470}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F470}\u{1F3FF}",
+    "\u{1F470}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F470}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F471}",
+    "\u{1F471}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F471}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F471}\u{1F3FB}",
+    "\u{1F471}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F471}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F471}\u{1F3FC}",
+    "\u{1F471}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F471}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F471}\u{1F3FD}",
+    "\u{1F471}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F471}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F471}\u{1F3FE}",
+    "\u{1F471}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F471}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F471}\u{1F3FF}",
+    "\u{1F471}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F471}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F472}",
+    "\u{1F472}\u{1F3FB}",
+    "\u{1F472}\u{1F3FC}",
+    "\u{1F472}\u{1F3FD}",
+    "\u{1F472}\u{1F3FE}",
+    "\u{1F472}\u{1F3FF}",
+    "\u{1F473}",
+    "\u{1F473}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F473}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F473}\u{1F3FB}",
+    "\u{1F473}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F473}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F473}\u{1F3FC}",
+    "\u{1F473}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F473}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F473}\u{1F3FD}",
+    "\u{1F473}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F473}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F473}\u{1F3FE}",
+    "\u{1F473}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F473}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F473}\u{1F3FF}",
+    "\u{1F473}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F473}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F474}",
+    "\u{1F474}\u{1F3FB}",
+    "\u{1F474}\u{1F3FC}",
+    "\u{1F474}\u{1F3FD}",
+    "\u{1F474}\u{1F3FE}",
+    "\u{1F474}\u{1F3FF}",
+    "\u{1F475}",
+    "\u{1F475}\u{1F3FB}",
+    "\u{1F475}\u{1F3FC}",
+    "\u{1F475}\u{1F3FD}",
+    "\u{1F475}\u{1F3FE}",
+    "\u{1F475}\u{1F3FF}",
+    "\u{1F476}",
+    "\u{1F476}\u{1F3FB}",
+    "\u{1F476}\u{1F3FC}",
+    "\u{1F476}\u{1F3FD}",
+    "\u{1F476}\u{1F3FE}",
+    "\u{1F476}\u{1F3FF}",
+    "\u{1F477}",
+    "\u{1F477}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F477}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F477}\u{1F3FB}",
+    "\u{1F477}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F477}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F477}\u{1F3FC}",
+    "\u{1F477}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F477}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F477}\u{1F3FD}",
+    "\u{1F477}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F477}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F477}\u{1F3FE}",
+    "\u{1F477}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F477}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F477}\u{1F3FF}",
+    "\u{1F477}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F477}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F478}",
+    "\u{1F478}\u{1F3FB}",
+    "\u{1F478}\u{1F3FC}",
+    "\u{1F478}\u{1F3FD}",
+    "\u{1F478}\u{1F3FE}",
+    "\u{1F478}\u{1F3FF}",
+    "\u{1F479}",
+    "\u{1F47A}",
+    "\u{1F47B}",
+    "\u{1F47C}",
+    "\u{1F47C}\u{1F3FB}",
+    "\u{1F47C}\u{1F3FC}",
+    "\u{1F47C}\u{1F3FD}",
+    "\u{1F47C}\u{1F3FE}",
+    "\u{1F47C}\u{1F3FF}",
+    "\u{1F47D}",
+    "\u{1F47E}",
+    "\u{1F47F}",
+    "\u{1F480}",
+    "\u{1F481}",
+    "\u{1F481}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F481}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F481}\u{1F3FB}",
+    "\u{1F481}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F481}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F481}\u{1F3FC}",
+    "\u{1F481}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F481}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F481}\u{1F3FD}",
+    "\u{1F481}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F481}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F481}\u{1F3FE}",
+    "\u{1F481}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F481}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F481}\u{1F3FF}",
+    "\u{1F481}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F481}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F482}",
+    "\u{1F482}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F482}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F482}\u{1F3FB}",
+    "\u{1F482}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F482}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F482}\u{1F3FC}",
+    "\u{1F482}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F482}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F482}\u{1F3FD}",
+    "\u{1F482}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F482}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F482}\u{1F3FE}",
+    "\u{1F482}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F482}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F482}\u{1F3FF}",
+    "\u{1F482}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F482}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F483}",
+    "\u{1F483}\u{1F3FB}",
+    "\u{1F483}\u{1F3FC}",
+    "\u{1F483}\u{1F3FD}",
+    "\u{1F483}\u{1F3FE}",
+    "\u{1F483}\u{1F3FF}",
+    "\u{1F484}",
+    "\u{1F485}",
+    "\u{1F485}\u{1F3FB}",
+    "\u{1F485}\u{1F3FC}",
+    "\u{1F485}\u{1F3FD}",
+    "\u{1F485}\u{1F3FE}",
+    "\u{1F485}\u{1F3FF}",
+    "\u{1F486}",
+    "\u{1F486}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F486}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F486}\u{1F3FB}",
+    "\u{1F486}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F486}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F486}\u{1F3FC}",
+    "\u{1F486}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F486}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F486}\u{1F3FD}",
+    "\u{1F486}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F486}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F486}\u{1F3FE}",
+    "\u{1F486}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F486}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F486}\u{1F3FF}",
+    "\u{1F486}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F486}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F487}",
+    "\u{1F487}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F487}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F487}\u{1F3FB}",
+    "\u{1F487}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F487}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F487}\u{1F3FC}",
+    "\u{1F487}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F487}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F487}\u{1F3FD}",
+    "\u{1F487}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F487}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F487}\u{1F3FE}",
+    "\u{1F487}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F487}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F487}\u{1F3FF}",
+    "\u{1F487}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F487}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F488}",
+    "\u{1F489}",
+    "\u{1F48A}",
+    "\u{1F48B}",
+    "\u{1F48C}",
+    "\u{1F48D}",
+    "\u{1F48E}",
+    "\u{1F48F}",
+    "\u{1F48F}\u{1F3FB}",
+    "\u{1F48F}\u{1F3FC}",
+    "\u{1F48F}\u{1F3FD}",
+    "\u{1F48F}\u{1F3FE}",
+    "\u{1F48F}\u{1F3FF}",
+    "\u{1F490}",
+    "\u{1F491}",
+    "\u{1F491}\u{1F3FB}",
+    "\u{1F491}\u{1F3FC}",
+    "\u{1F491}\u{1F3FD}",
+    "\u{1F491}\u{1F3FE}",
+    "\u{1F491}\u{1F3FF}",
+    "\u{1F492}",
+    "\u{1F493}",
+    "\u{1F494}",
+    "\u{1F495}",
+    "\u{1F496}",
+    "\u{1F497}",
+    "\u{1F498}",
+    "\u{1F499}",
+    "\u{1F49A}",
+    "\u{1F49B}",
+    "\u{1F49C}",
+    "\u{1F49D}",
+    "\u{1F49E}",
+    "\u{1F49F}",
+    "\u{1F4A0}",
+    "\u{1F4A1}",
+    "\u{1F4A2}",
+    "\u{1F4A3}",
+    "\u{1F4A4}",
+    "\u{1F4A5}",
+    "\u{1F4A6}",
+    "\u{1F4A7}",
+    "\u{1F4A8}",
+    "\u{1F4A9}",
+    "\u{1F4AA}",
+    "\u{1F4AA}\u{1F3FB}",
+    "\u{1F4AA}\u{1F3FC}",
+    "\u{1F4AA}\u{1F3FD}",
+    "\u{1F4AA}\u{1F3FE}",
+    "\u{1F4AA}\u{1F3FF}",
+    "\u{1F4AB}",
+    "\u{1F4AC}",
+    "\u{1F4AD}",
+    "\u{1F4AE}",
+    "\u{1F4AF}",
+    "\u{1F4B0}",
+    "\u{1F4B1}",
+    "\u{1F4B2}",
+    "\u{1F4B3}",
+    "\u{1F4B4}",
+    "\u{1F4B5}",
+    "\u{1F4B6}",
+    "\u{1F4B7}",
+    "\u{1F4B8}",
+    "\u{1F4B9}",
+    "\u{1F4BA}",
+    "\u{1F4BB}",
+    "\u{1F4BC}",
+    "\u{1F4BD}",
+    "\u{1F4BE}",
+    "\u{1F4BF}",
+    "\u{1F4C0}",
+    "\u{1F4C1}",
+    "\u{1F4C2}",
+    "\u{1F4C3}",
+    "\u{1F4C4}",
+    "\u{1F4C5}",
+    "\u{1F4C6}",
+    "\u{1F4C7}",
+    "\u{1F4C8}",
+    "\u{1F4C9}",
+    "\u{1F4CA}",
+    "\u{1F4CB}",
+    "\u{1F4CC}",
+    "\u{1F4CD}",
+    "\u{1F4CE}",
+    "\u{1F4CF}",
+    "\u{1F4D0}",
+    "\u{1F4D1}",
+    "\u{1F4D2}",
+    "\u{1F4D3}",
+    "\u{1F4D4}",
+    "\u{1F4D5}",
+    "\u{1F4D6}",
+    "\u{1F4D7}",
+    "\u{1F4D8}",
+    "\u{1F4D9}",
+    "\u{1F4DA}",
+    "\u{1F4DB}",
+    "\u{1F4DC}",
+    "\u{1F4DD}",
+    "\u{1F4DE}",
+    "\u{1F4DF}",
+    "\u{1F4E0}",
+    "\u{1F4E1}",
+    "\u{1F4E2}",
+    "\u{1F4E3}",
+    "\u{1F4E4}",
+    "\u{1F4E5}",
+    "\u{1F4E6}",
+    "\u{1F4E7}",
+    "\u{1F4E8}",
+    "\u{1F4E9}",
+    "\u{1F4EA}",
+    "\u{1F4EB}",
+    "\u{1F4EC}",
+    "\u{1F4ED}",
+    "\u{1F4EE}",
+    "\u{1F4EF}",
+    "\u{1F4F0}",
+    "\u{1F4F1}",
+    "\u{1F4F2}",
+    "\u{1F4F3}",
+    "\u{1F4F4}",
+    "\u{1F4F5}",
+    "\u{1F4F6}",
+    "\u{1F4F7}",
+    "\u{1F4F8}",
+    "\u{1F4F9}",
+    "\u{1F4FA}",
+    "\u{1F4FB}",
+    "\u{1F4FC}",
+    "\u{1F4FD}\u{FE0F}",
+    "\u{1F4FF}",
+    "\u{1F500}",
+    "\u{1F501}",
+    "\u{1F502}",
+    "\u{1F503}",
+    "\u{1F504}",
+    "\u{1F505}",
+    "\u{1F506}",
+    "\u{1F507}",
+    "\u{1F508}",
+    "\u{1F509}",
+    "\u{1F50A}",
+    "\u{1F50B}",
+    "\u{1F50C}",
+    "\u{1F50D}",
+    "\u{1F50E}",
+    "\u{1F50F}",
+    "\u{1F510}",
+    "\u{1F511}",
+    "\u{1F512}",
+    "\u{1F513}",
+    "\u{1F514}",
+    "\u{1F515}",
+    "\u{1F516}",
+    "\u{1F517}",
+    "\u{1F518}",
+    "\u{1F519}",
+    "\u{1F51A}",
+    "\u{1F51B}",
+    "\u{1F51C}",
+    "\u{1F51D}",
+    "\u{1F51E}",
+    "\u{1F51F}",
+    "\u{1F520}",
+    "\u{1F521}",
+    "\u{1F522}",
+    "\u{1F523}",
+    "\u{1F524}",
+    "\u{1F525}",
+    "\u{1F526}",
+    "\u{1F527}",
+    "\u{1F528}",
+    "\u{1F529}",
+    "\u{1F52A}",
+    "\u{1F52B}",
+    "\u{1F52C}",
+    "\u{1F52D}",
+    "\u{1F52E}",
+    "\u{1F52F}",
+    "\u{1F530}",
+    "\u{1F531}",
+    "\u{1F532}",
+    "\u{1F533}",
+    "\u{1F534}",
+    "\u{1F535}",
+    "\u{1F536}",
+    "\u{1F537}",
+    "\u{1F538}",
+    "\u{1F539}",
+    "\u{1F53A}",
+    "\u{1F53B}",
+    "\u{1F53C}",
+    "\u{1F53D}",
+    "\u{1F549}\u{FE0F}",
+    "\u{1F54A}\u{FE0F}",
+    "\u{1F54B}",
+    "\u{1F54C}",
+    "\u{1F54D}",
+    "\u{1F54E}",
+    "\u{1F550}",
+    "\u{1F551}",
+    "\u{1F552}",
+    "\u{1F553}",
+    "\u{1F554}",
+    "\u{1F555}",
+    "\u{1F556}",
+    "\u{1F557}",
+    "\u{1F558}",
+    "\u{1F559}",
+    "\u{1F55A}",
+    "\u{1F55B}",
+    "\u{1F55C}",
+    "\u{1F55D}",
+    "\u{1F55E}",
+    "\u{1F55F}",
+    "\u{1F560}",
+    "\u{1F561}",
+    "\u{1F562}",
+    "\u{1F563}",
+    "\u{1F564}",
+    "\u{1F565}",
+    "\u{1F566}",
+    "\u{1F567}",
+    "\u{1F56F}\u{FE0F}",
+    "\u{1F570}\u{FE0F}",
+    "\u{1F573}\u{FE0F}",
+    "\u{1F574}\u{FE0F}",
+    "\u{1F574}\u{1F3FB}",
+    "\u{1F574}\u{1F3FC}",
+    "\u{1F574}\u{1F3FD}",
+    "\u{1F574}\u{1F3FE}",
+    "\u{1F574}\u{1F3FF}",
+    "\u{1F575}\u{FE0F}",
+    "\u{1F575}\u{FE0F}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F575}\u{FE0F}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F575}\u{1F3FB}",
+    "\u{1F575}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F575}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F575}\u{1F3FC}",
+    "\u{1F575}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F575}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F575}\u{1F3FD}",
+    "\u{1F575}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F575}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F575}\u{1F3FE}",
+    "\u{1F575}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F575}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F575}\u{1F3FF}",
+    "\u{1F575}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F575}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F576}\u{FE0F}",
+    "\u{1F577}\u{FE0F}",
+    "\u{1F578}\u{FE0F}",
+    "\u{1F579}\u{FE0F}",
+    "\u{1F57A}",
+    "\u{1F57A}\u{1F3FB}",
+    "\u{1F57A}\u{1F3FC}",
+    "\u{1F57A}\u{1F3FD}",
+    "\u{1F57A}\u{1F3FE}",
+    "\u{1F57A}\u{1F3FF}",
+    "\u{1F587}\u{FE0F}",
+    "\u{1F58A}\u{FE0F}",
+    "\u{1F58B}\u{FE0F}",
+    "\u{1F58C}\u{FE0F}",
+    "\u{1F58D}\u{FE0F}",
+    "\u{1F590}\u{FE0F}",
+    "\u{1F590}\u{1F3FB}",
+    "\u{1F590}\u{1F3FC}",
+    "\u{1F590}\u{1F3FD}",
+    "\u{1F590}\u{1F3FE}",
+    "\u{1F590}\u{1F3FF}",
+    "\u{1F595}",
+    "\u{1F595}\u{1F3FB}",
+    "\u{1F595}\u{1F3FC}",
+    "\u{1F595}\u{1F3FD}",
+    "\u{1F595}\u{1F3FE}",
+    "\u{1F595}\u{1F3FF}",
+    "\u{1F596}",
+    "\u{1F596}\u{1F3FB}",
+    "\u{1F596}\u{1F3FC}",
+    "\u{1F596}\u{1F3FD}",
+    "\u{1F596}\u{1F3FE}",
+    "\u{1F596}\u{1F3FF}",
+    "\u{1F5A4}",
+    "\u{1F5A5}\u{FE0F}",
+    "\u{1F5A8}\u{FE0F}",
+    "\u{1F5B1}\u{FE0F}",
+    "\u{1F5B2}\u{FE0F}",
+    "\u{1F5BC}\u{FE0F}",
+    "\u{1F5C2}\u{FE0F}",
+    "\u{1F5C3}\u{FE0F}",
+    "\u{1F5C4}\u{FE0F}",
+    "\u{1F5D1}\u{FE0F}",
+    "\u{1F5D2}\u{FE0F}",
+    "\u{1F5D3}\u{FE0F}",
+    "\u{1F5DC}\u{FE0F}",
+    "\u{1F5DD}\u{FE0F}",
+    "\u{1F5DE}\u{FE0F}",
+    "\u{1F5E1}\u{FE0F}",
+    "\u{1F5E3}\u{FE0F}",
+    "\u{1F5E8}\u{FE0F}",
+    "\u{1F5EF}\u{FE0F}",
+    "\u{1F5F3}\u{FE0F}",
+    "\u{1F5FA}\u{FE0F}",
+    "\u{1F5FB}",
+    "\u{1F5FC}",
+    "\u{1F5FD}",
+    "\u{1F5FE}",
+    "\u{1F5FF}",
+    "\u{1F600}",
+    "\u{1F601}",
+    "\u{1F602}",
+    "\u{1F603}",
+    "\u{1F604}",
+    "\u{1F605}",
+    "\u{1F606}",
+    "\u{1F607}",
+    "\u{1F608}",
+    "\u{1F609}",
+    "\u{1F60A}",
+    "\u{1F60B}",
+    "\u{1F60C}",
+    "\u{1F60D}",
+    "\u{1F60E}",
+    "\u{1F60F}",
+    "\u{1F610}",
+    "\u{1F611}",
+    "\u{1F612}",
+    "\u{1F613}",
+    "\u{1F614}",
+    "\u{1F615}",
+    "\u{1F616}",
+    "\u{1F617}",
+    "\u{1F618}",
+    "\u{1F619}",
+    "\u{1F61A}",
+    "\u{1F61B}",
+    "\u{1F61C}",
+    "\u{1F61D}",
+    "\u{1F61E}",
+    "\u{1F61F}",
+    "\u{1F620}",
+    "\u{1F621}",
+    "\u{1F622}",
+    "\u{1F623}",
+    "\u{1F624}",
+    "\u{1F625}",
+    "\u{1F626}",
+    "\u{1F627}",
+    "\u{1F628}",
+    "\u{1F629}",
+    "\u{1F62A}",
+    "\u{1F62B}",
+    "\u{1F62C}",
+    "\u{1F62D}",
+    "\u{1F62E}",
+    "\u{1F62E}\u{200D}\u{1F4A8}",
+    "\u{1F62F}",
+    "\u{1F630}",
+    "\u{1F631}",
+    "\u{1F632}",
+    "\u{1F633}",
+    "\u{1F634}",
+    "\u{1F635}",
+    "\u{1F635}\u{200D}\u{1F4AB}",
+    "\u{1F636}",
+    "\u{1F636}\u{200D}\u{1F32B}\u{FE0F}",
+    "\u{1F637}",
+    "\u{1F638}",
+    "\u{1F639}",
+    "\u{1F63A}",
+    "\u{1F63B}",
+    "\u{1F63C}",
+    "\u{1F63D}",
+    "\u{1F63E}",
+    "\u{1F63F}",
+    "\u{1F640}",
+    "\u{1F641}",
+    "\u{1F642}",
+    "\u{1F642}\u{200D}\u{2194}\u{FE0F}",
+    "\u{1F642}\u{200D}\u{2195}\u{FE0F}",
+    "\u{1F643}",
+    "\u{1F644}",
+    "\u{1F645}",
+    "\u{1F645}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F645}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F645}\u{1F3FB}",
+    "\u{1F645}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F645}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F645}\u{1F3FC}",
+    "\u{1F645}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F645}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F645}\u{1F3FD}",
+    "\u{1F645}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F645}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F645}\u{1F3FE}",
+    "\u{1F645}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F645}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F645}\u{1F3FF}",
+    "\u{1F645}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F645}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F646}",
+    "\u{1F646}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F646}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F646}\u{1F3FB}",
+    "\u{1F646}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F646}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F646}\u{1F3FC}",
+    "\u{1F646}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F646}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F646}\u{1F3FD}",
+    "\u{1F646}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F646}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F646}\u{1F3FE}",
+    "\u{1F646}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F646}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F646}\u{1F3FF}",
+    "\u{1F646}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F646}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F647}",
+    "\u{1F647}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F647}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F647}\u{1F3FB}",
+    "\u{1F647}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F647}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F647}\u{1F3FC}",
+    "\u{1F647}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F647}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F647}\u{1F3FD}",
+    "\u{1F647}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F647}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F647}\u{1F3FE}",
+    "\u{1F647}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F647}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F647}\u{1F3FF}",
+    "\u{1F647}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F647}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F648}",
+    "\u{1F649}",
+    "\u{1F64A}",
+    "\u{1F64B}",
+    "\u{1F64B}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F64B}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F64B}\u{1F3FB}",
+    "\u{1F64B}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F64B}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F64B}\u{1F3FC}",
+    "\u{1F64B}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F64B}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F64B}\u{1F3FD}",
+    "\u{1F64B}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F64B}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F64B}\u{1F3FE}",
+    "\u{1F64B}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F64B}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F64B}\u{1F3FF}",
+    "\u{1F64B}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F64B}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F64C}",
+    "\u{1F64C}\u{1F3FB}",
+    "\u{1F64C}\u{1F3FC}",
+    "\u{1F64C}\u{1F3FD}",
+    "\u{1F64C}\u{1F3FE}",
+    "\u{1F64C}\u{1F3FF}",
+    "\u{1F64D}",
+    "\u{1F64D}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F64D}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F64D}\u{1F3FB}",
+    "\u{1F64D}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F64D}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F64D}\u{1F3FC}",
+    "\u{1F64D}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F64D}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F64D}\u{1F3FD}",
+    "\u{1F64D}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F64D}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F64D}\u{1F3FE}",
+    "\u{1F64D}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F64D}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F64D}\u{1F3FF}",
+    "\u{1F64D}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F64D}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F64E}",
+    "\u{1F64E}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F64E}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F64E}\u{1F3FB}",
+    "\u{1F64E}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F64E}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F64E}\u{1F3FC}",
+    "\u{1F64E}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F64E}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F64E}\u{1F3FD}",
+    "\u{1F64E}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F64E}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F64E}\u{1F3FE}",
+    "\u{1F64E}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F64E}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F64E}\u{1F3FF}",
+    "\u{1F64E}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F64E}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F64F}",
+    "\u{1F64F}\u{1F3FB}",
+    "\u{1F64F}\u{1F3FC}",
+    "\u{1F64F}\u{1F3FD}",
+    "\u{1F64F}\u{1F3FE}",
+    "\u{1F64F}\u{1F3FF}",
+    "\u{1F680}",
+    "\u{1F681}",
+    "\u{1F682}",
+    "\u{1F683}",
+    "\u{1F684}",
+    "\u{1F685}",
+    "\u{1F686}",
+    "\u{1F687}",
+    "\u{1F688}",
+    "\u{1F689}",
+    "\u{1F68A}",
+    "\u{1F68B}",
+    "\u{1F68C}",
+    "\u{1F68D}",
+    "\u{1F68E}",
+    "\u{1F68F}",
+    "\u{1F690}",
+    "\u{1F691}",
+    "\u{1F692}",
+    "\u{1F693}",
+    "\u{1F694}",
+    "\u{1F695}",
+    "\u{1F696}",
+    "\u{1F697}",
+    "\u{1F698}",
+    "\u{1F699}",
+    "\u{1F69A}",
+    "\u{1F69B}",
+    "\u{1F69C}",
+    "\u{1F69D}",
+    "\u{1F69E}",
+    "\u{1F69F}",
+    "\u{1F6A0}",
+    "\u{1F6A1}",
+    "\u{1F6A2}",
+    "\u{1F6A3}",
+    "\u{1F6A3}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6A3}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6A3}\u{1F3FB}",
+    "\u{1F6A3}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6A3}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6A3}\u{1F3FC}",
+    "\u{1F6A3}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6A3}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6A3}\u{1F3FD}",
+    "\u{1F6A3}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6A3}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6A3}\u{1F3FE}",
+    "\u{1F6A3}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6A3}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6A3}\u{1F3FF}",
+    "\u{1F6A3}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6A3}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6A4}",
+    "\u{1F6A5}",
+    "\u{1F6A6}",
+    "\u{1F6A7}",
+    "\u{1F6A8}",
+    "\u{1F6A9}",
+    "\u{1F6AA}",
+    "\u{1F6AB}",
+    "\u{1F6AC}",
+    "\u{1F6AD}",
+    "\u{1F6AE}",
+    "\u{1F6AF}",
+    "\u{1F6B0}",
+    "\u{1F6B1}",
+    "\u{1F6B2}",
+    "\u{1F6B3}",
+    "\u{1F6B4}",
+    "\u{1F6B4}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6B4}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6B4}\u{1F3FB}",
+    "\u{1F6B4}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6B4}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6B4}\u{1F3FC}",
+    "\u{1F6B4}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6B4}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6B4}\u{1F3FD}",
+    "\u{1F6B4}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6B4}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6B4}\u{1F3FE}",
+    "\u{1F6B4}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6B4}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6B4}\u{1F3FF}",
+    "\u{1F6B4}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6B4}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6B5}",
+    "\u{1F6B5}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6B5}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6B5}\u{1F3FB}",
+    "\u{1F6B5}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6B5}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6B5}\u{1F3FC}",
+    "\u{1F6B5}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6B5}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6B5}\u{1F3FD}",
+    "\u{1F6B5}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6B5}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6B5}\u{1F3FE}",
+    "\u{1F6B5}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6B5}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6B5}\u{1F3FF}",
+    "\u{1F6B5}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6B5}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6B6}",
+    "\u{1F6B6}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6B6}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F6B6}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6B6}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F6B6}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FB}",
+    "\u{1F6B6}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FB}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FC}",
+    "\u{1F6B6}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FD}",
+    "\u{1F6B6}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FE}",
+    "\u{1F6B6}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FE}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FF}",
+    "\u{1F6B6}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F6B6}\u{1F3FF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F6B7}",
+    "\u{1F6B8}",
+    "\u{1F6B9}",
+    "\u{1F6BA}",
+    "\u{1F6BB}",
+    "\u{1F6BC}",
+    "\u{1F6BD}",
+    "\u{1F6BE}",
+    "\u{1F6BF}",
+    "\u{1F6C0}",
+    "\u{1F6C0}\u{1F3FB}",
+    "\u{1F6C0}\u{1F3FC}",
+    "\u{1F6C0}\u{1F3FD}",
+    "\u{1F6C0}\u{1F3FE}",
+    "\u{1F6C0}\u{1F3FF}",
+    "\u{1F6C1}",
+    "\u{1F6C2}",
+    "\u{1F6C3}",
+    "\u{1F6C4}",
+    "\u{1F6C5}",
+    "\u{1F6CB}\u{FE0F}",
+    "\u{1F6CC}",
+    "\u{1F6CC}\u{1F3FB}",
+    "\u{1F6CC}\u{1F3FC}",
+    "\u{1F6CC}\u{1F3FD}",
+    "\u{1F6CC}\u{1F3FE}",
+    "\u{1F6CC}\u{1F3FF}",
+    "\u{1F6CD}\u{FE0F}",
+    "\u{1F6CE}\u{FE0F}",
+    "\u{1F6CF}\u{FE0F}",
+    "\u{1F6D0}",
+    "\u{1F6D1}",
+    "\u{1F6D2}",
+    "\u{1F6D5}",
+    "\u{1F6D6}",
+    "\u{1F6D7}",
+    "\u{1F6D8}",
+    "\u{1F6DC}",
+    "\u{1F6DD}",
+    "\u{1F6DE}",
+    "\u{1F6DF}",
+    "\u{1F6E0}\u{FE0F}",
+    "\u{1F6E1}\u{FE0F}",
+    "\u{1F6E2}\u{FE0F}",
+    "\u{1F6E3}\u{FE0F}",
+    "\u{1F6E4}\u{FE0F}",
+    "\u{1F6E5}\u{FE0F}",
+    "\u{1F6E9}\u{FE0F}",
+    "\u{1F6EB}",
+    "\u{1F6EC}",
+    "\u{1F6F0}\u{FE0F}",
+    "\u{1F6F3}\u{FE0F}",
+    "\u{1F6F4}",
+    "\u{1F6F5}",
+    "\u{1F6F6}",
+    "\u{1F6F7}",
+    "\u{1F6F8}",
+    "\u{1F6F9}",
+    "\u{1F6FA}",
+    "\u{1F6FB}",
+    "\u{1F6FC}",
+    "\u{1F7E0}",
+    "\u{1F7E1}",
+    "\u{1F7E2}",
+    "\u{1F7E3}",
+    "\u{1F7E4}",
+    "\u{1F7E5}",
+    "\u{1F7E6}",
+    "\u{1F7E7}",
+    "\u{1F7E8}",
+    "\u{1F7E9}",
+    "\u{1F7EA}",
+    "\u{1F7EB}",
+    "\u{1F7F0}",
+    "\u{1F90C}",
+    "\u{1F90C}\u{1F3FB}",
+    "\u{1F90C}\u{1F3FC}",
+    "\u{1F90C}\u{1F3FD}",
+    "\u{1F90C}\u{1F3FE}",
+    "\u{1F90C}\u{1F3FF}",
+    "\u{1F90D}",
+    "\u{1F90E}",
+    "\u{1F90F}",
+    "\u{1F90F}\u{1F3FB}",
+    "\u{1F90F}\u{1F3FC}",
+    "\u{1F90F}\u{1F3FD}",
+    "\u{1F90F}\u{1F3FE}",
+    "\u{1F90F}\u{1F3FF}",
+    "\u{1F910}",
+    "\u{1F911}",
+    "\u{1F912}",
+    "\u{1F913}",
+    "\u{1F914}",
+    "\u{1F915}",
+    "\u{1F916}",
+    "\u{1F917}",
+    "\u{1F918}",
+    "\u{1F918}\u{1F3FB}",
+    "\u{1F918}\u{1F3FC}",
+    "\u{1F918}\u{1F3FD}",
+    "\u{1F918}\u{1F3FE}",
+    "\u{1F918}\u{1F3FF}",
+    "\u{1F919}",
+    "\u{1F919}\u{1F3FB}",
+    "\u{1F919}\u{1F3FC}",
+    "\u{1F919}\u{1F3FD}",
+    "\u{1F919}\u{1F3FE}",
+    "\u{1F919}\u{1F3FF}",
+    "\u{1F91A}",
+    "\u{1F91A}\u{1F3FB}",
+    "\u{1F91A}\u{1F3FC}",
+    "\u{1F91A}\u{1F3FD}",
+    "\u{1F91A}\u{1F3FE}",
+    "\u{1F91A}\u{1F3FF}",
+    "\u{1F91B}",
+    "\u{1F91B}\u{1F3FB}",
+    "\u{1F91B}\u{1F3FC}",
+    "\u{1F91B}\u{1F3FD}",
+    "\u{1F91B}\u{1F3FE}",
+    "\u{1F91B}\u{1F3FF}",
+    "\u{1F91C}",
+    "\u{1F91C}\u{1F3FB}",
+    "\u{1F91C}\u{1F3FC}",
+    "\u{1F91C}\u{1F3FD}",
+    "\u{1F91C}\u{1F3FE}",
+    "\u{1F91C}\u{1F3FF}",
+    "\u{1F91D}",
+    "\u{1F91D}\u{1F3FB}",
+    "\u{1F91D}\u{1F3FC}",
+    "\u{1F91D}\u{1F3FD}",
+    "\u{1F91D}\u{1F3FE}",
+    "\u{1F91D}\u{1F3FF}",
+    "\u{1F91E}",
+    "\u{1F91E}\u{1F3FB}",
+    "\u{1F91E}\u{1F3FC}",
+    "\u{1F91E}\u{1F3FD}",
+    "\u{1F91E}\u{1F3FE}",
+    "\u{1F91E}\u{1F3FF}",
+    "\u{1F91F}",
+    "\u{1F91F}\u{1F3FB}",
+    "\u{1F91F}\u{1F3FC}",
+    "\u{1F91F}\u{1F3FD}",
+    "\u{1F91F}\u{1F3FE}",
+    "\u{1F91F}\u{1F3FF}",
+    "\u{1F920}",
+    "\u{1F921}",
+    "\u{1F922}",
+    "\u{1F923}",
+    "\u{1F924}",
+    "\u{1F925}",
+    "\u{1F926}",
+    "\u{1F926}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F926}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F926}\u{1F3FB}",
+    "\u{1F926}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F926}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F926}\u{1F3FC}",
+    "\u{1F926}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F926}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F926}\u{1F3FD}",
+    "\u{1F926}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F926}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F926}\u{1F3FE}",
+    "\u{1F926}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F926}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F926}\u{1F3FF}",
+    "\u{1F926}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F926}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F927}",
+    "\u{1F928}",
+    "\u{1F929}",
+    "\u{1F92A}",
+    "\u{1F92B}",
+    "\u{1F92C}",
+    "\u{1F92D}",
+    "\u{1F92E}",
+    "\u{1F92F}",
+    "\u{1F930}",
+    "\u{1F930}\u{1F3FB}",
+    "\u{1F930}\u{1F3FC}",
+    "\u{1F930}\u{1F3FD}",
+    "\u{1F930}\u{1F3FE}",
+    "\u{1F930}\u{1F3FF}",
+    "\u{1F931}",
+    "\u{1F931}\u{1F3FB}",
+    "\u{1F931}\u{1F3FC}",
+    "\u{1F931}\u{1F3FD}",
+    "\u{1F931}\u{1F3FE}",
+    "\u{1F931}\u{1F3FF}",
+    "\u{1F932}",
+    "\u{1F932}\u{1F3FB}",
+    "\u{1F932}\u{1F3FC}",
+    "\u{1F932}\u{1F3FD}",
+    "\u{1F932}\u{1F3FE}",
+    "\u{1F932}\u{1F3FF}",
+    "\u{1F933}",
+    "\u{1F933}\u{1F3FB}",
+    "\u{1F933}\u{1F3FC}",
+    "\u{1F933}\u{1F3FD}",
+    "\u{1F933}\u{1F3FE}",
+    "\u{1F933}\u{1F3FF}",
+    "\u{1F934}",
+    "\u{1F934}\u{1F3FB}",
+    "\u{1F934}\u{1F3FC}",
+    "\u{1F934}\u{1F3FD}",
+    "\u{1F934}\u{1F3FE}",
+    "\u{1F934}\u{1F3FF}",
+    "\u{1F935}",
+    "\u{1F935}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F935}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F935}\u{1F3FB}",
+    "\u{1F935}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F935}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F935}\u{1F3FC}",
+    "\u{1F935}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F935}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F935}\u{1F3FD}",
+    "\u{1F935}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F935}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F935}\u{1F3FE}",
+    "\u{1F935}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F935}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F935}\u{1F3FF}",
+    "\u{1F935}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F935}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F936}",
+    "\u{1F936}\u{1F3FB}",
+    "\u{1F936}\u{1F3FC}",
+    "\u{1F936}\u{1F3FD}",
+    "\u{1F936}\u{1F3FE}",
+    "\u{1F936}\u{1F3FF}",
+    "\u{1F937}",
+    "\u{1F937}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F937}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F937}\u{1F3FB}",
+    "\u{1F937}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F937}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F937}\u{1F3FC}",
+    "\u{1F937}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F937}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F937}\u{1F3FD}",
+    "\u{1F937}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F937}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F937}\u{1F3FE}",
+    "\u{1F937}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F937}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F937}\u{1F3FF}",
+    "\u{1F937}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F937}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F938}",
+    "\u{1F938}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F938}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F938}\u{1F3FB}",
+    "\u{1F938}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F938}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F938}\u{1F3FC}",
+    "\u{1F938}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F938}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F938}\u{1F3FD}",
+    "\u{1F938}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F938}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F938}\u{1F3FE}",
+    "\u{1F938}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F938}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F938}\u{1F3FF}",
+    "\u{1F938}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F938}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F939}",
+    "\u{1F939}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F939}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F939}\u{1F3FB}",
+    "\u{1F939}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F939}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F939}\u{1F3FC}",
+    "\u{1F939}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F939}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F939}\u{1F3FD}",
+    "\u{1F939}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F939}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F939}\u{1F3FE}",
+    "\u{1F939}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F939}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F939}\u{1F3FF}",
+    "\u{1F939}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F939}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F93A}",
+    "\u{1F93C}",
+    "\u{1F93C}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F93C}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F93C}\u{1F3FB}",
+    "\u{1F93C}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F93C}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F93C}\u{1F3FC}",
+    "\u{1F93C}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F93C}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F93C}\u{1F3FD}",
+    "\u{1F93C}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F93C}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F93C}\u{1F3FE}",
+    "\u{1F93C}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F93C}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F93C}\u{1F3FF}",
+    "\u{1F93C}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F93C}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F93D}",
+    "\u{1F93D}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F93D}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F93D}\u{1F3FB}",
+    "\u{1F93D}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F93D}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F93D}\u{1F3FC}",
+    "\u{1F93D}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F93D}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F93D}\u{1F3FD}",
+    "\u{1F93D}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F93D}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F93D}\u{1F3FE}",
+    "\u{1F93D}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F93D}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F93D}\u{1F3FF}",
+    "\u{1F93D}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F93D}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F93E}",
+    "\u{1F93E}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F93E}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F93E}\u{1F3FB}",
+    "\u{1F93E}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F93E}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F93E}\u{1F3FC}",
+    "\u{1F93E}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F93E}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F93E}\u{1F3FD}",
+    "\u{1F93E}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F93E}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F93E}\u{1F3FE}",
+    "\u{1F93E}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F93E}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F93E}\u{1F3FF}",
+    "\u{1F93E}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F93E}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F93F}",
+    "\u{1F940}",
+    "\u{1F941}",
+    "\u{1F942}",
+    "\u{1F943}",
+    "\u{1F944}",
+    "\u{1F945}",
+    "\u{1F947}",
+    "\u{1F948}",
+    "\u{1F949}",
+    "\u{1F94A}",
+    "\u{1F94B}",
+    "\u{1F94C}",
+    "\u{1F94D}",
+    "\u{1F94E}",
+    "\u{1F94F}",
+    "\u{1F950}",
+    "\u{1F951}",
+    "\u{1F952}",
+    "\u{1F953}",
+    "\u{1F954}",
+    "\u{1F955}",
+    "\u{1F956}",
+    "\u{1F957}",
+    "\u{1F958}",
+    "\u{1F959}",
+    "\u{1F95A}",
+    "\u{1F95B}",
+    "\u{1F95C}",
+    "\u{1F95D}",
+    "\u{1F95E}",
+    "\u{1F95F}",
+    "\u{1F960}",
+    "\u{1F961}",
+    "\u{1F962}",
+    "\u{1F963}",
+    "\u{1F964}",
+    "\u{1F965}",
+    "\u{1F966}",
+    "\u{1F967}",
+    "\u{1F968}",
+    "\u{1F969}",
+    "\u{1F96A}",
+    "\u{1F96B}",
+    "\u{1F96C}",
+    "\u{1F96D}",
+    "\u{1F96E}",
+    "\u{1F96F}",
+    "\u{1F970}",
+    "\u{1F971}",
+    "\u{1F972}",
+    "\u{1F973}",
+    "\u{1F974}",
+    "\u{1F975}",
+    "\u{1F976}",
+    "\u{1F977}",
+    "\u{1F977}\u{1F3FB}",
+    "\u{1F977}\u{1F3FC}",
+    "\u{1F977}\u{1F3FD}",
+    "\u{1F977}\u{1F3FE}",
+    "\u{1F977}\u{1F3FF}",
+    "\u{1F978}",
+    "\u{1F979}",
+    "\u{1F97A}",
+    "\u{1F97B}",
+    "\u{1F97C}",
+    "\u{1F97D}",
+    "\u{1F97E}",
+    "\u{1F97F}",
+    "\u{1F980}",
+    "\u{1F981}",
+    "\u{1F982}",
+    "\u{1F983}",
+    "\u{1F984}",
+    "\u{1F985}",
+    "\u{1F986}",
+    "\u{1F987}",
+    "\u{1F988}",
+    "\u{1F989}",
+    "\u{1F98A}",
+    "\u{1F98B}",
+    "\u{1F98C}",
+    "\u{1F98D}",
+    "\u{1F98E}",
+    "\u{1F98F}",
+    "\u{1F990}",
+    "\u{1F991}",
+    "\u{1F992}",
+    "\u{1F993}",
+    "\u{1F994}",
+    "\u{1F995}",
+    "\u{1F996}",
+    "\u{1F997}",
+    "\u{1F998}",
+    "\u{1F999}",
+    "\u{1F99A}",
+    "\u{1F99B}",
+    "\u{1F99C}",
+    "\u{1F99D}",
+    "\u{1F99E}",
+    "\u{1F99F}",
+    "\u{1F9A0}",
+    "\u{1F9A1}",
+    "\u{1F9A2}",
+    "\u{1F9A3}",
+    "\u{1F9A4}",
+    "\u{1F9A5}",
+    "\u{1F9A6}",
+    "\u{1F9A7}",
+    "\u{1F9A8}",
+    "\u{1F9A9}",
+    "\u{1F9AA}",
+    "\u{1F9AB}",
+    "\u{1F9AC}",
+    "\u{1F9AD}",
+    "\u{1F9AE}",
+    "\u{1F9AF}",
+    "\u{1F9B0}",
+    "\u{1F9B1}",
+    "\u{1F9B2}",
+    "\u{1F9B3}",
+    "\u{1F9B4}",
+    "\u{1F9B5}",
+    "\u{1F9B5}\u{1F3FB}",
+    "\u{1F9B5}\u{1F3FC}",
+    "\u{1F9B5}\u{1F3FD}",
+    "\u{1F9B5}\u{1F3FE}",
+    "\u{1F9B5}\u{1F3FF}",
+    "\u{1F9B6}",
+    "\u{1F9B6}\u{1F3FB}",
+    "\u{1F9B6}\u{1F3FC}",
+    "\u{1F9B6}\u{1F3FD}",
+    "\u{1F9B6}\u{1F3FE}",
+    "\u{1F9B6}\u{1F3FF}",
+    "\u{1F9B7}",
+    "\u{1F9B8}",
+    "\u{1F9B8}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9B8}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9B8}\u{1F3FB}",
+    "\u{1F9B8}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9B8}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9B8}\u{1F3FC}",
+    "\u{1F9B8}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9B8}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9B8}\u{1F3FD}",
+    "\u{1F9B8}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9B8}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9B8}\u{1F3FE}",
+    "\u{1F9B8}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9B8}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9B8}\u{1F3FF}",
+    "\u{1F9B8}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9B8}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9B9}",
+    "\u{1F9B9}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9B9}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9B9}\u{1F3FB}",
+    "\u{1F9B9}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9B9}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9B9}\u{1F3FC}",
+    "\u{1F9B9}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9B9}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9B9}\u{1F3FD}",
+    "\u{1F9B9}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9B9}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9B9}\u{1F3FE}",
+    "\u{1F9B9}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9B9}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9B9}\u{1F3FF}",
+    "\u{1F9B9}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9B9}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9BA}",
+    "\u{1F9BB}",
+    "\u{1F9BB}\u{1F3FB}",
+    "\u{1F9BB}\u{1F3FC}",
+    "\u{1F9BB}\u{1F3FD}",
+    "\u{1F9BB}\u{1F3FE}",
+    "\u{1F9BB}\u{1F3FF}",
+    "\u{1F9BC}",
+    "\u{1F9BD}",
+    "\u{1F9BE}",
+    "\u{1F9BF}",
+    "\u{1F9C0}",
+    "\u{1F9C1}",
+    "\u{1F9C2}",
+    "\u{1F9C3}",
+    "\u{1F9C4}",
+    "\u{1F9C5}",
+    "\u{1F9C6}",
+    "\u{1F9C7}",
+    "\u{1F9C8}",
+    "\u{1F9C9}",
+    "\u{1F9CA}",
+    "\u{1F9CB}",
+    "\u{1F9CC}",
+    "\u{1F9CD}",
+    "\u{1F9CD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9CD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9CD}\u{1F3FB}",
+    "\u{1F9CD}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9CD}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9CD}\u{1F3FC}",
+    "\u{1F9CD}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9CD}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9CD}\u{1F3FD}",
+    "\u{1F9CD}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9CD}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9CD}\u{1F3FE}",
+    "\u{1F9CD}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9CD}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9CD}\u{1F3FF}",
+    "\u{1F9CD}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9CD}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9CE}",
+    "\u{1F9CE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9CE}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9CE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9CE}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9CE}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FB}",
+    "\u{1F9CE}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FB}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FC}",
+    "\u{1F9CE}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FD}",
+    "\u{1F9CE}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FE}",
+    "\u{1F9CE}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FE}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FF}",
+    "\u{1F9CE}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9CE}\u{1F3FF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9CF}",
+    "\u{1F9CF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9CF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9CF}\u{1F3FB}",
+    "\u{1F9CF}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9CF}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9CF}\u{1F3FC}",
+    "\u{1F9CF}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9CF}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9CF}\u{1F3FD}",
+    "\u{1F9CF}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9CF}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9CF}\u{1F3FE}",
+    "\u{1F9CF}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9CF}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9CF}\u{1F3FF}",
+    "\u{1F9CF}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9CF}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D0}",
+    "\u{1F9D1}",
+    "\u{1F9D1}\u{200D}\u{2695}\u{FE0F}",
+    "\u{1F9D1}\u{200D}\u{2696}\u{FE0F}",
+    "\u{1F9D1}\u{200D}\u{2708}\u{FE0F}",
+    "\u{1F9D1}\u{200D}\u{1F33E}",
+    "\u{1F9D1}\u{200D}\u{1F373}",
+    "\u{1F9D1}\u{200D}\u{1F37C}",
+    "\u{1F9D1}\u{200D}\u{1F384}",
+    "\u{1F9D1}\u{200D}\u{1F393}",
+    "\u{1F9D1}\u{200D}\u{1F3A4}",
+    "\u{1F9D1}\u{200D}\u{1F3A8}",
+    "\u{1F9D1}\u{200D}\u{1F3EB}",
+    "\u{1F9D1}\u{200D}\u{1F3ED}",
+    "\u{1F9D1}\u{200D}\u{1F4BB}",
+    "\u{1F9D1}\u{200D}\u{1F4BC}",
+    "\u{1F9D1}\u{200D}\u{1F527}",
+    "\u{1F9D1}\u{200D}\u{1F52C}",
+    "\u{1F9D1}\u{200D}\u{1F680}",
+    "\u{1F9D1}\u{200D}\u{1F692}",
+    "\u{1F9D1}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}",
+    "\u{1F9D1}\u{200D}\u{1F9AF}",
+    "\u{1F9D1}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9D1}\u{200D}\u{1F9B0}",
+    "\u{1F9D1}\u{200D}\u{1F9B1}",
+    "\u{1F9D1}\u{200D}\u{1F9B2}",
+    "\u{1F9D1}\u{200D}\u{1F9B3}",
+    "\u{1F9D1}\u{200D}\u{1F9BC}",
+    "\u{1F9D1}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9D1}\u{200D}\u{1F9BD}",
+    "\u{1F9D1}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9D1}\u{200D}\u{1F9D1}\u{200D}\u{1F9D2}",
+    "\u{1F9D1}\u{200D}\u{1F9D1}\u{200D}\u{1F9D2}\u{200D}\u{1F9D2}",
+    "\u{1F9D1}\u{200D}\u{1F9D2}",
+    "\u{1F9D1}\u{200D}\u{1F9D2}\u{200D}\u{1F9D2}",
+    "\u{1F9D1}\u{200D}\u{1FA70}",
+    "\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2695}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2696}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2708}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F33E}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F373}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F37C}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F384}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F393}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F3A4}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F3A8}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F3EB}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F3ED}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F4BB}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F4BC}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F527}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F52C}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F680}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F692}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9AF}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9B0}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9B1}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9B2}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9B3}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9BC}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9BD}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1FA70}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FB}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2695}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2696}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2708}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F33E}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F373}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F37C}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F384}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F393}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F3A4}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F3A8}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F3EB}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F3ED}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F4BB}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F4BC}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F527}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F52C}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F680}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F692}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9AF}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9B0}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9B1}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9B2}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9B3}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9BC}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9BD}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1FA70}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FC}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2695}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2696}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2708}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F33E}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F373}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F37C}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F384}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F393}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F3A4}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F3A8}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F3EB}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F3ED}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F4BB}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F4BC}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F527}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F52C}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F680}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F692}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9AF}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9B0}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9B1}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9B2}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9B3}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9BC}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9BD}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1FA70}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FD}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2695}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2696}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2708}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F33E}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F373}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F37C}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F384}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F393}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F3A4}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F3A8}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F3EB}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F3ED}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F4BB}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F4BC}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F527}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F52C}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F680}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F692}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9AF}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9B0}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9B1}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9B2}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9B3}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9BC}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9BD}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1FA70}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FE}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2695}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2696}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2708}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F33E}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F373}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F37C}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F384}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F393}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F3A4}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F3A8}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F3EB}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F3ED}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F430}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F4BB}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F4BC}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F527}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F52C}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F680}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F692}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FF}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9AF}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9B0}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9B1}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9B2}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9B3}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9BC}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9BD}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1FA70}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FB}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FC}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FD}",
+    "\u{1F9D1}\u{1F3FF}\u{200D}\u{1FAEF}\u{200D}\u{1F9D1}\u{1F3FE}",
+    "\u{1F9D2}",
+    "\u{1F9D2}\u{1F3FB}",
+    "\u{1F9D2}\u{1F3FC}",
+    "\u{1F9D2}\u{1F3FD}",
+    "\u{1F9D2}\u{1F3FE}",
+    "\u{1F9D2}\u{1F3FF}",
+    "\u{1F9D3}",
+    "\u{1F9D3}\u{1F3FB}",
+    "\u{1F9D3}\u{1F3FC}",
+    "\u{1F9D3}\u{1F3FD}",
+    "\u{1F9D3}\u{1F3FE}",
+    "\u{1F9D3}\u{1F3FF}",
+    "\u{1F9D4}",
+    "\u{1F9D4}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D4}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D4}\u{1F3FB}",
+    "\u{1F9D4}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D4}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D4}\u{1F3FC}",
+    "\u{1F9D4}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D4}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D4}\u{1F3FD}",
+    "\u{1F9D4}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D4}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D4}\u{1F3FE}",
+    "\u{1F9D4}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D4}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D4}\u{1F3FF}",
+    "\u{1F9D4}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D4}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D5}",
+    "\u{1F9D5}\u{1F3FB}",
+    "\u{1F9D5}\u{1F3FC}",
+    "\u{1F9D5}\u{1F3FD}",
+    "\u{1F9D5}\u{1F3FE}",
+    "\u{1F9D5}\u{1F3FF}",
+    "\u{1F9D6}",
+    "\u{1F9D6}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D6}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D6}\u{1F3FB}",
+    "\u{1F9D6}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D6}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D6}\u{1F3FC}",
+    "\u{1F9D6}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D6}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D6}\u{1F3FD}",
+    "\u{1F9D6}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D6}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D6}\u{1F3FE}",
+    "\u{1F9D6}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D6}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D6}\u{1F3FF}",
+    "\u{1F9D6}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D6}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D7}",
+    "\u{1F9D7}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D7}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D7}\u{1F3FB}",
+    "\u{1F9D7}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D7}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D7}\u{1F3FC}",
+    "\u{1F9D7}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D7}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D7}\u{1F3FD}",
+    "\u{1F9D7}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D7}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D7}\u{1F3FE}",
+    "\u{1F9D7}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D7}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D7}\u{1F3FF}",
+    "\u{1F9D7}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D7}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D8}",
+    "\u{1F9D8}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D8}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D8}\u{1F3FB}",
+    "\u{1F9D8}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D8}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D8}\u{1F3FC}",
+    "\u{1F9D8}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D8}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D8}\u{1F3FD}",
+    "\u{1F9D8}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D8}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D8}\u{1F3FE}",
+    "\u{1F9D8}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D8}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D8}\u{1F3FF}",
+    "\u{1F9D8}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D8}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D9}",
+    "\u{1F9D9}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D9}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D9}\u{1F3FB}",
+    "\u{1F9D9}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D9}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D9}\u{1F3FC}",
+    "\u{1F9D9}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D9}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D9}\u{1F3FD}",
+    "\u{1F9D9}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D9}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D9}\u{1F3FE}",
+    "\u{1F9D9}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D9}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9D9}\u{1F3FF}",
+    "\u{1F9D9}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9D9}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DA}",
+    "\u{1F9DA}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DA}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DA}\u{1F3FB}",
+    "\u{1F9DA}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DA}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DA}\u{1F3FC}",
+    "\u{1F9DA}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DA}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DA}\u{1F3FD}",
+    "\u{1F9DA}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DA}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DA}\u{1F3FE}",
+    "\u{1F9DA}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DA}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DA}\u{1F3FF}",
+    "\u{1F9DA}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DA}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DB}",
+    "\u{1F9DB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DB}\u{1F3FB}",
+    "\u{1F9DB}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DB}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DB}\u{1F3FC}",
+    "\u{1F9DB}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DB}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DB}\u{1F3FD}",
+    "\u{1F9DB}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DB}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DB}\u{1F3FE}",
+    "\u{1F9DB}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DB}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DB}\u{1F3FF}",
+    "\u{1F9DB}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DB}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DC}",
+    "\u{1F9DC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DC}\u{1F3FB}",
+    "\u{1F9DC}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DC}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DC}\u{1F3FC}",
+    "\u{1F9DC}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DC}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DC}\u{1F3FD}",
+    "\u{1F9DC}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DC}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DC}\u{1F3FE}",
+    "\u{1F9DC}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DC}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DC}\u{1F3FF}",
+    "\u{1F9DC}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DC}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DD}",
+    "\u{1F9DD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DD}\u{1F3FB}",
+    "\u{1F9DD}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DD}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DD}\u{1F3FC}",
+    "\u{1F9DD}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DD}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DD}\u{1F3FD}",
+    "\u{1F9DD}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DD}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DD}\u{1F3FE}",
+    "\u{1F9DD}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DD}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DD}\u{1F3FF}",
+    "\u{1F9DD}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DD}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DE}",
+    "\u{1F9DE}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DE}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9DF}",
+    "\u{1F9DF}\u{200D}\u{2640}\u{FE0F}",
+    "\u{1F9DF}\u{200D}\u{2642}\u{FE0F}",
+    "\u{1F9E0}",
+    "\u{1F9E1}",
+    "\u{1F9E2}",
+    "\u{1F9E3}",
+    "\u{1F9E4}",
+    "\u{1F9E5}",
+    "\u{1F9E6}",
+    "\u{1F9E7}",
+    "\u{1F9E8}",
+    "\u{1F9E9}",
+    "\u{1F9EA}",
+    "\u{1F9EB}",
+    "\u{1F9EC}",
+    "\u{1F9ED}",
+    "\u{1F9EE}",
+    "\u{1F9EF}",
+    "\u{1F9F0}",
+    "\u{1F9F1}",
+    "\u{1F9F2}",
+    "\u{1F9F3}",
+    "\u{1F9F4}",
+    "\u{1F9F5}",
+    "\u{1F9F6}",
+    "\u{1F9F7}",
+    "\u{1F9F8}",
+    "\u{1F9F9}",
+    "\u{1F9FA}",
+    "\u{1F9FB}",
+    "\u{1F9FC}",
+    "\u{1F9FD}",
+    "\u{1F9FE}",
+    "\u{1F9FF}",
+    "\u{1FA70}",
+    "\u{1FA71}",
+    "\u{1FA72}",
+    "\u{1FA73}",
+    "\u{1FA74}",
+    "\u{1FA75}",
+    "\u{1FA76}",
+    "\u{1FA77}",
+    "\u{1FA78}",
+    "\u{1FA79}",
+    "\u{1FA7A}",
+    "\u{1FA7B}",
+    "\u{1FA7C}",
+    "\u{1FA80}",
+    "\u{1FA81}",
+    "\u{1FA82}",
+    "\u{1FA83}",
+    "\u{1FA84}",
+    "\u{1FA85}",
+    "\u{1FA86}",
+    "\u{1FA87}",
+    "\u{1FA88}",
+    "\u{1FA89}",
+    "\u{1FA8A}",
+    "\u{1FA8E}",
+    "\u{1FA8F}",
+    "\u{1FA90}",
+    "\u{1FA91}",
+    "\u{1FA92}",
+    "\u{1FA93}",
+    "\u{1FA94}",
+    "\u{1FA95}",
+    "\u{1FA96}",
+    "\u{1FA97}",
+    "\u{1FA98}",
+    "\u{1FA99}",
+    "\u{1FA9A}",
+    "\u{1FA9B}",
+    "\u{1FA9C}",
+    "\u{1FA9D}",
+    "\u{1FA9E}",
+    "\u{1FA9F}",
+    "\u{1FAA0}",
+    "\u{1FAA1}",
+    "\u{1FAA2}",
+    "\u{1FAA3}",
+    "\u{1FAA4}",
+    "\u{1FAA5}",
+    "\u{1FAA6}",
+    "\u{1FAA7}",
+    "\u{1FAA8}",
+    "\u{1FAA9}",
+    "\u{1FAAA}",
+    "\u{1FAAB}",
+    "\u{1FAAC}",
+    "\u{1FAAD}",
+    "\u{1FAAE}",
+    "\u{1FAAF}",
+    "\u{1FAB0}",
+    "\u{1FAB1}",
+    "\u{1FAB2}",
+    "\u{1FAB3}",
+    "\u{1FAB4}",
+    "\u{1FAB5}",
+    "\u{1FAB6}",
+    "\u{1FAB7}",
+    "\u{1FAB8}",
+    "\u{1FAB9}",
+    "\u{1FABA}",
+    "\u{1FABB}",
+    "\u{1FABC}",
+    "\u{1FABD}",
+    "\u{1FABE}",
+    "\u{1FABF}",
+    "\u{1FAC0}",
+    "\u{1FAC1}",
+    "\u{1FAC2}",
+    "\u{1FAC3}",
+    "\u{1FAC3}\u{1F3FB}",
+    "\u{1FAC3}\u{1F3FC}",
+    "\u{1FAC3}\u{1F3FD}",
+    "\u{1FAC3}\u{1F3FE}",
+    "\u{1FAC3}\u{1F3FF}",
+    "\u{1FAC4}",
+    "\u{1FAC4}\u{1F3FB}",
+    "\u{1FAC4}\u{1F3FC}",
+    "\u{1FAC4}\u{1F3FD}",
+    "\u{1FAC4}\u{1F3FE}",
+    "\u{1FAC4}\u{1F3FF}",
+    "\u{1FAC5}",
+    "\u{1FAC5}\u{1F3FB}",
+    "\u{1FAC5}\u{1F3FC}",
+    "\u{1FAC5}\u{1F3FD}",
+    "\u{1FAC5}\u{1F3FE}",
+    "\u{1FAC5}\u{1F3FF}",
+    "\u{1FAC6}",
+    "\u{1FAC8}",
+    "\u{1FACD}",
+    "\u{1FACE}",
+    "\u{1FACF}",
+    "\u{1FAD0}",
+    "\u{1FAD1}",
+    "\u{1FAD2}",
+    "\u{1FAD3}",
+    "\u{1FAD4}",
+    "\u{1FAD5}",
+    "\u{1FAD6}",
+    "\u{1FAD7}",
+    "\u{1FAD8}",
+    "\u{1FAD9}",
+    "\u{1FADA}",
+    "\u{1FADB}",
+    "\u{1FADC}",
+    "\u{1FADF}",
+    "\u{1FAE0}",
+    "\u{1FAE1}",
+    "\u{1FAE2}",
+    "\u{1FAE3}",
+    "\u{1FAE4}",
+    "\u{1FAE5}",
+    "\u{1FAE6}",
+    "\u{1FAE7}",
+    "\u{1FAE8}",
+    "\u{1FAE9}",
+    "\u{1FAEA}",
+    "\u{1FAEF}",
+    "\u{1FAF0}",
+    "\u{1FAF0}\u{1F3FB}",
+    "\u{1FAF0}\u{1F3FC}",
+    "\u{1FAF0}\u{1F3FD}",
+    "\u{1FAF0}\u{1F3FE}",
+    "\u{1FAF0}\u{1F3FF}",
+    "\u{1FAF1}",
+    "\u{1FAF1}\u{1F3FB}",
+    "\u{1FAF1}\u{1F3FB}\u{200D}\u{1FAF2}\u{1F3FC}",
+    "\u{1FAF1}\u{1F3FB}\u{200D}\u{1FAF2}\u{1F3FD}",
+    "\u{1FAF1}\u{1F3FB}\u{200D}\u{1FAF2}\u{1F3FE}",
+    "\u{1FAF1}\u{1F3FB}\u{200D}\u{1FAF2}\u{1F3FF}",
+    "\u{1FAF1}\u{1F3FC}",
+    "\u{1FAF1}\u{1F3FC}\u{200D}\u{1FAF2}\u{1F3FB}",
+    "\u{1FAF1}\u{1F3FC}\u{200D}\u{1FAF2}\u{1F3FD}",
+    "\u{1FAF1}\u{1F3FC}\u{200D}\u{1FAF2}\u{1F3FE}",
+    "\u{1FAF1}\u{1F3FC}\u{200D}\u{1FAF2}\u{1F3FF}",
+    "\u{1FAF1}\u{1F3FD}",
+    "\u{1FAF1}\u{1F3FD}\u{200D}\u{1FAF2}\u{1F3FB}",
+    "\u{1FAF1}\u{1F3FD}\u{200D}\u{1FAF2}\u{1F3FC}",
+    "\u{1FAF1}\u{1F3FD}\u{200D}\u{1FAF2}\u{1F3FE}",
+    "\u{1FAF1}\u{1F3FD}\u{200D}\u{1FAF2}\u{1F3FF}",
+    "\u{1FAF1}\u{1F3FE}",
+    "\u{1FAF1}\u{1F3FE}\u{200D}\u{1FAF2}\u{1F3FB}",
+    "\u{1FAF1}\u{1F3FE}\u{200D}\u{1FAF2}\u{1F3FC}",
+    "\u{1FAF1}\u{1F3FE}\u{200D}\u{1FAF2}\u{1F3FD}",
+    "\u{1FAF1}\u{1F3FE}\u{200D}\u{1FAF2}\u{1F3FF}",
+    "\u{1FAF1}\u{1F3FF}",
+    "\u{1FAF1}\u{1F3FF}\u{200D}\u{1FAF2}\u{1F3FB}",
+    "\u{1FAF1}\u{1F3FF}\u{200D}\u{1FAF2}\u{1F3FC}",
+    "\u{1FAF1}\u{1F3FF}\u{200D}\u{1FAF2}\u{1F3FD}",
+    "\u{1FAF1}\u{1F3FF}\u{200D}\u{1FAF2}\u{1F3FE}",
+    "\u{1FAF2}",
+    "\u{1FAF2}\u{1F3FB}",
+    "\u{1FAF2}\u{1F3FC}",
+    "\u{1FAF2}\u{1F3FD}",
+    "\u{1FAF2}\u{1F3FE}",
+    "\u{1FAF2}\u{1F3FF}",
+    "\u{1FAF3}",
+    "\u{1FAF3}\u{1F3FB}",
+    "\u{1FAF3}\u{1F3FC}",
+    "\u{1FAF3}\u{1F3FD}",
+    "\u{1FAF3}\u{1F3FE}",
+    "\u{1FAF3}\u{1F3FF}",
+    "\u{1FAF4}",
+    "\u{1FAF4}\u{1F3FB}",
+    "\u{1FAF4}\u{1F3FC}",
+    "\u{1FAF4}\u{1F3FD}",
+    "\u{1FAF4}\u{1F3FE}",
+    "\u{1FAF4}\u{1F3FF}",
+    "\u{1FAF5}",
+    "\u{1FAF5}\u{1F3FB}",
+    "\u{1FAF5}\u{1F3FC}",
+    "\u{1FAF5}\u{1F3FD}",
+    "\u{1FAF5}\u{1F3FE}",
+    "\u{1FAF5}\u{1F3FF}",
+    "\u{1FAF6}",
+    "\u{1FAF6}\u{1F3FB}",
+    "\u{1FAF6}\u{1F3FC}",
+    "\u{1FAF6}\u{1F3FD}",
+    "\u{1FAF6}\u{1F3FE}",
+    "\u{1FAF6}\u{1F3FF}",
+    "\u{1FAF7}",
+    "\u{1FAF7}\u{1F3FB}",
+    "\u{1FAF7}\u{1F3FC}",
+    "\u{1FAF7}\u{1F3FD}",
+    "\u{1FAF7}\u{1F3FE}",
+    "\u{1FAF7}\u{1F3FF}",
+    "\u{1FAF8}",
+    "\u{1FAF8}\u{1F3FB}",
+    "\u{1FAF8}\u{1F3FC}",
+    "\u{1FAF8}\u{1F3FD}",
+    "\u{1FAF8}\u{1F3FE}",
+    "\u{1FAF8}\u{1F3FF}",
+);
 
+/// The end of each sequence in [`RGI_EMOJI`].
+#[cfg(feature = "rgi-emoji")]
 #[rustfmt::skip]
-pub(crate) static EMOJI_MODIFIER_LEAF_4: [(u8, u8); 4] = [
-    (0x74, 0x75),
-    (0x7A, 0x7A),
-    (0x90, 0x90),
-    (0x95, 0x96),
+pub(crate) static RGI_EMOJI_ENDS: [u16; 3953] = [
+    7, 14, 21, 28, 35, 42, 49, 56, 63, 70,
+    77, 84, 89, 94, 100, 106, 112, 118, 124, 130,
+    136, 142, 148, 154, 160, 166, 169, 172, 178, 184,
+    187, 190, 193, 196, 202, 208, 214, 217, 223, 229,
+    232, 238, 244, 250, 256, 262, 268, 274, 280, 286,
+    292, 295, 298, 304, 310, 316, 322, 328, 334, 340,
+    343, 346, 352, 358, 365, 372, 379, 386, 393, 399,
+    405, 411, 417, 423, 429, 435, 441, 447, 453, 459,
+    465, 468, 471, 474, 477, 480, 483, 486, 489, 492,
+    495, 498, 501, 507, 513, 519, 525, 531, 537, 543,
+    549, 552, 558, 561, 567, 573, 579, 585, 591, 597,
+    603, 609, 612, 618, 621, 624, 630, 636, 639, 642,
+    645, 648, 654, 657, 663, 669, 675, 688, 691, 697,
+    700, 706, 712, 715, 718, 724, 727, 733, 739, 745,
+    760, 775, 782, 798, 814, 821, 837, 853, 860, 876,
+    892, 899, 915, 931, 938, 954, 970, 973, 976, 982,
+    985, 991, 997, 1000, 1007, 1014, 1021, 1028, 1035, 1038,
+    1045, 1052, 1059, 1066, 1073, 1079, 1086, 1093, 1100, 1107,
+    1114, 1120, 1127, 1134, 1141, 1148, 1155, 1161, 1167, 1173,
+    1179, 1185, 1191, 1194, 1200, 1206, 1212, 1218, 1221, 1224,
+    1227, 1230, 1233, 1236, 1242, 1248, 1261, 1274, 1277, 1280,
+    1283, 1289, 1292, 1295, 1301, 1307, 1313, 1319, 1325, 1328,
+    1331, 1334, 1337, 1343, 1349, 1355, 1361, 1365, 1369, 1376,
+    1383, 1390, 1397, 1401, 1405, 1409, 1413, 1417, 1421, 1425,
+    1429, 1433, 1437, 1441, 1449, 1457, 1465, 1473, 1481, 1489,
+    1497, 1505, 1513, 1521, 1529, 1537, 1545, 1553, 1561, 1569,
+    1577, 1585, 1593, 1601, 1609, 1617, 1625, 1633, 1641, 1649,
+    1657, 1665, 1673, 1681, 1689, 1697, 1705, 1713, 1721, 1729,
+    1737, 1745, 1753, 1761, 1769, 1777, 1785, 1793, 1801, 1809,
+    1817, 1825, 1833, 1841, 1849, 1857, 1865, 1873, 1881, 1889,
+    1897, 1905, 1913, 1921, 1929, 1937, 1945, 1953, 1961, 1969,
+    1977, 1985, 1993, 2001, 2009, 2017, 2025, 2033, 2041, 2049,
+    2057, 2065, 2073, 2081, 2089, 2097, 2105, 2113, 2121, 2129,
+    2137, 2145, 2153, 2161, 2169, 2177, 2185, 2193, 2201, 2209,
+    2217, 2225, 2233, 2241, 2249, 2257, 2265, 2273, 2281, 2289,
+    2297, 2305, 2313, 2321, 2329, 2337, 2345, 2353, 2361, 2369,
+    2377, 2385, 2393, 2401, 2409, 2417, 2425, 2433, 2441, 2449,
+    2457, 2465, 2473, 2481, 2489, 2497, 2505, 2513, 2521, 2529,
+    2537, 2545, 2553, 2561, 2569, 2577, 2585, 2593, 2601, 2609,
+    2617, 2625, 2633, 2641, 2649, 2657, 2665, 2673, 2681, 2689,
+    2697, 2705, 2713, 2721, 2729, 2737, 2745, 2753, 2761, 2769,
+    2777, 2785, 2793, 2801, 2809, 2817, 2825, 2833, 2841, 2849,
+    2857, 2865, 2873, 2881, 2889, 2897, 2905, 2913, 2921, 2929,
+    2937, 2945, 2953, 2961, 2969, 2977, 2985, 2993, 3001, 3009,
+    3017, 3025, 3033, 3041, 3049, 3057, 3065, 3073, 3081, 3089,
+    3097, 3105, 3113, 3121, 3129, 3137, 3145, 3153, 3161, 3169,
+    3177, 3185, 3193, 3201, 3209, 3217, 3225, 3233, 3241, 3249,
+    3257, 3265, 3273, 3281, 3289, 3297, 3305, 3313, 3321, 3329,
+    3337, 3345, 3353, 3361, 3369, 3377, 3385, 3393, 3401, 3409,
+    3417, 3425, 3433, 3441, 3449, 3457, 3465, 3473, 3481, 3489,
+    3497, 3505, 3513, 3517, 3524, 3528, 3532, 3536, 3540, 3544,
+    3548, 3552, 3559, 3563, 3567, 3571, 3575, 3579, 3583, 3587,
+    3591, 3595, 3599, 3603, 3607, 3611, 3615, 3619, 3623, 3627,
+    3631, 3635, 3639, 3643, 3647, 3651, 3655, 3659, 3663, 3667,
+    3671, 3675, 3679, 3683, 3687, 3691, 3695, 3699, 3703, 3707,
+    3711, 3718, 3725, 3732, 3739, 3746, 3753, 3760, 3767, 3774,
+    3781, 3785, 3789, 3793, 3797, 3801, 3805, 3809, 3813, 3817,
+    3824, 3828, 3832, 3836, 3840, 3844, 3848, 3852, 3856, 3860,
+    3864, 3868, 3872, 3876, 3880, 3891, 3895, 3899, 3903, 3907,
+    3911, 3915, 3919, 3930, 3934, 3938, 3942, 3946, 3950, 3954,
+    3958, 3962, 3966, 3970, 3974, 3978, 3982, 3986, 3990, 3994,
+    3998, 4002, 4006, 4010, 4014, 4018, 4022, 4026, 4030, 4034,
+    4038, 4042, 4046, 4050, 4054, 4058, 4062, 4066, 4070, 4074,
+    4078, 4082, 4086, 4090, 4094, 4098, 4102, 4106, 4110, 4114,
+    4118, 4122, 4126, 4133, 4137, 4141, 4145, 4149, 4153, 4157,
+    4161, 4165, 4173, 4181, 4189, 4197, 4205, 4209, 4213, 4217,
+    4221, 4225, 4229, 4233, 4237, 4241, 4245, 4249, 4253, 4257,
+    4261, 4268, 4275, 4282, 4289, 4296, 4303, 4310, 4314, 4318,
+    4322, 4326, 4330, 4334, 4338, 4342, 4346, 4350, 4354, 4358,
+    4362, 4366, 4370, 4374, 4378, 4382, 4386, 4390, 4394, 4398,
+    4402, 4406, 4410, 4414, 4418, 4422, 4426, 4430, 4434, 4438,
+    4442, 4446, 4450, 4458, 4466, 4474, 4482, 4490, 4494, 4507,
+    4529, 4542, 4564, 4577, 4585, 4602, 4628, 4645, 4671, 4688,
+    4696, 4713, 4739, 4756, 4782, 4799, 4807, 4824, 4850, 4867,
+    4893, 4910, 4918, 4935, 4961, 4978, 5004, 5021, 5029, 5046,
+    5072, 5089, 5115, 5132, 5136, 5149, 5162, 5170, 5187, 5204,
+    5212, 5229, 5246, 5254, 5271, 5288, 5296, 5313, 5330, 5338,
+    5355, 5372, 5376, 5380, 5384, 5392, 5400, 5408, 5416, 5424,
+    5428, 5432, 5436, 5449, 5462, 5470, 5487, 5504, 5512, 5529,
+    5546, 5554, 5571, 5588, 5596, 5613, 5630, 5638, 5655, 5672,
+    5679, 5695, 5711, 5719, 5736, 5753, 5761, 5778, 5795, 5803,
+    5820, 5837, 5845, 5862, 5879, 5887, 5904, 5921, 5928, 5944,
+    5960, 5968, 5985, 6002, 6010, 6027, 6044, 6052, 6069, 6086,
+    6094, 6111, 6128, 6136, 6153, 6170, 6177, 6184, 6188, 6192,
+    6196, 6200, 6204, 6211, 6218, 6225, 6232, 6239, 6246, 6253,
+    6260, 6267, 6274, 6281, 6288, 6292, 6296, 6300, 6304, 6308,
+    6312, 6316, 6320, 6324, 6328, 6332, 6336, 6340, 6344, 6348,
+    6352, 6356, 6363, 6379, 6393, 6397, 6410, 6438, 6466, 6494,
+    6501, 6508, 6512, 6516, 6520, 6524, 6528, 6532, 6536, 6540,
+    6544, 6548, 6552, 6556, 6560, 6564, 6568, 6572, 6576, 6586,
+    6590, 6594, 6598, 6602, 6606, 6610, 6614, 6618, 6622, 6626,
+    6630, 6634, 6638, 6649, 6653, 6657, 6661, 6665, 6669, 6673,
+    6677, 6681, 6685, 6689, 6693, 6697, 6701, 6705, 6709, 6713,
+    6717, 6727, 6738, 6742, 6746, 6750, 6754, 6758, 6762, 6766,
+    6770, 6774, 6778, 6782, 6786, 6790, 6794, 6798, 6802, 6806,
+    6810, 6814, 6818, 6822, 6835, 6839, 6843, 6847, 6854, 6858,
+    6865, 6882, 6886, 6894, 6902, 6910, 6918, 6926, 6930, 6938,
+    6946, 6954, 6962, 6970, 6974, 6978, 6982, 6990, 6998, 7006,
+    7014, 7022, 7026, 7034, 7042, 7050, 7058, 7066, 7070, 7078,
+    7086, 7094, 7102, 7110, 7114, 7122, 7130, 7138, 7146, 7154,
+    7158, 7166, 7174, 7182, 7190, 7198, 7202, 7210, 7218, 7226,
+    7234, 7242, 7246, 7254, 7262, 7270, 7278, 7286, 7290, 7298,
+    7306, 7314, 7322, 7330, 7334, 7342, 7350, 7358, 7366, 7374,
+    7378, 7386, 7394, 7402, 7410, 7418, 7422, 7430, 7438, 7446,
+    7454, 7462, 7466, 7470, 7474, 7478, 7482, 7486, 7490, 7494,
+    7498, 7502, 7506, 7510, 7514, 7518, 7522, 7526, 7530, 7534,
+    7538, 7542, 7546, 7550, 7558, 7566, 7574, 7582, 7590, 7594,
+    7602, 7610, 7618, 7626, 7634, 7638, 7651, 7664, 7677, 7697,
+    7724, 7735, 7746, 7757, 7768, 7779, 7790, 7801, 7812, 7823,
+    7841, 7852, 7870, 7888, 7906, 7931, 7949, 7974, 7999, 8017,
+    8042, 8060, 8085, 8110, 8121, 8132, 8143, 8154, 8165, 8176,
+    8187, 8207, 8218, 8229, 8240, 8251, 8262, 8282, 8293, 8313,
+    8321, 8338, 8355, 8372, 8400, 8428, 8456, 8484, 8512, 8547,
+    8582, 8617, 8652, 8687, 8702, 8717, 8732, 8747, 8762, 8777,
+    8792, 8807, 8833, 8859, 8885, 8911, 8926, 8941, 8956, 8971,
+    8986, 9001, 9027, 9053, 9079, 9105, 9120, 9144, 9159, 9174,
+    9189, 9204, 9219, 9243, 9258, 9282, 9308, 9334, 9360, 9386,
+    9394, 9411, 9428, 9445, 9473, 9501, 9529, 9557, 9585, 9620,
+    9655, 9690, 9725, 9760, 9775, 9790, 9805, 9820, 9835, 9850,
+    9865, 9880, 9906, 9932, 9958, 9984, 9999, 10014, 10029, 10044,
+    10059, 10074, 10100, 10126, 10152, 10178, 10193, 10217, 10232, 10247,
+    10262, 10277, 10292, 10316, 10331, 10355, 10381, 10407, 10433, 10459,
+    10467, 10484, 10501, 10518, 10546, 10574, 10602, 10630, 10658, 10693,
+    10728, 10763, 10798, 10833, 10848, 10863, 10878, 10893, 10908, 10923,
+    10938, 10953, 10979, 11005, 11031, 11057, 11072, 11087, 11102, 11117,
+    11132, 11147, 11173, 11199, 11225, 11251, 11266, 11290, 11305, 11320,
+    11335, 11350, 11365, 11389, 11404, 11428, 11454, 11480, 11506, 11532,
+    11540, 11557, 11574, 11591, 11619, 11647, 11675, 11703, 11731, 11766,
+    11801, 11836, 11871, 11906, 11921, 11936, 11951, 11966, 11981, 11996,
+    12011, 12026, 12052, 12078, 12104, 12130, 12145, 12160, 12175, 12190,
+    12205, 12220, 12246, 12272, 12298, 12324, 12339, 12363, 12378, 12393,
+    12408, 12423, 12438, 12462, 12477, 12501, 12527, 12553, 12579, 12605,
+    12613, 12630, 12647, 12664, 12692, 12720, 12748, 12776, 12804, 12839,
+    12874, 12909, 12944, 12979, 12994, 13009, 13024, 13039, 13054, 13069,
+    13084, 13099, 13125, 13151, 13177, 13203, 13218, 13233, 13248, 13263,
+    13278, 13293, 13319, 13345, 13371, 13397, 13412, 13436, 13451, 13466,
+    13481, 13496, 13511, 13535, 13550, 13574, 13600, 13626, 13652, 13678,
+    13682, 13695, 13708, 13721, 13741, 13761, 13788, 13815, 13826, 13837,
+    13848, 13859, 13870, 13881, 13892, 13903, 13914, 13932, 13943, 13961,
+    13979, 13997, 14022, 14040, 14065, 14090, 14101, 14112, 14123, 14134,
+    14145, 14156, 14167, 14187, 14198, 14209, 14220, 14231, 14242, 14262,
+    14273, 14293, 14301, 14318, 14335, 14352, 14380, 14408, 14436, 14464,
+    14492, 14520, 14548, 14576, 14604, 14632, 14667, 14702, 14737, 14772,
+    14807, 14842, 14877, 14912, 14947, 14982, 14997, 15012, 15027, 15042,
+    15057, 15072, 15087, 15102, 15128, 15154, 15180, 15206, 15221, 15236,
+    15251, 15266, 15281, 15296, 15322, 15348, 15374, 15400, 15426, 15452,
+    15478, 15504, 15519, 15543, 15558, 15573, 15588, 15603, 15618, 15642,
+    15657, 15681, 15707, 15733, 15759, 15785, 15793, 15810, 15827, 15844,
+    15872, 15900, 15928, 15956, 15984, 16012, 16040, 16068, 16096, 16124,
+    16159, 16194, 16229, 16264, 16299, 16334, 16369, 16404, 16439, 16474,
+    16489, 16504, 16519, 16534, 16549, 16564, 16579, 16594, 16620, 16646,
+    16672, 16698, 16713, 16728, 16743, 16758, 16773, 16788, 16814, 16840,
+    16866, 16892, 16918, 16944, 16970, 16996, 17011, 17035, 17050, 17065,
+    17080, 17095, 17110, 17134, 17149, 17173, 17199, 17225, 17251, 17277,
+    17285, 17302, 17319, 17336, 17364, 17392, 17420, 17448, 17476, 17504,
+    17532, 17560, 17588, 17616, 17651, 17686, 17721, 17756, 17791, 17826,
+    17861, 17896, 17931, 17966, 17981, 17996, 18011, 18026, 18041, 18056,
+    18071, 18086, 18112, 18138, 18164, 18190, 18205, 18220, 18235, 18250,
+    18265, 18280, 18306, 18332, 18358, 18384, 18410, 18436, 18462, 18488,
+    18503, 18527, 18542, 18557, 18572, 18587, 18602, 18626, 18641, 18665,
+    18691, 18717, 18743, 18769, 18777, 18794, 18811, 18828, 18856, 18884,
+    18912, 18940, 18968, 18996, 19024, 19052, 19080, 19108, 19143, 19178,
+    19213, 19248, 19283, 19318, 19353, 19388, 19423, 19458, 19473, 19488,
+    19503, 19518, 19533, 19548, 19563, 19578, 19604, 19630, 19656, 19682,
+    19697, 19712, 19727, 19742, 19757, 19772, 19798, 19824, 19850, 19876,
+    19902, 19928, 19954, 19980, 19995, 20019, 20034, 20049, 20064, 20079,
+    20094, 20118, 20133, 20157, 20183, 20209, 20235, 20261, 20269, 20286,
+    20303, 20320, 20348, 20376, 20404, 20432, 20460, 20488, 20516, 20544,
+    20572, 20600, 20635, 20670, 20705, 20740, 20775, 20810, 20845, 20880,
+    20915, 20950, 20965, 20980, 20995, 21010, 21025, 21040, 21055, 21070,
+    21096, 21122, 21148, 21174, 21189, 21204, 21219, 21234, 21249, 21264,
+    21290, 21316, 21342, 21368, 21394, 21420, 21446, 21472, 21487, 21511,
+    21526, 21541, 21556, 21571, 21586, 21610, 21625, 21649, 21675, 21701,
+    21727, 21753, 21757, 21761, 21769, 21777, 21785, 21793, 21801, 21805,
+    21813, 21821, 21829, 21837, 21845, 21849, 21857, 21865, 21873, 21881,
+    21889, 21893, 21906, 21919, 21927, 21944, 21961, 21969, 21986, 22003,
+    22011, 22028, 22045, 22053, 22070, 22087, 22095, 22112, 22129, 22133,
+    22146, 22159, 22167, 22184, 22201, 22209, 22226, 22243, 22251, 22268,
+    22285, 22293, 22310, 22327, 22335, 22352, 22369, 22373, 22386, 22399,
+    22407, 22424, 22441, 22449, 22466, 22483, 22491, 22508, 22525, 22533,
+    22550, 22567, 22575, 22592, 22609, 22613, 22626, 22639, 22647, 22664,
+    22681, 22689, 22706, 22723, 22731, 22748, 22765, 22773, 22790, 22807,
+    22815, 22832, 22849, 22853, 22861, 22869, 22877, 22885, 22893, 22897,
+    22910, 22923, 22931, 22948, 22965, 22973, 22990, 23007, 23015, 23032,
+    23049, 23057, 23074, 23091, 23099, 23116, 23133, 23137, 23145, 23153,
+    23161, 23169, 23177, 23181, 23189, 23197, 23205, 23213, 23221, 23225,
+    23233, 23241, 23249, 23257, 23265, 23269, 23282, 23295, 23303, 23320,
+    23337, 23345, 23362, 23379, 23387, 23404, 23421, 23429, 23446, 23463,
+    23471, 23488, 23505, 23509, 23517, 23525, 23533, 23541, 23549, 23553,
+    23557, 23561, 23565, 23573, 23581, 23589, 23597, 23605, 23609, 23613,
+    23617, 23621, 23625, 23638, 23651, 23659, 23676, 23693, 23701, 23718,
+    23735, 23743, 23760, 23777, 23785, 23802, 23819, 23827, 23844, 23861,
+    23865, 23878, 23891, 23899, 23916, 23933, 23941, 23958, 23975, 23983,
+    24000, 24017, 24025, 24042, 24059, 24067, 24084, 24101, 24105, 24113,
+    24121, 24129, 24137, 24145, 24149, 24153, 24161, 24169, 24177, 24185,
+    24193, 24197, 24210, 24223, 24231, 24248, 24265, 24273, 24290, 24307,
+    24315, 24332, 24349, 24357, 24374, 24391, 24399, 24416, 24433, 24437,
+    24450, 24463, 24471, 24488, 24505, 24513, 24530, 24547, 24555, 24572,
+    24589, 24597, 24614, 24631, 24639, 24656, 24673, 24677, 24681, 24685,
+    24689, 24693, 24697, 24701, 24705, 24713, 24721, 24729, 24737, 24745,
+    24749, 24753, 24761, 24769, 24777, 24785, 24793, 24797, 24801, 24805,
+    24809, 24813, 24817, 24821, 24825, 24829, 24833, 24837, 24841, 24845,
+    24849, 24853, 24857, 24861, 24865, 24869, 24873, 24877, 24881, 24885,
+    24889, 24893, 24901, 24909, 24917, 24925, 24933, 24937, 24941, 24945,
+    24949, 24953, 24957, 24961, 24965, 24969, 24973, 24977, 24981, 24985,
+    24989, 24993, 24997, 25001, 25005, 25009, 25013, 25017, 25021, 25025,
+    25029, 25033, 25037, 25041, 25045, 25049, 25053, 25057, 25061, 25065,
+    25069, 25073, 25077, 25081, 25085, 25089, 25093, 25097, 25101, 25105,
+    25109, 25113, 25117, 25121, 25125, 25129, 25133, 25137, 25141, 25145,
+    25149, 25153, 25157, 25161, 25165, 25169, 25173, 25177, 25181, 25185,
+    25189, 25193, 25197, 25201, 25205, 25209, 25213, 25217, 25221, 25225,
+    25229, 25233, 25237, 25241, 25245, 25249, 25253, 25257, 25261, 25268,
+    25272, 25276, 25280, 25284, 25288, 25292, 25296, 25300, 25304, 25308,
+    25312, 25316, 25320, 25324, 25328, 25332, 25336, 25340, 25344, 25348,
+    25352, 25356, 25360, 25364, 25368, 25372, 25376, 25380, 25384, 25388,
+    25392, 25396, 25400, 25404, 25408, 25412, 25416, 25420, 25424, 25428,
+    25432, 25436, 25440, 25444, 25448, 25452, 25456, 25460, 25464, 25468,
+    25472, 25476, 25480, 25484, 25488, 25492, 25496, 25500, 25504, 25508,
+    25512, 25516, 25520, 25527, 25534, 25538, 25542, 25546, 25550, 25554,
+    25558, 25562, 25566, 25570, 25574, 25578, 25582, 25586, 25590, 25594,
+    25598, 25602, 25606, 25610, 25614, 25618, 25622, 25626, 25630, 25634,
+    25638, 25642, 25646, 25653, 25660, 25667, 25674, 25682, 25690, 25698,
+    25706, 25714, 25721, 25737, 25753, 25761, 25778, 25795, 25803, 25820,
+    25837, 25845, 25862, 25879, 25887, 25904, 25921, 25929, 25946, 25963,
+    25970, 25977, 25984, 25991, 25995, 26003, 26011, 26019, 26027, 26035,
+    26042, 26049, 26056, 26063, 26070, 26077, 26085, 26093, 26101, 26109,
+    26117, 26121, 26129, 26137, 26145, 26153, 26161, 26165, 26173, 26181,
+    26189, 26197, 26205, 26209, 26216, 26223, 26230, 26237, 26244, 26251,
+    26258, 26265, 26272, 26279, 26286, 26293, 26300, 26307, 26314, 26321,
+    26328, 26335, 26342, 26349, 26353, 26357, 26361, 26365, 26369, 26373,
+    26377, 26381, 26385, 26389, 26393, 26397, 26401, 26405, 26409, 26413,
+    26417, 26421, 26425, 26429, 26433, 26437, 26441, 26445, 26449, 26453,
+    26457, 26461, 26465, 26469, 26473, 26477, 26481, 26485, 26489, 26493,
+    26497, 26501, 26505, 26509, 26513, 26517, 26521, 26525, 26529, 26533,
+    26537, 26541, 26545, 26549, 26553, 26557, 26568, 26572, 26576, 26580,
+    26584, 26588, 26592, 26596, 26607, 26611, 26625, 26629, 26633, 26637,
+    26641, 26645, 26649, 26653, 26657, 26661, 26665, 26669, 26673, 26686,
+    26699, 26703, 26707, 26711, 26724, 26737, 26745, 26762, 26779, 26787,
+    26804, 26821, 26829, 26846, 26863, 26871, 26888, 26905, 26913, 26930,
+    26947, 26951, 26964, 26977, 26985, 27002, 27019, 27027, 27044, 27061,
+    27069, 27086, 27103, 27111, 27128, 27145, 27153, 27170, 27187, 27191,
+    27204, 27217, 27225, 27242, 27259, 27267, 27284, 27301, 27309, 27326,
+    27343, 27351, 27368, 27385, 27393, 27410, 27427, 27431, 27435, 27439,
+    27443, 27456, 27469, 27477, 27494, 27511, 27519, 27536, 27553, 27561,
+    27578, 27595, 27603, 27620, 27637, 27645, 27662, 27679, 27683, 27691,
+    27699, 27707, 27715, 27723, 27727, 27740, 27753, 27761, 27778, 27795,
+    27803, 27820, 27837, 27845, 27862, 27879, 27887, 27904, 27921, 27929,
+    27946, 27963, 27967, 27980, 27993, 28001, 28018, 28035, 28043, 28060,
+    28077, 28085, 28102, 28119, 28127, 28144, 28161, 28169, 28186, 28203,
+    28207, 28215, 28223, 28231, 28239, 28247, 28251, 28255, 28259, 28263,
+    28267, 28271, 28275, 28279, 28283, 28287, 28291, 28295, 28299, 28303,
+    28307, 28311, 28315, 28319, 28323, 28327, 28331, 28335, 28339, 28343,
+    28347, 28351, 28355, 28359, 28363, 28367, 28371, 28375, 28379, 28383,
+    28387, 28391, 28404, 28417, 28425, 28442, 28459, 28467, 28484, 28501,
+    28509, 28526, 28543, 28551, 28568, 28585, 28593, 28610, 28627, 28631,
+    28635, 28639, 28643, 28647, 28651, 28655, 28659, 28663, 28667, 28671,
+    28675, 28679, 28683, 28687, 28691, 28695, 28708, 28721, 28729, 28746,
+    28763, 28771, 28788, 28805, 28813, 28830, 28847, 28855, 28872, 28889,
+    28897, 28914, 28931, 28935, 28948, 28961, 28969, 28986, 29003, 29011,
+    29028, 29045, 29053, 29070, 29087, 29095, 29112, 29129, 29137, 29154,
+    29171, 29175, 29188, 29210, 29223, 29245, 29258, 29266, 29283, 29309,
+    29326, 29352, 29369, 29377, 29394, 29420, 29437, 29463, 29480, 29488,
+    29505, 29531, 29548, 29574, 29591, 29599, 29616, 29642, 29659, 29685,
+    29702, 29710, 29727, 29753, 29770, 29796, 29813, 29817, 29821, 29825,
+    29829, 29833, 29837, 29841, 29845, 29849, 29853, 29861, 29869, 29877,
+    29885, 29893, 29897, 29901, 29905, 29909, 29913, 29920, 29924, 29932,
+    29940, 29948, 29956, 29964, 29971, 29978, 29985, 29989, 29993, 29997,
+    30001, 30005, 30009, 30013, 30017, 30021, 30025, 30029, 30036, 30043,
+    30050, 30057, 30064, 30071, 30078, 30082, 30086, 30093, 30100, 30104,
+    30108, 30112, 30116, 30120, 30124, 30128, 30132, 30136, 30140, 30144,
+    30148, 30152, 30156, 30160, 30164, 30168, 30172, 30176, 30180, 30184,
+    30188, 30192, 30200, 30208, 30216, 30224, 30232, 30236, 30240, 30244,
+    30252, 30260, 30268, 30276, 30284, 30288, 30292, 30296, 30300, 30304,
+    30308, 30312, 30316, 30320, 30328, 30336, 30344, 30352, 30360, 30364,
+    30372, 30380, 30388, 30396, 30404, 30408, 30416, 30424, 30432, 30440,
+    30448, 30452, 30460, 30468, 30476, 30484, 30492, 30496, 30504, 30512,
+    30520, 30528, 30536, 30540, 30548, 30556, 30564, 30572, 30580, 30584,
+    30592, 30600, 30608, 30616, 30624, 30628, 30636, 30644, 30652, 30660,
+    30668, 30672, 30676, 30680, 30684, 30688, 30692, 30696, 30709, 30722,
+    30730, 30747, 30764, 30772, 30789, 30806, 30814, 30831, 30848, 30856,
+    30873, 30890, 30898, 30915, 30932, 30936, 30940, 30944, 30948, 30952,
+    30956, 30960, 30964, 30968, 30972, 30980, 30988, 30996, 31004, 31012,
+    31016, 31024, 31032, 31040, 31048, 31056, 31060, 31068, 31076, 31084,
+    31092, 31100, 31104, 31112, 31120, 31128, 31136, 31144, 31148, 31156,
+    31164, 31172, 31180, 31188, 31192, 31205, 31218, 31226, 31243, 31260,
+    31268, 31285, 31302, 31310, 31327, 31344, 31352, 31369, 31386, 31394,
+    31411, 31428, 31432, 31440, 31448, 31456, 31464, 31472, 31476, 31489,
+    31502, 31510, 31527, 31544, 31552, 31569, 31586, 31594, 31611, 31628,
+    31636, 31653, 31670, 31678, 31695, 31712, 31716, 31729, 31742, 31750,
+    31767, 31784, 31792, 31809, 31826, 31834, 31851, 31868, 31876, 31893,
+    31910, 31918, 31935, 31952, 31956, 31969, 31982, 31990, 32007, 32024,
+    32032, 32049, 32066, 32074, 32091, 32108, 32116, 32133, 32150, 32158,
+    32175, 32192, 32196, 32200, 32213, 32226, 32234, 32251, 32268, 32276,
+    32293, 32310, 32318, 32335, 32352, 32360, 32377, 32394, 32402, 32419,
+    32436, 32440, 32453, 32466, 32474, 32491, 32508, 32516, 32533, 32550,
+    32558, 32575, 32592, 32600, 32617, 32634, 32642, 32659, 32676, 32680,
+    32693, 32706, 32714, 32731, 32748, 32756, 32773, 32790, 32798, 32815,
+    32832, 32840, 32857, 32874, 32882, 32899, 32916, 32920, 32924, 32928,
+    32932, 32936, 32940, 32944, 32948, 32952, 32956, 32960, 32964, 32968,
+    32972, 32976, 32980, 32984, 32988, 32992, 32996, 33000, 33004, 33008,
+    33012, 33016, 33020, 33024, 33028, 33032, 33036, 33040, 33044, 33048,
+    33052, 33056, 33060, 33064, 33068, 33072, 33076, 33080, 33084, 33088,
+    33092, 33096, 33100, 33104, 33108, 33112, 33116, 33120, 33124, 33128,
+    33132, 33136, 33140, 33148, 33156, 33164, 33172, 33180, 33184, 33188,
+    33192, 33196, 33200, 33204, 33208, 33212, 33216, 33220, 33224, 33228,
+    33232, 33236, 33240, 33244, 33248, 33252, 33256, 33260, 33264, 33268,
+    33272, 33276, 33280, 33284, 33288, 33292, 33296, 33300, 33304, 33308,
+    33312, 33316, 33320, 33324, 33328, 33332, 33336, 33340, 33344, 33348,
+    33352, 33356, 33360, 33364, 33368, 33372, 33376, 33380, 33384, 33388,
+    33392, 33396, 33400, 33404, 33408, 33412, 33416, 33420, 33424, 33428,
+    33436, 33444, 33452, 33460, 33468, 33472, 33480, 33488, 33496, 33504,
+    33512, 33516, 33520, 33533, 33546, 33554, 33571, 33588, 33596, 33613,
+    33630, 33638, 33655, 33672, 33680, 33697, 33714, 33722, 33739, 33756,
+    33760, 33773, 33786, 33794, 33811, 33828, 33836, 33853, 33870, 33878,
+    33895, 33912, 33920, 33937, 33954, 33962, 33979, 33996, 34000, 34004,
+    34012, 34020, 34028, 34036, 34044, 34048, 34052, 34056, 34060, 34064,
+    34068, 34072, 34076, 34080, 34084, 34088, 34092, 34096, 34100, 34104,
+    34108, 34112, 34116, 34129, 34142, 34150, 34167, 34184, 34192, 34209,
+    34226, 34234, 34251, 34268, 34276, 34293, 34310, 34318, 34335, 34352,
+    34356, 34369, 34391, 34404, 34426, 34439, 34447, 34464, 34490, 34507,
+    34533, 34550, 34558, 34575, 34601, 34618, 34644, 34661, 34669, 34686,
+    34712, 34729, 34755, 34772, 34780, 34797, 34823, 34840, 34866, 34883,
+    34891, 34908, 34934, 34951, 34977, 34994, 34998, 35011, 35024, 35032,
+    35049, 35066, 35074, 35091, 35108, 35116, 35133, 35150, 35158, 35175,
+    35192, 35200, 35217, 35234, 35238, 35242, 35255, 35268, 35281, 35292,
+    35303, 35314, 35325, 35336, 35347, 35358, 35369, 35380, 35391, 35402,
+    35413, 35424, 35435, 35446, 35464, 35475, 35495, 35506, 35517, 35528,
+    35539, 35550, 35570, 35581, 35601, 35619, 35644, 35655, 35673, 35684,
+    35692, 35709, 35726, 35743, 35778, 35813, 35848, 35883, 35911, 35939,
+    35967, 35995, 36010, 36025, 36040, 36055, 36070, 36085, 36100, 36115,
+    36130, 36156, 36182, 36208, 36234, 36249, 36264, 36279, 36294, 36309,
+    36324, 36350, 36376, 36402, 36428, 36454, 36469, 36493, 36508, 36523,
+    36538, 36553, 36568, 36592, 36607, 36631, 36646, 36672, 36698, 36724,
+    36750, 36758, 36775, 36792, 36809, 36844, 36879, 36914, 36949, 36977,
+    37005, 37033, 37061, 37076, 37091, 37106, 37121, 37136, 37151, 37166,
+    37181, 37196, 37222, 37248, 37274, 37300, 37315, 37330, 37345, 37360,
+    37375, 37390, 37416, 37442, 37468, 37494, 37520, 37535, 37559, 37574,
+    37589, 37604, 37619, 37634, 37658, 37673, 37697, 37712, 37738, 37764,
+    37790, 37816, 37824, 37841, 37858, 37875, 37910, 37945, 37980, 38015,
+    38043, 38071, 38099, 38127, 38142, 38157, 38172, 38187, 38202, 38217,
+    38232, 38247, 38262, 38288, 38314, 38340, 38366, 38381, 38396, 38411,
+    38426, 38441, 38456, 38482, 38508, 38534, 38560, 38586, 38601, 38625,
+    38640, 38655, 38670, 38685, 38700, 38724, 38739, 38763, 38778, 38804,
+    38830, 38856, 38882, 38890, 38907, 38924, 38941, 38976, 39011, 39046,
+    39081, 39109, 39137, 39165, 39193, 39208, 39223, 39238, 39253, 39268,
+    39283, 39298, 39313, 39328, 39354, 39380, 39406, 39432, 39447, 39462,
+    39477, 39492, 39507, 39522, 39548, 39574, 39600, 39626, 39652, 39667,
+    39691, 39706, 39721, 39736, 39751, 39766, 39790, 39805, 39829, 39844,
+    39870, 39896, 39922, 39948, 39956, 39973, 39990, 40007, 40042, 40077,
+    40112, 40147, 40175, 40203, 40231, 40259, 40274, 40289, 40304, 40319,
+    40334, 40349, 40364, 40379, 40394, 40420, 40446, 40472, 40498, 40513,
+    40528, 40543, 40558, 40573, 40588, 40614, 40640, 40666, 40692, 40718,
+    40733, 40757, 40772, 40787, 40802, 40817, 40832, 40856, 40871, 40895,
+    40910, 40936, 40962, 40988, 41014, 41018, 41026, 41034, 41042, 41050,
+    41058, 41062, 41070, 41078, 41086, 41094, 41102, 41106, 41119, 41132,
+    41140, 41157, 41174, 41182, 41199, 41216, 41224, 41241, 41258, 41266,
+    41283, 41300, 41308, 41325, 41342, 41346, 41354, 41362, 41370, 41378,
+    41386, 41390, 41403, 41416, 41424, 41441, 41458, 41466, 41483, 41500,
+    41508, 41525, 41542, 41550, 41567, 41584, 41592, 41609, 41626, 41630,
+    41643, 41656, 41664, 41681, 41698, 41706, 41723, 41740, 41748, 41765,
+    41782, 41790, 41807, 41824, 41832, 41849, 41866, 41870, 41883, 41896,
+    41904, 41921, 41938, 41946, 41963, 41980, 41988, 42005, 42022, 42030,
+    42047, 42064, 42072, 42089, 42106, 42110, 42123, 42136, 42144, 42161,
+    42178, 42186, 42203, 42220, 42228, 42245, 42262, 42270, 42287, 42304,
+    42312, 42329, 42346, 42350, 42363, 42376, 42384, 42401, 42418, 42426,
+    42443, 42460, 42468, 42485, 42502, 42510, 42527, 42544, 42552, 42569,
+    42586, 42590, 42603, 42616, 42624, 42641, 42658, 42666, 42683, 42700,
+    42708, 42725, 42742, 42750, 42767, 42784, 42792, 42809, 42826, 42830,
+    42843, 42856, 42864, 42881, 42898, 42906, 42923, 42940, 42948, 42965,
+    42982, 42990, 43007, 43024, 43032, 43049, 43066, 43070, 43083, 43096,
+    43104, 43121, 43138, 43146, 43163, 43180, 43188, 43205, 43222, 43230,
+    43247, 43264, 43272, 43289, 43306, 43310, 43323, 43336, 43340, 43353,
+    43366, 43370, 43374, 43378, 43382, 43386, 43390, 43394, 43398, 43402,
+    43406, 43410, 43414, 43418, 43422, 43426, 43430, 43434, 43438, 43442,
+    43446, 43450, 43454, 43458, 43462, 43466, 43470, 43474, 43478, 43482,
+    43486, 43490, 43494, 43498, 43502, 43506, 43510, 43514, 43518, 43522,
+    43526, 43530, 43534, 43538, 43542, 43546, 43550, 43554, 43558, 43562,
+    43566, 43570, 43574, 43578, 43582, 43586, 43590, 43594, 43598, 43602,
+    43606, 43610, 43614, 43618, 43622, 43626, 43630, 43634, 43638, 43642,
+    43646, 43650, 43654, 43658, 43662, 43666, 43670, 43674, 43678, 43682,
+    43686, 43690, 43694, 43698, 43702, 43706, 43710, 43714, 43718, 43722,
+    43726, 43730, 43734, 43738, 43742, 43746, 43750, 43754, 43758, 43762,
+    43766, 43770, 43774, 43778, 43782, 43786, 43790, 43794, 43798, 43802,
+    43806, 43814, 43822, 43830, 43838, 43846, 43850, 43858, 43866, 43874,
+    43882, 43890, 43894, 43902, 43910, 43918, 43926, 43934, 43938, 43942,
+    43946, 43950, 43954, 43958, 43962, 43966, 43970, 43974, 43978, 43982,
+    43986, 43990, 43994, 43998, 44002, 44006, 44010, 44014, 44018, 44022,
+    44026, 44030, 44034, 44038, 44042, 44046, 44050, 44054, 44058, 44062,
+    44070, 44078, 44086, 44094, 44102, 44106, 44114, 44133, 44152, 44171,
+    44190, 44198, 44217, 44236, 44255, 44274, 44282, 44301, 44320, 44339,
+    44358, 44366, 44385, 44404, 44423, 44442, 44450, 44469, 44488, 44507,
+    44526, 44530, 44538, 44546, 44554, 44562, 44570, 44574, 44582, 44590,
+    44598, 44606, 44614, 44618, 44626, 44634, 44642, 44650, 44658, 44662,
+    44670, 44678, 44686, 44694, 44702, 44706, 44714, 44722, 44730, 44738,
+    44746, 44750, 44758, 44766, 44774, 44782, 44790, 44794, 44802, 44810,
+    44818, 44826, 44834,
 ];
 
-#[rustfmt::skip]
-pub(crate) static EMOJI_MODIFIER_LEAF_5: [(u8, u8); 6] = [
-    (0x45, 0x47),
-    (0x4B, 0x4F),
-    (0xA3, 0xA3),
-    (0xB4, 0xB6),
-    (0xC0, 0xC0),
-    (0xCC, 0xCC),
+/// The Powerline symbols, and the Powerline Extra symbols around them,
+/// where Nerd Fonts 3.4 places them.
+pub(crate) const POWERLINE_TABLE: [(char, char, u8); 4] = [
+    // Powerline and Powerline Extra
+    ('\u{E0A0}', '\u{E0A3}', 1),
+    ('\u{E0B0}', '\u{E0C8}', 1),
+    ('\u{E0CA}', '\u{E0CA}', 1),
+    ('\u{E0CC}', '\u{E0D7}', 1),
 ];
 
-#[rustfmt::skip]
-pub(crate) static EMOJI_MODIFIER_LEAF_6: [(u8, u8); 12] = [
-    (0x0C, 0x0C),
-    (0x0F, 0x0F),
-    (0x18, 0x1F),
-    (0x26, 0x26),
-    (0x30, 0x39),
-    (0x3C, 0x3E),
-    (0x77, 0x77),
-    (0xB5, 0xB6),
-    (0xB8, 0xB9),
-    (0xBB, 0xBB),
-    (0xCD, 0xCF),
-    (0xD1, 0xDD),
+/// The glyph sets of Nerd Fonts 3.4 in the Private Use Areas, one column wide.
+pub(crate) const NERD_FONTS_MONO_TABLE: [(char, char, u8); 14] = [
+    // Pomicons
+    ('\u{E000}', '\u{E00A}', 1),
+    // Powerline and Powerline Extra
+    ('\u{E0A0}', '\u{E0A3}', 1),
+    ('\u{E0B0}', '\u{E0C8}', 1),
+    ('\u{E0CA}', '\u{E0CA}', 1),
+    ('\u{E0CC}', '\u{E0D7}', 1),
+    // Font Awesome Extension
+    ('\u{E200}', '\u{E2A9}', 1),
+    // Weather Icons
+    ('\u{E300}', '\u{E3E3}', 1),
+    // Seti-UI and Custom
+    ('\u{E5FA}', '\u{E6B7}', 1),
+    // Devicons
+    ('\u{E700}', '\u{E8EF}', 1),
+    // Codicons
+    ('\u{EA60}', '\u{EC1E}', 1),
+    // Font Awesome
+    ('\u{ED00}', '\u{F2FF}', 1),
+    // Font Logos
+    ('\u{F300}', '\u{F381}', 1),
+    // Octicons
+    ('\u{F400}', '\u{F533}', 1),
+    // Material Design Icons
+    ('\u{F0001}', '\u{F1AF0}', 1),
 ];
 
-#[rustfmt::skip]
-pub(crate) static EMOJI_MODIFIER_LEAF_7: [(u8, u8); 2] = [
-    (0xC3, 0xC5),
-    (0xF0, 0xF8),
+/// Like [`NERD_FONTS_MONO_TABLE`], with the icons two columns wide.
+pub(crate) const NERD_FONTS_TABLE: [(char, char, u8); 14] = [
+    // Pomicons
+    ('\u{E000}', '\u{E00A}', 2),
+    // Powerline and Powerline Extra
+    ('\u{E0A0}', '\u{E0A3}', 1),
+    ('\u{E0B0}', '\u{E0C8}', 1),
+    ('\u{E0CA}', '\u{E0CA}', 1),
+    ('\u{E0CC}', '\u{E0D7}', 1),
+    // Font Awesome Extension
+    ('\u{E200}', '\u{E2A9}', 2),
+    // Weather Icons
+    ('\u{E300}', '\u{E3E3}', 2),
+    // Seti-UI and Custom
+    ('\u{E5FA}', '\u{E6B7}', 2),
+    // Devicons
+    ('\u{E700}', '\u{E8EF}', 2),
+    // Codicons
+    ('\u{EA60}', '\u{EC1E}', 2),
+    // Font Awesome
+    ('\u{ED00}', '\u{F2FF}', 2),
+    // Font Logos
+    ('\u{F300}', '\u{F381}', 2),
+    // Octicons
+    ('\u{F400}', '\u{F533}', 2),
+    // Material Design Icons
+    ('\u{F0001}', '\u{F1AF0}', 2),
 ];
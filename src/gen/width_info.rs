@@ -15,28 +15,51 @@ impl WidthInfo {
     /// No special handling necessary
     pub(crate) const DEFAULT: Self = Self(0);
     pub(crate) const LINE_FEED: Self = Self(0b0000000000000001);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
     pub(crate) const EMOJI_MODIFIER: Self = Self(0b0000000000000010);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
     pub(crate) const REGIONAL_INDICATOR: Self = Self(0b0000000000000011);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
     pub(crate) const SEVERAL_REGIONAL_INDICATOR: Self = Self(0b0000000000000100);
+    #[cfg(not(feature = "no-emoji-sequences"))]
     pub(crate) const EMOJI_PRESENTATION: Self = Self(0b0000000000000101);
+    #[cfg(not(feature = "no-emoji-sequences"))]
     pub(crate) const ZWJ_EMOJI_PRESENTATION: Self = Self(0b0001000000000110);
+    #[cfg(not(feature = "no-emoji-sequences"))]
     pub(crate) const VS16_ZWJ_EMOJI_PRESENTATION: Self = Self(0b1001000000000110);
+    #[cfg(not(feature = "no-emoji-sequences"))]
     pub(crate) const KEYCAP_ZWJ_EMOJI_PRESENTATION: Self = Self(0b0001000000000111);
+    #[cfg(not(feature = "no-emoji-sequences"))]
     pub(crate) const VS16_KEYCAP_ZWJ_EMOJI_PRESENTATION: Self = Self(0b1001000000000111);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
     pub(crate) const REGIONAL_INDICATOR_ZWJ_PRESENTATION: Self = Self(0b0000000000001001);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
     pub(crate) const EVEN_REGIONAL_INDICATOR_ZWJ_PRESENTATION: Self = Self(0b0000000000001010);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
     pub(crate) const ODD_REGIONAL_INDICATOR_ZWJ_PRESENTATION: Self = Self(0b0000000000001011);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
     pub(crate) const TAG_END_ZWJ_EMOJI_PRESENTATION: Self = Self(0b0000000000010000);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
     pub(crate) const TAG_D1_END_ZWJ_EMOJI_PRESENTATION: Self = Self(0b0000000000010001);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
     pub(crate) const TAG_D2_END_ZWJ_EMOJI_PRESENTATION: Self = Self(0b0000000000010010);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
     pub(crate) const TAG_D3_END_ZWJ_EMOJI_PRESENTATION: Self = Self(0b0000000000010011);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
     pub(crate) const TAG_A1_END_ZWJ_EMOJI_PRESENTATION: Self = Self(0b0000000000011001);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
     pub(crate) const TAG_A2_END_ZWJ_EMOJI_PRESENTATION: Self = Self(0b0000000000011010);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
     pub(crate) const TAG_A3_END_ZWJ_EMOJI_PRESENTATION: Self = Self(0b0000000000011011);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
     pub(crate) const TAG_A4_END_ZWJ_EMOJI_PRESENTATION: Self = Self(0b0000000000011100);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
     pub(crate) const TAG_A5_END_ZWJ_EMOJI_PRESENTATION: Self = Self(0b0000000000011101);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
     pub(crate) const TAG_A6_END_ZWJ_EMOJI_PRESENTATION: Self = Self(0b0000000000011110);
+    #[cfg(not(feature = "bmp-only"))]
     pub(crate) const KIRAT_RAI_VOWEL_SIGN_E: Self = Self(0b0000000000100000);
+    #[cfg(not(feature = "bmp-only"))]
     pub(crate) const KIRAT_RAI_VOWEL_SIGN_AI: Self = Self(0b0000000000100001);
     pub(crate) const VARIATION_SELECTOR_1_2_OR_3: Self = Self(0b0000001000000000);
     pub(crate) const VARIATION_SELECTOR_15: Self = Self(0b0100000000000000);
@@ -53,7 +76,9 @@ impl WidthInfo {
     pub(crate) const ZWJ_TIFINAGH_CONSONANT: Self = Self(0b0011110000000011);
     pub(crate) const TIFINAGH_JOINER_CONSONANT: Self = Self(0b0011110000000100);
     pub(crate) const LISU_TONE_LETTER_MYA_NA_JEU: Self = Self(0b0011110000000101);
+    #[cfg(not(feature = "bmp-only"))]
     pub(crate) const OLD_TURKIC_LETTER_ORKHON_I: Self = Self(0b0011100000000110);
+    #[cfg(not(feature = "bmp-only"))]
     pub(crate) const ZWJ_OLD_TURKIC_LETTER_ORKHON_I: Self = Self(0b0011110000000110);
     pub(crate) const KHMER_COENG_ELIGIBLE_LETTER: Self = Self(0b0011110000000111);
 }
@@ -27,8 +27,8 @@
 //!
 //! # `"cjk"` feature flag
 //!
-//! This crate has one Cargo feature flag, `"cjk"`
-//! (enabled by default).
+//! The `"cjk"` Cargo feature flag is
+//! enabled by default.
 //! It enables the [`UnicodeWidthChar::width_cjk`]
 //! and [`UnicodeWidthStr::width_cjk`],
 //! which perform an alternate width calculation
@@ -42,12 +42,62 @@
 //! use unicode_width::UnicodeWidthStr;
 //!
 //! let teststr = "“𘀀”";
+//! # #[cfg(not(feature = "bmp-only"))]
 //! assert_eq!(teststr.width(), 4);
 //!
 //! #[cfg(feature = "cjk")]
+//! # #[cfg(not(feature = "bmp-only"))]
 //! assert_eq!(teststr.width_cjk(), 6);
 //! ```
 //!
+//! # Table layout feature flags
+//!
+//! By default, widths are looked up in a three-level trie, which balances
+//! lookup speed against the amount of static data (about 16 KB with `"cjk"`).
+//! Two opt-in feature flags select a different layout:
+//!
+//! - `"layout-speed"` uses a two-level table with one byte per character
+//!   in each 256-character block. Lookups are one step shorter, at the cost
+//!   of about 46 KB of static data.
+//! - `"layout-size"` stores the tables as sorted lists of runs that are
+//!   binary searched, for about 6 KB of static data. Lookups are slower,
+//!   and there is no fast path for measuring non-ASCII UTF-8 directly.
+//!
+//! If both flags are enabled, `"layout-speed"` takes precedence.
+//! The choice of layout never affects the computed widths.
+//!
+//! These sizes leave out the roughly 1 KB of emoji tables that every layout shares.
+//! `scripts/unicode.py` prints the exact size of each table of each layout when it
+//! regenerates them, and the benches in `benches/benches.rs` can be run with each
+//! layout flag to compare their speed.
+//!
+//! # Subset feature flags
+//!
+//! Targets that only ever display some scripts can drop parts of the tables
+//! with the following opt-in feature flags. Unlike the layout flags, these
+//! *do* change the computed widths, as documented below.
+//!
+//! - `"bmp-only"` keeps only the tables for the Basic Multilingual Plane,
+//!   shrinking them (to about 10 KB with the default layout, or 4 KB
+//!   with `"layout-size"`). Every supplementary-plane character is given
+//!   the fallback width of its plane: 2 in planes 2 and 3, 0 in plane 14,
+//!   and 1 elsewhere (with `"cjk"`, planes 15 and 16 are 2 as well).
+//!   Sequences that need a supplementary-plane character to be recognized,
+//!   such as emoji modifier sequences, flags, emoji tag sequences, and the
+//!   Kirat Rai and Old Turkic ligatures, are no longer treated specially.
+//! - `"no-emoji-sequences"` drops the handling of emoji and text presentation
+//!   sequences, emoji ZWJ sequences, emoji modifiers, flags, and tags, along with
+//!   their tables. Every character is then measured on its own: U+FE0E and U+FE0F are
+//!   zero-width, `Emoji_Presentation` characters have width 2, and regional indicators have width 1.
+//!
+//! # `"rgi-emoji"` feature flag
+//!
+//! Rule 1 below gives every well-formed emoji ZWJ sequence width 2, though platforms only
+//! draw the ones that are Recommended for General Interchange (RGI) as a single emoji.
+//! The opt-in `"rgi-emoji"` feature flag embeds the RGI set of emoji sequences (about 53 KB)
+//! for `is_rgi_emoji`, and for `width_rgi`, which measures the other ZWJ sequences
+//! as the parts they are drawn as. It doesn't change the other functions of the crate.
+//!
 //! # Rules for determining width
 //!
 //! This crate currently uses the following rules to determine the width of a
@@ -178,12 +228,63 @@
 )]
 #![no_std]
 
+#[cfg(feature = "config")]
+extern crate std;
+
+#[cfg(feature = "cjk")]
+pub use counter::display_width_cjk;
+pub use counter::{display_width, WidthCounter};
+#[cfg(not(feature = "no-emoji-sequences"))]
+pub use emoji::EmojiSupport;
+pub use explain::{explain_char, explain_str, ExplainStr, Rule, WidthReason};
+#[cfg(feature = "cjk")]
+pub use explain::{explain_char_cjk, explain_str_cjk};
+#[cfg(feature = "config")]
+pub use overrides::WidthOverridesBuf;
+pub use overrides::{SequenceWidth, WidthOverrides};
+pub use padded::Padded;
+#[cfg(all(feature = "rgi-emoji", feature = "cjk"))]
+pub use rgi::width_rgi_cjk;
+#[cfg(feature = "rgi-emoji")]
+pub use rgi::{is_rgi_emoji, width_rgi};
 pub use tables::UNICODE_VERSION;
+#[cfg(feature = "cjk")]
+pub use terminal::cells_cjk;
+pub use terminal::{cells, Cell, Cells, TerminalScreen};
 
+#[cfg(feature = "cjk")]
+pub mod ambiguous;
+#[cfg(feature = "config")]
+pub mod config;
+mod counter;
+#[cfg(not(feature = "no-emoji-sequences"))]
+mod emoji;
+mod explain;
+pub mod fallback;
+#[cfg(feature = "layout-speed")]
+#[cfg_attr(not(feature = "bmp-only"), path = "gen/layout_speed.rs")]
+#[cfg_attr(feature = "bmp-only", path = "gen/layout_speed_bmp.rs")]
+mod layout;
+#[cfg(all(feature = "layout-size", not(feature = "layout-speed")))]
+#[cfg_attr(not(feature = "bmp-only"), path = "gen/layout_size.rs")]
+#[cfg_attr(feature = "bmp-only", path = "gen/layout_size_bmp.rs")]
+mod layout;
+#[cfg(not(any(feature = "layout-speed", feature = "layout-size")))]
+#[cfg_attr(not(feature = "bmp-only"), path = "gen/layout_trie.rs")]
+#[cfg_attr(feature = "bmp-only", path = "gen/layout_trie_bmp.rs")]
+mod layout;
 mod lookup;
+mod overrides;
+mod padded;
+pub mod presets;
 mod props;
+#[cfg(feature = "rgi-emoji")]
+mod rgi;
+pub mod table;
 #[path = "gen/tables.rs"]
 mod tables;
+mod terminal;
+mod utf16;
 mod width_info;
 
 #[cfg(test)]
@@ -257,16 +358,88 @@ pub trait UnicodeWidthStr: private::Sealed {
 impl UnicodeWidthStr for str {
     #[inline]
     fn width(&self) -> usize {
-        lookup::str_width(self.chars())
+        lookup::str_slice_width::<false>(self)
     }
 
     #[cfg(feature = "cjk")]
     #[inline]
     fn width_cjk(&self) -> usize {
-        lookup::str_width_cjk(self.chars())
+        lookup::str_slice_width::<true>(self)
     }
 }
 
+/// Returns the sum of the [`UnicodeWidthChar::width`] of each character
+/// in `s`, with control characters counted as zero-width.
+///
+/// Unlike [`UnicodeWidthStr::width`], this ignores all of the rules for
+/// sequences of characters (such as emoji ZWJ sequences, variation selectors,
+/// and script-specific ligatures), as `wcswidth` and many terminals do.
+/// It never consults the state machine those rules need,
+/// so it is also less code to link in.
+///
+/// ```rust
+/// use unicode_width::{width_simple, UnicodeWidthStr};
+///
+/// assert_eq!(width_simple("ｈｉ\n"), 4);
+/// // Each character of the emoji ZWJ sequence is measured on its own.
+/// # #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+/// assert_eq!("👩\u{200D}🔬".width(), 2);
+/// # #[cfg(not(feature = "bmp-only"))]
+/// assert_eq!(width_simple("👩\u{200D}🔬"), 4);
+/// ```
+pub fn width_simple(s: &str) -> usize {
+    lookup::str_simple_width::<false>(s)
+}
+
+/// Like [`width_simple`], but sums [`UnicodeWidthChar::width_cjk`].
+#[cfg(feature = "cjk")]
+pub fn width_simple_cjk(s: &str) -> usize {
+    lookup::str_simple_width::<true>(s)
+}
+
+/// Returns the smallest and largest widths that `s` could be displayed with,
+/// depending on how much of the width rules the terminal showing it follows.
+///
+/// The contexts taken into account are:
+///
+/// - the default and (with the `cjk` feature) the CJK treatment of ambiguous-width characters,
+/// - terminals that ignore variation selectors such as U+FE0F and U+FE0E,
+/// - terminals that show emoji ZWJ sequences as their separate emoji,
+/// - terminals that don't form script-specific ligatures.
+///
+/// The string is bounded a piece at a time, so a single terminal may not reach
+/// either bound, but none of these contexts gives a width outside of them.
+///
+/// ```rust
+/// use unicode_width::width_bounds;
+///
+/// assert_eq!(width_bounds("ｈｉ"), (4, 4));
+/// # #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+/// assert_eq!(width_bounds("👩\u{200D}🔬"), (2, 4));
+/// # #[cfg(not(feature = "no-emoji-sequences"))]
+/// assert_eq!(width_bounds("\u{2764}\u{FE0F}"), (1, 2));
+/// ```
+pub fn width_bounds(s: &str) -> (usize, usize) {
+    lookup::str_width_bounds(s)
+}
+
+/// Returns whether the width of `c`, or of the characters around it, can depend on
+/// the context that [`width_bounds`] takes into account.
+///
+/// This is the case for characters that are ambiguous-width (with the `cjk` feature),
+/// and for those that can take part in a sequence with the characters before them.
+///
+/// ```rust
+/// use unicode_width::is_width_context_dependent;
+///
+/// assert!(!is_width_context_dependent('a'));
+/// assert!(is_width_context_dependent('\u{FE0F}'));
+/// assert!(is_width_context_dependent('\u{200D}'));
+/// ```
+pub fn is_width_context_dependent(c: char) -> bool {
+    lookup::is_context_dependent(c)
+}
+
 /// Like [`UnicodeWidthStr::width`] but for iterators over [`char`].
 pub fn char_iter_width<S: DoubleEndedIterator<Item = char>>(s: S) -> usize {
     lookup::str_width(s)
@@ -277,3 +450,96 @@ pub fn char_iter_width<S: DoubleEndedIterator<Item = char>>(s: S) -> usize {
 pub fn char_iter_width_cjk<S: DoubleEndedIterator<Item = char>>(s: S) -> usize {
     lookup::str_width_cjk(s)
 }
+
+/// Like [`UnicodeWidthChar::width`], but usable in `const` contexts.
+/// Called at runtime, it reads its own copy of the tables, so prefer the method there.
+///
+/// ```rust
+/// const WIDTH: Option<usize> = unicode_width::const_char_width('ｈ');
+/// assert_eq!(WIDTH, Some(2));
+/// ```
+pub const fn const_char_width(c: char) -> Option<usize> {
+    lookup::const_char_width_generic::<false>(c)
+}
+
+/// Like [`UnicodeWidthChar::width_cjk`], but usable in `const` contexts.
+#[cfg(feature = "cjk")]
+pub const fn const_char_width_cjk(c: char) -> Option<usize> {
+    lookup::const_char_width_generic::<true>(c)
+}
+
+/// Like [`UnicodeWidthStr::width`], but usable in `const` contexts,
+/// for example to size a buffer for a fixed layout at compile time.
+/// Called at runtime, it reads its own copy of the tables, so prefer the method there.
+///
+/// ```rust
+/// const HEADER: &str = "名前 | Name";
+/// const COLUMNS: usize = unicode_width::const_str_width(HEADER);
+/// assert_eq!(COLUMNS, 11);
+/// ```
+pub const fn const_str_width(s: &str) -> usize {
+    lookup::const_str_width_generic::<false>(s)
+}
+
+/// Like [`UnicodeWidthStr::width_cjk`], but usable in `const` contexts.
+#[cfg(feature = "cjk")]
+pub const fn const_str_width_cjk(s: &str) -> usize {
+    lookup::const_str_width_generic::<true>(s)
+}
+
+/// Like [`UnicodeWidthStr::width`], but for bytes that may not be valid UTF-8,
+/// such as output read from a terminal or a child process.
+///
+/// Each maximal invalid subpart of `bytes` is measured as one `'\u{FFFD}'`
+/// REPLACEMENT CHARACTER, as [`String::from_utf8_lossy`] would replace it.
+/// If `bytes` ends partway through a sequence that could still be completed,
+/// those bytes are not measured. Instead, their count is returned
+/// alongside the width, so that the caller can carry them over to the next buffer.
+///
+/// ```rust
+/// use unicode_width::width_utf8_lossy;
+///
+/// assert_eq!(width_utf8_lossy(b"ab\xFFcd"), (5, 0));
+/// // The first two bytes of '日' (E6 97 A5) are held back.
+/// assert_eq!(width_utf8_lossy(b"ab\xE6\x97"), (2, 2));
+/// ```
+///
+/// [`String::from_utf8_lossy`]: https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf8_lossy
+pub fn width_utf8_lossy(bytes: &[u8]) -> (usize, usize) {
+    lookup::utf8_lossy_width::<false>(bytes)
+}
+
+/// Like [`width_utf8_lossy`], but measures like [`UnicodeWidthStr::width_cjk`].
+#[cfg(feature = "cjk")]
+pub fn width_utf8_lossy_cjk(bytes: &[u8]) -> (usize, usize) {
+    lookup::utf8_lossy_width::<true>(bytes)
+}
+
+/// Like [`UnicodeWidthStr::width`], but for UTF-16 text,
+/// without converting it to a [`str`] first.
+///
+/// Unpaired surrogates are measured as if they had been replaced with
+/// `'\u{FFFD}'` REPLACEMENT CHARACTER, as when mapping the errors from
+/// [`char::decode_utf16`] to [`char::REPLACEMENT_CHARACTER`].
+/// So an unpaired surrogate has width 1, and does not take part in any sequence.
+///
+/// ```rust
+/// use unicode_width::width_utf16;
+///
+/// let text: Vec<u16> = "ｈｉ👋".encode_utf16().collect();
+/// # #[cfg(not(feature = "bmp-only"))]
+/// assert_eq!(width_utf16(&text), 6);
+/// assert_eq!(width_utf16(&[0x61, 0xD83D, 0x62]), 3);
+/// ```
+pub fn width_utf16(units: &[u16]) -> usize {
+    lookup::str_width(utf16::Utf16Chars(units))
+}
+
+/// Like [`width_utf16`], but measures like [`UnicodeWidthStr::width_cjk`].
+///
+/// The replacement character is ambiguous-width,
+/// so in this case unpaired surrogates have width 2.
+#[cfg(feature = "cjk")]
+pub fn width_utf16_cjk(units: &[u16]) -> usize {
+    lookup::str_width_cjk(utf16::Utf16Chars(units))
+}
@@ -11,21 +11,72 @@
 #[path = "gen/lookup.rs"]
 mod gen;
 
-pub(crate) use gen::lookup_width;
+pub(crate) use gen::TRANSPARENT_ZERO_WIDTH;
 
 #[cfg(feature = "cjk")]
-pub(crate) use gen::lookup_width_cjk;
+pub(crate) use gen::SOLIDUS_TRANSPARENT;
 
+use crate::explain::Rule;
+use crate::layout::{Tables, CONST_TABLES, TABLES};
 use crate::props::*;
 use crate::width_info::WidthInfo;
 
+pub(crate) use crate::layout::lookup_props;
+
+/// How [`width_step_in_generic`] arrived at the width of a character,
+/// relative to the characters that follow it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Step {
+    /// The character was measured on its own, by table lookup.
+    Lookup,
+    /// The character was absorbed into the sequence that follows it,
+    /// without completing any rule.
+    Absorbed,
+    /// The character continued the sequence that follows it,
+    /// according to the given rule.
+    Rule(Rule),
+}
+
+/// Returns the width of `c` from the lookup tables, ignoring ambiguous width.
+#[inline]
+pub(crate) fn lookup_width(c: char) -> (u8, WidthInfo) {
+    gen::lookup_width(&TABLES, c as u32)
+}
+
+/// Returns the width of `c` from the lookup tables, treating ambiguous width as wide.
+#[cfg(feature = "cjk")]
+#[inline]
+pub(crate) fn lookup_width_cjk(c: char) -> (u8, WidthInfo) {
+    gen::lookup_width_cjk(&TABLES, c as u32)
+}
+
+/// Returns whether `c` is narrow, but wide in an East Asian context.
+#[cfg(feature = "cjk")]
+#[inline]
+pub(crate) fn is_ambiguous(c: char) -> bool {
+    is_ambiguous_in(&TABLES, c as u32)
+}
+
+/// Like [`is_ambiguous`], but usable in `const` contexts.
+#[cfg(feature = "cjk")]
+#[inline]
+pub(crate) const fn const_is_ambiguous(c: char) -> bool {
+    is_ambiguous_in(&CONST_TABLES, c as u32)
+}
+
+#[cfg(feature = "cjk")]
+#[inline]
+const fn is_ambiguous_in(tables: &Tables, cp: u32) -> bool {
+    gen::lookup_width(tables, cp).0 == 1 && gen::lookup_width_cjk(tables, cp).0 == 2
+}
+
 #[inline]
-fn lookup_width_generic<const IS_CJK: bool>(c: char) -> (u8, WidthInfo) {
+const fn lookup_width_generic<const IS_CJK: bool>(tables: &Tables, cp: u32) -> (u8, WidthInfo) {
     #[cfg(feature = "cjk")]
     if IS_CJK {
-        return lookup_width_cjk(c);
+        return gen::lookup_width_cjk(tables, cp);
     }
-    lookup_width(c)
+    gen::lookup_width(tables, cp)
 }
 
 /// Returns the [UAX #11](https://www.unicode.org/reports/tr11/) based width of `c`, or
@@ -33,7 +84,7 @@ fn lookup_width_generic<const IS_CJK: bool>(c: char) -> (u8, WidthInfo) {
 /// Ambiguous width characters are treated as narrow.
 #[inline]
 pub fn single_char_width(c: char) -> Option<usize> {
-    single_char_width_generic::<false>(c)
+    single_char_width_generic::<false>(&TABLES, c as u32)
 }
 
 /// Returns the [UAX #11](https://www.unicode.org/reports/tr11/) based width of `c`, or
@@ -42,22 +93,28 @@ pub fn single_char_width(c: char) -> Option<usize> {
 #[cfg(feature = "cjk")]
 #[inline]
 pub fn single_char_width_cjk(c: char) -> Option<usize> {
-    single_char_width_generic::<true>(c)
+    single_char_width_generic::<true>(&TABLES, c as u32)
+}
+
+/// Like [`single_char_width`], but usable in `const` contexts.
+#[inline]
+pub const fn const_char_width_generic<const IS_CJK: bool>(c: char) -> Option<usize> {
+    single_char_width_generic::<IS_CJK>(&CONST_TABLES, c as u32)
 }
 
 #[inline]
-fn single_char_width_generic<const IS_CJK: bool>(c: char) -> Option<usize> {
-    if c < '\u{7F}' {
-        if c >= '\u{20}' {
+const fn single_char_width_generic<const IS_CJK: bool>(tables: &Tables, cp: u32) -> Option<usize> {
+    if cp < 0x7F {
+        if cp >= 0x20 {
             // U+0020 to U+007F (exclusive) are single-width ASCII codepoints
             Some(1)
         } else {
             // U+0000 to U+0020 (exclusive) are control codes
             None
         }
-    } else if c >= '\u{A0}' {
+    } else if cp >= 0xA0 {
         // No characters >= U+00A0 are control codes, so we can consult the lookup tables
-        Some(lookup_width_generic::<IS_CJK>(c).0.into())
+        Some(lookup_width_generic::<IS_CJK>(tables, cp).0 as usize)
     } else {
         // U+007F to U+00A0 (exclusive) are control codes
         None
@@ -82,57 +139,95 @@ pub(crate) fn width_in_str_cjk(c: char, next_info: WidthInfo) -> (i8, WidthInfo)
 #[inline]
 pub(crate) fn width_in_generic<const IS_CJK: bool>(
     c: char,
-    mut next_info: WidthInfo,
+    next_info: WidthInfo,
 ) -> (i8, WidthInfo) {
+    let (width, info, _) = width_step_in_generic::<IS_CJK>(c, next_info);
+    (width, info)
+}
+
+/// Like [`width_in_generic`], but also reports how `c` relates to the
+/// characters after it (see [`Step`]).
+#[inline]
+pub(crate) fn width_step_in_generic<const IS_CJK: bool>(
+    c: char,
+    next_info: WidthInfo,
+) -> (i8, WidthInfo, Step) {
+    width_step::<IS_CJK>(&TABLES, c as u32, next_info)
+}
+
+/// The width state machine behind [`width_step_in_generic`], reading the tables through `tables`.
+/// It works on codepoints rather than `char`s, since a `const fn` can't build a `char`
+/// from a `u32` within the MSRV.
+#[inline]
+const fn width_step<const IS_CJK: bool>(
+    tables: &Tables,
+    cp: u32,
+    mut next_info: WidthInfo,
+) -> (i8, WidthInfo, Step) {
+    #[cfg(not(feature = "no-emoji-sequences"))]
     if next_info.is_emoji_presentation() {
-        if starts_emoji_presentation_seq(c) {
-            let width = if next_info.is_zwj_emoji_presentation() {
-                0
+        if starts_emoji_presentation_seq(tables, cp) {
+            let (width, rule) = if next_info.is_zwj_emoji_presentation() {
+                (0, Rule::EmojiZwjSequence)
             } else {
-                2
+                (2, Rule::EmojiPresentationSequence)
             };
-            return (width, WidthInfo::EMOJI_PRESENTATION);
+            return (width, WidthInfo::EMOJI_PRESENTATION, Step::Rule(rule));
         } else {
             next_info = next_info.unset_emoji_presentation();
         }
     }
 
+    // '<', '=' or '>' under a solidus overlay
     if IS_CJK
         && (matches!(
             next_info,
             WidthInfo::COMBINING_LONG_SOLIDUS_OVERLAY | WidthInfo::SOLIDUS_OVERLAY_ALEF
-        ) && matches!(c, '<' | '=' | '>'))
+        ) && matches!(cp, 0x3C..=0x3E))
     {
-        return (2, WidthInfo::DEFAULT);
+        return (2, WidthInfo::DEFAULT, Step::Rule(Rule::SolidusOverlay));
     }
 
-    if c <= '\u{A0}' {
-        match c {
-            '\n' => (1, WidthInfo::LINE_FEED),
-            '\r' if next_info == WidthInfo::LINE_FEED => (0, WidthInfo::DEFAULT),
-            _ => (1, WidthInfo::DEFAULT),
+    if cp <= 0xA0 {
+        match cp {
+            // '\n'
+            0x0A => (1, WidthInfo::LINE_FEED, Step::Lookup),
+            // '\r'
+            0x0D if matches!(next_info, WidthInfo::LINE_FEED) => (
+                0,
+                WidthInfo::DEFAULT,
+                Step::Rule(Rule::CarriageReturnLineFeed),
+            ),
+            _ => (1, WidthInfo::DEFAULT, Step::Lookup),
         }
     } else {
         // Fast path
-        if next_info != WidthInfo::DEFAULT {
-            if c == '\u{FE0F}' {
-                return (0, next_info.set_emoji_presentation());
+        if !matches!(next_info, WidthInfo::DEFAULT) {
+            #[cfg(not(feature = "no-emoji-sequences"))]
+            if cp == 0xFE0F {
+                return (0, next_info.set_emoji_presentation(), Step::Absorbed);
             }
 
             if IS_CJK {
-                if matches!(c, '\u{FE00}' | '\u{FE02}') {
-                    return (0, next_info.set_vs1_2_3());
+                if matches!(cp, 0xFE00 | 0xFE02) {
+                    return (0, next_info.set_vs1_2_3(), Step::Absorbed);
                 }
             } else {
-                if c == '\u{FE01}' {
-                    return (0, next_info.set_vs1_2_3());
+                if cp == 0xFE01 {
+                    return (0, next_info.set_vs1_2_3(), Step::Absorbed);
                 }
-                if c == '\u{FE0E}' {
-                    return (0, next_info.set_text_presentation());
+                #[cfg(not(feature = "no-emoji-sequences"))]
+                if cp == 0xFE0E {
+                    return (0, next_info.set_text_presentation(), Step::Absorbed);
                 }
+                #[cfg(not(feature = "no-emoji-sequences"))]
                 if next_info.is_text_presentation() {
-                    if starts_non_ideographic_text_presentation_seq(c) {
-                        return (1, WidthInfo::DEFAULT);
+                    if starts_non_ideographic_text_presentation_seq(tables, cp) {
+                        return (
+                            1,
+                            WidthInfo::DEFAULT,
+                            Step::Rule(Rule::TextPresentationSequence),
+                        );
                     } else {
                         next_info = next_info.unset_text_presentation();
                     }
@@ -140,189 +235,319 @@ pub(crate) fn width_in_generic<const IS_CJK: bool>(
             }
 
             if next_info.is_vs1_2_3() {
-                if matches!(c, '\u{2018}' | '\u{2019}' | '\u{201C}' | '\u{201D}') {
-                    return (if IS_CJK { 1 } else { 2 }, WidthInfo::DEFAULT);
+                if matches!(cp, 0x2018 | 0x2019 | 0x201C | 0x201D) {
+                    return (
+                        if IS_CJK { 1 } else { 2 },
+                        WidthInfo::DEFAULT,
+                        Step::Rule(Rule::QuotationMarkVariationSequence),
+                    );
                 } else {
                     next_info = next_info.unset_vs1_2_3();
                 }
             }
             if next_info.is_ligature_transparent() {
-                if c == '\u{200D}' {
-                    return (0, next_info.set_zwj_bit());
-                } else if is_ligature_transparent(c) {
-                    return (0, next_info);
+                if cp == 0x200D {
+                    return (0, next_info.set_zwj_bit(), Step::Absorbed);
+                } else if is_ligature_transparent(cp) {
+                    return (0, next_info, Step::Absorbed);
                 }
             }
 
-            match (next_info, c) {
+            match (next_info, cp) {
                 #[cfg(feature = "cjk")]
                 (WidthInfo::COMBINING_LONG_SOLIDUS_OVERLAY, _)
-                    if IS_CJK && is_solidus_transparent(c) =>
+                    if IS_CJK && is_solidus_transparent(tables, cp) =>
                 {
                     return (
-                        lookup_width_generic::<IS_CJK>(c).0 as i8,
+                        lookup_width_generic::<IS_CJK>(tables, cp).0 as i8,
                         WidthInfo::COMBINING_LONG_SOLIDUS_OVERLAY,
+                        Step::Absorbed,
                     );
                 }
-                (WidthInfo::JOINING_GROUP_ALEF, '\u{0338}') if IS_CJK => {
-                    return (0, WidthInfo::SOLIDUS_OVERLAY_ALEF);
+                (WidthInfo::JOINING_GROUP_ALEF, 0x0338) if IS_CJK => {
+                    return (0, WidthInfo::SOLIDUS_OVERLAY_ALEF, Step::Absorbed);
                 }
                 // Arabic Lam-Alef ligature
-                (WidthInfo::JOINING_GROUP_ALEF, _) if is_joining_group_lam(c) => {
-                    return (0, WidthInfo::DEFAULT)
+                (WidthInfo::JOINING_GROUP_ALEF, _) if is_joining_group_lam(cp) => {
+                    return (0, WidthInfo::DEFAULT, Step::Rule(Rule::ArabicLamAlef))
                 }
-                (WidthInfo::SOLIDUS_OVERLAY_ALEF, _) if IS_CJK && is_joining_group_lam(c) => {
-                    return (0, WidthInfo::DEFAULT)
+                (WidthInfo::SOLIDUS_OVERLAY_ALEF, _) if IS_CJK && is_joining_group_lam(cp) => {
+                    return (0, WidthInfo::DEFAULT, Step::Rule(Rule::ArabicLamAlef))
                 }
-                (WidthInfo::JOINING_GROUP_ALEF, _) if is_transparent_zero_width(c) => {
-                    return (0, WidthInfo::JOINING_GROUP_ALEF);
+                (WidthInfo::JOINING_GROUP_ALEF, _) if is_transparent_zero_width(tables, cp) => {
+                    return (0, WidthInfo::JOINING_GROUP_ALEF, Step::Absorbed);
                 }
 
                 // Hebrew Alef-ZWJ-Lamed ligature
-                (WidthInfo::ZWJ_HEBREW_LETTER_LAMED, '\u{05D0}') => {
-                    return (0, WidthInfo::DEFAULT);
+                (WidthInfo::ZWJ_HEBREW_LETTER_LAMED, 0x05D0) => {
+                    return (0, WidthInfo::DEFAULT, Step::Rule(Rule::HebrewAlefLamed));
                 }
 
                 // Khmer coeng signs
-                (WidthInfo::KHMER_COENG_ELIGIBLE_LETTER, '\u{17D2}') => {
-                    return (-1, WidthInfo::DEFAULT);
+                (WidthInfo::KHMER_COENG_ELIGIBLE_LETTER, 0x17D2) => {
+                    return (-1, WidthInfo::DEFAULT, Step::Rule(Rule::KhmerCoeng));
                 }
 
                 // Buginese <a, -i> ZWJ ya ligature
-                (WidthInfo::ZWJ_BUGINESE_LETTER_YA, '\u{1A17}') => {
-                    return (0, WidthInfo::BUGINESE_VOWEL_SIGN_I_ZWJ_LETTER_YA)
+                (WidthInfo::ZWJ_BUGINESE_LETTER_YA, 0x1A17) => {
+                    return (
+                        0,
+                        WidthInfo::BUGINESE_VOWEL_SIGN_I_ZWJ_LETTER_YA,
+                        Step::Absorbed,
+                    )
                 }
-                (WidthInfo::BUGINESE_VOWEL_SIGN_I_ZWJ_LETTER_YA, '\u{1A15}') => {
-                    return (0, WidthInfo::DEFAULT)
+                (WidthInfo::BUGINESE_VOWEL_SIGN_I_ZWJ_LETTER_YA, 0x1A15) => {
+                    return (0, WidthInfo::DEFAULT, Step::Rule(Rule::BugineseAIYa))
                 }
 
                 // Tifinagh bi-consonants
-                (WidthInfo::TIFINAGH_CONSONANT | WidthInfo::ZWJ_TIFINAGH_CONSONANT, '\u{2D7F}') => {
-                    return (1, WidthInfo::TIFINAGH_JOINER_CONSONANT);
+                (WidthInfo::TIFINAGH_CONSONANT | WidthInfo::ZWJ_TIFINAGH_CONSONANT, 0x2D7F) => {
+                    return (1, WidthInfo::TIFINAGH_JOINER_CONSONANT, Step::Absorbed);
                 }
-                (WidthInfo::ZWJ_TIFINAGH_CONSONANT, '\u{2D31}'..='\u{2D65}' | '\u{2D6F}') => {
-                    return (0, WidthInfo::DEFAULT);
+                (WidthInfo::ZWJ_TIFINAGH_CONSONANT, 0x2D31..=0x2D65 | 0x2D6F) => {
+                    return (0, WidthInfo::DEFAULT, Step::Rule(Rule::TifinaghBiconsonant));
                 }
-                (WidthInfo::TIFINAGH_JOINER_CONSONANT, '\u{2D31}'..='\u{2D65}' | '\u{2D6F}') => {
-                    return (-1, WidthInfo::DEFAULT);
+                (WidthInfo::TIFINAGH_JOINER_CONSONANT, 0x2D31..=0x2D65 | 0x2D6F) => {
+                    return (
+                        -1,
+                        WidthInfo::DEFAULT,
+                        Step::Rule(Rule::TifinaghBiconsonant),
+                    );
                 }
 
                 // Lisu tone letter combinations
-                (WidthInfo::LISU_TONE_LETTER_MYA_NA_JEU, '\u{A4F8}'..='\u{A4FB}') => {
-                    return (0, WidthInfo::DEFAULT);
+                (WidthInfo::LISU_TONE_LETTER_MYA_NA_JEU, 0xA4F8..=0xA4FB) => {
+                    return (0, WidthInfo::DEFAULT, Step::Rule(Rule::LisuToneLetter));
                 }
 
                 // Old Turkic ligature
-                (WidthInfo::ZWJ_OLD_TURKIC_LETTER_ORKHON_I, '\u{10C32}') => {
-                    return (0, WidthInfo::DEFAULT);
+                #[cfg(not(feature = "bmp-only"))]
+                (WidthInfo::ZWJ_OLD_TURKIC_LETTER_ORKHON_I, 0x10C32) => {
+                    return (0, WidthInfo::DEFAULT, Step::Rule(Rule::OldTurkicLigature));
                 }
                 // Emoji modifier
-                (WidthInfo::EMOJI_MODIFIER, _) if is_emoji_modifier_base(c) => {
-                    return (0, WidthInfo::EMOJI_PRESENTATION);
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+                (WidthInfo::EMOJI_MODIFIER, _) if is_emoji_modifier_base(tables, cp) => {
+                    return (
+                        0,
+                        WidthInfo::EMOJI_PRESENTATION,
+                        Step::Rule(Rule::EmojiModifierSequence),
+                    );
                 }
 
                 // Regional indicator
-                (
-                    WidthInfo::REGIONAL_INDICATOR | WidthInfo::SEVERAL_REGIONAL_INDICATOR,
-                    '\u{1F1E6}'..='\u{1F1FF}',
-                ) => return (1, WidthInfo::SEVERAL_REGIONAL_INDICATOR),
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+                (WidthInfo::REGIONAL_INDICATOR, 0x1F1E6..=0x1F1FF) => {
+                    return (
+                        1,
+                        WidthInfo::SEVERAL_REGIONAL_INDICATOR,
+                        Step::Rule(Rule::EmojiFlagSequence),
+                    )
+                }
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+                (WidthInfo::SEVERAL_REGIONAL_INDICATOR, 0x1F1E6..=0x1F1FF) => {
+                    return (1, WidthInfo::SEVERAL_REGIONAL_INDICATOR, Step::Absorbed)
+                }
 
                 // ZWJ emoji
+                #[cfg(not(feature = "no-emoji-sequences"))]
+                (WidthInfo::EMOJI_PRESENTATION, 0x200D) => {
+                    return (0, WidthInfo::ZWJ_EMOJI_PRESENTATION, Step::Absorbed)
+                }
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
                 (
-                    WidthInfo::EMOJI_PRESENTATION
-                    | WidthInfo::SEVERAL_REGIONAL_INDICATOR
+                    WidthInfo::SEVERAL_REGIONAL_INDICATOR
                     | WidthInfo::EVEN_REGIONAL_INDICATOR_ZWJ_PRESENTATION
                     | WidthInfo::ODD_REGIONAL_INDICATOR_ZWJ_PRESENTATION
                     | WidthInfo::EMOJI_MODIFIER,
-                    '\u{200D}',
-                ) => return (0, WidthInfo::ZWJ_EMOJI_PRESENTATION),
-                (WidthInfo::ZWJ_EMOJI_PRESENTATION, '\u{20E3}') => {
-                    return (0, WidthInfo::KEYCAP_ZWJ_EMOJI_PRESENTATION);
-                }
-                (WidthInfo::VS16_ZWJ_EMOJI_PRESENTATION, _) if starts_emoji_presentation_seq(c) => {
-                    return (0, WidthInfo::EMOJI_PRESENTATION)
-                }
-                (WidthInfo::VS16_KEYCAP_ZWJ_EMOJI_PRESENTATION, '0'..='9' | '#' | '*') => {
-                    return (0, WidthInfo::EMOJI_PRESENTATION)
+                    0x200D,
+                ) => return (0, WidthInfo::ZWJ_EMOJI_PRESENTATION, Step::Absorbed),
+                #[cfg(not(feature = "no-emoji-sequences"))]
+                (WidthInfo::ZWJ_EMOJI_PRESENTATION, 0x20E3) => {
+                    return (0, WidthInfo::KEYCAP_ZWJ_EMOJI_PRESENTATION, Step::Absorbed);
+                }
+                #[cfg(not(feature = "no-emoji-sequences"))]
+                (WidthInfo::VS16_ZWJ_EMOJI_PRESENTATION, _)
+                    if starts_emoji_presentation_seq(tables, cp) =>
+                {
+                    return (
+                        0,
+                        WidthInfo::EMOJI_PRESENTATION,
+                        Step::Rule(Rule::EmojiZwjSequence),
+                    )
+                }
+                // '0'..='9', '#' or '*'
+                #[cfg(not(feature = "no-emoji-sequences"))]
+                (WidthInfo::VS16_KEYCAP_ZWJ_EMOJI_PRESENTATION, 0x30..=0x39 | 0x23 | 0x2A) => {
+                    return (
+                        0,
+                        WidthInfo::EMOJI_PRESENTATION,
+                        Step::Rule(Rule::EmojiZwjSequence),
+                    )
                 }
-                (WidthInfo::ZWJ_EMOJI_PRESENTATION, '\u{1F1E6}'..='\u{1F1FF}') => {
-                    return (1, WidthInfo::REGIONAL_INDICATOR_ZWJ_PRESENTATION);
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+                (WidthInfo::ZWJ_EMOJI_PRESENTATION, 0x1F1E6..=0x1F1FF) => {
+                    return (
+                        1,
+                        WidthInfo::REGIONAL_INDICATOR_ZWJ_PRESENTATION,
+                        Step::Absorbed,
+                    );
                 }
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
                 (
                     WidthInfo::REGIONAL_INDICATOR_ZWJ_PRESENTATION
                     | WidthInfo::ODD_REGIONAL_INDICATOR_ZWJ_PRESENTATION,
-                    '\u{1F1E6}'..='\u{1F1FF}',
-                ) => return (-1, WidthInfo::EVEN_REGIONAL_INDICATOR_ZWJ_PRESENTATION),
-                (
-                    WidthInfo::EVEN_REGIONAL_INDICATOR_ZWJ_PRESENTATION,
-                    '\u{1F1E6}'..='\u{1F1FF}',
-                ) => return (3, WidthInfo::ODD_REGIONAL_INDICATOR_ZWJ_PRESENTATION),
-                (WidthInfo::ZWJ_EMOJI_PRESENTATION, '\u{1F3FB}'..='\u{1F3FF}') => {
-                    return (0, WidthInfo::EMOJI_MODIFIER);
+                    0x1F1E6..=0x1F1FF,
+                ) => {
+                    return (
+                        -1,
+                        WidthInfo::EVEN_REGIONAL_INDICATOR_ZWJ_PRESENTATION,
+                        Step::Rule(Rule::EmojiZwjSequence),
+                    )
+                }
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+                (WidthInfo::EVEN_REGIONAL_INDICATOR_ZWJ_PRESENTATION, 0x1F1E6..=0x1F1FF) => {
+                    return (
+                        3,
+                        WidthInfo::ODD_REGIONAL_INDICATOR_ZWJ_PRESENTATION,
+                        Step::Absorbed,
+                    )
                 }
-                (WidthInfo::ZWJ_EMOJI_PRESENTATION, '\u{E007F}') => {
-                    return (0, WidthInfo::TAG_END_ZWJ_EMOJI_PRESENTATION);
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+                (WidthInfo::ZWJ_EMOJI_PRESENTATION, 0x1F3FB..=0x1F3FF) => {
+                    return (0, WidthInfo::EMOJI_MODIFIER, Step::Absorbed);
                 }
-                (WidthInfo::TAG_END_ZWJ_EMOJI_PRESENTATION, '\u{E0061}'..='\u{E007A}') => {
-                    return (0, WidthInfo::TAG_A1_END_ZWJ_EMOJI_PRESENTATION);
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+                (WidthInfo::ZWJ_EMOJI_PRESENTATION, 0xE007F) => {
+                    return (0, WidthInfo::TAG_END_ZWJ_EMOJI_PRESENTATION, Step::Absorbed);
                 }
-                (WidthInfo::TAG_A1_END_ZWJ_EMOJI_PRESENTATION, '\u{E0061}'..='\u{E007A}') => {
-                    return (0, WidthInfo::TAG_A2_END_ZWJ_EMOJI_PRESENTATION)
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+                (WidthInfo::TAG_END_ZWJ_EMOJI_PRESENTATION, 0xE0061..=0xE007A) => {
+                    return (
+                        0,
+                        WidthInfo::TAG_A1_END_ZWJ_EMOJI_PRESENTATION,
+                        Step::Absorbed,
+                    );
                 }
-                (WidthInfo::TAG_A2_END_ZWJ_EMOJI_PRESENTATION, '\u{E0061}'..='\u{E007A}') => {
-                    return (0, WidthInfo::TAG_A3_END_ZWJ_EMOJI_PRESENTATION)
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+                (WidthInfo::TAG_A1_END_ZWJ_EMOJI_PRESENTATION, 0xE0061..=0xE007A) => {
+                    return (
+                        0,
+                        WidthInfo::TAG_A2_END_ZWJ_EMOJI_PRESENTATION,
+                        Step::Absorbed,
+                    )
+                }
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+                (WidthInfo::TAG_A2_END_ZWJ_EMOJI_PRESENTATION, 0xE0061..=0xE007A) => {
+                    return (
+                        0,
+                        WidthInfo::TAG_A3_END_ZWJ_EMOJI_PRESENTATION,
+                        Step::Absorbed,
+                    )
                 }
-                (WidthInfo::TAG_A3_END_ZWJ_EMOJI_PRESENTATION, '\u{E0061}'..='\u{E007A}') => {
-                    return (0, WidthInfo::TAG_A4_END_ZWJ_EMOJI_PRESENTATION)
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+                (WidthInfo::TAG_A3_END_ZWJ_EMOJI_PRESENTATION, 0xE0061..=0xE007A) => {
+                    return (
+                        0,
+                        WidthInfo::TAG_A4_END_ZWJ_EMOJI_PRESENTATION,
+                        Step::Absorbed,
+                    )
                 }
-                (WidthInfo::TAG_A4_END_ZWJ_EMOJI_PRESENTATION, '\u{E0061}'..='\u{E007A}') => {
-                    return (0, WidthInfo::TAG_A5_END_ZWJ_EMOJI_PRESENTATION)
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+                (WidthInfo::TAG_A4_END_ZWJ_EMOJI_PRESENTATION, 0xE0061..=0xE007A) => {
+                    return (
+                        0,
+                        WidthInfo::TAG_A5_END_ZWJ_EMOJI_PRESENTATION,
+                        Step::Absorbed,
+                    )
                 }
-                (WidthInfo::TAG_A5_END_ZWJ_EMOJI_PRESENTATION, '\u{E0061}'..='\u{E007A}') => {
-                    return (0, WidthInfo::TAG_A6_END_ZWJ_EMOJI_PRESENTATION)
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+                (WidthInfo::TAG_A5_END_ZWJ_EMOJI_PRESENTATION, 0xE0061..=0xE007A) => {
+                    return (
+                        0,
+                        WidthInfo::TAG_A6_END_ZWJ_EMOJI_PRESENTATION,
+                        Step::Absorbed,
+                    )
                 }
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
                 (
                     WidthInfo::TAG_END_ZWJ_EMOJI_PRESENTATION
                     | WidthInfo::TAG_A1_END_ZWJ_EMOJI_PRESENTATION
                     | WidthInfo::TAG_A2_END_ZWJ_EMOJI_PRESENTATION
                     | WidthInfo::TAG_A3_END_ZWJ_EMOJI_PRESENTATION
                     | WidthInfo::TAG_A4_END_ZWJ_EMOJI_PRESENTATION,
-                    '\u{E0030}'..='\u{E0039}',
-                ) => return (0, WidthInfo::TAG_D1_END_ZWJ_EMOJI_PRESENTATION),
-                (WidthInfo::TAG_D1_END_ZWJ_EMOJI_PRESENTATION, '\u{E0030}'..='\u{E0039}') => {
-                    return (0, WidthInfo::TAG_D2_END_ZWJ_EMOJI_PRESENTATION);
+                    0xE0030..=0xE0039,
+                ) => {
+                    return (
+                        0,
+                        WidthInfo::TAG_D1_END_ZWJ_EMOJI_PRESENTATION,
+                        Step::Absorbed,
+                    )
                 }
-                (WidthInfo::TAG_D2_END_ZWJ_EMOJI_PRESENTATION, '\u{E0030}'..='\u{E0039}') => {
-                    return (0, WidthInfo::TAG_D3_END_ZWJ_EMOJI_PRESENTATION);
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+                (WidthInfo::TAG_D1_END_ZWJ_EMOJI_PRESENTATION, 0xE0030..=0xE0039) => {
+                    return (
+                        0,
+                        WidthInfo::TAG_D2_END_ZWJ_EMOJI_PRESENTATION,
+                        Step::Absorbed,
+                    );
                 }
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+                (WidthInfo::TAG_D2_END_ZWJ_EMOJI_PRESENTATION, 0xE0030..=0xE0039) => {
+                    return (
+                        0,
+                        WidthInfo::TAG_D3_END_ZWJ_EMOJI_PRESENTATION,
+                        Step::Absorbed,
+                    );
+                }
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
                 (
                     WidthInfo::TAG_A3_END_ZWJ_EMOJI_PRESENTATION
                     | WidthInfo::TAG_A4_END_ZWJ_EMOJI_PRESENTATION
                     | WidthInfo::TAG_A5_END_ZWJ_EMOJI_PRESENTATION
                     | WidthInfo::TAG_A6_END_ZWJ_EMOJI_PRESENTATION
                     | WidthInfo::TAG_D3_END_ZWJ_EMOJI_PRESENTATION,
-                    '\u{1F3F4}',
-                ) => return (0, WidthInfo::EMOJI_PRESENTATION),
+                    0x1F3F4,
+                ) => {
+                    return (
+                        0,
+                        WidthInfo::EMOJI_PRESENTATION,
+                        Step::Rule(Rule::EmojiZwjSequence),
+                    )
+                }
+                #[cfg(not(feature = "no-emoji-sequences"))]
                 (WidthInfo::ZWJ_EMOJI_PRESENTATION, _)
-                    if lookup_width_generic::<IS_CJK>(c).1 == WidthInfo::EMOJI_PRESENTATION =>
+                    if matches!(
+                        lookup_width_generic::<IS_CJK>(tables, cp).1,
+                        WidthInfo::EMOJI_PRESENTATION
+                    ) =>
                 {
-                    return (0, WidthInfo::EMOJI_PRESENTATION)
+                    return (
+                        0,
+                        WidthInfo::EMOJI_PRESENTATION,
+                        Step::Rule(Rule::EmojiZwjSequence),
+                    )
                 }
 
-                (WidthInfo::KIRAT_RAI_VOWEL_SIGN_E, '\u{16D63}') => {
-                    return (0, WidthInfo::DEFAULT);
+                #[cfg(not(feature = "bmp-only"))]
+                (WidthInfo::KIRAT_RAI_VOWEL_SIGN_E, 0x16D63) => {
+                    return (0, WidthInfo::DEFAULT, Step::Rule(Rule::KiratRai));
                 }
-                (WidthInfo::KIRAT_RAI_VOWEL_SIGN_E, '\u{16D67}') => {
-                    return (0, WidthInfo::KIRAT_RAI_VOWEL_SIGN_AI);
+                #[cfg(not(feature = "bmp-only"))]
+                (WidthInfo::KIRAT_RAI_VOWEL_SIGN_E, 0x16D67) => {
+                    return (0, WidthInfo::KIRAT_RAI_VOWEL_SIGN_AI, Step::Absorbed);
                 }
-                (WidthInfo::KIRAT_RAI_VOWEL_SIGN_E, '\u{16D68}') => {
-                    return (1, WidthInfo::KIRAT_RAI_VOWEL_SIGN_E);
+                #[cfg(not(feature = "bmp-only"))]
+                (WidthInfo::KIRAT_RAI_VOWEL_SIGN_E, 0x16D68) => {
+                    return (1, WidthInfo::KIRAT_RAI_VOWEL_SIGN_E, Step::Absorbed);
                 }
-                (WidthInfo::KIRAT_RAI_VOWEL_SIGN_E, '\u{16D69}') => {
-                    return (0, WidthInfo::DEFAULT);
+                #[cfg(not(feature = "bmp-only"))]
+                (WidthInfo::KIRAT_RAI_VOWEL_SIGN_E, 0x16D69) => {
+                    return (0, WidthInfo::DEFAULT, Step::Rule(Rule::KiratRai));
                 }
-                (WidthInfo::KIRAT_RAI_VOWEL_SIGN_AI, '\u{16D63}') => {
-                    return (0, WidthInfo::DEFAULT);
+                #[cfg(not(feature = "bmp-only"))]
+                (WidthInfo::KIRAT_RAI_VOWEL_SIGN_AI, 0x16D63) => {
+                    return (0, WidthInfo::DEFAULT, Step::Rule(Rule::KiratRai));
                 }
 
                 // Fallback
@@ -330,11 +555,132 @@ pub(crate) fn width_in_generic<const IS_CJK: bool>(
             }
         }
 
-        let ret = lookup_width_generic::<IS_CJK>(c);
-        (ret.0 as i8, ret.1)
+        let ret = lookup_width_generic::<IS_CJK>(tables, cp);
+        (ret.0 as i8, ret.1, Step::Lookup)
     }
 }
 
+/// Whether [`width_step_in_generic`] measures `c` by table lookup alone,
+/// whatever follows it. If so, the characters before `c` can be measured
+/// without looking any further, and `c` ends the segment it is in.
+///
+/// This must be kept in sync with the rules above. It is checked against
+/// them by the `segment_ends_are_context_free` unit test.
+pub(crate) fn is_segment_end<const IS_CJK: bool>(c: char) -> bool {
+    if c <= '\u{A0}' {
+        return match c {
+            '\r' | '#' | '*' | '0'..='9' => false,
+            '<' | '=' | '>' => !IS_CJK,
+            _ => true,
+        };
+    }
+    let cp = c as u32;
+    if matches!(
+        c,
+        '\u{05D0}'
+            | '\u{17D2}'
+            | '\u{1A15}'
+            | '\u{1A17}'
+            | '\u{200D}'
+            | '\u{2018}'
+            | '\u{2019}'
+            | '\u{201C}'
+            | '\u{201D}'
+            | '\u{20E3}'
+            | '\u{2D31}'..='\u{2D7F}'
+            | '\u{A4F8}'..='\u{A4FB}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{10C32}'
+            | '\u{16D63}'..='\u{16D69}'
+            | '\u{1F1E6}'..='\u{1F1FF}'
+            | '\u{1F3F4}'
+            | '\u{1F3FB}'..='\u{1F3FF}'
+            | '\u{E0000}'..='\u{E007F}'
+    ) || !matches!(
+        lookup_width_generic::<IS_CJK>(&TABLES, cp).1,
+        WidthInfo::DEFAULT
+    ) || is_ligature_transparent(cp)
+        || is_transparent_zero_width(&TABLES, cp)
+        || is_joining_group_lam(cp)
+    {
+        return false;
+    }
+    #[cfg(feature = "cjk")]
+    if IS_CJK && is_solidus_transparent(&TABLES, cp) {
+        return false;
+    }
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    if starts_emoji_presentation_seq(&TABLES, cp)
+        || starts_non_ideographic_text_presentation_seq(&TABLES, cp)
+    {
+        return false;
+    }
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    if is_emoji_modifier_base(&TABLES, cp) {
+        return false;
+    }
+    true
+}
+
+/// Whether no sequence can span the boundary between `c` and the character `next` after it.
+///
+/// This holds whenever [`is_segment_end`] holds for `c`, and also between most pairs of
+/// ASCII characters, such as in a run of digits. An ASCII character leaves off with the info
+/// it has on its own, or with [`WidthInfo::EMOJI_PRESENTATION`] if it starts an emoji
+/// presentation sequence, so there the boundary holds if `c` is measured the same after both.
+///
+/// This is checked against the rules above by the `segment_boundaries_are_context_free` unit test.
+pub(crate) fn is_segment_boundary<const IS_CJK: bool>(c: char, next: char) -> bool {
+    if is_segment_end::<IS_CJK>(c) {
+        return true;
+    }
+    if !next.is_ascii() {
+        return false;
+    }
+    let alone = width_step_in_generic::<IS_CJK>(c, WidthInfo::DEFAULT);
+    let next_info = width_step_in_generic::<IS_CJK>(next, WidthInfo::DEFAULT).1;
+    if alone.2 != Step::Lookup || width_step_in_generic::<IS_CJK>(c, next_info) != alone {
+        return false;
+    }
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    if starts_emoji_presentation_seq(&TABLES, next as u32)
+        && width_step_in_generic::<IS_CJK>(c, WidthInfo::EMOJI_PRESENTATION) != alone
+    {
+        return false;
+    }
+    true
+}
+
+/// Finds the boundaries between emoji flag sequences in a run of regional indicators,
+/// going from front to back.
+///
+/// The regional indicators in a run pair up from the front. Each of them is as wide as
+/// it is on its own, except in a run that a ZWJ follows, where the run as a whole still is.
+/// So no sequence spans the boundary between the end of a pair and another regional
+/// indicator, but finding it takes the count from the front that the rules above don't keep.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct FlagBoundaries {
+    /// Whether an odd number of regional indicators came just before.
+    odd: bool,
+}
+
+impl FlagBoundaries {
+    /// Moves past `c`, and returns whether it ends an emoji flag sequence
+    /// that the character `next` after it cannot continue.
+    pub(crate) fn is_flag_end(&mut self, c: char, next: char) -> bool {
+        if !is_regional_indicator(c) {
+            self.odd = false;
+            return false;
+        }
+        self.odd = !self.odd;
+        !self.odd && is_regional_indicator(next)
+    }
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    matches!(c, '\u{1F1E6}'..='\u{1F1FF}')
+}
+
 #[inline]
 pub fn str_width<S: DoubleEndedIterator<Item = char>>(s: S) -> usize {
     s.rfold((0usize, WidthInfo::DEFAULT), |(sum, next_info), c| {
@@ -353,3 +699,280 @@ pub fn str_width_cjk<S: DoubleEndedIterator<Item = char>>(s: S) -> usize {
     })
     .0
 }
+
+/// Measures `s` as if it were followed by characters that left off with `next_info`.
+/// Returns the (wrapping) width of `s` and the info to carry on to the characters before it.
+#[inline]
+pub(crate) fn str_width_from<const IS_CJK: bool>(
+    s: &str,
+    next_info: WidthInfo,
+) -> (usize, WidthInfo) {
+    s.chars().rfold((0usize, next_info), |(sum, next_info), c| {
+        let (add, info) = width_in_generic::<IS_CJK>(c, next_info);
+        (sum.wrapping_add_signed(isize::from(add)), info)
+    })
+}
+
+/// Returns the sum of the widths of the characters of `s` measured on their own,
+/// with control characters counted as zero-width.
+#[inline]
+pub fn str_simple_width<const IS_CJK: bool>(s: &str) -> usize {
+    s.chars()
+        .map(|c| single_char_width_generic::<IS_CJK>(&TABLES, c as u32).unwrap_or(0))
+        .sum()
+}
+
+/// Whether `c` ends a segment in every mode, see [`is_segment_end`].
+#[inline]
+fn is_segment_end_in_all_modes(c: char) -> bool {
+    #[cfg(feature = "cjk")]
+    if !is_segment_end::<true>(c) {
+        return false;
+    }
+    is_segment_end::<false>(c)
+}
+
+/// Whether the width of `c`, or of the characters next to it, can change with
+/// the mode or with how much of the sequence rules a terminal follows.
+pub(crate) fn is_context_dependent(c: char) -> bool {
+    #[cfg(feature = "cjk")]
+    if single_char_width(c) != single_char_width_cjk(c) {
+        return true;
+    }
+    !is_segment_end_in_all_modes(c)
+}
+
+/// Returns the smallest and largest widths that `s` has when measured
+/// with the sequence rules, with emoji ZWJ sequences and ligatures split up
+/// into their parts, and with each character measured on its own.
+fn piece_width_bounds<const IS_CJK: bool>(s: &str) -> (usize, usize) {
+    let widths = [
+        str_slice_width::<IS_CJK>(s),
+        s.split('\u{200D}').map(str_slice_width::<IS_CJK>).sum(),
+        s.chars()
+            .rfold((0usize, WidthInfo::DEFAULT), |(sum, next_info), c| {
+                // "\r\n" is still a single line break, rather than a sequence to split up.
+                let next_info = match next_info {
+                    WidthInfo::LINE_FEED => WidthInfo::LINE_FEED,
+                    _ => WidthInfo::DEFAULT,
+                };
+                let (add, info) = width_in_generic::<IS_CJK>(c, next_info);
+                (sum.wrapping_add_signed(isize::from(add)), info)
+            })
+            .0,
+    ];
+    let min = widths.iter().copied().min().unwrap_or(0);
+    let max = widths.iter().copied().max().unwrap_or(0);
+    (min, max)
+}
+
+/// Returns the smallest and largest widths of `s` across every mode and
+/// every way of measuring it that [`piece_width_bounds`] tries.
+pub(crate) fn str_width_bounds(s: &str) -> (usize, usize) {
+    let (mut min, mut max) = (0usize, 0usize);
+    let mut rest = s;
+    while !rest.is_empty() {
+        // Each piece ends with a character that is measured the same way whatever
+        // follows, so the pieces can be bounded one at a time.
+        let end = rest
+            .char_indices()
+            .find(|&(_, c)| is_segment_end_in_all_modes(c))
+            .map_or(rest.len(), |(i, c)| i + c.len_utf8());
+        let (piece, next) = rest.split_at(end);
+        let bounds = piece_width_bounds::<false>(piece);
+        #[cfg(feature = "cjk")]
+        let bounds = {
+            let cjk = piece_width_bounds::<true>(piece);
+            (bounds.0.min(cjk.0), bounds.1.max(cjk.1))
+        };
+        min = min.wrapping_add(bounds.0);
+        max = max.wrapping_add(bounds.1);
+        rest = next;
+    }
+    (min, max)
+}
+
+/// Like [`str_width`], but skips over runs of printable ASCII a word at a time.
+#[inline]
+pub fn str_slice_width<const IS_CJK: bool>(s: &str) -> usize {
+    let mut rest = s;
+    let mut sum = 0usize;
+    let mut next_info = WidthInfo::DEFAULT;
+    loop {
+        if matches!(next_info, WidthInfo::DEFAULT) {
+            // Printable ASCII never continues a sequence, so with no sequence pending,
+            // each character in the run has width 1.
+            let run = printable_ascii_suffix_len(rest.as_bytes());
+            sum += run;
+            rest = &rest[..rest.len() - run];
+
+            // Likewise, most other characters in the BMP don't take part in any sequence,
+            // and their width can be read straight from their UTF-8 bytes.
+            if let Some((width, len)) = utf8_suffix_width::<IS_CJK>(rest.as_bytes()) {
+                sum += usize::from(width);
+                rest = &rest[..rest.len() - len];
+                continue;
+            }
+        }
+        let mut chars = rest.chars();
+        let Some(c) = chars.next_back() else {
+            return sum;
+        };
+        let (add, info) = width_in_generic::<IS_CJK>(c, next_info);
+        sum = sum.wrapping_add_signed(isize::from(add));
+        next_info = info;
+        rest = chars.as_str();
+    }
+}
+
+/// If `bytes` ends with a two- or three-byte UTF-8 sequence for a character that
+/// (when nothing follows it) is measured by table lookup alone, returns its width and length.
+///
+/// The `UTF8_INDEX` tables have one entry per 64 codepoints of the BMP, which are selected by
+/// the low 5 bits of a two-byte sequence's lead byte, or by the low 4 bits of a three-byte
+/// sequence's lead byte followed by the low 6 bits of its first continuation byte.
+/// The selected row of `UTF8_LEAVES` packs four 2-bit widths into each byte,
+/// and is indexed by the low 6 bits of the last continuation byte.
+/// Characters up to U+00A0 and those needing special handling are stored as width 3.
+#[cfg(any(feature = "layout-speed", not(feature = "layout-size")))]
+#[inline]
+pub(crate) fn utf8_suffix_width<const IS_CJK: bool>(bytes: &[u8]) -> Option<(u8, usize)> {
+    #[cfg(feature = "cjk")]
+    use crate::layout::UTF8_INDEX_CJK;
+    use crate::layout::{UTF8_INDEX, UTF8_LEAVES};
+
+    let (index, last, len) = match *bytes {
+        [.., lead @ 0xC2..=0xDF, last @ 0x80..=0xBF] => (usize::from(lead & 0x1F), last, 2),
+        [.., lead @ 0xE0..=0xEF, mid @ 0x80..=0xBF, last @ 0x80..=0xBF] => (
+            usize::from(lead & 0x0F) << 6 | usize::from(mid & 0x3F),
+            last,
+            3,
+        ),
+        _ => return None,
+    };
+
+    #[cfg(feature = "cjk")]
+    let leaf_idx = if IS_CJK {
+        UTF8_INDEX_CJK.0[index]
+    } else {
+        UTF8_INDEX.0[index]
+    };
+    #[cfg(not(feature = "cjk"))]
+    let leaf_idx = UTF8_INDEX.0[index];
+
+    let offset = usize::from(last & 0x3F);
+    let width = UTF8_LEAVES.0[usize::from(leaf_idx)][offset >> 2] >> (2 * (offset & 0b11)) & 0b11;
+    (width < 3).then_some((width, len))
+}
+
+/// The `layout-size` tables are not indexed by UTF-8 bytes,
+/// so every character is looked up through [`lookup_width`].
+#[cfg(all(feature = "layout-size", not(feature = "layout-speed")))]
+#[inline]
+pub(crate) fn utf8_suffix_width<const IS_CJK: bool>(_bytes: &[u8]) -> Option<(u8, usize)> {
+    None
+}
+
+/// Returns the length of the run of bytes in `0x20..=0x7E` at the end of `bytes`.
+#[inline]
+fn printable_ascii_suffix_len(bytes: &[u8]) -> usize {
+    const WORD: usize = core::mem::size_of::<usize>();
+    const LO: usize = usize::MAX / 0xFF;
+    const HI: usize = LO * 0x80;
+
+    let run_len = |bytes: &[u8]| {
+        bytes
+            .iter()
+            .rev()
+            .take_while(|&&b| matches!(b, 0x20..=0x7E))
+            .count()
+    };
+
+    // Most non-ASCII text never gets as far as loading a word.
+    if !matches!(bytes.last(), Some(0x20..=0x7E)) {
+        return 0;
+    }
+
+    let mut len = 0;
+    let mut words = bytes.rchunks_exact(WORD);
+    for chunk in &mut words {
+        let word = usize::from_ne_bytes(chunk.try_into().unwrap());
+        // Adding 0x01 to an ASCII byte sets its top bit iff it is 0x7F, and adding 0x60
+        // sets it iff it is at least 0x20. Neither addition can carry into the next byte.
+        if (word | word.wrapping_add(LO)) & HI != 0 || word.wrapping_add(LO * 0x60) & HI != HI {
+            return len + run_len(chunk);
+        }
+        len += WORD;
+    }
+    len + run_len(words.remainder())
+}
+
+/// Returns the width of `bytes` with each maximal invalid subpart replaced by
+/// `'\u{FFFD}'`, and the length of the incomplete sequence at the end of `bytes`, if any.
+#[inline]
+pub fn utf8_lossy_width<const IS_CJK: bool>(mut bytes: &[u8]) -> (usize, usize) {
+    let mut width = 0;
+    loop {
+        let (valid, error) = match core::str::from_utf8(bytes) {
+            Ok(s) => (s, None),
+            Err(e) => {
+                let (valid, rest) = bytes.split_at(e.valid_up_to());
+                // `valid_up_to` always falls on a char boundary.
+                let valid = core::str::from_utf8(valid).unwrap_or_default();
+                (valid, Some((e.error_len(), rest)))
+            }
+        };
+        // `'\u{FFFD}'` doesn't take part in any sequence, so each valid run
+        // can be measured on its own.
+        width += str_slice_width::<IS_CJK>(valid);
+        match error {
+            None => return (width, 0),
+            Some((None, rest)) => return (width, rest.len()),
+            Some((Some(len), rest)) => {
+                width += lookup_width_generic::<IS_CJK>(&TABLES, char::REPLACEMENT_CHARACTER as u32)
+                    .0 as usize;
+                bytes = &rest[len..];
+            }
+        }
+    }
+}
+
+/// Like [`str_width`], but usable in `const` contexts.
+/// Walks the UTF-8 bytes of `s` backwards instead of using [`str::chars`].
+#[inline]
+pub const fn const_str_width_generic<const IS_CJK: bool>(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut end = bytes.len();
+    let mut sum = 0usize;
+    let mut next_info = WidthInfo::DEFAULT;
+    while end > 0 {
+        // Skip back over continuation bytes to the start of the last `char`.
+        let mut start = end - 1;
+        while bytes[start] & 0xC0 == 0x80 {
+            start -= 1;
+        }
+        let cp = decode_utf8(bytes, start, end);
+        let (add, info, _) = width_step::<IS_CJK>(&CONST_TABLES, cp, next_info);
+        sum = sum.wrapping_add_signed(add as isize);
+        next_info = info;
+        end = start;
+    }
+    sum
+}
+
+/// Decodes the codepoint whose UTF-8 encoding is `bytes[start..end]`.
+#[inline]
+const fn decode_utf8(bytes: &[u8], start: usize, end: usize) -> u32 {
+    let mut cp = match end - start {
+        1 => return bytes[start] as u32,
+        2 => (bytes[start] & 0x1F) as u32,
+        3 => (bytes[start] & 0x0F) as u32,
+        _ => (bytes[start] & 0x07) as u32,
+    };
+    let mut i = start + 1;
+    while i < end {
+        cp = cp << 6 | (bytes[i] & 0x3F) as u32;
+        i += 1;
+    }
+    cp
+}
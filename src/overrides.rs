@@ -0,0 +1,362 @@
+// Copyright 2012-2026 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::cmp::Ordering;
+#[cfg(feature = "config")]
+use std::vec::Vec;
+
+use crate::lookup::{self, FlagBoundaries, Step};
+use crate::width_info::WidthInfo;
+
+/// A table of widths for ranges of characters, to use in place of the built-in tables,
+/// like kitty's `narrow_symbols`, WezTerm's `cell_widths`, or Vim's `setcellwidths()`.
+///
+/// The ranges are inclusive `(first, last, width)` triples, which must be sorted
+/// and must not overlap. The table is borrowed, so it can be a `const`.
+///
+/// When measuring a string, an overridden character is given its width from this table
+/// wherever it would have been measured on its own. The rules for sequences still apply
+/// to it, so an emoji ZWJ sequence, an emoji presentation sequence, or a flag made up of
+/// overridden characters keeps its width of 2. The characters that a sequence absorbs,
+/// such as the first regional indicator of a flag, are measured by its rules alone.
+///
+/// Exact sequences of characters can be given a width as well, with
+/// [`WidthOverrides::with_sequences`].
+///
+/// ```rust
+/// use unicode_width::WidthOverrides;
+///
+/// // Powerline symbols, drawn two columns wide, and narrow arrows.
+/// const OVERRIDES: WidthOverrides = WidthOverrides::new(&[
+///     ('\u{2190}', '\u{21FF}', 1),
+///     ('\u{E0A0}', '\u{E0D4}', 2),
+/// ]);
+/// assert_eq!(OVERRIDES.char_width('\u{E0B0}'), Some(2));
+/// assert_eq!(OVERRIDES.str_width("\u{E0B0} main"), 7);
+/// # #[cfg(not(feature = "no-emoji-sequences"))]
+/// assert_eq!(OVERRIDES.str_width("\u{2194}\u{FE0F}"), 2);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WidthOverrides<'a> {
+    ranges: &'a [(char, char, u8)],
+    sequences: &'a [(&'a str, SequenceWidth)],
+}
+
+/// The width to give a sequence of characters registered with
+/// [`WidthOverrides::with_sequences`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SequenceWidth {
+    /// The sequence has the given width.
+    Fixed(u8),
+    /// The sequence is as wide as its characters measured one by one,
+    /// as if no rule for sequences applied to it.
+    Sum,
+}
+
+impl<'a> WidthOverrides<'a> {
+    /// Creates a table of overrides from `ranges`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a range is empty, or if the ranges are not sorted or overlap.
+    pub const fn new(ranges: &'a [(char, char, u8)]) -> Self {
+        let mut i = 0;
+        while i < ranges.len() {
+            let (first, last, _) = ranges[i];
+            assert!(first <= last, "width override range is empty");
+            if i > 0 {
+                assert!(
+                    ranges[i - 1].1 < first,
+                    "width override ranges are not sorted, or overlap"
+                );
+            }
+            i += 1;
+        }
+        WidthOverrides {
+            ranges,
+            sequences: &[],
+        }
+    }
+
+    /// Returns the table with `sequences` registered, replacing any registered before.
+    ///
+    /// When measuring a string, it is scanned from front to back for the sequences,
+    /// and where several of them start at the same character, the longest one wins.
+    /// Each match is measured as its [`SequenceWidth`] says, and the text between matches
+    /// is measured as usual, as if it were split from the matches. So a registered sequence
+    /// takes precedence over any built-in rule for a sequence that overlaps it.
+    ///
+    /// Each sequence is looked for in turn, so the list is meant to be short.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the sequences is empty.
+    ///
+    /// ```rust
+    /// use unicode_width::{SequenceWidth, WidthOverrides};
+    ///
+    /// const OVERRIDES: WidthOverrides = WidthOverrides::new(&[]).with_sequences(&[
+    ///     // Shown as its separate emoji.
+    ///     ("\u{1F9D1}\u{200D}\u{1F9B0}", SequenceWidth::Sum),
+    ///     // Shown as a single ligature.
+    ///     ("->", SequenceWidth::Fixed(1)),
+    /// ]);
+    /// # #[cfg(not(feature = "bmp-only"))]
+    /// assert_eq!(OVERRIDES.str_width("\u{1F9D1}\u{200D}\u{1F9B0}"), 4);
+    /// assert_eq!(OVERRIDES.str_width("a -> b"), 5);
+    /// ```
+    pub const fn with_sequences(self, sequences: &'a [(&'a str, SequenceWidth)]) -> Self {
+        let mut i = 0;
+        while i < sequences.len() {
+            assert!(
+                !sequences[i].0.is_empty(),
+                "width override sequence is empty"
+            );
+            i += 1;
+        }
+        WidthOverrides {
+            ranges: self.ranges,
+            sequences,
+        }
+    }
+
+    /// Returns the ranges of the table.
+    pub const fn ranges(&self) -> &'a [(char, char, u8)] {
+        self.ranges
+    }
+
+    /// Returns the sequences registered with [`WidthOverrides::with_sequences`].
+    pub const fn sequences(&self) -> &'a [(&'a str, SequenceWidth)] {
+        self.sequences
+    }
+
+    /// Returns the width that the table gives `c`, if any.
+    pub fn get(&self, c: char) -> Option<usize> {
+        let i = self
+            .ranges
+            .binary_search_by(|&(first, last, _)| {
+                if last < c {
+                    Ordering::Less
+                } else if first > c {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .ok()?;
+        Some(usize::from(self.ranges[i].2))
+    }
+
+    /// Like [`UnicodeWidthChar::width`](crate::UnicodeWidthChar::width),
+    /// but with the widths from this table.
+    pub fn char_width(&self, c: char) -> Option<usize> {
+        self.get(c).or_else(|| lookup::single_char_width(c))
+    }
+
+    /// Like [`UnicodeWidthChar::width_cjk`](crate::UnicodeWidthChar::width_cjk),
+    /// but with the widths from this table.
+    #[cfg(feature = "cjk")]
+    pub fn char_width_cjk(&self, c: char) -> Option<usize> {
+        self.get(c).or_else(|| lookup::single_char_width_cjk(c))
+    }
+
+    /// Like [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width),
+    /// but with the widths from this table.
+    pub fn str_width(&self, s: &str) -> usize {
+        self.str_width_generic::<false>(s)
+    }
+
+    /// Like [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk),
+    /// but with the widths from this table.
+    #[cfg(feature = "cjk")]
+    pub fn str_width_cjk(&self, s: &str) -> usize {
+        self.str_width_generic::<true>(s)
+    }
+
+    fn str_width_generic<const IS_CJK: bool>(&self, s: &str) -> usize {
+        if self.sequences.is_empty() {
+            return self.chars_width::<IS_CJK>(s);
+        }
+        let mut sum = 0usize;
+        let mut gap_start = 0;
+        let mut i = 0;
+        while let Some(c) = s[i..].chars().next() {
+            if let Some((len, width)) = self.sequence_at::<IS_CJK>(&s[i..]) {
+                sum = sum
+                    .wrapping_add(self.chars_width::<IS_CJK>(&s[gap_start..i]))
+                    .wrapping_add(width);
+                i += len;
+                gap_start = i;
+            } else {
+                i += c.len_utf8();
+            }
+        }
+        sum.wrapping_add(self.chars_width::<IS_CJK>(&s[gap_start..]))
+    }
+
+    /// Returns the length and width of the longest registered sequence that `s` starts with.
+    fn sequence_at<const IS_CJK: bool>(&self, s: &str) -> Option<(usize, usize)> {
+        let (seq, width) = self
+            .sequences
+            .iter()
+            .filter(|(seq, _)| s.starts_with(seq))
+            .max_by_key(|(seq, _)| seq.len())?;
+        let width = match width {
+            SequenceWidth::Fixed(width) => usize::from(*width),
+            SequenceWidth::Sum => seq.chars().fold(0usize, |sum, c| {
+                let (add, _) = lookup::width_in_generic::<IS_CJK>(c, WidthInfo::DEFAULT);
+                let add = self.get(c).map_or(isize::from(add), |w| w as isize);
+                sum.wrapping_add_signed(add)
+            }),
+        };
+        Some((seq.len(), width))
+    }
+
+    /// Measures `s` with the overridden widths of its characters.
+    fn chars_width<const IS_CJK: bool>(&self, s: &str) -> usize {
+        if self.ranges.is_empty() {
+            return lookup::str_slice_width::<IS_CJK>(s);
+        }
+        str_width_with::<IS_CJK>(s, |c| self.get(c))
+    }
+}
+
+/// Measures `s` with the widths that `get` gives its characters in place of those
+/// from the tables. The rules for sequences still apply to overridden characters.
+pub(crate) fn str_width_with<const IS_CJK: bool>(
+    s: &str,
+    get: impl Fn(char) -> Option<usize>,
+) -> usize {
+    // Each flag in a run of regional indicators is measured on its own, so that
+    // the one left over at the end of an odd run is measured by lookup.
+    let mut flags = FlagBoundaries::default();
+    let mut sum = 0usize;
+    let mut start = 0;
+    let mut chars = s.char_indices().peekable();
+    while let (Some((_, c)), Some(&(i, next))) = (chars.next(), chars.peek()) {
+        if flags.is_flag_end(c, next) {
+            sum = sum.wrapping_add(piece_width_with::<IS_CJK>(&s[start..i], &get));
+            start = i;
+        }
+    }
+    sum.wrapping_add(piece_width_with::<IS_CJK>(&s[start..], &get))
+}
+
+fn piece_width_with<const IS_CJK: bool>(s: &str, get: impl Fn(char) -> Option<usize>) -> usize {
+    // How much the override of the last character measured by lookup changed its width.
+    // The sequence rules build on the width from the tables, so the change is undone
+    // if that character turns out to end a sequence.
+    let mut overridden = 0isize;
+    s.chars()
+        .rfold((0usize, WidthInfo::DEFAULT), |(sum, next_info), c| {
+            let (add, info, step) = lookup::width_step_in_generic::<IS_CJK>(c, next_info);
+            let add = match step {
+                Step::Lookup => {
+                    overridden = get(c).map_or(0, |w| w as isize - isize::from(add));
+                    isize::from(add) + overridden
+                }
+                Step::Absorbed => isize::from(add),
+                Step::Rule(_) => isize::from(add) - core::mem::take(&mut overridden),
+            };
+            (sum.wrapping_add_signed(add), info)
+        })
+        .0
+}
+
+/// An owned, growable table of width overrides for ranges of characters,
+/// such as one read from a configuration file with the [`config`](crate::config) parsers.
+///
+/// ```rust
+/// use unicode_width::WidthOverridesBuf;
+///
+/// let mut overrides = WidthOverridesBuf::new();
+/// overrides.insert('\u{E000}', '\u{F8FF}', 2);
+/// // Later ranges take precedence over the parts of earlier ones they overlap.
+/// overrides.insert('\u{E0B0}', '\u{E0B3}', 1);
+/// assert_eq!(
+///     overrides.ranges(),
+///     [
+///         ('\u{E000}', '\u{E0AF}', 2),
+///         ('\u{E0B0}', '\u{E0B3}', 1),
+///         ('\u{E0B4}', '\u{F8FF}', 2),
+///     ]
+/// );
+/// assert_eq!(overrides.as_overrides().str_width("\u{E0B0}\u{E0B4}"), 3);
+/// ```
+#[cfg(feature = "config")]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WidthOverridesBuf {
+    ranges: Vec<(char, char, u8)>,
+}
+
+#[cfg(feature = "config")]
+impl WidthOverridesBuf {
+    /// Creates an empty table.
+    pub const fn new() -> Self {
+        WidthOverridesBuf { ranges: Vec::new() }
+    }
+
+    /// Overrides the width of `first..=last` with `width`,
+    /// in place of any width given to those characters before.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `first` is after `last`.
+    pub fn insert(&mut self, first: char, last: char, width: u8) {
+        assert!(first <= last, "width override range is empty");
+        let start = self.ranges.partition_point(|&(_, l, _)| l < first);
+        let end = self.ranges.partition_point(|&(f, _, _)| f <= last);
+        // Keep the parts of the overlapping ranges that stick out on either side.
+        let mut replacement = Vec::with_capacity(3);
+        if let Some(&(f, _, w)) = self.ranges[start..end].first() {
+            if f < first {
+                replacement.push((f, char_before(first), w));
+            }
+        }
+        replacement.push((first, last, width));
+        if let Some(&(_, l, w)) = self.ranges[start..end].last() {
+            if l > last {
+                replacement.push((char_after(last), l, w));
+            }
+        }
+        self.ranges.splice(start..end, replacement);
+    }
+
+    /// Returns the ranges of the table, sorted and without overlaps.
+    pub fn ranges(&self) -> &[(char, char, u8)] {
+        &self.ranges
+    }
+
+    /// Borrows the table, to measure text with.
+    pub fn as_overrides(&self) -> WidthOverrides<'_> {
+        WidthOverrides {
+            ranges: &self.ranges,
+            sequences: &[],
+        }
+    }
+}
+
+/// Returns the character before `c`, which must not be `'\0'`.
+#[cfg(feature = "config")]
+fn char_before(c: char) -> char {
+    match c {
+        '\u{E000}' => '\u{D7FF}',
+        _ => char::from_u32(c as u32 - 1).unwrap_or(c),
+    }
+}
+
+/// Returns the character after `c`, which must not be `char::MAX`.
+#[cfg(feature = "config")]
+fn char_after(c: char) -> char {
+    match c {
+        '\u{D7FF}' => '\u{E000}',
+        _ => char::from_u32(c as u32 + 1).unwrap_or(c),
+    }
+}
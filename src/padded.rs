@@ -0,0 +1,139 @@
+// Copyright 2012-2026 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt::{self, Alignment, Display, Formatter, Write};
+
+use crate::explain::{self, ExplainStr};
+use crate::lookup;
+
+/// Wraps a string so that its [`Display`] implementation pads and truncates it
+/// by displayed width in columns, rather than by number of `char`s.
+///
+/// - The width parameter (as in `{:8}`) is the minimum number of columns to fill.
+///   The fill character and alignment are honored, and the default alignment is left.
+///   Each copy of the fill character counts as its own width (at least 1 column),
+///   and any column that is left over is filled with a space.
+/// - The precision parameter (as in `{:.8}`) is the maximum number of columns to keep.
+///   The string is cut short at the end of the last whole sequence that fits,
+///   so ligatures and emoji sequences are never split.
+///
+/// ```rust
+/// use unicode_width::Padded;
+///
+/// assert_eq!(format!("[{:>6}]", Padded::new("ｈｉ")), "[  ｈｉ]");
+/// assert_eq!(format!("[{:-^7}]", Padded::new("ｈｉ")), "[-ｈｉ--]");
+/// assert_eq!(format!("[{:5.3}]", Padded::new("ｈｉ!")), "[ｈ   ]");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Padded<'a> {
+    s: &'a str,
+    #[cfg(feature = "cjk")]
+    is_cjk: bool,
+}
+
+impl<'a> Padded<'a> {
+    /// Wraps `s`, to be measured like [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+    pub const fn new(s: &'a str) -> Self {
+        Padded {
+            s,
+            #[cfg(feature = "cjk")]
+            is_cjk: false,
+        }
+    }
+
+    /// Wraps `s`, to be measured like [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+    #[cfg(feature = "cjk")]
+    pub const fn new_cjk(s: &'a str) -> Self {
+        Padded { s, is_cjk: true }
+    }
+
+    fn explain(&self) -> ExplainStr<'a> {
+        #[cfg(feature = "cjk")]
+        if self.is_cjk {
+            return explain::explain_str_cjk(self.s);
+        }
+        explain::explain_str(self.s)
+    }
+
+    fn width(&self) -> usize {
+        #[cfg(feature = "cjk")]
+        if self.is_cjk {
+            return lookup::str_slice_width::<true>(self.s);
+        }
+        lookup::str_slice_width::<false>(self.s)
+    }
+
+    fn char_width(&self, c: char) -> Option<usize> {
+        #[cfg(feature = "cjk")]
+        if self.is_cjk {
+            return lookup::single_char_width_cjk(c);
+        }
+        lookup::single_char_width(c)
+    }
+
+    /// Returns the longest prefix of the string that is at most `max_width` columns wide
+    /// and ends between two segments, along with its width.
+    fn truncated(&self, max_width: Option<usize>) -> (&'a str, usize) {
+        let width = self.width();
+        match max_width {
+            Some(max_width) if width > max_width => {
+                // Keep segments from the front for as long as they fit.
+                let (mut end, mut width) = (0, 0);
+                for (range, seg_width, _) in self.explain() {
+                    if width + seg_width > max_width {
+                        break;
+                    }
+                    width += seg_width;
+                    end = range.end;
+                }
+                (&self.s[..end], width)
+            }
+            _ => (self.s, width),
+        }
+    }
+
+    fn write_fill(&self, f: &mut Formatter<'_>, columns: usize) -> fmt::Result {
+        let fill = f.fill();
+        write_fill(f, fill, self.char_width(fill), columns)
+    }
+}
+
+/// Fills `columns` columns with copies of `fill`, which is `fill_width` columns wide,
+/// and spaces for any that are left over.
+pub(crate) fn write_fill<W: Write + ?Sized>(
+    out: &mut W,
+    fill: char,
+    fill_width: Option<usize>,
+    columns: usize,
+) -> fmt::Result {
+    let fill_width = fill_width.filter(|&w| w > 0).unwrap_or(1);
+    for _ in 0..columns / fill_width {
+        out.write_char(fill)?;
+    }
+    for _ in 0..columns % fill_width {
+        out.write_str(" ")?;
+    }
+    Ok(())
+}
+
+impl Display for Padded<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (s, width) = self.truncated(f.precision());
+        let padding = f.width().unwrap_or(0).saturating_sub(width);
+        let (before, after) = match f.align() {
+            None | Some(Alignment::Left) => (0, padding),
+            Some(Alignment::Right) => (padding, 0),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        };
+        self.write_fill(f, before)?;
+        f.write_str(s)?;
+        self.write_fill(f, after)
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2012-2026 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Ready-made [`WidthOverrides`] for the icon fonts that are common in terminals.
+//!
+//! [Nerd Fonts] and [Powerline] put their glyphs in the Private Use Area, which the
+//! built-in tables give width 1 (or 2 in an East Asian context) like other
+//! private-use characters. How many cells these glyphs actually take depends on the
+//! font, and on how the terminal is set up to draw them:
+//!
+//! - [`POWERLINE`] gives the Powerline separators and symbols width 1.
+//! - [`NERD_FONTS_MONO`] gives every Nerd Fonts glyph width 1, as the "Mono" variants of
+//!   the fonts draw them.
+//! - [`NERD_FONTS`] gives the Nerd Fonts icons width 2, as the regular variants draw them
+//!   when the terminal gives them the room, and keeps the Powerline glyphs at width 1.
+//!
+//! The Nerd Fonts glyphs outside of the Private Use Area, such as the box-drawing
+//! characters, are left alone. [`Preset`] picks one of these at runtime, such as from
+//! a configuration file.
+//!
+//! ```rust
+//! use unicode_width::presets::{self, Preset};
+//!
+//! let prompt = "\u{F418} main \u{E0B0}";
+//! assert_eq!(presets::NERD_FONTS.str_width(prompt), 9);
+//! assert_eq!(presets::NERD_FONTS_MONO.str_width(prompt), 8);
+//!
+//! let preset = Preset::from_name("nerd-fonts").unwrap();
+//! assert_eq!(preset.overrides().str_width(prompt), 9);
+//! ```
+//!
+//! [Nerd Fonts]: https://www.nerdfonts.com/
+//! [Powerline]: https://github.com/powerline/powerline
+
+use crate::tables::{NERD_FONTS_MONO_TABLE, NERD_FONTS_TABLE, POWERLINE_TABLE};
+use crate::WidthOverrides;
+
+/// Gives the Powerline glyphs width 1.
+pub const POWERLINE: WidthOverrides<'static> = WidthOverrides::new(&POWERLINE_TABLE);
+
+/// Gives every Nerd Fonts glyph in the Private Use Areas width 1,
+/// for the "Mono" variants of the fonts.
+pub const NERD_FONTS_MONO: WidthOverrides<'static> = WidthOverrides::new(&NERD_FONTS_MONO_TABLE);
+
+/// Gives the Nerd Fonts icons in the Private Use Areas width 2, and the Powerline glyphs
+/// width 1, for the regular variants of the fonts.
+pub const NERD_FONTS: WidthOverrides<'static> = WidthOverrides::new(&NERD_FONTS_TABLE);
+
+/// One of the presets in this module, to be chosen at runtime.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Preset {
+    /// [`POWERLINE`].
+    Powerline,
+    /// [`NERD_FONTS_MONO`].
+    NerdFontsMono,
+    /// [`NERD_FONTS`].
+    NerdFonts,
+}
+
+impl Preset {
+    /// All of the presets.
+    pub const ALL: [Preset; 3] = [Preset::Powerline, Preset::NerdFontsMono, Preset::NerdFonts];
+
+    /// Returns the name of the preset: `"powerline"`, `"nerd-fonts-mono"`, or `"nerd-fonts"`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Preset::Powerline => "powerline",
+            Preset::NerdFontsMono => "nerd-fonts-mono",
+            Preset::NerdFonts => "nerd-fonts",
+        }
+    }
+
+    /// Returns the preset with the given [`name`](Preset::name), if there is one.
+    pub fn from_name(name: &str) -> Option<Preset> {
+        Preset::ALL.into_iter().find(|preset| preset.name() == name)
+    }
+
+    /// Returns the overrides of the preset.
+    pub const fn overrides(self) -> WidthOverrides<'static> {
+        match self {
+            Preset::Powerline => POWERLINE,
+            Preset::NerdFontsMono => NERD_FONTS_MONO,
+            Preset::NerdFonts => NERD_FONTS,
+        }
+    }
+}
@@ -8,8 +8,8 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::tables::*;
-use core::cmp::Ordering;
+use crate::layout::Tables;
+use crate::lookup::lookup_props;
 
 #[path = "gen/props.rs"]
 mod gen;
@@ -20,46 +20,16 @@ pub use gen::*;
 /// `Joining_Type=Transparent`. Used by the Alef-Lamed ligatures.
 /// See also [`is_ligature_transparent`], a near-subset of this (only ZWJ is excepted)
 /// which is transparent for non-Arabic ligatures.
-pub(crate) fn is_transparent_zero_width(c: char) -> bool {
-    if crate::lookup::lookup_width(c).0 != 0 {
-        // Not zero-width
-        false
-    } else {
-        let cp: u32 = c.into();
-        NON_TRANSPARENT_ZERO_WIDTHS
-            .binary_search_by(|&(lo, hi)| {
-                let lo = u32::from_le_bytes([lo[0], lo[1], lo[2], 0]);
-                let hi = u32::from_le_bytes([hi[0], hi[1], hi[2], 0]);
-                if cp < lo {
-                    Ordering::Greater
-                } else if cp > hi {
-                    Ordering::Less
-                } else {
-                    Ordering::Equal
-                }
-            })
-            .is_err()
-    }
+#[inline]
+pub(crate) const fn is_transparent_zero_width(tables: &Tables, cp: u32) -> bool {
+    lookup_props(tables, cp) & crate::lookup::TRANSPARENT_ZERO_WIDTH != 0
 }
 
 /// Whether this character is transparent wrt the effect of
 /// U+0338 COMBINING LONG SOLIDUS OVERLAY
 /// on its base character.
 #[cfg(feature = "cjk")]
-pub fn is_solidus_transparent(c: char) -> bool {
-    let cp: u32 = c.into();
-    is_ligature_transparent(c)
-        || SOLIDUS_TRANSPARENT
-            .binary_search_by(|&(lo, hi)| {
-                let lo = u32::from_le_bytes([lo[0], lo[1], lo[2], 0]);
-                let hi = u32::from_le_bytes([hi[0], hi[1], hi[2], 0]);
-                if cp < lo {
-                    Ordering::Greater
-                } else if cp > hi {
-                    Ordering::Less
-                } else {
-                    Ordering::Equal
-                }
-            })
-            .is_ok()
+#[inline]
+pub(crate) const fn is_solidus_transparent(tables: &Tables, cp: u32) -> bool {
+    lookup_props(tables, cp) & crate::lookup::SOLIDUS_TRANSPARENT != 0
 }
@@ -0,0 +1,131 @@
+// Copyright 2012-2026 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::cmp::Ordering;
+
+use crate::explain::{self, ExplainStr, Rule};
+use crate::lookup;
+use crate::tables::{RGI_EMOJI, RGI_EMOJI_ENDS};
+
+/// Returns the `i`th sequence of the RGI emoji table.
+fn rgi_emoji(i: usize) -> &'static str {
+    let start = match i {
+        0 => 0,
+        _ => usize::from(RGI_EMOJI_ENDS[i - 1]),
+    };
+    &RGI_EMOJI[start..usize::from(RGI_EMOJI_ENDS[i])]
+}
+
+/// Returns whether `s` is exactly one emoji sequence that is Recommended for
+/// General Interchange ([RGI]), as listed in `emoji-sequences.txt` and
+/// `emoji-zwj-sequences.txt` for the version of Unicode in
+/// [`UNICODE_VERSION`](crate::UNICODE_VERSION).
+///
+/// These are the emoji that platforms are expected to draw as a single image.
+/// Only fully-qualified sequences are RGI, so an emoji that needs a `'\u{FE0F}'`
+/// isn't RGI without one.
+///
+/// Only available with the `"rgi-emoji"` feature.
+///
+/// ```rust
+/// use unicode_width::is_rgi_emoji;
+///
+/// assert!(is_rgi_emoji("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"));
+/// assert!(is_rgi_emoji("\u{2764}\u{FE0F}"));
+/// assert!(!is_rgi_emoji("\u{2764}"));
+/// assert!(!is_rgi_emoji("\u{1F468}\u{200D}\u{1F525}"));
+/// ```
+///
+/// [RGI]: https://www.unicode.org/reports/tr51/#def_rgi_set
+pub fn is_rgi_emoji(s: &str) -> bool {
+    let (mut low, mut high) = (0, RGI_EMOJI_ENDS.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match rgi_emoji(mid).as_bytes().cmp(s.as_bytes()) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return true,
+        }
+    }
+    false
+}
+
+/// Like [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width),
+/// but only the emoji ZWJ sequences that are RGI have width 2.
+///
+/// Platforms only draw a ZWJ sequence as a single emoji if it is RGI (see [`is_rgi_emoji`]).
+/// Any other well-formed ZWJ sequence is drawn as its parts, so it is measured as they are:
+/// from the front, the longest run of parts that makes up an RGI sequence is measured
+/// together, and the `'\u{200D}'` ZERO WIDTH JOINERs between the runs are left out.
+///
+/// Only available with the `"rgi-emoji"` feature.
+///
+/// ```rust
+/// use unicode_width::{width_rgi, UnicodeWidthStr};
+///
+/// let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+/// let family_on_fire = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F525}";
+/// # #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+/// # {
+/// assert_eq!(family_on_fire.width(), 2);
+/// assert_eq!(width_rgi(family), 2);
+/// assert_eq!(width_rgi(family_on_fire), 4);
+/// # }
+/// ```
+pub fn width_rgi(s: &str) -> usize {
+    if !s.contains('\u{200D}') {
+        return lookup::str_slice_width::<false>(s);
+    }
+    segments_width::<false>(s, explain::explain_str(s))
+}
+
+/// Like [`width_rgi`], but measures like
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+///
+/// Only available with the `"rgi-emoji"` and `"cjk"` features.
+#[cfg(feature = "cjk")]
+pub fn width_rgi_cjk(s: &str) -> usize {
+    if !s.contains('\u{200D}') {
+        return lookup::str_slice_width::<true>(s);
+    }
+    segments_width::<true>(s, explain::explain_str_cjk(s))
+}
+
+fn segments_width<const IS_CJK: bool>(s: &str, segments: ExplainStr<'_>) -> usize {
+    segments
+        .map(|(range, width, rule)| {
+            let segment = &s[range];
+            match rule {
+                // A ZWJ sequence that starts with a modifier sequence is explained as one.
+                Rule::EmojiZwjSequence | Rule::EmojiModifierSequence
+                    if segment.contains('\u{200D}') && !is_rgi_emoji(segment) =>
+                {
+                    zwj_fallback_width::<IS_CJK>(segment)
+                }
+                _ => width,
+            }
+        })
+        .fold(0, usize::wrapping_add)
+}
+
+/// Measures a ZWJ sequence that isn't RGI as the runs of its parts that are.
+fn zwj_fallback_width<const IS_CJK: bool>(mut rest: &str) -> usize {
+    let mut width = 0usize;
+    while !rest.is_empty() {
+        let end = core::iter::once(rest.len())
+            .chain(rest.rmatch_indices('\u{200D}').map(|(i, _)| i))
+            .find(|&end| is_rgi_emoji(&rest[..end]))
+            .unwrap_or_else(|| rest.find('\u{200D}').unwrap_or(rest.len()));
+        width = width.wrapping_add(lookup::str_slice_width::<IS_CJK>(&rest[..end]));
+        rest = &rest[end..];
+        rest = rest.strip_prefix('\u{200D}').unwrap_or(rest);
+    }
+    width
+}
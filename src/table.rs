@@ -0,0 +1,862 @@
+// Copyright 2012-2026 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tables of text whose columns are aligned by displayed width.
+//!
+//! A [`Table`] lays out rows of cells, with the width of each column fitted to its
+//! widest cell. Cells may span several lines, separated by `'\n'`.
+//!
+//! ```rust
+//! use unicode_width::table::{Align, Borders, Column, Table};
+//!
+//! let mut table = Table::new();
+//! table.borders = Borders::ASCII;
+//! table.columns[1].align = Align::Right;
+//! let mut out = String::new();
+//! table
+//!     .write(&mut out, &[["名前", "Size"], ["日本語.txt", "12"], ["a.txt", "3456"]])
+//!     .unwrap();
+//! assert_eq!(
+//!     out,
+//!     "\
+//! +------------+------+
+//! | 名前       | Size |
+//! +------------+------+
+//! | 日本語.txt |   12 |
+//! | a.txt      | 3456 |
+//! +------------+------+
+//! "
+//! );
+//! ```
+//!
+//! [`DynTable`] does the same for rows whose number of cells is only known at run time.
+//!
+//! [`columnize`] arranges a list of items into a [`Grid`] of as many columns as fit
+//! on a line, and [`format_markdown`] realigns the pipe tables of an existing Markdown document.
+
+use core::fmt::{self, Write};
+use core::str::Lines;
+
+use crate::lookup;
+use crate::padded::{self, Padded};
+
+/// How the text of a cell is placed within its column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Align {
+    /// Padded on the right.
+    #[default]
+    Left,
+    /// Padded on both sides, with any odd column of padding on the right.
+    Center,
+    /// Padded on the left.
+    Right,
+}
+
+/// The layout of a single column of a [`Table`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Column {
+    /// How the text of each cell is placed within the column.
+    pub align: Align,
+    /// The column is at least this wide, even if all of its cells are narrower.
+    pub min_width: usize,
+    /// The column is at most this wide. Lines of a cell that are wider are cut short
+    /// at the end of the last whole sequence that fits, like [`Padded`] does.
+    pub max_width: Option<usize>,
+}
+
+impl Column {
+    /// A left-aligned column that is exactly as wide as its widest cell.
+    pub const DEFAULT: Column = Column {
+        align: Align::Left,
+        min_width: 0,
+        max_width: None,
+    };
+}
+
+impl Default for Column {
+    fn default() -> Self {
+        Column::DEFAULT
+    }
+}
+
+/// A horizontal line drawn across a [`Table`].
+///
+/// Each part should be as wide as the corresponding part of [`Borders`],
+/// so that the junctions line up with the column separators.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HorizontalLine<'a> {
+    /// Drawn at the left edge, in place of [`Borders::left`].
+    pub left: &'a str,
+    /// Repeated across the width of each column.
+    pub fill: char,
+    /// Drawn between columns, in place of [`Borders::separator`].
+    pub junction: &'a str,
+    /// Drawn at the right edge, in place of [`Borders::right`].
+    pub right: &'a str,
+}
+
+/// The strings drawn around and between the cells of a [`Table`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Borders<'a> {
+    /// Drawn before the first cell of each line.
+    pub left: &'a str,
+    /// Drawn between the cells of each line.
+    pub separator: &'a str,
+    /// Drawn after the last cell of each line.
+    pub right: &'a str,
+    /// Drawn above the first row.
+    pub top: Option<HorizontalLine<'a>>,
+    /// Drawn between the first row and the rest, to set it apart as a header.
+    pub header: Option<HorizontalLine<'a>>,
+    /// Drawn below the last row.
+    pub bottom: Option<HorizontalLine<'a>>,
+}
+
+impl Borders<'static> {
+    /// Columns separated by two spaces, with no lines.
+    pub const NONE: Borders<'static> = Borders {
+        left: "",
+        separator: "  ",
+        right: "",
+        top: None,
+        header: None,
+        bottom: None,
+    };
+
+    /// Borders drawn with `|`, `-`, and `+`.
+    pub const ASCII: Borders<'static> = {
+        const LINE: HorizontalLine<'static> = HorizontalLine {
+            left: "+-",
+            fill: '-',
+            junction: "-+-",
+            right: "-+",
+        };
+        Borders {
+            left: "| ",
+            separator: " | ",
+            right: " |",
+            top: Some(LINE),
+            header: Some(LINE),
+            bottom: Some(LINE),
+        }
+    };
+
+    /// Borders drawn with box-drawing characters.
+    ///
+    /// These characters are ambiguous-width,
+    /// so this style only lines up outside of an East Asian context.
+    pub const BOX: Borders<'static> = Borders {
+        left: "│ ",
+        separator: " │ ",
+        right: " │",
+        top: Some(HorizontalLine {
+            left: "┌─",
+            fill: '─',
+            junction: "─┬─",
+            right: "─┐",
+        }),
+        header: Some(HorizontalLine {
+            left: "├─",
+            fill: '─',
+            junction: "─┼─",
+            right: "─┤",
+        }),
+        bottom: Some(HorizontalLine {
+            left: "└─",
+            fill: '─',
+            junction: "─┴─",
+            right: "─┘",
+        }),
+    };
+}
+
+/// Whether text is measured like [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width)
+/// or like [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Mode {
+    #[cfg(feature = "cjk")]
+    is_cjk: bool,
+}
+
+impl Mode {
+    const DEFAULT: Mode = Mode {
+        #[cfg(feature = "cjk")]
+        is_cjk: false,
+    };
+
+    #[cfg(feature = "cjk")]
+    const CJK: Mode = Mode { is_cjk: true };
+
+    fn width(self, s: &str) -> usize {
+        #[cfg(feature = "cjk")]
+        if self.is_cjk {
+            return lookup::str_slice_width::<true>(s);
+        }
+        lookup::str_slice_width::<false>(s)
+    }
+
+    fn char_width(self, c: char) -> Option<usize> {
+        #[cfg(feature = "cjk")]
+        if self.is_cjk {
+            return lookup::single_char_width_cjk(c);
+        }
+        lookup::single_char_width(c)
+    }
+
+    fn padded(self, s: &str) -> Padded<'_> {
+        #[cfg(feature = "cjk")]
+        if self.is_cjk {
+            return Padded::new_cjk(s);
+        }
+        Padded::new(s)
+    }
+
+    /// Writes `text` aligned within `width` columns, cut short if it is wider.
+    /// If `trim` is set, no padding is written after the text.
+    fn write_cell<W: Write + ?Sized>(
+        self,
+        out: &mut W,
+        text: &str,
+        align: Align,
+        width: usize,
+        trim: bool,
+    ) -> fmt::Result {
+        let text = self.padded(text);
+        match align {
+            Align::Left if trim => write!(out, "{text:.width$}"),
+            Align::Left => write!(out, "{text:<width$.width$}"),
+            Align::Center => write!(out, "{text:^width$.width$}"),
+            Align::Right => write!(out, "{text:>width$.width$}"),
+        }
+    }
+}
+
+/// Lays out rows of `N` cells each, so that every column is as wide as its widest cell.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Clone, Copy, Debug)]
+pub struct Table<'a, const N: usize> {
+    /// The layout of each column.
+    pub columns: [Column; N],
+    /// The strings drawn around and between the cells.
+    pub borders: Borders<'a>,
+    mode: Mode,
+}
+
+impl<'a, const N: usize> Table<'a, N> {
+    /// Creates a table of [`Column::DEFAULT`] columns with [`Borders::NONE`], whose cells
+    /// are measured like [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+    pub const fn new() -> Self {
+        Table {
+            columns: [Column::DEFAULT; N],
+            borders: Borders::NONE,
+            mode: Mode::DEFAULT,
+        }
+    }
+
+    /// Like [`Table::new`], but the cells are measured like
+    /// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+    #[cfg(feature = "cjk")]
+    pub const fn new_cjk() -> Self {
+        Table {
+            mode: Mode::CJK,
+            ..Table::new()
+        }
+    }
+
+    fn layout(&self) -> Layout<'_, 'a> {
+        Layout {
+            columns: &self.columns,
+            borders: &self.borders,
+            mode: self.mode,
+        }
+    }
+
+    /// Returns the width each column would be given if the table had the given `rows`,
+    /// not counting the borders.
+    pub fn column_widths(&self, rows: &[[&str; N]]) -> [usize; N] {
+        let mut widths = [0; N];
+        self.layout().fit_widths(&mut widths, rows);
+        widths
+    }
+
+    /// Writes the table with the given `rows` to `out`, ending each line with `'\n'`.
+    ///
+    /// If [`Borders::right`] is empty, left-aligned cells in the last column are not
+    /// padded, so that no line ends in trailing whitespace.
+    pub fn write<W: Write + ?Sized>(&self, out: &mut W, rows: &[[&str; N]]) -> fmt::Result {
+        let widths = self.column_widths(rows);
+        self.layout()
+            .write(out, rows, &widths, &mut [""; N].map(str::lines))
+    }
+}
+
+impl<const N: usize> Default for Table<'_, N> {
+    fn default() -> Self {
+        Table::new()
+    }
+}
+
+/// The most columns a [`DynTable`] can have.
+const MAX_TABLE_COLUMNS: usize = 64;
+
+/// Like [`Table`], but with the number of columns set when the table is made
+/// rather than at compile time, for rows whose length is only known at run time.
+///
+/// The rows can be any slices of strings, such as a `Vec<Vec<String>>`,
+/// but each of them must have one cell per column.
+///
+/// ```rust
+/// use unicode_width::table::{Align, Column, DynTable};
+///
+/// let header = ["Name", "Size"];
+/// let columns = header.map(|_| Column::DEFAULT);
+/// let mut rows = vec![header.map(String::from).to_vec()];
+/// rows.push(vec!["日本語.txt".to_string(), "12".to_string()]);
+/// let mut out = String::new();
+/// DynTable::new(&columns).write(&mut out, &rows).unwrap();
+/// assert_eq!(out, "Name        Size\n日本語.txt  12\n");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DynTable<'a> {
+    /// The layout of each column.
+    pub columns: &'a [Column],
+    /// The strings drawn around and between the cells.
+    pub borders: Borders<'a>,
+    mode: Mode,
+}
+
+impl<'a> DynTable<'a> {
+    /// Creates a table with the given `columns` and [`Borders::NONE`], whose cells
+    /// are measured like [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than 64 columns.
+    pub const fn new(columns: &'a [Column]) -> Self {
+        assert!(
+            columns.len() <= MAX_TABLE_COLUMNS,
+            "a table can have at most 64 columns"
+        );
+        DynTable {
+            columns,
+            borders: Borders::NONE,
+            mode: Mode::DEFAULT,
+        }
+    }
+
+    /// Like [`DynTable::new`], but the cells are measured like
+    /// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+    #[cfg(feature = "cjk")]
+    pub const fn new_cjk(columns: &'a [Column]) -> Self {
+        DynTable {
+            mode: Mode::CJK,
+            ..DynTable::new(columns)
+        }
+    }
+
+    fn layout(&self) -> Layout<'_, 'a> {
+        Layout {
+            columns: self.columns,
+            borders: &self.borders,
+            mode: self.mode,
+        }
+    }
+
+    /// Writes the table with the given `rows` to `out`, like [`Table::write`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a row doesn't have one cell per column.
+    pub fn write<W, R, S>(&self, out: &mut W, rows: &[R]) -> fmt::Result
+    where
+        W: Write + ?Sized,
+        R: AsRef<[S]>,
+        S: AsRef<str>,
+    {
+        for row in rows {
+            assert_eq!(
+                row.as_ref().len(),
+                self.columns.len(),
+                "a table row must have one cell per column"
+            );
+        }
+        let n = self.columns.len();
+        let mut widths = [0; MAX_TABLE_COLUMNS];
+        self.layout().fit_widths(&mut widths[..n], rows);
+        let mut lines = [""; MAX_TABLE_COLUMNS].map(str::lines);
+        self.layout()
+            .write(out, rows, &widths[..n], &mut lines[..n])
+    }
+}
+
+/// The columns, borders, and mode of a [`Table`] or [`DynTable`].
+struct Layout<'t, 'a> {
+    columns: &'t [Column],
+    borders: &'t Borders<'a>,
+    mode: Mode,
+}
+
+impl Layout<'_, '_> {
+    /// Fits `widths` to the widest lines of the cells of `rows`,
+    /// within the bounds of each column.
+    fn fit_widths<R: AsRef<[S]>, S: AsRef<str>>(&self, widths: &mut [usize], rows: &[R]) {
+        for row in rows {
+            for (width, cell) in widths.iter_mut().zip(row.as_ref()) {
+                for line in cell.as_ref().lines() {
+                    *width = (*width).max(self.mode.width(line));
+                }
+            }
+        }
+        for (width, column) in widths.iter_mut().zip(self.columns) {
+            if let Some(max_width) = column.max_width {
+                *width = (*width).min(max_width);
+            }
+            *width = (*width).max(column.min_width);
+        }
+    }
+
+    /// Writes `rows` with the columns `widths` wide, using `lines`,
+    /// which has one slot per column, to go through the lines of each row.
+    fn write<'s, W, R, S>(
+        &self,
+        out: &mut W,
+        rows: &'s [R],
+        widths: &[usize],
+        lines: &mut [Lines<'s>],
+    ) -> fmt::Result
+    where
+        W: Write + ?Sized,
+        R: AsRef<[S]>,
+        S: AsRef<str> + 's,
+    {
+        if let Some(line) = &self.borders.top {
+            self.write_line(out, line, widths)?;
+        }
+        for (i, row) in rows.iter().enumerate() {
+            if i == 1 {
+                if let Some(line) = &self.borders.header {
+                    self.write_line(out, line, widths)?;
+                }
+            }
+            for (cell_lines, cell) in lines.iter_mut().zip(row.as_ref()) {
+                *cell_lines = cell.as_ref().lines();
+            }
+            self.write_row(out, lines, widths)?;
+        }
+        if let Some(line) = &self.borders.bottom {
+            self.write_line(out, line, widths)?;
+        }
+        Ok(())
+    }
+
+    fn write_row<W: Write + ?Sized>(
+        &self,
+        out: &mut W,
+        lines: &mut [Lines<'_>],
+        widths: &[usize],
+    ) -> fmt::Result {
+        let height = lines
+            .iter()
+            .map(|cell_lines| cell_lines.clone().count())
+            .max();
+        for _ in 0..height.unwrap_or(0).max(1) {
+            out.write_str(self.borders.left)?;
+            for (j, cell_lines) in lines.iter_mut().enumerate() {
+                if j > 0 {
+                    out.write_str(self.borders.separator)?;
+                }
+                let trim = j + 1 == widths.len() && self.borders.right.is_empty();
+                let text = cell_lines.next().unwrap_or("");
+                self.mode
+                    .write_cell(out, text, self.columns[j].align, widths[j], trim)?;
+            }
+            out.write_str(self.borders.right)?;
+            out.write_char('\n')?;
+        }
+        Ok(())
+    }
+
+    fn write_line<W: Write + ?Sized>(
+        &self,
+        out: &mut W,
+        line: &HorizontalLine<'_>,
+        widths: &[usize],
+    ) -> fmt::Result {
+        let fill_width = self.mode.char_width(line.fill);
+        out.write_str(line.left)?;
+        for (j, &width) in widths.iter().enumerate() {
+            if j > 0 {
+                out.write_str(line.junction)?;
+            }
+            padded::write_fill(out, line.fill, fill_width, width)?;
+        }
+        out.write_str(line.right)?;
+        out.write_char('\n')
+    }
+}
+
+/// The most columns a Markdown table can have for [`format_markdown`] to realign it.
+const MAX_MARKDOWN_COLUMNS: usize = 64;
+
+/// Writes the Markdown document `markdown` to `out`, with the cells of each
+/// [pipe table](https://github.github.com/gfm/#tables-extension-) padded so that
+/// their columns line up, and all other lines left as they are.
+///
+/// Each column is aligned as its delimiter row says (`:--`, `:-:`, or `--:`).
+/// A table is recognized by a header row and a delimiter row that both contain `|`
+/// and have the same number of cells, and it ends at the first line without a `|`.
+/// The header sets the number of columns: rows with fewer cells are filled out with
+/// empty ones, and cells past the last column are written after it, unpadded.
+/// Tables inside fenced code blocks, and tables of more than 64 columns, are left as they are.
+///
+/// ```rust
+/// let mut out = String::new();
+/// unicode_width::table::format_markdown(&mut out, "\
+/// | Name | Size |
+/// |-|-:|
+/// | 日本語.txt | 12 |
+/// ").unwrap();
+/// assert_eq!(out, "\
+/// | Name       | Size |
+/// | ---------- | ---: |
+/// | 日本語.txt |   12 |
+/// ");
+/// ```
+pub fn format_markdown<W: Write + ?Sized>(out: &mut W, markdown: &str) -> fmt::Result {
+    format_markdown_in(Mode::DEFAULT, out, markdown)
+}
+
+/// Like [`format_markdown`], but measures the cells like
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(feature = "cjk")]
+pub fn format_markdown_cjk<W: Write + ?Sized>(out: &mut W, markdown: &str) -> fmt::Result {
+    format_markdown_in(Mode::CJK, out, markdown)
+}
+
+fn format_markdown_in<W: Write + ?Sized>(mode: Mode, out: &mut W, markdown: &str) -> fmt::Result {
+    let mut rest = markdown;
+    let mut fence = None;
+    while !rest.is_empty() {
+        let (line, after) = split_line(rest);
+        let trimmed = line.trim_start_matches(' ');
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+        } else if let Some(marker) = ["```", "~~~"].into_iter().find(|&m| trimmed.starts_with(m)) {
+            fence = Some(marker);
+        } else if let Some(len) = markdown_table_len(rest) {
+            let (table, after) = rest.split_at(len);
+            if !write_markdown_table(mode, out, table)? {
+                out.write_str(table)?;
+            }
+            rest = after;
+            continue;
+        }
+        out.write_str(line)?;
+        rest = after;
+    }
+    Ok(())
+}
+
+/// Splits off the first line of `s`, including its line terminator.
+fn split_line(s: &str) -> (&str, &str) {
+    s.split_at(s.find('\n').map_or(s.len(), |i| i + 1))
+}
+
+/// Removes the line terminator from `line`, and returns both.
+fn strip_terminator(line: &str) -> (&str, &str) {
+    let content = line.trim_end_matches(['\n', '\r']);
+    (content, &line[content.len()..])
+}
+
+/// Returns the unescaped cells of a row of a Markdown table, trimmed of whitespace.
+fn markdown_cells(line: &str) -> impl Iterator<Item = &str> {
+    let mut line = line.trim();
+    line = line.strip_prefix('|').unwrap_or(line);
+    if line.ends_with('|') && !line.ends_with("\\|") {
+        line = &line[..line.len() - 1];
+    }
+    let mut rest = Some(line);
+    core::iter::from_fn(move || {
+        let s = rest?;
+        let bytes = s.as_bytes();
+        let end = (0..bytes.len()).find(|&i| bytes[i] == b'|' && (i == 0 || bytes[i - 1] != b'\\'));
+        match end {
+            Some(end) => {
+                rest = Some(&s[end + 1..]);
+                Some(s[..end].trim())
+            }
+            None => {
+                rest = None;
+                Some(s.trim())
+            }
+        }
+    })
+}
+
+/// Parses a cell of the delimiter row of a Markdown table.
+/// Returns `None` if it is not one, and `Some(None)` if it doesn't specify an alignment.
+fn markdown_align(cell: &str) -> Option<Option<Align>> {
+    let left = cell.starts_with(':');
+    let right = cell.len() > 1 && cell.ends_with(':');
+    let dashes = &cell[usize::from(left)..cell.len() - usize::from(right)];
+    if dashes.is_empty() || dashes.bytes().any(|b| b != b'-') {
+        return None;
+    }
+    Some(match (left, right) {
+        (false, false) => None,
+        (true, false) => Some(Align::Left),
+        (true, true) => Some(Align::Center),
+        (false, true) => Some(Align::Right),
+    })
+}
+
+/// If `s` starts with a Markdown table, returns its length in bytes.
+fn markdown_table_len(s: &str) -> Option<usize> {
+    let (header, rest) = split_line(s);
+    let (delimiter, mut rest) = split_line(rest);
+    if !header.contains('|')
+        || !delimiter.contains('|')
+        || markdown_cells(delimiter).any(|cell| markdown_align(cell).is_none())
+        || markdown_cells(header).count() != markdown_cells(delimiter).count()
+    {
+        return None;
+    }
+    let mut len = header.len() + delimiter.len();
+    while !rest.is_empty() {
+        let (row, after) = split_line(rest);
+        if !row.contains('|') {
+            break;
+        }
+        len += row.len();
+        rest = after;
+    }
+    Some(len)
+}
+
+/// Writes the Markdown table `table` with its columns aligned.
+/// Returns `false`, having written nothing, if it has too many columns.
+fn write_markdown_table<W: Write + ?Sized>(
+    mode: Mode,
+    out: &mut W,
+    table: &str,
+) -> Result<bool, fmt::Error> {
+    let mut widths = [3; MAX_MARKDOWN_COLUMNS];
+    let mut aligns = [None; MAX_MARKDOWN_COLUMNS];
+    // The header sets the number of columns. Cells past it in the rows below
+    // are written as they are, after the aligned ones.
+    let num_columns = markdown_cells(split_line(table).0).count();
+    if num_columns > MAX_MARKDOWN_COLUMNS {
+        return Ok(false);
+    }
+    for (i, line) in table.lines().enumerate() {
+        for (j, cell) in markdown_cells(line).take(num_columns).enumerate() {
+            if i == 1 {
+                aligns[j] = markdown_align(cell).flatten();
+            } else {
+                widths[j] = widths[j].max(mode.width(cell));
+            }
+        }
+    }
+    let (widths, aligns) = (&widths[..num_columns], &aligns[..num_columns]);
+
+    for (i, line) in table.split_inclusive('\n').enumerate() {
+        let (line, terminator) = strip_terminator(line);
+        let mut cells = markdown_cells(line);
+        out.write_char('|')?;
+        for (&width, &align) in widths.iter().zip(aligns) {
+            out.write_char(' ')?;
+            if i == 1 {
+                let (left, right) = match align {
+                    None => ("-", "-"),
+                    Some(Align::Left) => (":", "-"),
+                    Some(Align::Center) => (":", ":"),
+                    Some(Align::Right) => ("-", ":"),
+                };
+                out.write_str(left)?;
+                padded::write_fill(out, '-', Some(1), width - 2)?;
+                out.write_str(right)?;
+            } else {
+                let cell = cells.next().unwrap_or("");
+                mode.write_cell(out, cell, align.unwrap_or_default(), width, false)?;
+            }
+            out.write_str(" |")?;
+        }
+        if i != 1 {
+            for cell in cells {
+                write!(out, " {cell} |")?;
+            }
+        }
+        out.write_str(terminator)?;
+    }
+    Ok(true)
+}
+
+/// The order in which [`columnize`] places items in a [`Grid`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// Down each column in turn, like `ls`.
+    #[default]
+    ColumnMajor,
+    /// Across each row in turn, like `ls -x`.
+    RowMajor,
+}
+
+/// The number of spaces between the columns of a [`Grid`].
+const GRID_SEPARATOR_WIDTH: usize = 2;
+
+/// The most columns that [`columnize`] places items in.
+const MAX_GRID_COLUMNS: usize = 256;
+
+/// A list of items arranged into rows and columns by [`columnize`].
+///
+/// The grid doesn't hold on to the items,
+/// so the same items must be passed to the methods that need them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Grid {
+    /// The number of columns that hold at least one item.
+    pub columns: usize,
+    /// The number of rows. Only the last row or column can be partly empty.
+    pub rows: usize,
+    /// The order in which the items are placed.
+    pub order: Order,
+    len: usize,
+    mode: Mode,
+}
+
+/// Arranges `items` into the fewest rows that fit within `line_width` columns,
+/// with two spaces between columns, the way `ls` and `column` lay out file names.
+///
+/// Each column is as wide as its widest item. If even a single column is wider
+/// than `line_width`, the items are placed in one column regardless.
+/// At most 256 columns are used, however wide the line.
+///
+/// ```rust
+/// use unicode_width::table::{columnize, Order};
+///
+/// let items = ["日本語.txt", "a", "bb", "ccc", "d"];
+/// let grid = columnize(&items, 20, Order::ColumnMajor);
+/// assert_eq!((grid.columns, grid.rows), (3, 2));
+/// let mut out = String::new();
+/// grid.write(&mut out, &items).unwrap();
+/// assert_eq!(out, "日本語.txt  bb   d\na           ccc\n");
+/// ```
+pub fn columnize<S: AsRef<str>>(items: &[S], line_width: usize, order: Order) -> Grid {
+    columnize_in(Mode::DEFAULT, items, line_width, order)
+}
+
+/// Like [`columnize`], but measures the items like
+/// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(feature = "cjk")]
+pub fn columnize_cjk<S: AsRef<str>>(items: &[S], line_width: usize, order: Order) -> Grid {
+    columnize_in(Mode::CJK, items, line_width, order)
+}
+
+fn columnize_in<S: AsRef<str>>(mode: Mode, items: &[S], line_width: usize, order: Order) -> Grid {
+    // Every column is at least one column wide, plus the separator.
+    let max_columns = (line_width + GRID_SEPARATOR_WIDTH) / (1 + GRID_SEPARATOR_WIDTH);
+    let max_columns = max_columns.min(MAX_GRID_COLUMNS);
+    for columns in (1..=max_columns.min(items.len())).rev() {
+        let grid = Grid::with_columns(mode, items.len(), columns, order);
+        // Column-major grids with this many columns can end up with fewer of them,
+        // in which case they were already tried.
+        if grid.columns == columns && grid.width(items) <= line_width {
+            return grid;
+        }
+    }
+    Grid::with_columns(mode, items.len(), 1, order)
+}
+
+impl Grid {
+    fn with_columns(mode: Mode, len: usize, columns: usize, order: Order) -> Grid {
+        let rows = (len + columns - 1) / columns;
+        let columns = match order {
+            Order::ColumnMajor if rows > 0 => (len + rows - 1) / rows,
+            _ => columns.min(len),
+        };
+        Grid {
+            columns,
+            rows,
+            order,
+            len,
+            mode,
+        }
+    }
+
+    /// Returns the index of the item at the given row and column, if there is one.
+    pub fn index(&self, row: usize, column: usize) -> Option<usize> {
+        if row >= self.rows || column >= self.columns {
+            return None;
+        }
+        let index = match self.order {
+            Order::ColumnMajor => column * self.rows + row,
+            Order::RowMajor => row * self.columns + column,
+        };
+        (index < self.len).then_some(index)
+    }
+
+    /// Returns the width of the widest item in the given column.
+    pub fn column_width<S: AsRef<str>>(&self, items: &[S], column: usize) -> usize {
+        (0..self.rows)
+            .filter_map(|row| self.index(row, column))
+            .map(|i| self.mode.width(items[i].as_ref()))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the widths of all the columns, measuring each item once.
+    fn column_widths<S: AsRef<str>>(&self, items: &[S]) -> [usize; MAX_GRID_COLUMNS] {
+        let mut widths = [0; MAX_GRID_COLUMNS];
+        for (i, item) in items[..self.len].iter().enumerate() {
+            let column = match self.order {
+                Order::ColumnMajor => i / self.rows,
+                Order::RowMajor => i % self.columns,
+            };
+            widths[column] = widths[column].max(self.mode.width(item.as_ref()));
+        }
+        widths
+    }
+
+    /// Returns the width of the widest row, including the space between columns.
+    pub fn width<S: AsRef<str>>(&self, items: &[S]) -> usize {
+        self.column_widths(items)[..self.columns]
+            .iter()
+            .sum::<usize>()
+            + GRID_SEPARATOR_WIDTH * self.columns.saturating_sub(1)
+    }
+
+    /// Writes the items to `out`, one row per line, ending each line with `'\n'`.
+    /// No line ends in trailing whitespace.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `items` is shorter than the list the grid was made for.
+    pub fn write<W: Write + ?Sized, S: AsRef<str>>(&self, out: &mut W, items: &[S]) -> fmt::Result {
+        let widths = self.column_widths(items);
+        for row in 0..self.rows {
+            for (column, &width) in widths[..self.columns].iter().enumerate() {
+                let Some(i) = self.index(row, column) else {
+                    break;
+                };
+                let item = items[i].as_ref();
+                if self.index(row, column + 1).is_some() {
+                    let width = width + GRID_SEPARATOR_WIDTH;
+                    self.mode.write_cell(out, item, Align::Left, width, false)?;
+                } else {
+                    out.write_str(item)?;
+                }
+            }
+            out.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
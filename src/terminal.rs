@@ -0,0 +1,312 @@
+// Copyright 2012-2026 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt::{self, Write};
+use core::iter::{FusedIterator, Peekable};
+
+use crate::explain::Segments;
+use crate::lookup;
+
+/// The distance between tab stops.
+const TAB_WIDTH: usize = 8;
+
+/// How far into an escape sequence the text written so far has got.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Escape {
+    None,
+    /// After `ESC`, and any intermediate bytes.
+    Esc,
+    /// In a control sequence, after `ESC [`.
+    Csi,
+    /// In a control string, such as an operating system command after `ESC ]`.
+    Str,
+    /// After an `ESC` in a control string, which may start the `ESC \` that ends it.
+    StrEsc,
+}
+
+/// Tracks where the cursor of a terminal with a given number of columns ends up
+/// as text is written to it, following the way VT-compatible terminals wrap lines.
+///
+/// Text is placed one segment at a time, where a segment is a sequence of characters
+/// that [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width) measures as a unit.
+///
+/// - Writing into the last column of a row leaves the cursor there, with a wrap pending.
+///   The next segment with a nonzero width moves to the start of the next row first.
+/// - A segment that doesn't fit in what is left of the row, such as a wide character
+///   in the last column, moves to the start of the next row, leaving the rest of the
+///   row empty. A segment wider than the whole screen runs on into the rows after it.
+/// - Zero-width segments, like combining marks, attach to the previous cell
+///   and don't move the cursor.
+/// - `'\r'` moves to the start of the row, and `'\n'` moves to the start of the next row,
+///   as it does through a terminal device with the usual `onlcr` setting.
+/// - `'\u{8}'` BACKSPACE moves one column to the left, and `'\t'` moves to the next tab stop
+///   (every 8 columns), or stays in the last column, without leaving the row.
+///   Both cancel a pending wrap.
+/// - Other control characters, and escape sequences such as those that set colors,
+///   are skipped. Escape sequences that move the cursor are not followed.
+///
+/// Segments split across calls to [`write_str`](fmt::Write::write_str) are placed
+/// as separate segments.
+///
+/// ```rust
+/// use core::fmt::Write;
+/// use unicode_width::TerminalScreen;
+///
+/// let mut screen = TerminalScreen::new(10);
+/// write!(screen, "\x1b[1m123456789日本\x1b[0m").unwrap();
+/// // "日" doesn't fit in the last column, so it goes on the next row.
+/// assert_eq!(screen.cursor(), (1, 4));
+/// assert_eq!(screen.rows(), 2);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TerminalScreen {
+    columns: usize,
+    row: usize,
+    column: usize,
+    wrap_pending: bool,
+    escape: Escape,
+    #[cfg(feature = "cjk")]
+    is_cjk: bool,
+}
+
+impl TerminalScreen {
+    /// Creates a screen `columns` columns wide, with the cursor at the top left,
+    /// whose text is measured like [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `columns` is zero.
+    pub const fn new(columns: usize) -> Self {
+        assert!(columns > 0, "a terminal screen needs at least one column");
+        TerminalScreen {
+            columns,
+            row: 0,
+            column: 0,
+            wrap_pending: false,
+            escape: Escape::None,
+            #[cfg(feature = "cjk")]
+            is_cjk: false,
+        }
+    }
+
+    /// Like [`TerminalScreen::new`], but the text is measured like
+    /// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+    #[cfg(feature = "cjk")]
+    pub const fn new_cjk(columns: usize) -> Self {
+        TerminalScreen {
+            is_cjk: true,
+            ..TerminalScreen::new(columns)
+        }
+    }
+
+    /// Returns the number of columns of the screen.
+    pub const fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the row and column of the cursor, counting from 0.
+    ///
+    /// While a wrap is pending, the cursor stays in the last column.
+    pub const fn cursor(&self) -> (usize, usize) {
+        (self.row, self.column)
+    }
+
+    /// Returns whether the cursor is in the last column of a row that has been filled,
+    /// so that the next character to be printed will go on the next row.
+    pub const fn is_wrap_pending(&self) -> bool {
+        self.wrap_pending
+    }
+
+    /// Returns the number of rows that the text written so far reaches,
+    /// including the row that the cursor is on.
+    pub const fn rows(&self) -> usize {
+        self.row + 1
+    }
+
+    fn segments<'a>(&self, s: &'a str) -> Segments<'a> {
+        #[cfg(feature = "cjk")]
+        if self.is_cjk {
+            return Segments::new_cjk(s);
+        }
+        Segments::new(s)
+    }
+
+    fn char_width(&self, c: char) -> usize {
+        #[cfg(feature = "cjk")]
+        if self.is_cjk {
+            return lookup::single_char_width_cjk(c).unwrap_or(0);
+        }
+        lookup::single_char_width(c).unwrap_or(0)
+    }
+
+    /// Feeds `c` to the escape sequence parser.
+    /// Returns whether it was part of an escape sequence.
+    fn escape(&mut self, c: char) -> bool {
+        self.escape = match (self.escape, c) {
+            (Escape::None, '\u{1B}') => Escape::Esc,
+            (Escape::None, _) => return false,
+            (Escape::Esc, '[') => Escape::Csi,
+            (Escape::Esc, ']' | 'P' | 'X' | '^' | '_') => Escape::Str,
+            (Escape::Esc, '\u{20}'..='\u{2F}') => Escape::Esc,
+            (Escape::Csi, '\u{40}'..='\u{7E}') => Escape::None,
+            (Escape::Csi, _) => Escape::Csi,
+            (Escape::Str, '\u{7}') => Escape::None,
+            (Escape::Str, '\u{1B}') => Escape::StrEsc,
+            (Escape::Str, _) => Escape::Str,
+            (Escape::Esc | Escape::StrEsc, _) => Escape::None,
+        };
+        true
+    }
+
+    fn control(&mut self, c: char) {
+        match c {
+            '\r' => self.column = 0,
+            '\n' => {
+                self.row += 1;
+                self.column = 0;
+            }
+            '\u{8}' => self.column = self.column.saturating_sub(1),
+            '\t' => {
+                self.column = (self.column / TAB_WIDTH + 1) * TAB_WIDTH;
+                self.column = self.column.min(self.columns - 1);
+            }
+            _ => return,
+        }
+        self.wrap_pending = false;
+    }
+
+    fn print(&mut self, width: usize) {
+        if width == 0 {
+            return;
+        }
+        if self.wrap_pending || (self.column > 0 && self.column + width > self.columns) {
+            self.row += 1;
+            self.column = 0;
+            self.wrap_pending = false;
+        }
+        // A segment wider than the screen runs on into the rows after it.
+        self.row += (width - 1) / self.columns;
+        self.column += (width - 1) % self.columns + 1;
+        if self.column == self.columns {
+            self.column -= 1;
+            self.wrap_pending = true;
+        }
+    }
+}
+
+impl Write for TerminalScreen {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for (range, width) in self.segments(s) {
+            let segment = &s[range];
+            if self.escape != Escape::None || segment.chars().any(char::is_control) {
+                // Control characters are segments of their own, except for "\r\n",
+                // but escape sequences can have any characters in them.
+                for c in segment.chars() {
+                    if !self.escape(c) {
+                        if c.is_control() {
+                            self.control(c);
+                        } else {
+                            self.print(self.char_width(c));
+                        }
+                    }
+                }
+            } else {
+                self.print(width);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One cell of a terminal screen, as yielded by [`cells`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cell<'a> {
+    /// The first cell of a segment, which is `width` columns wide.
+    ///
+    /// `text` holds the whole segment, along with any zero-width segments after it.
+    Start {
+        /// The text to be drawn starting at this cell.
+        text: &'a str,
+        /// The number of cells that `text` covers.
+        width: usize,
+    },
+    /// A cell covered by the [`Cell::Start`] before it.
+    Continuation,
+}
+
+/// Iterator over the cells of a string, returned by [`cells`].
+#[derive(Clone, Debug)]
+pub struct Cells<'a> {
+    s: &'a str,
+    segments: Peekable<Segments<'a>>,
+    /// The number of [`Cell::Continuation`]s left to yield for the last segment.
+    continuations: usize,
+}
+
+impl<'a> Iterator for Cells<'a> {
+    type Item = Cell<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.continuations > 0 {
+            self.continuations -= 1;
+            return Some(Cell::Continuation);
+        }
+        let (range, width) = self.segments.next()?;
+        let mut end = range.end;
+        while let Some((_, 0)) = self.segments.peek() {
+            if let Some((next, _)) = self.segments.next() {
+                end = next.end;
+            }
+        }
+        self.continuations = width.saturating_sub(1);
+        Some(Cell::Start {
+            text: &self.s[range.start..end],
+            width,
+        })
+    }
+}
+
+impl FusedIterator for Cells<'_> {}
+
+/// Returns an iterator over the cells that `s` covers when laid out on a single row,
+/// measured like [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+///
+/// Each segment that the width rules measure as a unit, such as a character
+/// with its combining marks, a flag, or a whole emoji ZWJ sequence, yields a [`Cell::Start`]
+/// followed by a [`Cell::Continuation`] for each extra column it is wide.
+/// Zero-width segments are attached to the cell before them, and only get a
+/// [`Cell::Start`] of their own, with a width of 0, at the start of the string.
+///
+/// ```rust
+/// use unicode_width::{cells, Cell};
+///
+/// let mut cells = cells("e\u{301}日");
+/// assert_eq!(cells.next(), Some(Cell::Start { text: "e\u{301}", width: 1 }));
+/// assert_eq!(cells.next(), Some(Cell::Start { text: "日", width: 2 }));
+/// assert_eq!(cells.next(), Some(Cell::Continuation));
+/// assert_eq!(cells.next(), None);
+/// ```
+pub fn cells(s: &str) -> Cells<'_> {
+    Cells {
+        s,
+        segments: Segments::new(s).peekable(),
+        continuations: 0,
+    }
+}
+
+/// Like [`cells`], but measures like [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk).
+#[cfg(feature = "cjk")]
+pub fn cells_cjk(s: &str) -> Cells<'_> {
+    Cells {
+        s,
+        segments: Segments::new_cjk(s).peekable(),
+        continuations: 0,
+    }
+}
@@ -8,14 +8,16 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::lookup::*;
-use crate::width_info::WidthInfo;
-
+// Canonical equivalence only holds with the full tables,
+// so the normalization tests are left out when building with a subset of them.
+#[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
 #[path = "gen/tables_test.rs"]
 mod tables_test;
 
-use tables_test::*;
+#[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+use {crate::lookup::*, crate::width_info::WidthInfo, tables_test::*};
 
+#[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
 fn str_width_test(s: &str, init: WidthInfo) -> isize {
     s.chars()
         .rfold((0, init), |(sum, next_info), c| -> (isize, WidthInfo) {
@@ -25,7 +27,10 @@ fn str_width_test(s: &str, init: WidthInfo) -> isize {
         .0
 }
 
-#[cfg(feature = "cjk")]
+#[cfg(all(
+    feature = "cjk",
+    not(any(feature = "bmp-only", feature = "no-emoji-sequences"))
+))]
 fn str_width_test_cjk(s: &str, init: WidthInfo) -> isize {
     s.chars()
         .rfold((0, init), |(sum, next_info), c| -> (isize, WidthInfo) {
@@ -36,6 +41,7 @@ fn str_width_test_cjk(s: &str, init: WidthInfo) -> isize {
 }
 
 #[test]
+#[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
 fn test_normalization() {
     for &(orig, nfc, nfd, nfkc, nfkd) in &NORMALIZATION_TEST {
         for init in NORMALIZATION_TEST_WIDTHS {
@@ -76,3 +82,147 @@ fn test_normalization() {
         }
     }
 }
+
+#[test]
+fn utf16_chars_both_directions() {
+    use crate::utf16::Utf16Chars;
+    use core::char::{decode_utf16, REPLACEMENT_CHARACTER};
+
+    let units = [
+        0x61, 0xD83D, 0xDC4B, 0xDC4B, 0xD83D, 0xD83D, 0x62, 0xDFFF, 0xDBFF, 0xDFFF, 0xD800,
+    ];
+    for end in 0..=units.len() {
+        for start in 0..=end {
+            let units = &units[start..end];
+            let mut expected = ['\0'; 11];
+            let mut len = 0;
+            for c in decode_utf16(units.iter().copied()) {
+                expected[len] = c.unwrap_or(REPLACEMENT_CHARACTER);
+                len += 1;
+            }
+            let expected = &expected[..len];
+            assert!(Utf16Chars(units).eq(expected.iter().copied()));
+            assert!(Utf16Chars(units).rev().eq(expected.iter().rev().copied()));
+        }
+    }
+}
+
+#[test]
+#[cfg(any(feature = "layout-speed", not(feature = "layout-size")))]
+fn utf8_tables_match_lookup_width() {
+    use crate::lookup::*;
+    use crate::width_info::WidthInfo;
+
+    fn check<const IS_CJK: bool>(lookup: fn(char) -> (u8, WidthInfo)) {
+        let mut buf = [0; 4];
+        let mut from_tables = 0;
+        for c in '\u{80}'..=char::MAX {
+            let bytes = c.encode_utf8(&mut buf).as_bytes();
+            match utf8_suffix_width::<IS_CJK>(bytes) {
+                Some((width, len)) => {
+                    assert!(c > '\u{A0}' && len == bytes.len(), "{c:?}");
+                    assert_eq!(lookup(c), (width, WidthInfo::DEFAULT), "{c:?}");
+                    assert_eq!(
+                        width_in_generic::<IS_CJK>(c, WidthInfo::DEFAULT).0 as u8,
+                        width
+                    );
+                    from_tables += 1;
+                }
+                None => {
+                    // Only characters with special handling are left to the state machine.
+                    assert!(
+                        c <= '\u{A0}'
+                            || c > '\u{FFFF}'
+                            || lookup(c).1 != WidthInfo::DEFAULT
+                            || matches!(c, '\u{2D7F}' | '\u{FF9E}' | '\u{FF9F}')
+                            || matches!(c, '\u{115F}' | '\u{17A4}' | '\u{17D8}')
+                            // The tables are shared with builds that do have emoji sequences.
+                            || cfg!(feature = "no-emoji-sequences"),
+                        "{c:?} missing from the UTF-8 tables"
+                    );
+                }
+            }
+        }
+        assert!(from_tables > 60_000);
+    }
+
+    check::<false>(lookup_width);
+    #[cfg(feature = "cjk")]
+    check::<true>(lookup_width_cjk);
+}
+
+#[test]
+#[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+fn segment_ends_are_context_free() {
+    use crate::lookup::{is_segment_end, width_step_in_generic, Step};
+
+    fn check<const IS_CJK: bool>(infos: &[WidthInfo]) {
+        for c in '\0'..'\u{20000}' {
+            if !is_segment_end::<IS_CJK>(c) {
+                continue;
+            }
+            let alone = width_step_in_generic::<IS_CJK>(c, WidthInfo::DEFAULT);
+            assert_eq!(alone.2, Step::Lookup, "{c:?}");
+            for &info in infos {
+                for info in [
+                    info,
+                    info.set_zwj_bit(),
+                    info.set_vs1_2_3(),
+                    info.set_emoji_presentation(),
+                    info.set_text_presentation(),
+                ] {
+                    let step = width_step_in_generic::<IS_CJK>(c, info);
+                    assert_eq!(step, alone, "{c:?} after {info:?}");
+                }
+            }
+        }
+    }
+
+    check::<false>(&NORMALIZATION_TEST_WIDTHS);
+    #[cfg(feature = "cjk")]
+    check::<true>(&NORMALIZATION_TEST_WIDTHS_CJK);
+}
+
+#[test]
+#[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+fn segment_boundaries_are_context_free() {
+    use crate::lookup::{is_segment_boundary, is_segment_end, width_step_in_generic, Step};
+
+    fn check<const IS_CJK: bool>(infos: &[WidthInfo]) {
+        for next in '\0'..='\u{7F}' {
+            // Every info that `next` can leave off with.
+            let mut next_infos = [WidthInfo::DEFAULT; 256];
+            let mut len = 0;
+            for &info in infos {
+                for info in [
+                    info,
+                    info.set_zwj_bit(),
+                    info.set_vs1_2_3(),
+                    info.set_emoji_presentation(),
+                    info.set_text_presentation(),
+                ] {
+                    let next_info = width_step_in_generic::<IS_CJK>(next, info).1;
+                    if !next_infos[..len].contains(&next_info) {
+                        next_infos[len] = next_info;
+                        len += 1;
+                    }
+                }
+            }
+            for c in '\0'..'\u{20000}' {
+                if is_segment_end::<IS_CJK>(c) || !is_segment_boundary::<IS_CJK>(c, next) {
+                    continue;
+                }
+                let alone = width_step_in_generic::<IS_CJK>(c, WidthInfo::DEFAULT);
+                assert_eq!(alone.2, Step::Lookup, "{c:?}");
+                for &next_info in &next_infos[..len] {
+                    let step = width_step_in_generic::<IS_CJK>(c, next_info);
+                    assert_eq!(step, alone, "{c:?} before {next:?}");
+                }
+            }
+        }
+    }
+
+    check::<false>(&NORMALIZATION_TEST_WIDTHS);
+    #[cfg(feature = "cjk")]
+    check::<true>(&NORMALIZATION_TEST_WIDTHS_CJK);
+}
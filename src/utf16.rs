@@ -0,0 +1,64 @@
+// Copyright 2012-2026 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/// Double-ended iterator over the `char`s of a UTF-16 slice.
+/// Unpaired surrogates are decoded as `'\u{FFFD}'`.
+///
+/// Unlike [`core::char::DecodeUtf16`], this can be walked backwards,
+/// which is the order in which widths are computed.
+#[derive(Clone, Debug)]
+pub(crate) struct Utf16Chars<'a>(pub(crate) &'a [u16]);
+
+const fn is_high_surrogate(u: u16) -> bool {
+    matches!(u, 0xD800..=0xDBFF)
+}
+
+const fn is_low_surrogate(u: u16) -> bool {
+    matches!(u, 0xDC00..=0xDFFF)
+}
+
+fn from_pair(high: u16, low: u16) -> char {
+    let cp = 0x10000 + ((u32::from(high) - 0xD800) << 10 | (u32::from(low) - 0xDC00));
+    char::from_u32(cp).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+impl Iterator for Utf16Chars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        match *self.0 {
+            [] => None,
+            [high, low, ref rest @ ..] if is_high_surrogate(high) && is_low_surrogate(low) => {
+                self.0 = rest;
+                Some(from_pair(high, low))
+            }
+            [u, ref rest @ ..] => {
+                self.0 = rest;
+                Some(char::from_u32(u32::from(u)).unwrap_or(char::REPLACEMENT_CHARACTER))
+            }
+        }
+    }
+}
+
+impl DoubleEndedIterator for Utf16Chars<'_> {
+    fn next_back(&mut self) -> Option<char> {
+        match *self.0 {
+            [] => None,
+            [ref rest @ .., high, low] if is_high_surrogate(high) && is_low_surrogate(low) => {
+                self.0 = rest;
+                Some(from_pair(high, low))
+            }
+            [ref rest @ .., u] => {
+                self.0 = rest;
+                Some(char::from_u32(u32::from(u)).unwrap_or(char::REPLACEMENT_CHARACTER))
+            }
+        }
+    }
+}
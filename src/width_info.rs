@@ -21,26 +21,29 @@ impl WidthInfo {
 
     /// Whether this width mode is ligature_transparent
     /// (has 5th MSB set.)
-    pub(crate) fn is_ligature_transparent(self) -> bool {
+    pub(crate) const fn is_ligature_transparent(self) -> bool {
         (self.0 & 0b0000_1000_0000_0000) == 0b0000_1000_0000_0000
     }
 
     /// Sets 6th MSB.
-    pub(crate) fn set_zwj_bit(self) -> Self {
+    pub(crate) const fn set_zwj_bit(self) -> Self {
         Self(self.0 | 0b0000_0100_0000_0000)
     }
 
     /// Has top bit set
-    pub(crate) fn is_emoji_presentation(self) -> bool {
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    pub(crate) const fn is_emoji_presentation(self) -> bool {
         (self.0 & WidthInfo::VARIATION_SELECTOR_16.0) == WidthInfo::VARIATION_SELECTOR_16.0
     }
 
-    pub(crate) fn is_zwj_emoji_presentation(self) -> bool {
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    pub(crate) const fn is_zwj_emoji_presentation(self) -> bool {
         (self.0 & 0b1011_0000_0000_0000) == 0b1001_0000_0000_0000
     }
 
     /// Set top bit
-    pub(crate) fn set_emoji_presentation(self) -> Self {
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    pub(crate) const fn set_emoji_presentation(self) -> Self {
         if (self.0 & LIGATURE_TRANSPARENT_MASK) == LIGATURE_TRANSPARENT_MASK
             || (self.0 & 0b1001_0000_0000_0000) == 0b0001_0000_0000_0000
         {
@@ -56,7 +59,8 @@ impl WidthInfo {
     }
 
     /// Clear top bit
-    pub(crate) fn unset_emoji_presentation(self) -> Self {
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    pub(crate) const fn unset_emoji_presentation(self) -> Self {
         if (self.0 & LIGATURE_TRANSPARENT_MASK) == LIGATURE_TRANSPARENT_MASK {
             Self(self.0 & !WidthInfo::VARIATION_SELECTOR_16.0)
         } else {
@@ -65,12 +69,14 @@ impl WidthInfo {
     }
 
     /// Has 2nd bit set
-    pub(crate) fn is_text_presentation(self) -> bool {
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    pub(crate) const fn is_text_presentation(self) -> bool {
         (self.0 & WidthInfo::VARIATION_SELECTOR_15.0) == WidthInfo::VARIATION_SELECTOR_15.0
     }
 
     /// Set 2nd bit
-    pub(crate) fn set_text_presentation(self) -> Self {
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    pub(crate) const fn set_text_presentation(self) -> Self {
         if (self.0 & LIGATURE_TRANSPARENT_MASK) == LIGATURE_TRANSPARENT_MASK {
             Self(
                 self.0
@@ -84,18 +90,19 @@ impl WidthInfo {
     }
 
     /// Clear 2nd bit
-    pub(crate) fn unset_text_presentation(self) -> Self {
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    pub(crate) const fn unset_text_presentation(self) -> Self {
         Self(self.0 & !WidthInfo::VARIATION_SELECTOR_15.0)
     }
 
     /// Has 7th bit set
-    pub(crate) fn is_vs1_2_3(self) -> bool {
+    pub(crate) const fn is_vs1_2_3(self) -> bool {
         (self.0 & WidthInfo::VARIATION_SELECTOR_1_2_OR_3.0)
             == WidthInfo::VARIATION_SELECTOR_1_2_OR_3.0
     }
 
     /// Set 7th bit
-    pub(crate) fn set_vs1_2_3(self) -> Self {
+    pub(crate) const fn set_vs1_2_3(self) -> Self {
         if (self.0 & LIGATURE_TRANSPARENT_MASK) == LIGATURE_TRANSPARENT_MASK {
             Self(
                 self.0
@@ -109,7 +116,7 @@ impl WidthInfo {
     }
 
     /// Clear 7th bit
-    pub(crate) fn unset_vs1_2_3(self) -> Self {
+    pub(crate) const fn unset_vs1_2_3(self) -> Self {
         Self(self.0 & !WidthInfo::VARIATION_SELECTOR_1_2_OR_3.0)
     }
 }
@@ -8,12 +8,28 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+#[cfg(any(
+    feature = "rgi-emoji",
+    not(any(feature = "bmp-only", feature = "no-emoji-sequences"))
+))]
 use std::{
     fs::File,
     io::{BufRead, BufReader},
 };
 
-use unicode_width::{char_iter_width, UnicodeWidthChar, UnicodeWidthStr};
+use std::fmt::Write;
+
+use unicode_width::{
+    char_iter_width, const_char_width, const_str_width, display_width, explain_char, explain_str,
+    is_width_context_dependent, width_bounds, width_simple, width_utf16, width_utf8_lossy, Padded,
+    Rule, SequenceWidth, UnicodeWidthChar, UnicodeWidthStr, WidthCounter, WidthOverrides,
+    WidthReason,
+};
+#[cfg(feature = "cjk")]
+use unicode_width::{
+    const_char_width_cjk, const_str_width_cjk, display_width_cjk, explain_char_cjk,
+    explain_str_cjk, width_simple_cjk, width_utf16_cjk, width_utf8_lossy_cjk,
+};
 
 macro_rules! assert_width {
     ($s:expr, $nocjk:expr, $cjk:expr $(,)?) => {{
@@ -32,12 +48,541 @@ fn test_str() {
 }
 
 #[test]
+#[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
 fn test_emoji() {
     assert_width!("👩", 2, 2); // Woman
     assert_width!("🔬", 2, 2); // Microscope
     assert_width!("👩‍🔬", 2, 2); // Woman scientist
 }
 
+#[test]
+fn test_width_simple() {
+    fn assert_width_simple(s: &str, nocjk: usize, _cjk: usize) {
+        assert_eq!(width_simple(s), nocjk, "{s:?} has the wrong simple width");
+        #[cfg(feature = "cjk")]
+        assert_eq!(
+            width_simple_cjk(s),
+            _cjk,
+            "{s:?} has the wrong simple width (CJK)"
+        );
+        assert_eq!(
+            width_simple(s),
+            s.chars().map(|c| c.width().unwrap_or(0)).sum::<usize>()
+        );
+    }
+
+    assert_width_simple("ｈｅｌｌｏ", 10, 10);
+    assert_width_simple("\0\r\n\x7F", 0, 0);
+    assert_width_simple("\u{2081}\u{2082}", 2, 4);
+    // No sequence rules apply.
+    assert_width_simple("\u{5D0}\u{200D}\u{5DC}", 2, 2);
+    assert_width_simple("\u{2764}\u{FE0F}", 1, 1);
+    #[cfg(not(feature = "bmp-only"))]
+    assert_width_simple("\u{1F469}\u{200D}\u{1F52C}", 4, 4);
+    #[cfg(not(feature = "bmp-only"))]
+    assert_width_simple("\u{1F1FA}\u{1F1F8}", 2, 2);
+}
+
+#[test]
+fn test_width_bounds() {
+    fn assert_bounds(s: &str, bounds: (usize, usize)) {
+        assert_eq!(width_bounds(s), bounds, "{s:?} has the wrong bounds");
+        let (min, max) = bounds;
+        assert!((min..=max).contains(&s.width()));
+        #[cfg(feature = "cjk")]
+        assert!((min..=max).contains(&s.width_cjk()));
+    }
+
+    assert_bounds("", (0, 0));
+    assert_bounds("hello, ｗｏｒｌｄ\r\n", (18, 18));
+    assert_bounds("e\u{301}", (1, 1));
+    // Alef-ZWJ-Lamed, with and without the ligature.
+    assert_bounds("\u{5D0}\u{200D}\u{5DC}", (1, 2));
+    assert_bounds("\u{5D0}\u{200D}\u{5DC}".repeat(10).as_str(), (10, 20));
+    #[cfg(feature = "cjk")]
+    assert_bounds("\u{2081}\u{2082}", (2, 4));
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    {
+        assert_bounds("\u{2764}\u{FE0F}", (1, 2));
+        assert_bounds("\u{1F469}\u{200D}\u{1F52C}", (2, 4));
+        // Split up, but with the heart's VS16 still honored.
+        assert_bounds("👩\u{200D}\u{2764}\u{FE0F}\u{200D}👨", (2, 6));
+        assert_bounds("\u{1F1FA}\u{1F1F8}", (2, 2));
+    }
+
+    assert!(!is_width_context_dependent('a'));
+    assert!(!is_width_context_dependent('\n'));
+    assert!(!is_width_context_dependent('ｈ'));
+    assert!(is_width_context_dependent('\u{200D}'));
+    assert!(is_width_context_dependent('\u{FE0F}'));
+    assert!(is_width_context_dependent('\u{5D0}'));
+    assert_eq!(
+        is_width_context_dependent('\u{2081}'),
+        cfg!(feature = "cjk")
+    );
+}
+
+#[test]
+fn test_fallback() {
+    use unicode_width::fallback::{
+        fallbacks, is_assigned, is_noncharacter, is_private_use, Fallback, Policy,
+    };
+
+    assert!(is_assigned('a'));
+    assert!(is_assigned('\u{FFFD}'));
+    assert!(is_assigned('\u{E000}'));
+    assert!(!is_assigned('\u{378}'));
+    assert!(!is_assigned('\u{FDD0}'));
+    #[cfg(not(feature = "bmp-only"))]
+    {
+        assert!(is_assigned('\u{1F600}'));
+        assert!(is_assigned('\u{20000}'));
+        assert!(!is_assigned('\u{2FFFD}'));
+        assert!(!is_assigned('\u{E0FFF}'));
+    }
+    for plane in 0..=16 {
+        let c = char::from_u32(plane << 16 | 0xFFFF).unwrap();
+        assert!(is_noncharacter(c) && !is_assigned(c), "{c:?}");
+    }
+    assert!(!is_noncharacter('\u{FFFD}'));
+    assert!(is_private_use('\u{F8FF}') && is_private_use('\u{10FFFD}'));
+    assert!(!is_private_use('\u{10FFFF}'));
+
+    assert_eq!(Fallback::of('a'), None);
+    assert_eq!(Fallback::of('\u{378}'), Some(Fallback::Unassigned));
+    assert_eq!(Fallback::of('\u{FFFE}'), Some(Fallback::Noncharacter));
+    assert_eq!(Fallback::of('\u{F0000}'), Some(Fallback::PrivateUse));
+
+    let s = "\u{E000}\u{301}x\u{FDD0}\u{378}";
+    assert_eq!(
+        fallbacks(s).collect::<Vec<_>>(),
+        [
+            (0, '\u{E000}', Fallback::PrivateUse),
+            (6, '\u{FDD0}', Fallback::Noncharacter),
+            (9, '\u{378}', Fallback::Unassigned),
+        ]
+    );
+
+    assert_eq!(Policy::DEFAULT.str_width(s), s.width());
+    assert_eq!(Policy::DEFAULT.char_width('\u{E000}'), '\u{E000}'.width());
+    let policy = Policy {
+        unassigned: Some(2),
+        #[cfg(feature = "cjk")]
+        unassigned_cjk: Some(0),
+        noncharacter: Some(0),
+        private_use: Some(2),
+        #[cfg(feature = "cjk")]
+        private_use_cjk: Some(1),
+    };
+    assert_eq!(policy.char_width('\u{378}'), Some(2));
+    assert_eq!(policy.char_width('a'), Some(1));
+    assert_eq!(policy.str_width(s), 5);
+    assert_eq!(policy.str_width(&s.repeat(20)), 5 * 20);
+    assert_eq!(policy.width_of(Fallback::PrivateUse), Some(2));
+    #[cfg(feature = "cjk")]
+    {
+        assert_eq!(policy.width_of_cjk(Fallback::PrivateUse), Some(1));
+        assert_eq!(policy.width_of_cjk(Fallback::Noncharacter), Some(0));
+
+        // Each mode has its own widths for private-use and unassigned code points.
+        assert_eq!(policy.char_width_cjk('\u{378}'), Some(0));
+        assert_eq!(policy.char_width_cjk('\u{E000}'), Some(1));
+        assert_eq!(policy.char_width_cjk('\u{FDD0}'), Some(0));
+        assert_eq!(policy.str_width_cjk(s), 1 + 1);
+
+        // A width for one mode leaves the tables in charge of the other.
+        assert_eq!('\u{E000}'.width_cjk(), Some(2));
+        let policy = Policy {
+            private_use: Some(1),
+            ..Policy::DEFAULT
+        };
+        assert_eq!(policy.char_width('\u{E000}'), Some(1));
+        assert_eq!(policy.char_width_cjk('\u{E000}'), Some(2));
+        assert_eq!(policy.str_width_cjk(s), 2 + 1 + 1 + 1);
+    }
+}
+
+#[test]
+fn test_width_overrides() {
+    const OVERRIDES: WidthOverrides = WidthOverrides::new(&[
+        ('\u{5DC}', '\u{5DC}', 2),
+        ('\u{2190}', '\u{21FF}', 2),
+        ('\u{E000}', '\u{F8FF}', 2),
+        ('\u{1F52C}', '\u{1F52C}', 1),
+    ]);
+    assert_eq!(OVERRIDES.get('\u{2190}'), Some(2));
+    assert_eq!(OVERRIDES.get('\u{2200}'), None);
+    assert_eq!(OVERRIDES.char_width('\u{E123}'), Some(2));
+    assert_eq!(OVERRIDES.char_width('a'), Some(1));
+    assert_eq!(OVERRIDES.char_width('\0'), None);
+
+    assert_eq!(OVERRIDES.str_width("\u{2192} x\u{E000}\u{301}"), 2 + 2 + 2);
+    assert_eq!(OVERRIDES.str_width("\u{5DC}"), 2);
+    // The Alef-ZWJ-Lamed ligature still has width 1.
+    assert_eq!(OVERRIDES.str_width("\u{5D0}\u{200D}\u{5DC}"), 1);
+    assert_eq!(OVERRIDES.str_width("\u{5D0}\u{5DC}"), 3);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    {
+        assert_eq!(OVERRIDES.str_width("\u{1F52C}"), 1);
+        assert_eq!(OVERRIDES.str_width("\u{1F469}\u{200D}\u{1F52C}"), 2);
+        assert_eq!(OVERRIDES.str_width("\u{2194}\u{FE0F}\u{2194}"), 4);
+    }
+    #[cfg(feature = "cjk")]
+    assert_eq!(OVERRIDES.str_width_cjk("\u{2192}\u{2081}"), 4);
+
+    let empty = WidthOverrides::new(&[]);
+    assert_eq!(empty.str_width("\u{2192}"), "\u{2192}".width());
+
+    // A flag keeps its width of 2, while a regional indicator on its own is overridden,
+    // even the one left over at the end of an odd run of them.
+    let regional_indicators = WidthOverrides::new(&[('\u{1F1E6}', '\u{1F1FF}', 2)]);
+    assert_eq!(regional_indicators.str_width("🇺"), 2);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    {
+        assert_eq!(regional_indicators.str_width("🇺🇸"), 2);
+        assert_eq!(regional_indicators.str_width("🇺🇸🇫🇷"), 2 + 2);
+        assert_eq!(regional_indicators.str_width("🇺🇸🇫"), 2 + 2);
+        assert_eq!(regional_indicators.str_width("a🇺🇸🇫🇷🇯"), 1 + 2 + 2 + 2);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_width_overrides_unsorted() {
+    WidthOverrides::new(&[('b', 'c', 2), ('a', 'b', 2)]);
+}
+
+#[test]
+fn test_sequence_overrides() {
+    const OVERRIDES: WidthOverrides = WidthOverrides::new(&[('\u{E000}', '\u{E000}', 2)])
+        .with_sequences(&[
+            ("ab", SequenceWidth::Fixed(5)),
+            ("abc", SequenceWidth::Fixed(1)),
+            ("\u{5D0}\u{200D}\u{5DC}", SequenceWidth::Sum),
+            ("x\u{E000}", SequenceWidth::Sum),
+            ("<=>", SequenceWidth::Fixed(2)),
+        ]);
+    // The longest match wins.
+    assert_eq!(OVERRIDES.str_width("abcd"), 2);
+    assert_eq!(OVERRIDES.str_width("abd"), 6);
+    assert_eq!(OVERRIDES.str_width("ab".repeat(3).as_str()), 15);
+    assert_eq!(OVERRIDES.str_width("x\u{E000}"), 3);
+    assert_eq!(OVERRIDES.str_width("<=><=>="), 5);
+    // Instead of the built-in ligature.
+    assert_eq!(OVERRIDES.str_width("\u{5D0}\u{200D}\u{5DC}"), 2);
+    assert_eq!(OVERRIDES.str_width("\u{5D0}\u{200D}\u{5DC}\u{301}"), 2);
+    #[cfg(feature = "cjk")]
+    assert_eq!(OVERRIDES.str_width_cjk("\u{2081}abc"), 3);
+
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    {
+        const FAMILY: WidthOverrides = WidthOverrides::new(&[])
+            .with_sequences(&[("\u{1F468}\u{200D}\u{1F469}", SequenceWidth::Sum)]);
+        assert_eq!(FAMILY.str_width("\u{1F468}\u{200D}\u{1F469}"), 4);
+        // The rest of the longer sequence is measured on its own.
+        assert_eq!(
+            FAMILY.str_width("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"),
+            6
+        );
+        assert_eq!(FAMILY.str_width("\u{1F469}\u{200D}\u{1F467}"), 2);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_sequence_overrides_empty() {
+    WidthOverrides::new(&[]).with_sequences(&[("", SequenceWidth::Sum)]);
+}
+
+#[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+#[test]
+fn test_emoji_support() {
+    use unicode_width::EmojiSupport;
+
+    let all = EmojiSupport::ALL;
+    let strings = [
+        "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+        "\u{1F468}\u{1F3FD}\u{200D}\u{1F680}",
+        "\u{1F1FA}\u{1F1F8}",
+        "\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}",
+        "#\u{FE0F}\u{20E3}",
+        "\u{2764}\u{FE0F}\u{200D}\u{1F525}",
+        "\u{231A}\u{FE0E}",
+        "\u{05D0}\u{200D}\u{05DC}",
+    ];
+    let cases = [
+        (EmojiSupport::ALL, [2, 2, 2, 2, 2, 2, 1, 1]),
+        (all.with_zwj_sequences(false), [6, 4, 2, 2, 2, 4, 1, 1]),
+        (all.with_modifier_sequences(false), [2, 6, 2, 2, 2, 2, 1, 1]),
+        (all.with_flags(false), [2, 2, 4, 2, 2, 2, 1, 1]),
+        (all.with_tag_sequences(false), [2, 2, 2, 2, 2, 2, 1, 1]),
+        (all.with_keycaps(false), [2, 2, 2, 2, 1, 2, 1, 1]),
+        (all.with_emoji_presentation(false), [2, 2, 2, 2, 1, 3, 1, 1]),
+        (all.with_text_presentation(false), [2, 2, 2, 2, 2, 2, 2, 1]),
+        (EmojiSupport::NONE, [6, 6, 4, 2, 1, 3, 2, 1]),
+    ];
+    for (support, widths) in cases {
+        for (s, width) in strings.iter().zip(widths) {
+            assert_eq!(support.str_width(s), width, "{s:?} with {support:?}");
+        }
+    }
+    for s in strings {
+        assert_eq!(EmojiSupport::ALL.str_width(s), s.width());
+    }
+    let mut support = EmojiSupport::NONE
+        .with_zwj_sequences(true)
+        .with_modifier_sequences(true)
+        .with_flags(true)
+        .with_tag_sequences(true)
+        .with_keycaps(true)
+        .with_emoji_presentation(true);
+    support.text_presentation = true;
+    assert_eq!(support, EmojiSupport::ALL);
+
+    #[cfg(feature = "cjk")]
+    {
+        assert_eq!("\u{231A}\u{FE0E}".width_cjk(), 2);
+        assert_eq!(EmojiSupport::ALL.str_width_cjk("\u{231A}\u{FE0E}"), 1);
+        assert_eq!(EmojiSupport::NONE.str_width_cjk("\u{231A}\u{FE0E}"), 2);
+        assert_eq!(EmojiSupport::NONE.str_width_cjk("\u{1F44D}\u{1F3FD}"), 4);
+    }
+}
+
+#[cfg(feature = "cjk")]
+#[test]
+fn test_ambiguous_policy() {
+    use unicode_width::ambiguous::{Block, Policy};
+
+    assert_eq!(Block::of('─'), Some(Block::BoxDrawing));
+    assert_eq!(Block::of('▒'), Some(Block::BlockElements));
+    assert_eq!(Block::of('\u{387}'), Some(Block::Greek));
+    #[cfg(not(feature = "bmp-only"))]
+    assert_eq!(Block::of('\u{1F100}'), Some(Block::EnclosedAlphanumerics));
+    assert_eq!(Block::of('\u{E000}'), Some(Block::Other));
+    assert_eq!(Block::of('α'), None);
+    assert_eq!(Block::of('Ж'), None);
+    assert_eq!(Block::of('a'), None);
+    assert_eq!(Block::of('日'), None);
+    for c in ('\0'..='\u{FFFF}').chain(['\u{1F100}', '\u{1F1AC}', '\u{1F1AD}']) {
+        let ambiguous = c.width() == Some(1) && c.width_cjk() == Some(2);
+        assert_eq!(Block::of(c).is_some(), ambiguous, "{c:?}");
+    }
+
+    let policy = Policy::ALL.narrow(Block::BoxDrawing);
+    assert!(!policy.is_wide(Block::BoxDrawing));
+    assert!(policy.is_wide(Block::Arrows));
+    assert_eq!(policy.widen(Block::BoxDrawing), Policy::ALL);
+    assert_eq!(policy.char_width('─'), Some(1));
+    assert_eq!(policy.char_width('→'), Some(2));
+    assert_eq!(policy.char_width('\0'), None);
+
+    let s = "┌─§─┐ ★ ①";
+    assert_eq!(Policy::ALL.str_width(s), s.width_cjk());
+    assert_eq!(policy.str_width(s), 12);
+    assert_eq!(Policy::NONE.str_width(s), s.width());
+    assert_eq!(Policy::NONE.widen(Block::Latin1Supplement).str_width(s), 10);
+
+    // Sequences keep their widths.
+    assert_eq!(Policy::NONE.str_width("<\u{338}"), 2);
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    assert_eq!(Policy::NONE.str_width("\u{2640}\u{FE0F}"), 2);
+}
+
+#[test]
+fn test_presets() {
+    use unicode_width::presets::{self, Preset};
+
+    // Octicons branch, Powerline separator, Material Design folder, Pomicons
+    let s = "\u{F418}\u{E0B0}\u{F024B}\u{E000}";
+    assert_eq!(s.width(), 4);
+    assert_eq!(presets::POWERLINE.str_width(s), 4);
+    assert_eq!(presets::NERD_FONTS_MONO.str_width(s), 4);
+    assert_eq!(presets::NERD_FONTS.str_width(s), 7);
+    assert_eq!(presets::NERD_FONTS.get('\u{E0D7}'), Some(1));
+    assert_eq!(presets::NERD_FONTS.get('\u{E0D8}'), None);
+    #[cfg(feature = "cjk")]
+    {
+        assert_eq!(s.width_cjk(), 8);
+        assert_eq!(presets::POWERLINE.str_width_cjk(s), 7);
+        assert_eq!(presets::NERD_FONTS_MONO.str_width_cjk(s), 4);
+    }
+
+    // The Mono and regular presets cover the same glyphs, and agree on the Powerline ones.
+    for c in ('\u{E000}'..='\u{F8FF}').chain('\u{F0000}'..='\u{10FFFD}') {
+        let mono = presets::NERD_FONTS_MONO.get(c);
+        assert_eq!(mono.is_some(), presets::NERD_FONTS.get(c).is_some());
+        assert!(mono.is_none() || mono == Some(1));
+        if let Some(width) = presets::POWERLINE.get(c) {
+            assert_eq!(presets::NERD_FONTS.get(c), Some(width));
+        }
+    }
+
+    for preset in Preset::ALL {
+        assert_eq!(Preset::from_name(preset.name()), Some(preset));
+    }
+    assert_eq!(
+        Preset::from_name("nerd-fonts").unwrap().overrides(),
+        presets::NERD_FONTS
+    );
+    assert_eq!(Preset::from_name("Nerd Fonts"), None);
+}
+
+#[cfg(feature = "config")]
+#[test]
+fn test_width_overrides_buf() {
+    use unicode_width::WidthOverridesBuf;
+
+    let mut overrides = WidthOverridesBuf::new();
+    overrides.insert('a', 'z', 2);
+    overrides.insert('k', 'm', 0);
+    overrides.insert('\u{D7FF}', '\u{E000}', 2);
+    overrides.insert('x', '\u{D7FF}', 1);
+    assert_eq!(
+        overrides.ranges(),
+        [
+            ('a', 'j', 2),
+            ('k', 'm', 0),
+            ('n', 'w', 2),
+            ('x', '\u{D7FF}', 1),
+            ('\u{E000}', '\u{E000}', 2),
+        ]
+    );
+    assert_eq!(overrides.as_overrides().str_width("akz\u{E000}"), 5);
+}
+
+#[cfg(feature = "config")]
+#[test]
+fn test_config_parsers() {
+    use unicode_width::config::{
+        parse_east_asian_width, parse_vim_cellwidths, parse_wezterm_cell_widths,
+    };
+
+    let eaw = parse_east_asian_width(
+        "# EastAsianWidth-17.0.0.txt\n\
+         \n\
+         2190..2199;A     # Sm     [5] LEFTWARDS ARROW..DOWNWARDS ARROW\n\
+         E0B0 ; W\n\
+         2192;Na\n",
+        2,
+    )
+    .unwrap();
+    assert_eq!(
+        eaw.ranges(),
+        [
+            ('\u{2190}', '\u{2191}', 2),
+            ('\u{2192}', '\u{2192}', 1),
+            ('\u{2193}', '\u{2199}', 2),
+            ('\u{E0B0}', '\u{E0B0}', 2),
+        ]
+    );
+
+    let error = |result: Result<_, unicode_width::config::ParseError>| {
+        let error = result.unwrap_err();
+        (error.line(), error.message().to_owned())
+    };
+    assert_eq!(
+        error(parse_east_asian_width("\n\n2190..2199", 1)),
+        (
+            3,
+            "expected `;` after the code points in `2190..2199`".into()
+        )
+    );
+    assert_eq!(
+        error(parse_east_asian_width("2199..2190;W", 1)),
+        (1, "the range `2199..2190` is empty".into())
+    );
+    assert_eq!(
+        error(parse_east_asian_width("D800;W", 1)),
+        (1, "`D800` is not a Unicode scalar value (U+D800)".into())
+    );
+    assert_eq!(
+        error(parse_east_asian_width("2190;Wide", 1)),
+        (
+            1,
+            "`Wide` is not an East_Asian_Width (expected W, F, Na, H, N, or A)".into()
+        )
+    );
+
+    let vim = parse_vim_cellwidths(
+        "\" Nerd Font icons\n\
+         call setcellwidths([\n\
+         \\ [0xe000, 0xf8ff, 2],\n\
+         \\ [8592, 8601, 2],\n\
+         \\ ])\n",
+    )
+    .unwrap();
+    assert_eq!(
+        vim.ranges(),
+        [('\u{2190}', '\u{2199}', 2), ('\u{E000}', '\u{F8FF}', 2)]
+    );
+    assert_eq!(
+        error(parse_vim_cellwidths("[\n[0xe000, 0xf8ff 2]]")),
+        (2, "expected `,`, found `2`".into())
+    );
+    assert_eq!(
+        error(parse_vim_cellwidths("[[0xe000, 0xf8ff, 2]")),
+        (1, "expected `,` or `]`, found the end".into())
+    );
+    assert_eq!(
+        error(parse_vim_cellwidths("[[0xf8ff, 0xe000, 2]]")),
+        (1, "the range is empty".into())
+    );
+    assert_eq!(
+        error(parse_vim_cellwidths("[[0x110000, 0x110000, 2]]")),
+        (
+            1,
+            "`0x110000` is not a Unicode scalar value (U+110000)".into()
+        )
+    );
+    assert_eq!(
+        error(parse_vim_cellwidths("[[0xe0g0, 0xe0ff, 2]]")),
+        (1, "`0xe0g0` is not a valid number".into())
+    );
+    assert_eq!(
+        error(parse_vim_cellwidths("[[0xe000, 0xe0ff, 2]] x")),
+        (1, "expected the end, found `x`".into())
+    );
+
+    let wezterm = parse_wezterm_cell_widths(
+        "config.cell_widths = {\n\
+         \x20 -- Powerline\n\
+         \x20 { first = 0xE0A0, last = 0xE0D4, width = 2 },\n\
+         \x20 { width = 0; first = 0x00AD };\n\
+         }\n",
+    )
+    .unwrap();
+    assert_eq!(
+        wezterm.ranges(),
+        [('\u{AD}', '\u{AD}', 0), ('\u{E0A0}', '\u{E0D4}', 2)]
+    );
+    assert_eq!(
+        error(parse_wezterm_cell_widths("{\n{ first = 0xE0A0 },\n}")),
+        (2, "the entry has no `width`".into())
+    );
+    assert_eq!(
+        error(parse_wezterm_cell_widths(
+            "{ { first = 1, first = 2, width = 1 } }"
+        )),
+        (1, "`first` is given twice".into())
+    );
+    assert_eq!(
+        error(parse_wezterm_cell_widths(
+            "{\n\n{ first = 0xE0A0, width = 3 } }"
+        )),
+        (3, "the width `3` is not 0, 1, or 2".into())
+    );
+    assert_eq!(
+        error(parse_wezterm_cell_widths("config.font_size = {}")),
+        (1, "expected an assignment to `cell_widths`".into())
+    );
+    assert_eq!(
+        error(parse_wezterm_cell_widths(
+            "{ { first = 0xE0A0, width = 2 } "
+        )),
+        (1, "expected `,` or `}`, found the end".into())
+    );
+}
+
 #[test]
 fn test_halfwidth_katakana() {
     assert_width!("ﾊﾟｸﾞ", 4, 4); // Halfwidth Katakana letters Pa, Gu (pug dog)
@@ -73,6 +618,7 @@ fn test_char2() {
 }
 
 #[test]
+#[cfg(not(feature = "bmp-only"))]
 fn unicode_12() {
     assert_width!('\u{1F971}', Some(2), Some(2));
 }
@@ -128,6 +674,7 @@ fn test_prepended_concatenation_marks() {
 #[test]
 fn test_gcb_prepend() {
     assert_width!("ൎഉ", 1, 1);
+    #[cfg(not(feature = "bmp-only"))]
     assert_width!("\u{11A89}", 0, 0);
 }
 
@@ -170,6 +717,7 @@ fn test_solidus_overlay() {
     assert_width!("=\u{301}\u{338}", 1, 2);
     assert_width!("=\u{338}\u{301}", 1, 2);
     assert_width!("=\u{FE0F}\u{338}", 1, 2);
+    #[cfg(not(feature = "no-emoji-sequences"))]
     assert_width!("#\u{FE0F}\u{338}", 2, 2);
     assert_width!("#\u{338}\u{FE0F}", 1, 1);
 
@@ -183,6 +731,7 @@ fn test_solidus_overlay() {
 }
 
 #[test]
+#[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
 fn test_emoji_presentation() {
     assert_width!('\u{0023}', Some(1), Some(1));
     assert_width!('\u{FE0F}', Some(0), Some(0));
@@ -202,6 +751,7 @@ fn test_emoji_presentation() {
 }
 
 #[test]
+#[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
 fn test_text_presentation() {
     assert_width!('\u{FE0E}', Some(0), Some(0));
     assert_width!('\u{2648}', Some(2), Some(2));
@@ -394,6 +944,7 @@ fn test_tifinagh_biconsonants() {
 }
 
 #[test]
+#[cfg(not(feature = "bmp-only"))]
 fn test_old_turkic_ligature() {
     assert_width!("\u{10C32}", 1, 1);
     assert_width!("\u{10C03}", 1, 1);
@@ -449,6 +1000,7 @@ fn test_khmer_sign_beyyal() {
 }
 
 #[test]
+#[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
 fn test_emoji_modifier() {
     assert_width!("\u{1F46A}", 2, 2);
     assert_width!("\u{1F3FB}", 2, 2);
@@ -457,6 +1009,7 @@ fn test_emoji_modifier() {
 }
 
 #[test]
+#[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
 fn test_emoji_zwj() {
     assert_width!("🧑‍🤝‍🧑", 2, 2);
 
@@ -572,6 +1125,7 @@ fn test_emoji_zwj() {
 }
 
 #[test]
+#[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
 fn emoji_test_file() {
     let norm_file = BufReader::new(
         File::open("tests/emoji-test.txt")
@@ -597,6 +1151,83 @@ fn emoji_test_file() {
     }
 }
 
+#[test]
+#[cfg(feature = "rgi-emoji")]
+fn rgi_emoji_test_file() {
+    use unicode_width::{is_rgi_emoji, width_rgi};
+
+    let emoji_file = BufReader::new(
+        File::open("tests/emoji-test.txt")
+            .expect("run `unicode.py` first to download `emoji-test.txt`"),
+    );
+    let mut rgi_count = 0;
+    for line in emoji_file.lines() {
+        let line = line.unwrap();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (cps, status) = line.split_once(';').unwrap();
+        let status = status.split('#').next().unwrap().trim();
+        let emoji: String = cps
+            .trim()
+            .split(' ')
+            .map(|s| char::try_from(u32::from_str_radix(s, 16).unwrap()).unwrap())
+            .collect();
+        let is_rgi = matches!(status, "fully-qualified" | "component");
+        assert_eq!(is_rgi_emoji(&emoji), is_rgi, "{emoji:?} is {status}");
+        if is_rgi {
+            rgi_count += 1;
+            #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+            assert_eq!(width_rgi(&emoji), 2, "{emoji:?}");
+        }
+    }
+    assert!(rgi_count > 3000);
+    assert!(!is_rgi_emoji(""));
+    assert!(!is_rgi_emoji("a"));
+    assert!(!is_rgi_emoji("\u{1F1FA}"));
+    assert_eq!(width_rgi("a\u{200D}b"), 2);
+}
+
+#[test]
+#[cfg(all(
+    feature = "rgi-emoji",
+    not(any(feature = "bmp-only", feature = "no-emoji-sequences"))
+))]
+fn test_width_rgi() {
+    use unicode_width::width_rgi;
+
+    // Chained families and flags, which are well-formed but not RGI
+    let s = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}";
+    assert_eq!(s.width(), 2);
+    assert_eq!(width_rgi(s), 4);
+    let s = "\u{1F1FA}\u{1F1F8}\u{200D}\u{1F1EC}\u{1F1E7}";
+    assert_eq!(s.width(), 2);
+    assert_eq!(width_rgi(s), 4);
+    // The longest RGI run at the front wins: kiss, then a fire
+    let s = "\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{200D}\u{1F525}";
+    assert_eq!(width_rgi(s), 4);
+    // A modifier sequence, then a heart on fire
+    let s = "\u{1F44D}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F525}";
+    assert_eq!(s.width(), 2);
+    assert_eq!(width_rgi(s), 4);
+    // Parts that aren't RGI on their own are measured one by one
+    let s = "\u{1F44D}\u{1F3FD}\u{200D}\u{1F525}\u{200D}\u{1F525}";
+    assert_eq!(s.width(), 2);
+    assert_eq!(width_rgi(s), 6);
+    // Only the ZWJ sequences change
+    let s = "abc \u{1F44D}\u{1F3FD} #\u{FE0F}\u{20E3} \u{1F468}\u{200D}\u{1F692} x";
+    assert_eq!(width_rgi(s), s.width());
+    #[cfg(feature = "cjk")]
+    {
+        use unicode_width::width_rgi_cjk;
+
+        let s = "\u{2605}\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F525}";
+        assert_eq!(width_rgi_cjk(s), 6);
+        assert_eq!(width_rgi(s), 5);
+    }
+}
+
 #[test]
 fn ambiguous_line_break() {
     assert_width!("\u{24EA}", 1, 2);
@@ -657,10 +1288,775 @@ fn test_vs1_vs2_vs3() {
 }
 
 #[test]
+#[cfg(not(feature = "bmp-only"))]
 fn test_char_iter() {
     assert_eq!(char_iter_width(['a', 'b', '🔬'].into_iter()), 4)
 }
 
+#[test]
+fn test_const_width() {
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    const WIDTHS: [usize; 4] = [
+        const_str_width("Ｈｅｌｌｏ, ｗｏｒｌｄ!"),
+        const_str_width("\u{0644}\u{0627}"),
+        const_str_width("👩‍🔬\r\n"),
+        const_str_width(""),
+    ];
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    assert_eq!(WIDTHS, [23, 1, 3, 0]);
+    const KHMER: Option<usize> = const_char_width('\u{17D8}');
+    assert_eq!(KHMER, Some(3));
+    #[cfg(all(feature = "cjk", not(feature = "bmp-only")))]
+    {
+        const AMBIGUOUS: usize = const_str_width_cjk("“𘀀”");
+        assert_eq!(AMBIGUOUS, 6);
+    }
+
+    let mut s = String::with_capacity(4);
+    for c in '\0'..=char::MAX {
+        s.clear();
+        s.push(c);
+        assert_eq!(const_char_width(c), c.width());
+        assert_eq!(const_str_width(&s), s.width());
+        #[cfg(feature = "cjk")]
+        {
+            assert_eq!(const_char_width_cjk(c), c.width_cjk());
+            assert_eq!(const_str_width_cjk(&s), s.width_cjk());
+        }
+    }
+
+    let emoji_file = std::fs::read_to_string("tests/emoji-test.txt")
+        .expect("run `unicode.py` first to download `emoji-test.txt`");
+    for line in emoji_file.lines() {
+        assert_eq!(const_str_width(line), line.width());
+        #[cfg(feature = "cjk")]
+        assert_eq!(const_str_width_cjk(line), line.width_cjk());
+    }
+}
+
+#[test]
+fn test_width_utf8_lossy() {
+    assert_eq!(width_utf8_lossy(b""), (0, 0));
+    assert_eq!(width_utf8_lossy("ｈｉ".as_bytes()), (4, 0));
+    // Maximal subparts: F0 9F is one replacement character, 80 is another.
+    assert_eq!(width_utf8_lossy(b"\xF0\x9F\x80a"), (2, 0));
+    assert_eq!(width_utf8_lossy(b"\xF0\x9Fa\x80"), (3, 0));
+    assert_eq!(width_utf8_lossy(b"\xC0\xAF"), (2, 0));
+    assert_eq!(width_utf8_lossy(b"\xED\xA0\x80"), (3, 0));
+    // Incomplete trailing sequences are held back.
+    assert_eq!(width_utf8_lossy(b"\xF0\x9F\x94"), (0, 3));
+    assert_eq!(width_utf8_lossy(b"\r\xF0"), (1, 1));
+    assert_eq!(width_utf8_lossy(b"\xFF\xE2\x80"), (1, 2));
+    // Replacement characters interrupt sequences.
+    assert_eq!(width_utf8_lossy(b"\r\xFF\n"), (3, 0));
+    assert_eq!(width_utf8_lossy("\u{0644}\u{0627}".as_bytes()), (1, 0));
+    assert_eq!(width_utf8_lossy(b"\xD9\x84\xFF\xD8\xA7"), (3, 0));
+
+    // Compare against `String::from_utf8_lossy` on corrupted copies of the emoji test file.
+    let emoji_file = std::fs::read_to_string("tests/emoji-test.txt")
+        .expect("run `unicode.py` first to download `emoji-test.txt`");
+    let mut seed = 0x2545_F491_u32;
+    for line in emoji_file.lines() {
+        let mut bytes = line.as_bytes().to_vec();
+        for _ in 0..3 {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            if !bytes.is_empty() {
+                let i = (seed >> 8) as usize % bytes.len();
+                bytes[i] = (seed >> 24) as u8;
+            }
+        }
+        bytes.truncate(bytes.len() - (seed as usize % 4).min(bytes.len()));
+        let (width, incomplete) = width_utf8_lossy(&bytes);
+        let complete = &bytes[..bytes.len() - incomplete];
+        assert!(incomplete < 4);
+        assert_eq!(
+            width,
+            String::from_utf8_lossy(complete).width(),
+            "{bytes:X?}"
+        );
+        #[cfg(feature = "cjk")]
+        assert_eq!(
+            width_utf8_lossy_cjk(&bytes),
+            (String::from_utf8_lossy(complete).width_cjk(), incomplete),
+            "{bytes:X?}"
+        );
+    }
+}
+
+#[test]
+fn test_width_utf16() {
+    let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
+    assert_eq!(width_utf16(&[]), 0);
+    assert_eq!(width_utf16(&utf16("Ｈｅｌｌｏ, ｗｏｒｌｄ!")), 23);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    assert_eq!(width_utf16(&utf16("👩‍🔬")), 2);
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    assert_eq!(width_utf16(&utf16("🇦🇦\u{200D}🇦🇦")), 2);
+    // Unpaired surrogates are replacement characters, which interrupt sequences.
+    assert_eq!(width_utf16(&[0xD83D]), 1);
+    assert_eq!(width_utf16(&[0xDC4B, 0xD83D]), 2);
+    #[cfg(not(feature = "bmp-only"))]
+    assert_eq!(width_utf16(&[0xD83D, 0xD83D, 0xDC4B]), 3);
+    assert_eq!(width_utf16(&[0x0644, 0xDC00, 0x0627]), 3);
+    #[cfg(feature = "cjk")]
+    assert_eq!(width_utf16_cjk(&[0x201C, 0xD800, 0x201D]), 6);
+
+    let emoji_file = std::fs::read_to_string("tests/emoji-test.txt")
+        .expect("run `unicode.py` first to download `emoji-test.txt`");
+    for line in emoji_file.lines() {
+        let mut units = utf16(line);
+        assert_eq!(width_utf16(&units), line.width());
+        #[cfg(feature = "cjk")]
+        assert_eq!(width_utf16_cjk(&units), line.width_cjk());
+
+        // Split a surrogate pair, if there is one.
+        if let Some(i) = units.iter().position(|&u| (0xD800..0xDC00).contains(&u)) {
+            units.insert(i + 1, u16::from(b'x'));
+            let lossy = String::from_utf16_lossy(&units);
+            assert_eq!(width_utf16(&units), lossy.width(), "{lossy:?}");
+            #[cfg(feature = "cjk")]
+            assert_eq!(width_utf16_cjk(&units), lossy.width_cjk(), "{lossy:?}");
+        }
+    }
+}
+
+#[test]
+fn test_width_counter() {
+    fn check(s: &str) {
+        assert_eq!(display_width(s), s.width(), "{s:?}");
+        #[cfg(feature = "cjk")]
+        assert_eq!(display_width_cjk(s), s.width_cjk(), "{s:?} (CJK)");
+
+        // Writing the string in two pieces, or a char at a time, doesn't change its width.
+        for (i, _) in s.char_indices() {
+            let mut counter = WidthCounter::new();
+            counter.write_str(&s[..i]).unwrap();
+            counter.write_str(&s[i..]).unwrap();
+            assert_eq!(counter.width(), s.width(), "{:?} + {:?}", &s[..i], &s[i..]);
+        }
+        let mut counter = WidthCounter::new();
+        for c in s.chars() {
+            counter.write_char(c).unwrap();
+        }
+        assert_eq!(counter.width(), s.width(), "{s:?} a char at a time");
+        #[cfg(feature = "cjk")]
+        {
+            let mut counter = WidthCounter::new_cjk();
+            for c in s.chars() {
+                counter.write_char(c).unwrap();
+            }
+            assert_eq!(
+                counter.width(),
+                s.width_cjk(),
+                "{s:?} a char at a time (CJK)"
+            );
+        }
+    }
+
+    check("");
+    check("hello, world");
+    check("ｈｅｌｌｏ\r\nｗｏｒｌｄ\n");
+    check("a\u{5D0}\u{200D}\u{5DC}b");
+    check("\u{644}\u{651}\u{64E}\u{627}");
+    check("\u{1780}\u{17D2}\u{1780}");
+    check("=\u{301}\u{338}");
+    check("1\u{FE0F}\u{20E3}2\u{20E3}#\u{FE0F}");
+    check("\u{2764}\u{FE0E} \u{2764}\u{FE0F}");
+    check("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}");
+    check("\u{1F1FA}\u{1F1F8}\u{1F1FA}\u{1F1F8}\u{1F1FA}");
+    check("\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}");
+    check(&"日本語のテキスト、".repeat(20));
+    check(&"\u{1780}\u{17D2}\u{1780}".repeat(40));
+
+    assert_eq!(display_width(&12345), 5);
+    // Padding counts `char`s, not columns.
+    assert_eq!(display_width(&format_args!("{:>8}|", "ｘ")), 10);
+}
+
+#[test]
+fn test_padded() {
+    assert_eq!(format!("[{}]", Padded::new("ｈｉ")), "[ｈｉ]");
+    assert_eq!(format!("[{:6}]", Padded::new("ｈｉ")), "[ｈｉ  ]");
+    assert_eq!(format!("[{:>6}]", Padded::new("ｈｉ")), "[  ｈｉ]");
+    assert_eq!(format!("[{:^7}]", Padded::new("ｈｉ")), "[ ｈｉ  ]");
+    assert_eq!(format!("[{:3}]", Padded::new("ｈｉ")), "[ｈｉ]");
+    assert_eq!(format!("[{:>1$}]", Padded::new("ｈｉ"), 5), "[ ｈｉ]");
+    // Wide fill characters count as two columns.
+    assert_eq!(format!("[{:＊<7}]", Padded::new("ｈｉ")), "[ｈｉ＊ ]");
+
+    // Truncation never splits a sequence.
+    assert_eq!(format!("[{:.3}]", Padded::new("ｈｉ")), "[ｈ]");
+    assert_eq!(format!("[{:.0}]", Padded::new("ｈｉ")), "[]");
+    assert_eq!(
+        format!("[{:4.3}]", Padded::new("ab\u{5D0}\u{200D}\u{5DC}")),
+        "[ab\u{5D0}\u{200D}\u{5DC} ]"
+    );
+    assert_eq!(
+        format!("[{:4.2}]", Padded::new("ab\u{5D0}\u{200D}\u{5DC}")),
+        "[ab  ]"
+    );
+    assert_eq!(
+        format!("[{:.2}]", Padded::new("e\u{301}e\u{301}e\u{301}")),
+        "[e\u{301}e\u{301}]"
+    );
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    assert_eq!(format!("[{:.3}]", Padded::new("👩‍🔬👩‍🔬")), "[👩‍🔬]");
+    // A run of flags is cut between two of them.
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    {
+        assert_eq!(format!("[{:.3}]", Padded::new("🇺🇸🇫🇷")), "[🇺🇸]");
+        assert_eq!(format!("[{:4.5}]", Padded::new("🇺🇸🇫🇷🇯🇵")), "[🇺🇸🇫🇷]");
+    }
+
+    #[cfg(feature = "cjk")]
+    {
+        assert_eq!(format!("[{:4}]", Padded::new("\u{2081}")), "[\u{2081}   ]");
+        assert_eq!(
+            format!("[{:4}]", Padded::new_cjk("\u{2081}")),
+            "[\u{2081}  ]"
+        );
+        assert_eq!(format!("[{:.1}]", Padded::new_cjk("\u{2081}")), "[]");
+    }
+}
+
+#[test]
+fn test_table() {
+    use unicode_width::table::{format_markdown, Align, Borders, Column, DynTable, Table};
+
+    let rows = [
+        ["ｉｄ", "name"],
+        ["1", "日本語\nにほんご"],
+        ["22", "e\u{301}"],
+    ];
+    let mut table = Table::new();
+    let mut out = String::new();
+    table.write(&mut out, &rows).unwrap();
+    assert_eq!(
+        out,
+        "ｉｄ  name\n1     日本語\n      にほんご\n22    e\u{301}\n"
+    );
+
+    table.borders = Borders::BOX;
+    table.columns = [
+        Column {
+            align: Align::Center,
+            min_width: 6,
+            max_width: None,
+        },
+        Column {
+            align: Align::Right,
+            min_width: 0,
+            max_width: Some(5),
+        },
+    ];
+    assert_eq!(table.column_widths(&rows), [6, 5]);
+    out.clear();
+    table.write(&mut out, &rows).unwrap();
+    assert_eq!(
+        out,
+        "\
+┌────────┬───────┐
+│  ｉｄ  │  name │
+├────────┼───────┤
+│   1    │  日本 │
+│        │  にほ │
+│   22   │     e\u{301} │
+└────────┴───────┘
+"
+    );
+
+    // A table whose number of columns is only known at run time lays out the same way.
+    let dyn_rows: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+        .collect();
+    let mut dyn_table = DynTable::new(&table.columns);
+    dyn_table.borders = table.borders;
+    let mut dyn_out = String::new();
+    dyn_table.write(&mut dyn_out, &dyn_rows).unwrap();
+    assert_eq!(dyn_out, out);
+
+    // Nothing is written for no rows, except for the borders.
+    out.clear();
+    Table::<3>::new().write(&mut out, &[]).unwrap();
+    assert_eq!(out, "");
+
+    let markdown = "\
+Intro | text
+
+| a | b\\|c |
+|:-:|---|
+| 日本語 |
+|  | x | extra |
+not a row
+
+```
+| a | b |
+|---|---|
+```
+";
+    out.clear();
+    format_markdown(&mut out, markdown).unwrap();
+    assert_eq!(
+        out,
+        "\
+Intro | text
+
+|   a    | b\\|c |
+| :----: | ---- |
+| 日本語 |      |
+|        | x    | extra |
+not a row
+
+```
+| a | b |
+|---|---|
+```
+"
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_dyn_table_ragged() {
+    use unicode_width::table::{Column, DynTable};
+
+    let rows: [&[&str]; 2] = [&["a", "b"], &["c"]];
+    let _ = DynTable::new(&[Column::DEFAULT; 2]).write(&mut String::new(), &rows);
+}
+
+#[test]
+fn test_columnize() {
+    use unicode_width::table::{columnize, Order};
+
+    let items = ["ｆｉｌｅ", "b", "日本語", "dd", "e", "ff", "g"];
+    let grid = columnize(&items, 80, Order::ColumnMajor);
+    assert_eq!((grid.columns, grid.rows), (7, 1));
+    assert_eq!(grid.width(&items), 8 + 1 + 6 + 2 + 1 + 2 + 1 + 2 * 6);
+
+    let grid = columnize(&items, 24, Order::ColumnMajor);
+    assert_eq!((grid.columns, grid.rows), (4, 2));
+    assert_eq!((grid.index(1, 2), grid.index(1, 3)), (Some(5), None));
+    let mut out = String::new();
+    grid.write(&mut out, &items).unwrap();
+    assert_eq!(out, "ｆｉｌｅ  日本語  e   g\nb         dd      ff\n");
+    assert!(out.lines().all(|line| line.width() <= 24));
+
+    let grid = columnize(&items, 20, Order::RowMajor);
+    assert_eq!((grid.columns, grid.rows), (3, 3));
+    assert_eq!(grid.column_width(&items, 0), 8);
+    out.clear();
+    grid.write(&mut out, &items).unwrap();
+    assert_eq!(out, "ｆｉｌｅ  b  日本語\ndd        e  ff\ng\n");
+
+    // Items wider than the line get a column of their own.
+    let grid = columnize(&items, 4, Order::ColumnMajor);
+    assert_eq!((grid.columns, grid.rows), (1, 7));
+
+    let grid = columnize::<&str>(&[], 80, Order::RowMajor);
+    assert_eq!((grid.columns, grid.rows), (0, 0));
+    out.clear();
+    grid.write::<_, &str>(&mut out, &[]).unwrap();
+    assert_eq!(out, "");
+
+    // Each item is measured once per write, however many rows there are.
+    let items: Vec<_> = (0..20_000).map(|i| "日".repeat(i % 7)).collect();
+    let grid = columnize(&items, 80, Order::ColumnMajor);
+    assert_eq!((grid.columns, grid.rows), (5, 4000));
+    out.clear();
+    grid.write(&mut out, &items).unwrap();
+    assert_eq!(out.lines().count(), grid.rows);
+    assert!(out.lines().all(|line| line.width() <= 80));
+    // The first row ends with the item of five "日" at index 16000.
+    assert_eq!(out.lines().next().map(str::width), Some(4 * (12 + 2) + 10));
+
+    // However wide the line, there are at most 256 columns.
+    let grid = columnize(&["a"; 1000], 10_000, Order::RowMajor);
+    assert_eq!((grid.columns, grid.rows), (256, 4));
+}
+
+#[test]
+fn test_terminal_screen() {
+    use std::fmt::Write;
+    use unicode_width::TerminalScreen;
+
+    let mut screen = TerminalScreen::new(4);
+    write!(screen, "abcd").unwrap();
+    assert_eq!(screen.cursor(), (0, 3));
+    assert!(screen.is_wrap_pending());
+    assert_eq!(screen.rows(), 1);
+    // A combining mark doesn't trigger the pending wrap.
+    write!(screen, "\u{301}").unwrap();
+    assert_eq!(screen.cursor(), (0, 3));
+    write!(screen, "e").unwrap();
+    assert_eq!((screen.cursor(), screen.is_wrap_pending()), ((1, 1), false));
+    // The wide character doesn't fit in the last column.
+    write!(screen, "fg日").unwrap();
+    assert_eq!(screen.cursor(), (2, 2));
+    write!(screen, "本").unwrap();
+    assert_eq!((screen.cursor(), screen.is_wrap_pending()), ((2, 3), true));
+    // The Hebrew Alef-ZWJ-Lamed ligature takes up a single cell.
+    write!(screen, "\r\u{5D0}\u{200D}\u{5DC}").unwrap();
+    assert_eq!((screen.cursor(), screen.is_wrap_pending()), ((2, 1), false));
+    writeln!(screen, "\u{8}\u{8}\tx").unwrap();
+    assert_eq!(screen.cursor(), (3, 0));
+    assert_eq!(screen.rows(), 4);
+
+    // Escape sequences are skipped, even when split across writes.
+    let mut screen = TerminalScreen::new(80);
+    write!(screen, "\x1b[1;31mred\x1b[").unwrap();
+    write!(screen, "0m \x1b]0;title 日本\x07\x1b]8;;url\x1b\\link").unwrap();
+    assert_eq!(screen.cursor(), (0, 8));
+    write!(screen, "\ta\tb\r\n").unwrap();
+    assert_eq!(screen.cursor(), (1, 0));
+
+    // Characters wider than the screen run on into the next row.
+    let mut screen = TerminalScreen::new(1);
+    write!(screen, "日").unwrap();
+    assert_eq!((screen.cursor(), screen.is_wrap_pending()), ((1, 0), true));
+    write!(screen, "a").unwrap();
+    assert_eq!((screen.cursor(), screen.rows()), ((2, 0), 3));
+
+    // A tab cancels a pending wrap, leaving the cursor in the last column.
+    let mut screen = TerminalScreen::new(10);
+    write!(screen, "0123456789").unwrap();
+    assert_eq!((screen.cursor(), screen.is_wrap_pending()), ((0, 9), true));
+    write!(screen, "\t").unwrap();
+    assert_eq!((screen.cursor(), screen.is_wrap_pending()), ((0, 9), false));
+    write!(screen, "a").unwrap();
+    assert_eq!((screen.cursor(), screen.is_wrap_pending()), ((0, 9), true));
+
+    // Each flag in a run of them is placed on its own, so the run wraps between flags,
+    // and a flag that would be cut off by the end of the row goes on the next one.
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    {
+        let mut screen = TerminalScreen::new(10);
+        write!(screen, "{}", "🇺🇸".repeat(10)).unwrap();
+        assert_eq!((screen.cursor(), screen.rows()), ((1, 9), 2));
+        let mut screen = TerminalScreen::new(5);
+        write!(screen, "{}", "🇺🇸".repeat(3)).unwrap();
+        assert_eq!((screen.cursor(), screen.rows()), ((1, 2), 2));
+    }
+}
+
+#[test]
+fn test_cells() {
+    use unicode_width::{cells, Cell};
+
+    let all: Vec<_> = cells("\u{301}a\u{301}\u{302}日\u{5D0}\u{200D}\u{5DC}").collect();
+    assert_eq!(
+        all,
+        [
+            Cell::Start {
+                text: "\u{301}",
+                width: 0
+            },
+            Cell::Start {
+                text: "a\u{301}\u{302}",
+                width: 1
+            },
+            Cell::Start {
+                text: "日",
+                width: 2
+            },
+            Cell::Continuation,
+            Cell::Start {
+                text: "\u{5D0}\u{200D}\u{5DC}",
+                width: 1
+            },
+        ]
+    );
+    assert_eq!(cells("").next(), None);
+
+    // The cells line up with the width of long strings, split up to be measured.
+    let s = "ｘ\u{301}y".repeat(40);
+    assert_eq!(cells(&s).count(), s.width());
+    let text: String = cells(&s)
+        .filter_map(|cell| match cell {
+            Cell::Start { text, .. } => Some(text),
+            Cell::Continuation => None,
+        })
+        .collect();
+    assert_eq!(text, s);
+
+    // A long run without any character that ends a segment on its own
+    // is still split up as it goes, rather than rescanned for every few cells.
+    let s = "#*0123456789".repeat(20_000) + "1\u{FE0F}\u{20E3}";
+    assert_eq!(cells(&s).count(), s.width());
+}
+
+#[test]
+#[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+fn test_cells_emoji() {
+    use unicode_width::{cells, Cell};
+
+    let all: Vec<_> = cells("👩\u{200D}🔬\u{2764}\u{FE0F}!").collect();
+    assert_eq!(
+        all,
+        [
+            Cell::Start {
+                text: "👩\u{200D}🔬",
+                width: 2
+            },
+            Cell::Continuation,
+            Cell::Start {
+                text: "\u{2764}\u{FE0F}",
+                width: 2
+            },
+            Cell::Continuation,
+            Cell::Start {
+                text: "!",
+                width: 1
+            },
+        ]
+    );
+
+    // Each flag in a run of them gets a cell of its own.
+    let all: Vec<_> = cells("🇺🇸🇫🇷").collect();
+    assert_eq!(
+        all,
+        [
+            Cell::Start {
+                text: "🇺🇸",
+                width: 2
+            },
+            Cell::Continuation,
+            Cell::Start {
+                text: "🇫🇷",
+                width: 2
+            },
+            Cell::Continuation,
+        ]
+    );
+    let s = "🇺🇸".repeat(1000) + "🇫";
+    assert_eq!(cells(&s).count(), s.width());
+    assert_eq!(
+        cells(&s)
+            .filter(|cell| matches!(cell, Cell::Start { width: 2, .. }))
+            .count(),
+        1000
+    );
+}
+
+#[test]
+fn ascii_runs_consistent() {
+    let pieces = [
+        "",
+        "a",
+        "0123456789abcdef",
+        "\r\n",
+        "\n",
+        "\r",
+        "\t",
+        "\x7F",
+        "#\u{FE0F}",
+        "1\u{FE0F}\u{20E3}",
+        "=\u{338}",
+        "<\u{301}\u{338}",
+        "é",
+        "日本",
+        "\u{0644}\u{0627}",
+        "🇦🇦\u{200D}🇦🇦",
+    ];
+    let mut s = String::new();
+    for a in pieces {
+        for b in pieces {
+            for pad in 0..17 {
+                s.clear();
+                s.push_str(a);
+                s.extend(std::iter::repeat('x').take(pad));
+                s.push_str(b);
+                assert_eq!(s.width(), char_iter_width(s.chars()), "{s:?}");
+                #[cfg(feature = "cjk")]
+                assert_eq!(
+                    s.width_cjk(),
+                    unicode_width::char_iter_width_cjk(s.chars()),
+                    "{s:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_explain_char() {
+    let reason = |width, rule| WidthReason { width, rule };
+    assert_eq!(explain_char('a'), reason(Some(1), Rule::Narrow));
+    assert_eq!(explain_char('\0'), reason(None, Rule::Control));
+    assert_eq!(explain_char('\u{301}'), reason(Some(0), Rule::ZeroWidth));
+    assert_eq!(explain_char('ｈ'), reason(Some(2), Rule::Wide));
+    assert_eq!(
+        explain_char('\u{FF9E}'),
+        reason(Some(1), Rule::NarrowSpecialCase)
+    );
+    assert_eq!(
+        explain_char('\u{115F}'),
+        reason(Some(2), Rule::WideSpecialCase)
+    );
+    assert_eq!(
+        explain_char('\u{17D8}'),
+        reason(Some(3), Rule::KhmerSignBeyyal)
+    );
+    #[cfg(feature = "cjk")]
+    {
+        assert_eq!(explain_char('\u{B7}'), reason(Some(1), Rule::Ambiguous));
+        assert_eq!(explain_char_cjk('\u{B7}'), reason(Some(2), Rule::Ambiguous));
+    }
+
+    for c in '\0'..=char::MAX {
+        assert_eq!(explain_char(c).width, c.width());
+        #[cfg(feature = "cjk")]
+        assert_eq!(explain_char_cjk(c).width, c.width_cjk());
+    }
+}
+
+#[test]
+fn test_explain_str() {
+    let segments = |s| explain_str(s).collect::<Vec<_>>();
+    assert_eq!(segments(""), []);
+    assert_eq!(segments("\r\n"), [(0..2, 1, Rule::CarriageReturnLineFeed)]);
+    assert_eq!(
+        segments("e\u{301}"),
+        [(0..1, 1, Rule::Narrow), (1..3, 0, Rule::ZeroWidth)]
+    );
+    #[cfg(not(feature = "no-emoji-sequences"))]
+    assert_eq!(
+        segments("#\u{FE0F}"),
+        [(0..4, 2, Rule::EmojiPresentationSequence)]
+    );
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    assert_eq!(
+        segments("👩‍🔬!"),
+        [
+            (0..11, 2, Rule::EmojiZwjSequence),
+            (11..12, 1, Rule::Narrow)
+        ]
+    );
+    assert_eq!(
+        segments("ល្ង"),
+        [(0..3, 1, Rule::Narrow), (3..9, 0, Rule::KhmerCoeng)]
+    );
+    assert_eq!(
+        segments("\u{2D4F}\u{2D7F}\u{2D3E}"),
+        [(0..9, 1, Rule::TifinaghBiconsonant)]
+    );
+    assert_eq!(
+        segments("\u{0644}\u{065F}\u{0627}x"),
+        [(0..6, 1, Rule::ArabicLamAlef), (6..7, 1, Rule::Narrow)]
+    );
+    #[cfg(feature = "cjk")]
+    assert_eq!(
+        explain_str_cjk("=\u{301}\u{338}").collect::<Vec<_>>(),
+        [(0..5, 2, Rule::SolidusOverlay)]
+    );
+}
+
+#[test]
+fn explain_str_adds_up() {
+    let emoji_file = std::fs::read_to_string("tests/emoji-test.txt")
+        .expect("run `unicode.py` first to download `emoji-test.txt`");
+    let strings = [
+        "🇦🇦\u{200D}🇦🇦🇦🇦\u{200D}🇦🇦",
+        "🏴\u{E0031}\u{E0031}\u{E0031}\u{E0063}\u{E007F}\u{200D}Ⓜ️",
+        "\u{1A15}\u{17B5}\u{200D}\u{FE0E}\u{1A17}\u{200D}\u{FE0F}\u{200D}\u{FE0F}",
+        "\u{06B8}\u{338}\u{FE0F}\u{0627}",
+        "1\t2\r\n3\u{85}4",
+        emoji_file.as_str(),
+    ];
+    for s in strings {
+        let mut start = 0;
+        let mut sum = 0;
+        for (range, width, _) in explain_str(s) {
+            assert_eq!(range.start, start);
+            start = range.end;
+            sum += width;
+        }
+        assert_eq!(start, s.len());
+        assert_eq!(sum, s.width(), "{s:?}");
+        #[cfg(feature = "cjk")]
+        assert_eq!(
+            explain_str_cjk(s).map(|(_, width, _)| width).sum::<usize>(),
+            s.width_cjk(),
+            "{s:?}"
+        );
+    }
+}
+
+#[test]
+fn explain_str_long_piece() {
+    // Long runs that no boundary splits, which take quadratic time to explain
+    // if each batch of segments measures the rest of the run again.
+    for unit in ["\u{0627}\u{064B}", "ល្ង", "\u{0644}\u{0627}", "🇺🇸"] {
+        let long = unit.repeat(40_000);
+        let short = unit.repeat(40);
+        let segments: Vec<_> = explain_str(&long).collect();
+        let short_segments: Vec<_> = explain_str(&short).collect();
+        // Away from the ends, the segments repeat along with the string.
+        let offset = long.len() - short.len();
+        let (front, back) = short_segments.split_at(short_segments.len() / 2);
+        assert_eq!(segments[..front.len()], *front, "{unit:?}");
+        for (segment, (range, width, rule)) in
+            segments[segments.len() - back.len()..].iter().zip(back)
+        {
+            assert_eq!(
+                *segment,
+                (range.start + offset..range.end + offset, *width, *rule)
+            );
+        }
+        let mut start = 0;
+        for (range, ..) in &segments {
+            assert_eq!(range.start, start);
+            start = range.end;
+        }
+        assert_eq!(start, long.len());
+        assert_eq!(
+            segments.iter().map(|(_, width, _)| width).sum::<usize>(),
+            long.width()
+        );
+    }
+}
+
+#[test]
+fn explain_str_flags() {
+    #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+    assert_eq!(
+        explain_str("🇺🇸🇫🇷🇯").collect::<Vec<_>>(),
+        [
+            (0..8, 2, Rule::EmojiFlagSequence),
+            (8..16, 2, Rule::EmojiFlagSequence),
+            (16..20, 1, Rule::Narrow)
+        ]
+    );
+    // The regional indicators in a run pair up from the front, whatever comes around it.
+    for count in 1..8 {
+        for before in ["", "a", "\u{200D}", "👩\u{200D}", "\u{0644}"] {
+            for after in ["", "a", "\u{200D}", "\u{200D}👩", "\u{FE0F}", "\u{1F3FB}"] {
+                let s = format!("{before}{}{after}", "🇺".repeat(count));
+                #[cfg(not(any(feature = "bmp-only", feature = "no-emoji-sequences")))]
+                if before.len() <= 1 && after.len() <= 1 {
+                    let flags = explain_str(&s)
+                        .filter(|(_, _, rule)| *rule == Rule::EmojiFlagSequence)
+                        .count();
+                    assert_eq!(flags, count / 2, "{s:?}");
+                }
+                assert_eq!(
+                    explain_str(&s).map(|(_, width, _)| width).sum::<usize>(),
+                    s.width(),
+                    "{s:?}"
+                );
+                #[cfg(feature = "cjk")]
+                assert_eq!(
+                    explain_str_cjk(&s)
+                        .map(|(_, width, _)| width)
+                        .sum::<usize>(),
+                    s.width_cjk(),
+                    "{s:?}"
+                );
+            }
+        }
+    }
+}
+
 // Test traits are unsealed
 
 #[cfg(feature = "cjk")]